@@ -31,6 +31,8 @@ pub const TEST_CONTRACT_CLASS_HASH: &str = "0x117";
 pub const TEST_ENTRY_POINT_SELECTOR: &str = "TEST_CONTRACT_SELECTOR";
 // snforge_std/src/cheatcodes.cairo::test_address
 pub const TEST_ADDRESS: &str = "0x01724987234973219347210837402";
+// L1 address passed as `from_address` to tests marked with `#[as_l1_handler]`
+pub const TEST_L1_HANDLER_FROM_ADDRESS: &str = "0x0123456789abcdef0123456789abcdef01234567";
 
 fn contract_class_no_entrypoints() -> ContractClass {
     let raw_contract_class = indoc!(
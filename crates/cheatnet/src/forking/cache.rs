@@ -14,13 +14,17 @@ use std::collections::HashMap;
 use std::fs;
 use std::fs::OpenOptions;
 use std::io::{Read, Write};
+use std::sync::OnceLock;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use url::Url;
 
-pub const CACHE_VERSION: usize = 3;
+pub const CACHE_VERSION: usize = 4;
 
 #[derive(Serialize, Deserialize, Debug)]
 struct ForkCacheContent {
     cache_version: usize,
+    // milliseconds since the UNIX epoch
+    created_at: u64,
     storage_at: HashMap<ContractAddress, HashMap<StorageKey, Felt252>>,
     nonce_at: HashMap<ContractAddress, Nonce>,
     class_hash_at: HashMap<ContractAddress, ClassHash>,
@@ -32,6 +36,7 @@ impl Default for ForkCacheContent {
     fn default() -> Self {
         Self {
             cache_version: CACHE_VERSION,
+            created_at: now_millis(),
             storage_at: Default::default(),
             nonce_at: Default::default(),
             class_hash_at: Default::default(),
@@ -54,6 +59,11 @@ impl ForkCacheContent {
         cache
     }
 
+    fn is_expired(&self, cache_ttl: Duration, run_started_at: u64) -> bool {
+        let cache_ttl = u64::try_from(cache_ttl.as_millis()).unwrap_or(u64::MAX);
+        self.created_at.saturating_add(cache_ttl) < run_started_at
+    }
+
     fn extend(&mut self, other: &Self) {
         // storage_at
         for (other_contract_address, other_storage) in &other.storage_at {
@@ -99,7 +109,10 @@ impl ForkCache {
         url: &Url,
         block_number: BlockNumber,
         cache_dir: &Utf8Path,
+        cache_ttl: Option<Duration>,
     ) -> Result<Self> {
+        // Cache created during the current run never expires in it, so TTL equal to 0 invalidates it once per run
+        let run_started_at = run_started_at();
         let cache_file = cache_file_path_from_fork_config(url, block_number, cache_dir)?;
         let mut file = OpenOptions::new()
             .write(true)
@@ -109,17 +122,30 @@ impl ForkCache {
             .open(&cache_file)
             .context("Could not open cache file")?;
 
+        file.lock_exclusive()
+            .context("Could not lock on cache file")?;
+
         let mut cache_file_content = String::new();
         file.read_to_string(&mut cache_file_content)
             .context("Could not read cache file")?;
 
         // File was just created
-        let fork_cache_content = if cache_file_content.is_empty() {
+        let mut fork_cache_content = if cache_file_content.is_empty() {
             ForkCacheContent::default()
         } else {
             ForkCacheContent::from_str(cache_file_content.as_str())
         };
 
+        if cache_ttl
+            .is_some_and(|cache_ttl| fork_cache_content.is_expired(cache_ttl, run_started_at))
+        {
+            file.set_len(0)
+                .context("Could not invalidate expired cache file")?;
+            fork_cache_content = ForkCacheContent::default();
+        }
+
+        file.unlock().context("Could not unlock cache file")?;
+
         Ok(ForkCache {
             fork_cache_content,
             cache_file,
@@ -237,6 +263,18 @@ impl ForkCache {
     }
 }
 
+fn now_millis() -> u64 {
+    let since_epoch = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("System time is before the UNIX epoch");
+    u64::try_from(since_epoch.as_millis()).expect("Timestamp does not fit in u64")
+}
+
+fn run_started_at() -> u64 {
+    static RUN_STARTED_AT: OnceLock<u64> = OnceLock::new();
+    *RUN_STARTED_AT.get_or_init(now_millis)
+}
+
 fn cache_file_path_from_fork_config(
     url: &Url,
     BlockNumber(block_number): BlockNumber,
@@ -15,6 +15,7 @@ use conversions::{FromConv, IntoConv};
 use flate2::read::GzDecoder;
 use num_bigint::BigUint;
 use runtime::starknet::context::SerializableGasPrices;
+use shared::rpc::{retry_with_backoff, RetryConfig};
 use starknet::core::types::{
     BlockId, ContractClass as ContractClassStarknet, Felt, MaybePendingBlockWithTxHashes,
     StarknetError,
@@ -30,7 +31,9 @@ use starknet_api::deprecated_contract_class::{
 use starknet_api::state::StorageKey;
 use std::cell::RefCell;
 use std::collections::HashMap;
+use std::future::Future;
 use std::io::Read;
+use std::time::Duration;
 use tokio::runtime::Runtime;
 use universal_sierra_compiler_api::{compile_sierra, SierraType};
 use url::Url;
@@ -41,23 +44,31 @@ pub struct ForkStateReader {
     block_number: BlockNumber,
     runtime: Runtime,
     cache: RefCell<ForkCache>,
+    retry_config: RetryConfig,
 }
 
 impl ForkStateReader {
-    pub fn new(url: Url, block_number: BlockNumber, cache_dir: &Utf8Path) -> Result<Self> {
+    pub fn new(
+        url: Url,
+        block_number: BlockNumber,
+        cache_dir: &Utf8Path,
+        cache_ttl: Option<Duration>,
+        retry_config: RetryConfig,
+    ) -> Result<Self> {
         Ok(ForkStateReader {
             cache: RefCell::new(
-                ForkCache::load_or_new(&url, block_number, cache_dir)
+                ForkCache::load_or_new(&url, block_number, cache_dir, cache_ttl)
                     .context("Could not create fork cache")?,
             ),
             client: JsonRpcClient::new(HttpTransport::new(url)),
             block_number,
             runtime: Runtime::new().expect("Could not instantiate Runtime"),
+            retry_config,
         })
     }
 
     pub fn chain_id(&self) -> Result<ChainId> {
-        let id = self.runtime.block_on(self.client.chain_id())?;
+        let id = self.block_on_with_retries(|| self.client.chain_id())?;
         let id = parse_cairo_short_string(&id)?;
         Ok(ChainId::from(id))
     }
@@ -65,6 +76,15 @@ impl ForkStateReader {
     fn block_id(&self) -> BlockId {
         BlockId::Number(self.block_number.0)
     }
+
+    fn block_on_with_retries<F, Fut, T>(&self, call: F) -> Result<T, ProviderError>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, ProviderError>>,
+    {
+        self.runtime
+            .block_on(retry_with_backoff(self.retry_config, call))
+    }
 }
 
 #[allow(clippy::needless_pass_by_value)]
@@ -86,10 +106,7 @@ impl BlockInfoReader for ForkStateReader {
             return Ok(cache_hit);
         }
 
-        match self
-            .runtime
-            .block_on(self.client.get_block_with_tx_hashes(self.block_id()))
-        {
+        match self.block_on_with_retries(|| self.client.get_block_with_tx_hashes(self.block_id())) {
             Ok(MaybePendingBlockWithTxHashes::Block(block)) => {
                 let block_info = BlockInfo {
                     block_number: BlockNumber(block.block_number),
@@ -126,11 +143,13 @@ impl StateReader for ForkStateReader {
             return Ok(cache_hit);
         }
 
-        match self.runtime.block_on(self.client.get_storage_at(
-            Felt::from_(contract_address),
-            Felt::from_(*key.0.key()),
-            self.block_id(),
-        )) {
+        match self.block_on_with_retries(|| {
+            self.client.get_storage_at(
+                Felt::from_(contract_address),
+                Felt::from_(*key.0.key()),
+                self.block_id(),
+            )
+        }) {
             Ok(value) => {
                 let value_sf = value.into_();
                 self.cache.borrow_mut()
@@ -150,10 +169,10 @@ impl StateReader for ForkStateReader {
             return Ok(cache_hit);
         }
 
-        match self.runtime.block_on(
+        match self.block_on_with_retries(|| {
             self.client
-                .get_nonce(self.block_id(), Felt::from_(contract_address)),
-        ) {
+                .get_nonce(self.block_id(), Felt::from_(contract_address))
+        }) {
             Ok(nonce) => {
                 let nonce = nonce.into_();
                 self.cache
@@ -176,10 +195,10 @@ impl StateReader for ForkStateReader {
             return Ok(cache_hit);
         }
 
-        match self.runtime.block_on(
+        match self.block_on_with_retries(|| {
             self.client
-                .get_class_hash_at(self.block_id(), Felt::from_(contract_address)),
-        ) {
+                .get_class_hash_at(self.block_id(), Felt::from_(contract_address))
+        }) {
             Ok(class_hash) => {
                 let class_hash = class_hash.into_();
                 self.cache
@@ -207,10 +226,10 @@ impl StateReader for ForkStateReader {
             if let Some(cache_hit) = cache.get_compiled_contract_class(&class_hash) {
                 Ok(cache_hit)
             } else {
-                match self.runtime.block_on(
+                match self.block_on_with_retries(|| {
                     self.client
-                        .get_class(self.block_id(), Felt::from_(class_hash)),
-                ) {
+                        .get_class(self.block_id(), Felt::from_(class_hash))
+                }) {
                     Ok(contract_class) => {
                         Ok(cache.insert_compiled_contract_class(class_hash, contract_class))
                     }
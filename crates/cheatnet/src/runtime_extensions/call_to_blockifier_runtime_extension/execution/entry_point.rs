@@ -66,6 +66,13 @@ pub fn execute_call_entry_point(
     if let Some(cheat_status) = get_mocked_function_cheat_status(entry_point, cheatnet_state) {
         if let CheatStatus::Cheated(ret_data, _) = (*cheat_status).clone() {
             cheat_status.decrement_cheat_span();
+            *cheatnet_state
+                .mocked_calls_counts
+                .entry((
+                    entry_point.storage_address,
+                    entry_point.entry_point_selector,
+                ))
+                .or_default() += 1;
             let ret_data_f252: Vec<Felt252> = ret_data
                 .iter()
                 .map(|datum| Felt252::from_(*datum))
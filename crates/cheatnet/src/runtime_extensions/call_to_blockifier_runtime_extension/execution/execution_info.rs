@@ -164,6 +164,10 @@ pub fn get_cheated_exec_info_ptr(
         );
     }
 
+    if let Some(contract_address) = cheated_data.contract_address {
+        new_exec_info[3] = MaybeRelocatable::Int(contract_address.into_());
+    }
+
     vm.load_data(ptr_cheated_exec_info, &new_exec_info).unwrap();
 
     ptr_cheated_exec_info
@@ -91,6 +91,24 @@ impl<'a> DeprecatedExtensionLogic for DeprecatedCheatableStarknetRuntimeExtensio
                     Ok(SyscallHandlingResult::Forwarded)
                 }
             }
+            DeprecatedSyscallSelector::GetContractAddress => {
+                if let Some(cheated_contract_address) = self
+                    .cheatnet_state
+                    .get_cheated_contract_address(contract_address)
+                {
+                    syscall_handler.syscall_ptr += 1;
+                    increment_syscall_count(syscall_handler, selector);
+
+                    let response = GetContractAddressResponse {
+                        address: cheated_contract_address,
+                    };
+
+                    response.write(vm, &mut syscall_handler.syscall_ptr)?;
+                    Ok(SyscallHandlingResult::Handled)
+                } else {
+                    Ok(SyscallHandlingResult::Forwarded)
+                }
+            }
             DeprecatedSyscallSelector::GetBlockNumber => {
                 if let Some(block_number) = self
                     .cheatnet_state
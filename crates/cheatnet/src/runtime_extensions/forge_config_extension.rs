@@ -29,10 +29,14 @@ impl<'a> ExtensionLogic for ForgeConfigExtension<'a> {
 
         match selector {
             "set_config_fork" => config_cheatcode!(fork),
+            "set_config_parallel_fork" => config_cheatcode!(parallel_fork),
             "set_config_available_gas" => config_cheatcode!(available_gas),
             "set_config_ignore" => config_cheatcode!(ignore),
             "set_config_should_panic" => config_cheatcode!(should_panic),
             "set_config_fuzzer" => config_cheatcode!(fuzzer),
+            "set_config_tag" => config_cheatcode!(tag),
+            "set_config_l1_handler" => config_cheatcode!(l1_handler),
+            "set_config_timeout" => config_cheatcode!(timeout),
             "is_config_mode" => Ok(CheatcodeHandlingResult::from_serializable(true)),
             _ => Ok(CheatcodeHandlingResult::Forwarded),
         }
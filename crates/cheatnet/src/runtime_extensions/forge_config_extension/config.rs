@@ -12,9 +12,15 @@ pub struct RawAvailableGasConfig {
 
 // fork
 
+#[derive(Debug, Clone, Copy, CairoDeserialize, PartialEq)]
+pub enum BlockTag {
+    Latest,
+    Pending,
+}
+
 #[derive(Debug, Clone, CairoDeserialize, PartialEq)]
 pub enum BlockId {
-    BlockTag,
+    BlockTag(BlockTag),
     BlockHash(Felt252),
     BlockNumber(u64),
 }
@@ -68,13 +74,38 @@ pub struct RawIgnoreConfig {
     pub is_ignored: bool,
 }
 
+// tag
+
+#[derive(Debug, Clone, CairoDeserialize)]
+pub struct RawTagConfig {
+    pub tags: Vec<ByteArray>,
+}
+
+// l1_handler
+
+#[derive(Debug, Clone, CairoDeserialize)]
+pub struct RawL1HandlerConfig {
+    pub is_l1_handler: bool,
+}
+
+// timeout
+
+#[derive(Debug, Clone, CairoDeserialize)]
+pub struct RawTimeoutConfig {
+    pub ms: u64,
+}
+
 // config
 
 #[derive(Debug, Default, Clone)]
 pub struct RawForgeConfig {
     pub fork: Option<RawForkConfig>,
+    pub parallel_fork: Option<RawForkConfig>,
     pub available_gas: Option<RawAvailableGasConfig>,
     pub ignore: Option<RawIgnoreConfig>,
     pub should_panic: Option<RawShouldPanicConfig>,
     pub fuzzer: Option<RawFuzzerConfig>,
+    pub tag: Option<RawTagConfig>,
+    pub l1_handler: Option<RawL1HandlerConfig>,
+    pub timeout: Option<RawTimeoutConfig>,
 }
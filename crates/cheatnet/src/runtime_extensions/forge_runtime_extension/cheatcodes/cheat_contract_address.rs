@@ -0,0 +1,51 @@
+use super::cheat_execution_info::{CheatArguments, ExecutionInfoMockOperations, Operation};
+use crate::state::CheatSpan;
+use crate::CheatnetState;
+use starknet_api::core::ContractAddress;
+
+impl CheatnetState {
+    pub fn cheat_contract_address(
+        &mut self,
+        target: ContractAddress,
+        contract_address: ContractAddress,
+        span: CheatSpan,
+    ) {
+        self.cheat_execution_info(ExecutionInfoMockOperations {
+            contract_address: Operation::Start(CheatArguments {
+                value: contract_address,
+                span,
+                target,
+            }),
+            ..Default::default()
+        });
+    }
+
+    pub fn start_cheat_contract_address_global(&mut self, contract_address: ContractAddress) {
+        self.cheat_execution_info(ExecutionInfoMockOperations {
+            contract_address: Operation::StartGlobal(contract_address),
+            ..Default::default()
+        });
+    }
+
+    pub fn start_cheat_contract_address(
+        &mut self,
+        target: ContractAddress,
+        contract_address: ContractAddress,
+    ) {
+        self.cheat_contract_address(target, contract_address, CheatSpan::Indefinite);
+    }
+
+    pub fn stop_cheat_contract_address(&mut self, target: ContractAddress) {
+        self.cheat_execution_info(ExecutionInfoMockOperations {
+            contract_address: Operation::Stop(target),
+            ..Default::default()
+        });
+    }
+
+    pub fn stop_cheat_contract_address_global(&mut self) {
+        self.cheat_execution_info(ExecutionInfoMockOperations {
+            contract_address: Operation::StopGlobal,
+            ..Default::default()
+        });
+    }
+}
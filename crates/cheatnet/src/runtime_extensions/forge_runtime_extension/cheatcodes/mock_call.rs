@@ -44,4 +44,17 @@ impl CheatnetState {
             contract_mocked_functions.remove(&function_selector);
         }
     }
+
+    /// Returns the number of calls to `function_selector` of a contract that returned mocked data
+    #[must_use]
+    pub fn get_mock_call_count(
+        &self,
+        contract_address: ContractAddress,
+        function_selector: EntryPointSelector,
+    ) -> u32 {
+        self.mocked_calls_counts
+            .get(&(contract_address, function_selector))
+            .copied()
+            .unwrap_or_default()
+    }
 }
@@ -6,6 +6,7 @@ use runtime::EnhancedHintError;
 pub mod cheat_block_number;
 pub mod cheat_block_timestamp;
 pub mod cheat_caller_address;
+pub mod cheat_contract_address;
 pub mod cheat_execution_info;
 pub mod cheat_sequencer_address;
 pub mod declare;
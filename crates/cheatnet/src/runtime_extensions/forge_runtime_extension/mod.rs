@@ -108,6 +108,17 @@ impl<'a> ExtensionLogic for ForgeExtension<'a> {
                     .stop_mock_call(contract_address, function_selector);
                 Ok(CheatcodeHandlingResult::from_serializable(()))
             }
+            "get_mock_call_count" => {
+                let contract_address = input_reader.read()?;
+                let function_selector = input_reader.read()?;
+
+                let count = extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .get_mock_call_count(contract_address, function_selector);
+                Ok(CheatcodeHandlingResult::from_serializable(count))
+            }
             "replace_bytecode" => {
                 let contract = input_reader.read()?;
                 let class = input_reader.read()?;
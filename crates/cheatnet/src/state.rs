@@ -311,6 +311,7 @@ pub struct CheatedData {
     pub block_number: Option<u64>,
     pub block_timestamp: Option<u64>,
     pub caller_address: Option<ContractAddress>,
+    pub contract_address: Option<ContractAddress>,
     pub sequencer_address: Option<ContractAddress>,
     pub tx_info: CheatedTxInfo,
 }
@@ -326,6 +327,7 @@ pub struct CheatnetState {
 
     pub mocked_functions:
         HashMap<ContractAddress, HashMap<EntryPointSelector, CheatStatus<Vec<Felt252>>>>,
+    pub mocked_calls_counts: HashMap<(ContractAddress, EntryPointSelector), u32>,
     pub replaced_bytecode_contracts: HashMap<ContractAddress, ClassHash>,
     pub detected_events: Vec<Event>,
     pub detected_messages_to_l1: Vec<MessageToL1>,
@@ -348,6 +350,7 @@ impl Default for CheatnetState {
             cheated_execution_info_contracts: Default::default(),
             global_cheated_execution_info: Default::default(),
             mocked_functions: Default::default(),
+            mocked_calls_counts: Default::default(),
             replaced_bytecode_contracts: Default::default(),
             detected_events: vec![],
             detected_messages_to_l1: vec![],
@@ -370,6 +373,7 @@ impl CheatnetState {
             block_number: execution_info.block_info.block_number.as_value(),
             block_timestamp: execution_info.block_info.block_timestamp.as_value(),
             caller_address: execution_info.caller_address.as_value(),
+            contract_address: execution_info.contract_address.as_value(),
             sequencer_address: execution_info.block_info.sequencer_address.as_value(),
             tx_info: CheatedTxInfo {
                 version: execution_info.tx_info.version.as_value(),
@@ -456,6 +460,16 @@ impl CheatnetState {
             .as_value()
     }
 
+    #[must_use]
+    pub fn get_cheated_contract_address(
+        &mut self,
+        address: ContractAddress,
+    ) -> Option<ContractAddress> {
+        self.get_cheated_execution_info_for_contract(address)
+            .contract_address
+            .as_value()
+    }
+
     pub fn update_cheats(&mut self, address: &ContractAddress) {
         self.progress_cheated_execution_info(*address);
     }
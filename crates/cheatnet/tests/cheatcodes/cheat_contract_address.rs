@@ -0,0 +1,123 @@
+use crate::common::assertions::assert_success;
+use cairo_vm::Felt252;
+use cheatnet::state::CheatSpan;
+use conversions::IntoConv;
+use starknet_api::core::ContractAddress;
+
+use super::test_environment::TestEnvironment;
+
+#[test]
+fn cheat_contract_address_simple() {
+    let mut test_env = TestEnvironment::new();
+
+    let contract_address = test_env.deploy("CheatContractAddressChecker", &[]);
+
+    test_env
+        .cheatnet_state
+        .start_cheat_contract_address(contract_address, ContractAddress::from(123_u128));
+
+    assert_success(
+        test_env.call_contract(&contract_address, "get_contract_address", &[]),
+        &[Felt252::from(123)],
+    );
+}
+
+#[test]
+fn cheat_contract_address_stop() {
+    let mut test_env = TestEnvironment::new();
+
+    let contract_address = test_env.deploy("CheatContractAddressChecker", &[]);
+
+    test_env
+        .cheatnet_state
+        .start_cheat_contract_address(contract_address, ContractAddress::from(123_u128));
+
+    assert_success(
+        test_env.call_contract(&contract_address, "get_contract_address", &[]),
+        &[Felt252::from(123)],
+    );
+
+    test_env
+        .cheatnet_state
+        .stop_cheat_contract_address(contract_address);
+
+    assert_success(
+        test_env.call_contract(&contract_address, "get_contract_address", &[]),
+        &[contract_address.into_()],
+    );
+}
+
+#[test]
+fn cheat_contract_address_does_not_change_storage() {
+    let mut test_env = TestEnvironment::new();
+
+    let contract_address = test_env.deploy("CheatContractAddressChecker", &[]);
+
+    test_env
+        .cheatnet_state
+        .start_cheat_contract_address(contract_address, ContractAddress::from(123_u128));
+
+    assert_success(
+        test_env.call_contract(&contract_address, "set_balance", &[Felt252::from(5)]),
+        &[],
+    );
+
+    test_env
+        .cheatnet_state
+        .stop_cheat_contract_address(contract_address);
+
+    assert_success(
+        test_env.call_contract(&contract_address, "get_balance", &[]),
+        &[Felt252::from(5)],
+    );
+}
+
+#[test]
+fn cheat_contract_address_simple_with_span() {
+    let mut test_env = TestEnvironment::new();
+
+    let contract_address = test_env.deploy("CheatContractAddressChecker", &[]);
+
+    test_env.cheatnet_state.cheat_contract_address(
+        contract_address,
+        ContractAddress::from(123_u128),
+        CheatSpan::TargetCalls(1),
+    );
+
+    assert_success(
+        test_env.call_contract(&contract_address, "get_contract_address", &[]),
+        &[Felt252::from(123)],
+    );
+    assert_success(
+        test_env.call_contract(&contract_address, "get_contract_address", &[]),
+        &[contract_address.into_()],
+    );
+}
+
+#[test]
+fn cheat_contract_address_global() {
+    let mut test_env = TestEnvironment::new();
+
+    let contract_address_a = test_env.deploy("CheatContractAddressChecker", &[]);
+    let contract_address_b = test_env.deploy("CheatContractAddressChecker", &[]);
+
+    test_env
+        .cheatnet_state
+        .start_cheat_contract_address_global(ContractAddress::from(123_u128));
+
+    assert_success(
+        test_env.call_contract(&contract_address_a, "get_contract_address", &[]),
+        &[Felt252::from(123)],
+    );
+    assert_success(
+        test_env.call_contract(&contract_address_b, "get_contract_address", &[]),
+        &[Felt252::from(123)],
+    );
+
+    test_env.cheatnet_state.stop_cheat_contract_address_global();
+
+    assert_success(
+        test_env.call_contract(&contract_address_b, "get_contract_address", &[]),
+        &[contract_address_b.into_()],
+    );
+}
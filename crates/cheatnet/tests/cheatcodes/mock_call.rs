@@ -792,3 +792,38 @@ fn mock_call_override_span() {
         &[111.into()],
     );
 }
+
+#[test]
+fn mock_call_count() {
+    let mut test_env = TestEnvironment::new();
+
+    let contract_address = test_env.deploy("MockChecker", &[111.into()]);
+    let selector = felt_selector_from_name("get_thing");
+
+    test_env.mock_call(
+        &contract_address,
+        "get_thing",
+        &[222],
+        CheatSpan::TargetCalls(2),
+    );
+
+    for ret_data in [222, 222, 111] {
+        assert_success(
+            test_env.call_contract(&contract_address, "get_thing", &[]),
+            &[ret_data.into()],
+        );
+    }
+
+    assert_eq!(
+        test_env
+            .cheatnet_state
+            .get_mock_call_count(contract_address, selector),
+        2
+    );
+    assert_eq!(
+        test_env
+            .cheatnet_state
+            .get_mock_call_count(contract_address, felt_selector_from_name("get_thing_twice")),
+        0
+    );
+}
@@ -8,6 +8,7 @@ mod test_environment;
 mod cheat_block_number;
 mod cheat_block_timestamp;
 mod cheat_caller_address;
+mod cheat_contract_address;
 mod cheat_execution_info;
 mod cheat_sequencer_address;
 mod declare;
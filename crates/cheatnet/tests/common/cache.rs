@@ -4,14 +4,18 @@ use std::fs;
 use std::path::PathBuf;
 use std::str::FromStr;
 
-pub fn read_cache(file_pattern: &str) -> Map<String, Value> {
+fn find_cache_file(file_pattern: &str) -> PathBuf {
     let mut cache_files = glob(file_pattern).unwrap().filter_map(Result::ok);
 
-    let cache_file = match (cache_files.next(), cache_files.next()) {
+    match (cache_files.next(), cache_files.next()) {
         (None, None) => panic!("Cache file not found"),
         (Some(cache_file), None) => cache_file,
         _ => panic!("Multiple matching cache files found"),
-    };
+    }
+}
+
+pub fn read_cache(file_pattern: &str) -> Map<String, Value> {
+    let cache_file = find_cache_file(file_pattern);
 
     let cache_content = fs::read_to_string(cache_file).expect("Could not read cache");
     let parsed_cache_content: Value =
@@ -22,6 +26,13 @@ pub fn read_cache(file_pattern: &str) -> Map<String, Value> {
         .clone()
 }
 
+pub fn write_cache(file_pattern: &str, cache: &Map<String, Value>) {
+    let cache_file = find_cache_file(file_pattern);
+
+    let cache_content = serde_json::to_string(cache).expect("Could not serialize cache");
+    fs::write(cache_file, cache_content).expect("Could not write cache");
+}
+
 pub fn purge_cache(directory: &str) {
     fs::remove_dir_all(PathBuf::from_str(directory).expect("Could not parse cache path"))
         .expect("Could not remove cache directory");
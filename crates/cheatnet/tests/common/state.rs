@@ -2,8 +2,10 @@ use blockifier::state::cached_state::CachedState;
 use cheatnet::constants::build_testing_state;
 use cheatnet::forking::state::ForkStateReader;
 use cheatnet::state::ExtendedStateReader;
+use shared::rpc::RetryConfig;
 use shared::test_utils::node_url::node_rpc_url;
 use starknet_api::block::BlockNumber;
+use std::time::Duration;
 
 pub fn create_cached_state() -> CachedState<ExtendedStateReader> {
     CachedState::new(ExtendedStateReader {
@@ -19,12 +21,27 @@ pub fn create_fork_cached_state(cache_dir: &str) -> CachedState<ExtendedStateRea
 pub fn create_fork_cached_state_at(
     block_number: u64,
     cache_dir: &str,
+) -> CachedState<ExtendedStateReader> {
+    create_fork_cached_state_at_with_ttl(block_number, cache_dir, None)
+}
+
+pub fn create_fork_cached_state_at_with_ttl(
+    block_number: u64,
+    cache_dir: &str,
+    cache_ttl: Option<Duration>,
 ) -> CachedState<ExtendedStateReader> {
     let node_url = node_rpc_url();
     CachedState::new(ExtendedStateReader {
         dict_state_reader: build_testing_state(),
         fork_state_reader: Some(
-            ForkStateReader::new(node_url, BlockNumber(block_number), cache_dir.into()).unwrap(),
+            ForkStateReader::new(
+                node_url,
+                BlockNumber(block_number),
+                cache_dir.into(),
+                cache_ttl,
+                RetryConfig::default(),
+            )
+            .unwrap(),
         ),
     })
 }
@@ -0,0 +1,32 @@
+#[starknet::interface]
+trait ICheatContractAddressChecker<TContractState> {
+    fn get_contract_address(ref self: TContractState) -> felt252;
+    fn get_balance(ref self: TContractState) -> felt252;
+    fn set_balance(ref self: TContractState, balance: felt252);
+}
+
+#[starknet::contract]
+mod CheatContractAddressChecker {
+    use starknet::ContractAddressIntoFelt252;
+    use traits::Into;
+
+    #[storage]
+    struct Storage {
+        balance: felt252,
+    }
+
+    #[abi(embed_v0)]
+    impl ICheatContractAddressChecker of super::ICheatContractAddressChecker<ContractState> {
+        fn get_contract_address(ref self: ContractState) -> felt252 {
+            starknet::get_contract_address().into()
+        }
+
+        fn get_balance(ref self: ContractState) -> felt252 {
+            self.balance.read()
+        }
+
+        fn set_balance(ref self: ContractState, balance: felt252) {
+            self.balance.write(balance);
+        }
+    }
+}
@@ -3,6 +3,7 @@ mod events;
 mod get_class_hash;
 mod mock;
 mod cheat_caller_address;
+mod cheat_contract_address;
 mod replace_bytecode;
 mod cheat_block_number;
 mod cheat_tx_info;
@@ -1,6 +1,8 @@
 use crate::common::assertions::{assert_error, assert_panic, assert_success};
-use crate::common::cache::{purge_cache, read_cache};
-use crate::common::state::{create_fork_cached_state, create_fork_cached_state_at};
+use crate::common::cache::{purge_cache, read_cache, write_cache};
+use crate::common::state::{
+    create_fork_cached_state, create_fork_cached_state_at, create_fork_cached_state_at_with_ttl,
+};
 use crate::common::{call_contract, deploy_contract, deploy_wrapper, felt_selector_from_name};
 use blockifier::state::cached_state::CachedState;
 use cairo_vm::vm::errors::hint_errors::HintError;
@@ -16,8 +18,10 @@ use conversions::IntoConv;
 use rayon::prelude::{IntoParallelRefIterator, ParallelIterator};
 use runtime::EnhancedHintError;
 use serde_json::Value;
+use shared::rpc::RetryConfig;
 use starknet_api::block::BlockNumber;
 use starknet_api::core::ContractAddress;
+use std::time::Duration;
 use tempfile::TempDir;
 
 #[test]
@@ -650,6 +654,11 @@ fn test_calling_nonexistent_url() {
                 nonexistent_url,
                 BlockNumber(1),
                 Utf8Path::from_path(temp_dir.path()).unwrap(),
+                None,
+                RetryConfig {
+                    retries: 0,
+                    ..Default::default()
+                },
             )
             .unwrap(),
         ),
@@ -676,3 +685,75 @@ fn test_calling_nonexistent_url() {
         "Unable to reach the node. Check your internet connection and node url",
     );
 }
+
+#[test]
+fn test_expired_cache_is_invalidated() {
+    fn run_test(cache_dir: &str, cache_ttl: Option<Duration>, call_get_block_info: bool) {
+        let mut cached_state = create_fork_cached_state_at_with_ttl(53_680, cache_dir, cache_ttl);
+        if call_get_block_info {
+            let _ = cached_state.state.get_block_info().unwrap();
+        }
+        let mut cheatnet_state = CheatnetState::default();
+
+        let contract_address = ContractAddress::try_from_hex_str(
+            "0x202de98471a4fae6bcbabb96cab00437d381abc58b02509043778074d6781e9",
+        )
+        .unwrap();
+
+        let selector = felt_selector_from_name("get_balance");
+        let output = call_contract(
+            &mut cached_state,
+            &mut cheatnet_state,
+            &contract_address,
+            selector,
+            &[],
+        );
+
+        assert_success(output, &[Felt252::from(0)]);
+    }
+
+    let cache_dir = TempDir::new().unwrap();
+
+    let is_block_info_cached = || {
+        let cache = read_cache(
+            cache_dir
+                .path()
+                .join(format!("*v{CACHE_VERSION}.json"))
+                .to_str()
+                .unwrap(),
+        );
+        cache["block_info"].as_object().is_some()
+    };
+    let cache_dir_str = cache_dir.path().to_str().unwrap();
+
+    run_test(cache_dir_str, None, true);
+    assert!(is_block_info_cached());
+
+    // Cache is still valid, block info is kept
+    run_test(cache_dir_str, Some(Duration::from_secs(3600)), false);
+    assert!(is_block_info_cached());
+
+    // Cache created during the current run is kept even with TTL equal to 0
+    run_test(cache_dir_str, Some(Duration::ZERO), false);
+    assert!(is_block_info_cached());
+
+    // Cache created before the current run expires
+    let cache_file_pattern = cache_dir.path().join(format!("*v{CACHE_VERSION}.json"));
+    let cache_file_pattern = cache_file_pattern.to_str().unwrap();
+    let mut cache = read_cache(cache_file_pattern);
+    cache.insert("created_at".to_string(), 0.into());
+    write_cache(cache_file_pattern, &cache);
+
+    run_test(cache_dir_str, Some(Duration::from_secs(3600)), false);
+    assert!(!is_block_info_cached());
+
+    // Cache created before the current run expires with TTL equal to 0
+    run_test(cache_dir_str, None, true);
+    assert!(is_block_info_cached());
+    let mut cache = read_cache(cache_file_pattern);
+    cache.insert("created_at".to_string(), 0.into());
+    write_cache(cache_file_pattern, &cache);
+
+    run_test(cache_dir_str, Some(Duration::ZERO), false);
+    assert!(!is_block_info_cached());
+}
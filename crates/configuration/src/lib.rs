@@ -1,6 +1,7 @@
 use anyhow::{anyhow, Context, Result};
 use scarb_metadata::{Metadata, PackageId};
 use serde_json::Number;
+use shared::print::print_as_warning;
 use std::{env, fs};
 
 use camino::Utf8PathBuf;
@@ -45,11 +46,21 @@ pub fn get_profile(
     let profile_name = profile.as_deref().unwrap_or("default");
     let tool_config = get_with_ownership(raw_config, tool)
         .unwrap_or(serde_json::Value::Object(serde_json::Map::new()));
+    let available_profiles: Vec<String> = tool_config
+        .as_object()
+        .map(|profiles| profiles.keys().cloned().collect())
+        .unwrap_or_default();
 
     match get_with_ownership(tool_config, profile_name) {
         Some(profile_value) => Ok(profile_value),
         None if profile_name == "default" => Ok(serde_json::Value::Object(Default::default())),
-        None => Err(anyhow!("Profile [{}] not found in config", profile_name)),
+        None if available_profiles.is_empty() => Err(anyhow!(
+            "Profile [{profile_name}] not found in config, no [{tool}.<profile>] profiles are defined"
+        )),
+        None => Err(anyhow!(
+            "Profile [{profile_name}] not found in config, available profiles: {}",
+            available_profiles.join(", ")
+        )),
     }
 }
 
@@ -73,9 +84,16 @@ pub fn load_global_config<T: GlobalConfig + Default>(
                 .context("Conversion from TOML value to JSON value should not fail.")?;
 
             let profile = get_profile(raw_config_json, T::tool_name(), profile)?;
-            T::from_raw(resolve_env_variables(profile)?)
+            T::from_raw(resolve_env_variables(profile, true)?)
+        }
+        None => {
+            if let Some(profile_name) = profile.as_deref().filter(|name| *name != "default") {
+                print_as_warning(&anyhow!(
+                    "Profile [{profile_name}] is ignored, {CONFIG_FILENAME} config file was not found in the current directory or any of its parents"
+                ));
+            }
+            Ok(T::default())
         }
-        None => Ok(T::default()),
     }
 }
 /// Loads config for a specific package from the `Scarb.toml` file
@@ -92,18 +110,23 @@ pub fn load_package_config<T: PackageConfig + Default>(
         .tool_metadata(T::tool_name())
         .cloned();
     match maybe_raw_metadata {
-        Some(raw_metadata) => T::from_raw(&resolve_env_variables(raw_metadata)?),
+        Some(raw_metadata) => T::from_raw(&resolve_env_variables(raw_metadata, false)?),
         None => Ok(T::default()),
     }
 }
 
-fn resolve_env_variables(config: serde_json::Value) -> Result<serde_json::Value> {
+/// Resolves `$NAME` values, and `${NAME}` inside values if `interpolate` is set,
+/// which is done only for snfoundry.toml
+fn resolve_env_variables(
+    config: serde_json::Value,
+    interpolate: bool,
+) -> Result<serde_json::Value> {
     match config {
         serde_json::Value::Object(map) => {
             let val = map
                 .into_iter()
                 .map(|(k, v)| -> Result<(String, serde_json::Value)> {
-                    Ok((k, resolve_env_variables(v)?))
+                    Ok((k, resolve_env_variables(v, interpolate)?))
                 })
                 .collect::<Result<serde_json::Map<String, serde_json::Value>>>()?;
             Ok(serde_json::Value::Object(val))
@@ -111,10 +134,13 @@ fn resolve_env_variables(config: serde_json::Value) -> Result<serde_json::Value>
         serde_json::Value::Array(val) => {
             let val = val
                 .into_iter()
-                .map(resolve_env_variables)
+                .map(|v| resolve_env_variables(v, interpolate))
                 .collect::<Result<Vec<serde_json::Value>>>()?;
             Ok(serde_json::Value::Array(val))
         }
+        serde_json::Value::String(val) if interpolate && val.contains("${") => {
+            Ok(serde_json::Value::String(interpolate_env_variables(&val)?))
+        }
         serde_json::Value::String(val) if val.starts_with('$') => resolve_env_variable(&val),
         val => Ok(val),
     }
@@ -132,6 +158,29 @@ fn resolve_env_variable(var: &str) -> Result<serde_json::Value> {
     Ok(serde_json::Value::String(value))
 }
 
+/// Replaces every `${NAME}` in the value with the value of the `NAME` environment variable
+fn interpolate_env_variables(value: &str) -> Result<String> {
+    let mut interpolated = String::new();
+    let mut rest = value;
+    while let Some(start) = rest.find("${") {
+        let length = rest[start..].find('}').with_context(|| {
+            format!("Missing closing `}}` of environment variable in config value {value}")
+        })?;
+        interpolated.push_str(&rest[..start]);
+        interpolated.push_str(&env_variable(&rest[start + 2..start + length])?);
+        rest = &rest[start + length + 1..];
+    }
+    interpolated.push_str(rest);
+
+    Ok(interpolated)
+}
+
+fn env_variable(name: &str) -> Result<String> {
+    env::var(name).with_context(|| {
+        format!("Failed to resolve environment variable {name} used in the config")
+    })
+}
+
 pub fn search_config_upwards_relative_to(current_dir: &Utf8PathBuf) -> Result<Utf8PathBuf> {
     current_dir
         .ancestors()
@@ -289,6 +338,34 @@ mod tests {
         assert_eq!(config.url, String::new());
     }
 
+    #[test]
+    fn load_config_profile_not_found() {
+        let tempdir = copy_config_to_tempdir("tests/data/stubtool_snfoundry.toml", None).unwrap();
+        let error = load_global_config::<StubConfig>(
+            &Some(Utf8PathBuf::try_from(tempdir.path().to_path_buf()).unwrap()),
+            &Some(String::from("profile100")),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "Profile [profile100] not found in config, available profiles: default, profile1, profile2, profile3, profile4, profile5, with-envs"
+        );
+    }
+
+    #[test]
+    fn load_config_profile_without_config_file() {
+        let tempdir = tempdir().expect("Failed to create a temporary directory");
+        let config = load_global_config::<StubConfig>(
+            &Some(Utf8PathBuf::try_from(tempdir.path().to_path_buf()).unwrap()),
+            &Some(String::from("profile1")),
+        )
+        .unwrap();
+
+        assert!(config.url.is_empty());
+        assert!(config.account.is_empty());
+    }
+
     #[derive(Debug, Default, Serialize, Deserialize)]
     pub struct StubComplexConfig {
         #[serde(default)]
@@ -369,4 +446,82 @@ mod tests {
         assert_eq!(config.nested.list_example, vec![true, false]);
         assert_eq!(config.nested.url_nested, 321.312);
     }
+
+    #[test]
+    fn interpolate_env_vars() {
+        env::set_var("INTERPOLATED_HOST123132", "rpc.example.com");
+        env::set_var("INTERPOLATED_KEY123132", "secret");
+        env::set_var("INTERPOLATED_INT123132", "123");
+
+        let config = resolve_env_variables(
+            serde_json::json!({
+                "url": "https://${INTERPOLATED_HOST123132}/v0_7/${INTERPOLATED_KEY123132}",
+                "account": "${INTERPOLATED_INT123132}",
+                "keystore": "plain-${INTERPOLATED_INT123132}",
+            }),
+            true,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config,
+            serde_json::json!({
+                "url": "https://rpc.example.com/v0_7/secret",
+                "account": "123",
+                "keystore": "plain-123",
+            })
+        );
+    }
+
+    #[test]
+    fn no_interpolation_without_flag() {
+        env::set_var("NOT_INTERPOLATED_KEY123132", "secret");
+
+        let config = resolve_env_variables(
+            serde_json::json!({
+                "url": "https://rpc.example.com/${NOT_INTERPOLATED_KEY123132}",
+            }),
+            false,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config,
+            serde_json::json!({
+                "url": "https://rpc.example.com/${NOT_INTERPOLATED_KEY123132}",
+            })
+        );
+    }
+
+    #[test]
+    fn interpolate_missing_env_var() {
+        let error = resolve_env_variables(
+            serde_json::json!({
+                "url": "https://${MISSING_VARIABLE123132}",
+            }),
+            true,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "Failed to resolve environment variable MISSING_VARIABLE123132 used in the config"
+        );
+    }
+
+    #[test]
+    fn interpolate_unclosed_env_var() {
+        let error = resolve_env_variables(
+            serde_json::json!({
+                "url": "https://${MISSING_VARIABLE123132",
+            }),
+            true,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "Missing closing `}` of environment variable in config value https://${MISSING_VARIABLE123132"
+        );
+    }
 }
@@ -58,9 +58,74 @@ pub fn run_coverage(saved_trace_data_paths: &[PathBuf]) -> Result<()> {
             "cairo-coverage failed to generate coverage - inspect the errors above for more info"
         })?;
 
+    let lcov = fs::read_to_string(&path_to_save_coverage)
+        .context("Failed to read the generated coverage report")?;
+    print_coverage_summary(&lcov);
+
     Ok(())
 }
 
+#[derive(Debug, PartialEq, Eq)]
+struct FileCoverage {
+    source_file: String,
+    lines_hit: usize,
+    lines_found: usize,
+}
+
+/// Counts hit and instrumented lines for each source file in the LCOV report
+fn summarize_lcov(lcov: &str) -> Vec<FileCoverage> {
+    let mut summary = vec![];
+    let mut current: Option<FileCoverage> = None;
+
+    for line in lcov.lines().map(str::trim) {
+        if let Some(source_file) = line.strip_prefix("SF:") {
+            current = Some(FileCoverage {
+                source_file: source_file.to_string(),
+                lines_hit: 0,
+                lines_found: 0,
+            });
+        } else if let Some(line_data) = line.strip_prefix("DA:") {
+            if let Some(file) = current.as_mut() {
+                let hits = line_data
+                    .split(',')
+                    .nth(1)
+                    .and_then(|hits| hits.parse::<u64>().ok())
+                    .unwrap_or(0);
+                file.lines_found += 1;
+                if hits > 0 {
+                    file.lines_hit += 1;
+                }
+            }
+        } else if line == "end_of_record" {
+            summary.extend(current.take());
+        }
+    }
+
+    summary
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn print_coverage_summary(lcov: &str) {
+    let summary = summarize_lcov(lcov);
+    if summary.is_empty() {
+        return;
+    }
+
+    println!("\nCoverage summary:");
+    for file in summary {
+        let percentage = if file.lines_found == 0 {
+            100.0
+        } else {
+            file.lines_hit as f64 / file.lines_found as f64 * 100.0
+        };
+        println!(
+            "{}: {}/{} lines ({percentage:.2}%)",
+            file.source_file, file.lines_hit, file.lines_found
+        );
+    }
+    println!("Report saved to {COVERAGE_DIR}/{OUTPUT_FILE_NAME}");
+}
+
 pub fn can_coverage_be_generated(scarb_metadata: &Metadata) -> Result<()> {
     let manifest = fs::read_to_string(&scarb_metadata.runtime_manifest)?.parse::<DocumentMut>()?;
 
@@ -103,3 +168,42 @@ fn contains_entry_with_value(table: &Table, key: &str, value: &str) -> bool {
         .get(key)
         .map_or(false, |entry| entry.to_string().trim() == value)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{summarize_lcov, FileCoverage};
+    use indoc::indoc;
+
+    #[test]
+    fn summarizes_lcov_per_file() {
+        let lcov = indoc! {"
+            TN:
+            SF:src/lib.cairo
+            FN:3,foo
+            DA:3,2
+            DA:4,0
+            DA:5,1
+            end_of_record
+            TN:
+            SF:src/other.cairo
+            DA:1,0
+            end_of_record
+        "};
+
+        assert_eq!(
+            summarize_lcov(lcov),
+            vec![
+                FileCoverage {
+                    source_file: "src/lib.cairo".to_string(),
+                    lines_hit: 2,
+                    lines_found: 3,
+                },
+                FileCoverage {
+                    source_file: "src/other.cairo".to_string(),
+                    lines_hit: 0,
+                    lines_found: 1,
+                },
+            ]
+        );
+    }
+}
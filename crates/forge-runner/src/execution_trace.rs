@@ -0,0 +1,161 @@
+use crate::test_file_name;
+use anyhow::{Context, Result};
+use cairo_vm::vm::trace::trace_entry::RelocatedTraceEntry;
+use cairo_vm::Felt252;
+use camino::{Utf8Path, Utf8PathBuf};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fs;
+use universal_sierra_compiler_api::AssembledProgramWithDebugInfo;
+
+const TRACES_DIR: &str = "traces";
+
+/// Program segment starts at address 1 after relocation
+const PROGRAM_BASE: usize = 1;
+
+/// Single step of the test execution
+#[derive(Debug, PartialEq, Serialize)]
+pub struct ExecutionTraceEntry {
+    pub pc: usize,
+    pub ap: usize,
+    pub fp: usize,
+    /// Index of the Sierra statement which the executed instruction was compiled from,
+    /// `None` for instructions of the entry code
+    pub sierra_statement_idx: Option<usize>,
+    /// Path and line of the statement in `<path>:<line>` format,
+    /// available when the package is compiled with statements code locations debug info
+    pub location: Option<String>,
+    /// Values of memory cells in the current frame, i.e. between `fp` and `ap`
+    pub stack: Vec<Option<String>>,
+}
+
+/// Builds the execution trace of a test from the VM trace.
+/// `header_size` is the size of the entry code placed before the compiled test program
+#[must_use]
+pub fn build_execution_trace(
+    trace: &[RelocatedTraceEntry],
+    memory: &[Option<Felt252>],
+    casm_program: &AssembledProgramWithDebugInfo,
+    header_size: usize,
+    statement_locations: &HashMap<usize, (Utf8PathBuf, u32)>,
+) -> Vec<ExecutionTraceEntry> {
+    let program_size = casm_program.assembled_cairo_program.bytecode.len();
+
+    trace
+        .iter()
+        .map(|entry| {
+            let sierra_statement_idx = entry
+                .pc
+                .checked_sub(PROGRAM_BASE + header_size)
+                .filter(|code_offset| *code_offset < program_size)
+                .and_then(|code_offset| {
+                    casm_program
+                        .debug_info
+                        .partition_point(|(offset, _)| *offset <= code_offset)
+                        .checked_sub(1)
+                });
+            let location = sierra_statement_idx
+                .and_then(|idx| statement_locations.get(&idx))
+                .map(|(path, line)| format!("{path}:{line}"));
+
+            let stack = (entry.fp..entry.ap)
+                .map(|address| {
+                    memory
+                        .get(address)
+                        .and_then(Option::as_ref)
+                        .map(Felt252::to_hex_string)
+                })
+                .collect();
+
+            ExecutionTraceEntry {
+                pc: entry.pc,
+                ap: entry.ap,
+                fp: entry.fp,
+                sierra_statement_idx,
+                location,
+                stack,
+            }
+        })
+        .collect()
+}
+
+/// Saves the execution trace to `<cache_dir>/traces/<test_name>.json`,
+/// with `::` in the test name replaced by `-`
+pub fn save_execution_trace(
+    cache_dir: &Utf8Path,
+    test_name: &str,
+    trace: &[ExecutionTraceEntry],
+) -> Result<Utf8PathBuf> {
+    let traces_dir = cache_dir.join(TRACES_DIR);
+    fs::create_dir_all(&traces_dir).context("Failed to create a traces directory")?;
+
+    let trace_path = traces_dir.join(test_file_name(test_name, "json"));
+    fs::write(&trace_path, serde_json::to_string(trace)?)
+        .context("Failed to write execution trace to a file")?;
+
+    Ok(trace_path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+    use universal_sierra_compiler_api::AssembledCairoProgramWithSerde;
+
+    #[test]
+    fn trace_file_name_has_no_path_separators() {
+        let temp_dir = TempDir::new().unwrap();
+        let cache_dir = Utf8Path::from_path(temp_dir.path()).unwrap();
+
+        let trace_path = save_execution_trace(cache_dir, "package::tests::test_a", &[]).unwrap();
+
+        assert_eq!(
+            trace_path,
+            cache_dir.join("traces/package-tests-test_a.json")
+        );
+        assert!(trace_path.exists());
+    }
+
+    #[test]
+    fn maps_pc_to_sierra_statements() {
+        let casm_program = AssembledProgramWithDebugInfo {
+            assembled_cairo_program: AssembledCairoProgramWithSerde {
+                bytecode: vec![0.into(); 6],
+                hints: vec![],
+            },
+            debug_info: vec![(0, 0), (2, 1), (5, 3)],
+        };
+        let header_size = 3;
+        let trace: Vec<_> = [1, 4, 6, 7, 9, 10]
+            .into_iter()
+            .map(|pc| RelocatedTraceEntry { pc, ap: 2, fp: 1 })
+            .collect();
+        let memory = vec![None, Some(Felt252::from(5))];
+
+        let statement_locations =
+            HashMap::from([(1, (Utf8PathBuf::from("tests/test_contract.cairo"), 7))]);
+
+        let execution_trace = build_execution_trace(
+            &trace,
+            &memory,
+            &casm_program,
+            header_size,
+            &statement_locations,
+        );
+
+        let statements: Vec<_> = execution_trace
+            .iter()
+            .map(|entry| entry.sierra_statement_idx)
+            .collect();
+        assert_eq!(
+            statements,
+            vec![None, Some(0), Some(1), Some(1), Some(2), None]
+        );
+        assert_eq!(execution_trace[0].stack, vec![Some("0x5".to_string())]);
+        assert_eq!(execution_trace[0].location, None);
+        assert_eq!(
+            execution_trace[2].location.as_deref(),
+            Some("tests/test_contract.cairo:7")
+        );
+    }
+}
@@ -1,8 +1,11 @@
+use crate::saved_state::SavedState;
 use camino::Utf8PathBuf;
 use cheatnet::runtime_extensions::forge_runtime_extension::contracts_data::ContractsData;
+use shared::rpc::RetryConfig;
 use std::collections::HashMap;
 use std::num::NonZeroU32;
 use std::sync::Arc;
+use std::time::Duration;
 
 #[derive(Debug, PartialEq)]
 pub struct ForgeConfig {
@@ -15,9 +18,21 @@ pub struct TestRunnerConfig {
     pub exit_first: bool,
     pub fuzzer_runs: NonZeroU32,
     pub fuzzer_seed: u64,
+    pub replay_fuzzer_corpus: bool,
+    pub fuzzer_shrink_iterations: u32,
     pub max_n_steps: Option<u32>,
     pub is_vm_trace_needed: bool,
+    pub save_execution_trace: bool,
     pub cache_dir: Utf8PathBuf,
+    pub fork_cache_ttl: Option<Duration>,
+    pub test_timeout: Option<Duration>,
+    /// Maximum number of tests running at once, see `--parallel`
+    pub max_parallel_tests: usize,
+    /// Path the state left by the test is saved to, see `--save-state`
+    pub save_state: Option<Utf8PathBuf>,
+    /// State all tests start with, see `--load-state`
+    pub initial_state: Option<SavedState>,
+    pub rpc_retry_config: RetryConfig,
     pub contracts_data: ContractsData,
     pub environment_variables: HashMap<String, String>,
 }
@@ -56,7 +71,12 @@ impl ExecutionDataToSave {
 pub struct RuntimeConfig<'a> {
     pub max_n_steps: Option<u32>,
     pub is_vm_trace_needed: bool,
+    pub save_execution_trace: bool,
     pub cache_dir: &'a Utf8PathBuf,
+    pub fork_cache_ttl: Option<Duration>,
+    pub save_state: bool,
+    pub initial_state: Option<&'a SavedState>,
+    pub rpc_retry_config: RetryConfig,
     pub contracts_data: &'a ContractsData,
     pub environment_variables: &'a HashMap<String, String>,
 }
@@ -67,7 +87,12 @@ impl<'a> RuntimeConfig<'a> {
         Self {
             max_n_steps: value.max_n_steps,
             is_vm_trace_needed: value.is_vm_trace_needed,
+            save_execution_trace: value.save_execution_trace,
             cache_dir: &value.cache_dir,
+            fork_cache_ttl: value.fork_cache_ttl,
+            save_state: value.save_state.is_some(),
+            initial_state: value.initial_state.as_ref(),
+            rpc_retry_config: value.rpc_retry_config,
             contracts_data: &value.contracts_data,
             environment_variables: &value.environment_variables,
         }
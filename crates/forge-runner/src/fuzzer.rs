@@ -4,9 +4,13 @@ use rand::rngs::StdRng;
 use rand::Rng;
 
 mod arguments;
+mod corpus;
 mod random;
+mod shrink;
 
+pub use corpus::CorpusManager;
 pub use random::RandomFuzzer;
+pub use shrink::shrink;
 use std::num::NonZeroU32;
 
 #[derive(Debug, Clone)]
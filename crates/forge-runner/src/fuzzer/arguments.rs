@@ -53,6 +53,19 @@ impl CairoType {
         }
     }
 
+    /// Number of felts the value is serialized to
+    pub fn serialized_len(self) -> usize {
+        match self {
+            CairoType::U8
+            | CairoType::U16
+            | CairoType::U32
+            | CairoType::U64
+            | CairoType::U128
+            | CairoType::Felt252 => 1,
+            CairoType::U256 => 2,
+        }
+    }
+
     pub fn min(self) -> Vec<Felt252> {
         match self {
             CairoType::U8
@@ -0,0 +1,114 @@
+use crate::test_file_name;
+use anyhow::{anyhow, Context, Result};
+use cairo_vm::Felt252;
+use camino::Utf8PathBuf;
+use std::fs;
+use std::io::ErrorKind;
+
+const CORPUS_DIR: &str = "corpus";
+
+/// Stores arguments that made fuzz tests fail, so they can be replayed on subsequent runs.
+/// Each test has its own file `<cache_dir>/corpus/<test_name>.json` containing a list of entries,
+/// with `::` in the test name replaced by `-`.
+#[derive(Debug, Clone)]
+pub struct CorpusManager {
+    corpus_dir: Utf8PathBuf,
+}
+
+impl CorpusManager {
+    #[must_use]
+    pub fn new(cache_dir: &Utf8PathBuf) -> Self {
+        Self {
+            corpus_dir: cache_dir.join(CORPUS_DIR),
+        }
+    }
+
+    fn corpus_file(&self, test_name: &str) -> Utf8PathBuf {
+        self.corpus_dir.join(test_file_name(test_name, "json"))
+    }
+
+    /// Loads saved entries for the test, skipping ones that do not match the number of felts
+    /// its arguments are serialized to
+    pub fn load(&self, test_name: &str, serialized_len: usize) -> Result<Vec<Vec<Felt252>>> {
+        let entries = self.load_raw(test_name)?;
+
+        Ok(entries
+            .into_iter()
+            .filter(|entry| entry.len() == serialized_len)
+            .collect())
+    }
+
+    /// Appends a failing input to the test corpus, unless it is already there
+    pub fn save(&self, test_name: &str, arguments: &[Felt252]) -> Result<()> {
+        let mut entries = self.load_raw(test_name)?;
+
+        if entries.iter().any(|entry| entry == arguments) {
+            return Ok(());
+        }
+        entries.push(arguments.to_vec());
+
+        let serialized: Vec<Vec<String>> = entries
+            .iter()
+            .map(|entry| entry.iter().map(Felt252::to_hex_string).collect())
+            .collect();
+
+        fs::create_dir_all(&self.corpus_dir)?;
+        fs::write(
+            self.corpus_file(test_name),
+            serde_json::to_string_pretty(&serialized)?,
+        )?;
+
+        Ok(())
+    }
+
+    fn load_raw(&self, test_name: &str) -> Result<Vec<Vec<Felt252>>> {
+        let path = self.corpus_file(test_name);
+
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(vec![]),
+            Err(err) => Err(err)?,
+        };
+
+        let entries: Vec<Vec<String>> = serde_json::from_str(&contents)
+            .with_context(|| format!("Failed to parse fuzzer corpus file at {path}"))?;
+
+        entries
+            .into_iter()
+            .map(|entry| {
+                entry
+                    .iter()
+                    .map(|value| {
+                        Felt252::from_hex(value).map_err(|_| {
+                            anyhow!("Invalid value {value} in fuzzer corpus file at {path}")
+                        })
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn save_and_load() {
+        let temp_dir = TempDir::new().unwrap();
+        let cache_dir = Utf8PathBuf::from_path_buf(temp_dir.path().to_path_buf()).unwrap();
+        let corpus = CorpusManager::new(&cache_dir);
+
+        assert!(corpus.load("tests::fuzz", 2).unwrap().is_empty());
+
+        let entry = vec![Felt252::from(1), Felt252::from(0x1234)];
+        corpus.save("tests::fuzz", &entry).unwrap();
+        corpus.save("tests::fuzz", &entry).unwrap();
+
+        assert_eq!(corpus.load("tests::fuzz", 2).unwrap(), vec![entry]);
+        assert!(corpus.load("tests::fuzz", 3).unwrap().is_empty());
+        assert!(corpus.load("tests::other_fuzz", 2).unwrap().is_empty());
+        assert!(cache_dir.join("corpus/tests-fuzz.json").exists());
+    }
+}
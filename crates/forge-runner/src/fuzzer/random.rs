@@ -41,6 +41,15 @@ impl RandomFuzzer {
             .collect()
     }
 
+    /// Number of felts all arguments are serialized to
+    pub fn serialized_arguments_len(&self) -> usize {
+        self.run_params
+            .arguments
+            .iter()
+            .map(|argument| argument.cairo_type.serialized_len())
+            .sum()
+    }
+
     fn next_run(&mut self) {
         self.run_params.executed_runs += 1;
     }
@@ -157,6 +166,18 @@ mod tests {
         assert!(fuzzer.run_params.arguments[0].run_with_min_value == current_run);
     }
 
+    #[test]
+    fn serialized_arguments_len_counts_felts() {
+        let fuzzer = RandomFuzzer::create(
+            1234,
+            NonZeroU32::new(3).unwrap(),
+            &["u256", "felt252", "u8"],
+        )
+        .unwrap();
+
+        assert_eq!(fuzzer.serialized_arguments_len(), 4);
+    }
+
     #[test]
     fn using_seed_consistent_result() {
         let seed = thread_rng().next_u64();
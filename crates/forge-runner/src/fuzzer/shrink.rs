@@ -0,0 +1,87 @@
+use cairo_vm::Felt252;
+use std::ops::Shr;
+
+/// Minimizes arguments of a failing fuzz test.
+/// Each argument is replaced with zero or its half as long as `fails` returns `true` for the changed arguments.
+/// `fails` is called at most `max_iterations` times.
+pub fn shrink<F>(mut arguments: Vec<Felt252>, max_iterations: u32, mut fails: F) -> Vec<Felt252>
+where
+    F: FnMut(&[Felt252]) -> bool,
+{
+    let mut iterations = 0;
+    let mut shrunk = true;
+
+    while shrunk {
+        shrunk = false;
+
+        for index in 0..arguments.len() {
+            for candidate in simpler_values(arguments[index]) {
+                if iterations == max_iterations {
+                    return arguments;
+                }
+                iterations += 1;
+
+                let mut changed = arguments.clone();
+                changed[index] = candidate;
+
+                if fails(&changed) {
+                    arguments = changed;
+                    shrunk = true;
+                    break;
+                }
+            }
+        }
+    }
+
+    arguments
+}
+
+fn simpler_values(value: Felt252) -> Vec<Felt252> {
+    if value == Felt252::ZERO {
+        return vec![];
+    }
+
+    let half = Felt252::from(value.to_biguint().shr(1));
+    if half == Felt252::ZERO {
+        vec![Felt252::ZERO]
+    } else {
+        vec![Felt252::ZERO, half]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shrinks_to_zero() {
+        let arguments = vec![Felt252::from(1234), Felt252::from(5678)];
+
+        let result = shrink(arguments, 100, |_| true);
+
+        assert_eq!(result, vec![Felt252::ZERO, Felt252::ZERO]);
+    }
+
+    #[test]
+    fn keeps_failing_arguments() {
+        let arguments = vec![Felt252::from(1000), Felt252::from(7)];
+
+        let result = shrink(arguments, 100, |args| args[0] >= Felt252::from(100));
+
+        assert_eq!(result, vec![Felt252::from(125), Felt252::ZERO]);
+    }
+
+    #[test]
+    fn respects_max_iterations() {
+        let arguments = vec![Felt252::from(1000)];
+        let mut calls = 0;
+
+        let result = shrink(arguments, 3, |args| {
+            calls += 1;
+            args[0] != Felt252::ZERO
+        });
+
+        assert_eq!(calls, 3);
+        assert_eq!(result, vec![Felt252::from(500)]);
+    }
+}
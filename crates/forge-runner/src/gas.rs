@@ -1,7 +1,7 @@
 use blockifier::abi::constants;
 use blockifier::fee::fee_utils::calculate_l1_gas_by_vm_usage;
 
-use crate::test_case_summary::{Single, TestCaseSummary};
+use crate::test_case_summary::{GasUsage, Single, TestCaseSummary};
 use blockifier::context::TransactionContext;
 use blockifier::fee::eth_gas_constants;
 use blockifier::fee::gas_usage::{
@@ -23,7 +23,7 @@ pub fn calculate_used_gas(
     transaction_context: &TransactionContext,
     state: &mut CachedState<ExtendedStateReader>,
     resources: UsedResources,
-) -> Result<u128, StateError> {
+) -> Result<GasUsage, StateError> {
     let versioned_constants = transaction_context.block_context.versioned_constants();
 
     let messaging_gas_vector = get_messages_costs(
@@ -41,7 +41,11 @@ pub fn calculate_used_gas(
 
     let gas = l1_data_cost + l1_gas_by_vm_costs + messaging_gas_vector + events_costs;
 
-    Ok(gas.l1_gas + gas.l1_data_gas)
+    Ok(GasUsage {
+        l1_gas: gas.l1_gas,
+        l1_data_gas: gas.l1_data_gas,
+        l2_gas: 0,
+    })
 }
 
 fn get_events_cost(
@@ -191,7 +195,10 @@ pub fn check_available_gas(
             arguments,
             gas_info,
             ..
-        } if available_gas.map_or(false, |available_gas| gas_info > available_gas as u128) => {
+        } if available_gas.map_or(false, |available_gas| {
+            gas_info.total() > available_gas as u128
+        }) =>
+        {
             TestCaseSummary::Failed {
                 name,
                 msg: Some(format!(
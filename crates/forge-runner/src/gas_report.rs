@@ -0,0 +1,285 @@
+use crate::build_trace_data::TEST_CODE_CONTRACT_NAME;
+use crate::test_case_summary::{AnyTestCaseSummary, GasUsage, TestCaseSummary};
+use anyhow::{Context, Result};
+use blockifier::fee::fee_utils::calculate_l1_gas_by_vm_usage;
+use blockifier::utils::u128_from_usize;
+use blockifier::versioned_constants::VersionedConstants;
+use cairo_vm::types::builtin_name::BuiltinName;
+use cairo_vm::vm::runners::cairo_runner::ExecutionResources;
+use std::collections::BTreeMap;
+use std::fmt;
+use trace_data::{CallTrace as ProfilerCallTrace, CallTraceNode as ProfilerCallTraceNode};
+
+/// Gas usage of passed tests and of contract functions called from them,
+/// printed after running tests with `--gas-report`
+#[derive(Debug, Default)]
+pub struct GasReport {
+    tests: Vec<(String, GasUsage)>,
+    /// Gas used by every call of a function, grouped by contract and function name
+    functions: BTreeMap<String, BTreeMap<String, Vec<u128>>>,
+}
+
+impl GasReport {
+    /// Adds the test gas usage and calls made by the test to the report.
+    /// Only passed tests which are not fuzz tests are included
+    pub fn add(&mut self, test_case_summary: &AnyTestCaseSummary) -> Result<()> {
+        if let AnyTestCaseSummary::Single(TestCaseSummary::Passed {
+            name,
+            gas_info,
+            trace_data,
+            ..
+        }) = test_case_summary
+        {
+            self.tests.push((name.clone(), *gas_info));
+            self.add_calls(trace_data)?;
+        }
+        Ok(())
+    }
+
+    fn add_calls(&mut self, call_trace: &ProfilerCallTrace) -> Result<()> {
+        let entry_point = &call_trace.entry_point;
+        if let (Some(contract_name), Some(function_name)) =
+            (&entry_point.contract_name, &entry_point.function_name)
+        {
+            if contract_name != TEST_CODE_CONTRACT_NAME {
+                self.functions
+                    .entry(contract_name.clone())
+                    .or_default()
+                    .entry(function_name.clone())
+                    .or_default()
+                    .push(call_gas(call_trace).with_context(|| {
+                        format!("Failed to calculate gas of {contract_name}::{function_name}")
+                    })?);
+            }
+        }
+
+        for nested_call in &call_trace.nested_calls {
+            if let ProfilerCallTraceNode::EntryPointCall(nested_call) = nested_call {
+                self.add_calls(nested_call)?;
+            }
+        }
+        Ok(())
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.tests.is_empty()
+    }
+}
+
+/// Gas of the computation done by the call, including calls it made
+fn call_gas(call_trace: &ProfilerCallTrace) -> Result<u128> {
+    let vm_resources = &call_trace.cumulative_resources.vm_resources;
+    let execution_resources = ExecutionResources {
+        n_steps: vm_resources.n_steps,
+        n_memory_holes: vm_resources.n_memory_holes,
+        builtin_instance_counter: vm_resources
+            .builtin_instance_counter
+            .iter()
+            .filter_map(|(name, count)| {
+                BuiltinName::from_str_with_suffix(name).map(|builtin| (builtin, *count))
+            })
+            .collect(),
+    };
+
+    let gas = calculate_l1_gas_by_vm_usage(
+        VersionedConstants::latest_constants(),
+        &execution_resources,
+        0,
+    )?;
+    Ok(gas.l1_gas)
+}
+
+#[derive(Debug, PartialEq)]
+struct FunctionStatistics {
+    min: u128,
+    avg: u128,
+    median: u128,
+    max: u128,
+    calls: usize,
+}
+
+impl FunctionStatistics {
+    fn new(gas_usages: &[u128]) -> Self {
+        let mut sorted = gas_usages.to_vec();
+        sorted.sort_unstable();
+
+        let calls = sorted.len();
+        let median = if calls % 2 == 0 {
+            (sorted[calls / 2 - 1] + sorted[calls / 2]) / 2
+        } else {
+            sorted[calls / 2]
+        };
+
+        Self {
+            min: sorted[0],
+            avg: sorted.iter().sum::<u128>() / u128_from_usize(calls),
+            median,
+            max: sorted[calls - 1],
+            calls,
+        }
+    }
+}
+
+impl fmt::Display for GasReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut rows = vec![vec![
+            "Test".to_string(),
+            "L1 gas".to_string(),
+            "L1 data gas".to_string(),
+            "L2 gas".to_string(),
+            "Total".to_string(),
+        ]];
+        rows.extend(self.tests.iter().map(|(name, gas)| {
+            vec![
+                name.clone(),
+                gas.l1_gas.to_string(),
+                gas.l1_data_gas.to_string(),
+                gas.l2_gas.to_string(),
+                gas.total().to_string(),
+            ]
+        }));
+        write!(f, "{}", format_table(&rows))?;
+
+        for (contract_name, functions) in &self.functions {
+            let mut rows = vec![
+                vec![
+                    format!("{contract_name} contract"),
+                    String::new(),
+                    String::new(),
+                    String::new(),
+                    String::new(),
+                    String::new(),
+                ],
+                vec![
+                    "Function Name".to_string(),
+                    "min".to_string(),
+                    "avg".to_string(),
+                    "median".to_string(),
+                    "max".to_string(),
+                    "# calls".to_string(),
+                ],
+            ];
+            rows.extend(functions.iter().map(|(function_name, gas_usages)| {
+                let statistics = FunctionStatistics::new(gas_usages);
+                vec![
+                    function_name.clone(),
+                    statistics.min.to_string(),
+                    statistics.avg.to_string(),
+                    statistics.median.to_string(),
+                    statistics.max.to_string(),
+                    statistics.calls.to_string(),
+                ]
+            }));
+            write!(f, "\n{}", format_table(&rows))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Formats rows as a markdown table with the first row as its header
+fn format_table(rows: &[Vec<String>]) -> String {
+    let widths: Vec<usize> = (0..rows[0].len())
+        .map(|column| rows.iter().map(|row| row[column].len()).max().unwrap())
+        .collect();
+
+    let format_row = |row: &Vec<String>| {
+        let cells: Vec<String> = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!(" {cell:width$} "))
+            .collect();
+        format!("|{}|\n", cells.join("|"))
+    };
+    let separator: Vec<String> = widths.iter().map(|width| "-".repeat(width + 2)).collect();
+
+    let mut table = format_row(&rows[0]);
+    table.push_str(&format!("|{}|\n", separator.join("|")));
+    for row in &rows[1..] {
+        table.push_str(&format_row(row));
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_table, FunctionStatistics, GasReport};
+    use crate::test_case_summary::GasUsage;
+    use indoc::indoc;
+
+    #[test]
+    fn function_statistics() {
+        assert_eq!(
+            FunctionStatistics::new(&[30, 10, 20, 60]),
+            FunctionStatistics {
+                min: 10,
+                avg: 30,
+                median: 25,
+                max: 60,
+                calls: 4,
+            }
+        );
+        assert_eq!(
+            FunctionStatistics::new(&[7]),
+            FunctionStatistics {
+                min: 7,
+                avg: 7,
+                median: 7,
+                max: 7,
+                calls: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn table_is_aligned() {
+        let rows = vec![
+            vec!["Test".to_string(), "Total".to_string()],
+            vec!["tests::test_transfer".to_string(), "12".to_string()],
+        ];
+
+        assert_eq!(
+            format_table(&rows),
+            indoc! {"
+                | Test                 | Total |
+                |----------------------|-------|
+                | tests::test_transfer | 12    |
+            "}
+        );
+    }
+
+    #[test]
+    fn report_with_functions() {
+        let mut report = GasReport {
+            tests: vec![(
+                "tests::test_mint".to_string(),
+                GasUsage {
+                    l1_gas: 5,
+                    l1_data_gas: 192,
+                    l2_gas: 0,
+                },
+            )],
+            ..GasReport::default()
+        };
+        report
+            .functions
+            .entry("NFT".to_string())
+            .or_default()
+            .insert("mint".to_string(), vec![3, 5]);
+
+        assert_eq!(
+            report.to_string(),
+            indoc! {"
+                | Test             | L1 gas | L1 data gas | L2 gas | Total |
+                |------------------|--------|-------------|--------|-------|
+                | tests::test_mint | 5      | 192         | 0      | 197   |
+
+                | NFT contract  |     |     |        |     |         |
+                |---------------|-----|-----|--------|-----|---------|
+                | Function Name | min | avg | median | max | # calls |
+                | mint          | 3   | 4   | 4      | 5   | 2       |
+            "}
+        );
+    }
+}
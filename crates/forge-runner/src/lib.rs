@@ -1,8 +1,8 @@
 use crate::build_trace_data::test_sierra_program_path::VersionedProgramPath;
 use crate::coverage_api::run_coverage;
 use crate::forge_config::{ExecutionDataToSave, ForgeConfig, TestRunnerConfig};
-use crate::fuzzer::RandomFuzzer;
-use crate::running::{run_fuzz_test, run_test};
+use crate::fuzzer::{CorpusManager, RandomFuzzer};
+use crate::running::{is_timed_out, run_fuzz_test, run_test, shrink_fuzz_test_failure};
 use crate::test_case_summary::TestCaseSummary;
 use anyhow::{anyhow, Result};
 use build_trace_data::save_trace_data;
@@ -20,16 +20,20 @@ use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::Arc;
 use test_case_summary::{AnyTestCaseSummary, Fuzzing};
-use tokio::sync::mpsc::{channel, Sender};
+use tokio::sync::Semaphore;
 use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
 use universal_sierra_compiler_api::AssembledProgramWithDebugInfo;
 
 pub mod build_trace_data;
 pub mod coverage_api;
+pub mod execution_trace;
 pub mod expected_result;
 pub mod forge_config;
+pub mod gas_report;
 pub mod package_tests;
 pub mod profiler_api;
+pub mod saved_state;
 pub mod test_case_summary;
 pub mod test_target_summary;
 
@@ -40,6 +44,13 @@ pub mod running;
 
 pub const CACHE_DIR: &str = ".snfoundry_cache";
 
+/// Name of a file with data of the test, `::` separators of the test path are replaced with `-`
+/// because `:` is not allowed in file names on Windows
+#[must_use]
+pub(crate) fn test_file_name(test_name: &str, extension: &str) -> String {
+    format!("{}.{extension}", test_name.replace("::", "-"))
+}
+
 const BUILTINS: [&str; 11] = [
     "Pedersen",
     "RangeCheck",
@@ -118,16 +129,23 @@ pub fn run_for_test_case(
     casm_program: Arc<AssembledProgramWithDebugInfo>,
     forge_config: Arc<ForgeConfig>,
     maybe_versioned_program_path: Arc<Option<VersionedProgramPath>>,
-    send: Sender<()>,
+    cancellation_token: CancellationToken,
+    test_slots: Arc<Semaphore>,
 ) -> JoinHandle<Result<AnyTestCaseSummary>> {
     if args.is_empty() {
         tokio::task::spawn(async move {
+            // only fuzz tests have a corpus to replay
+            if forge_config.test_runner_config.replay_fuzzer_corpus {
+                return Ok(AnyTestCaseSummary::Single(TestCaseSummary::Skipped {}));
+            }
+
             let res = run_test(
                 case,
                 casm_program,
                 forge_config.test_runner_config.clone(),
                 maybe_versioned_program_path,
-                send,
+                cancellation_token,
+                test_slots,
             )
             .await??;
             Ok(AnyTestCaseSummary::Single(res))
@@ -140,7 +158,8 @@ pub fn run_for_test_case(
                 casm_program,
                 forge_config.test_runner_config.clone(),
                 maybe_versioned_program_path,
-                send,
+                cancellation_token,
+                test_slots,
             )
             .await??;
             Ok(AnyTestCaseSummary::Fuzzing(res))
@@ -171,14 +190,16 @@ fn run_with_fuzzing(
     casm_program: Arc<AssembledProgramWithDebugInfo>,
     test_runner_config: Arc<TestRunnerConfig>,
     maybe_versioned_program_path: Arc<Option<VersionedProgramPath>>,
-    send: Sender<()>,
+    cancellation_token: CancellationToken,
+    test_slots: Arc<Semaphore>,
 ) -> JoinHandle<Result<TestCaseSummary<Fuzzing>>> {
     tokio::task::spawn(async move {
-        if send.is_closed() {
+        if cancellation_token.is_cancelled() {
             return Ok(TestCaseSummary::Skipped {});
         }
 
-        let (fuzzing_send, mut fuzzing_rec) = channel(1);
+        // Cancelled when any of the fuzzing runs fails or when the whole test run is cancelled
+        let fuzzing_token = cancellation_token.child_token();
         let arg_types = args.iter().map(argument_type_name).collect::<Vec<_>>();
 
         let (fuzzer_runs, fuzzer_seed) = match case.config.fuzzer_config {
@@ -193,19 +214,36 @@ fn run_with_fuzzing(
         };
         let mut fuzzer = RandomFuzzer::create(fuzzer_seed, fuzzer_runs, &arg_types)?;
 
+        let corpus = CorpusManager::new(&test_runner_config.cache_dir);
+        let corpus_entries = corpus.load(&case.name, fuzzer.serialized_arguments_len())?;
+
+        let generated_runs = if test_runner_config.replay_fuzzer_corpus {
+            0
+        } else {
+            fuzzer_runs.get()
+        };
+        let expected_runs = u32::try_from(corpus_entries.len())? + generated_runs;
+
+        if expected_runs == 0 {
+            return Ok(TestCaseSummary::Skipped {});
+        }
+
         let mut tasks = FuturesUnordered::new();
 
-        for _ in 1..=fuzzer_runs.get() {
-            let args = fuzzer.next_args();
+        // inputs that failed in previous runs are executed first
+        let inputs = corpus_entries
+            .into_iter()
+            .chain((0..generated_runs).map(|_| fuzzer.next_args()));
 
+        for args in inputs {
             tasks.push(run_fuzz_test(
                 args,
                 case.clone(),
                 casm_program.clone(),
                 test_runner_config.clone(),
                 maybe_versioned_program_path.clone(),
-                send.clone(),
-                fuzzing_send.clone(),
+                fuzzing_token.clone(),
+                test_slots.clone(),
             ));
         }
 
@@ -216,11 +254,34 @@ fn run_with_fuzzing(
             results.push(result.clone());
 
             if let TestCaseSummary::Failed { .. } = result {
-                fuzzing_rec.close();
+                fuzzing_token.cancel();
                 break;
             }
         }
 
+        let mut unminimized_arguments = None;
+        // runs used for minimizing have no timeout, so arguments of a run which timed out are kept
+        if let Some(TestCaseSummary::Failed { arguments, .. }) =
+            results.last().filter(|result| !is_timed_out(result))
+        {
+            let arguments = arguments.clone();
+            let minimized_failure = shrink_fuzz_test_failure(
+                arguments.clone(),
+                case.clone(),
+                casm_program.clone(),
+                test_runner_config.clone(),
+                maybe_versioned_program_path.clone(),
+                test_slots,
+            )
+            .await??;
+
+            if let Some(minimized_failure) = minimized_failure {
+                results.pop();
+                results.push(minimized_failure);
+                unminimized_arguments = Some(arguments);
+            }
+        }
+
         let runs = u32::try_from(
             results
                 .iter()
@@ -233,17 +294,22 @@ fn run_with_fuzzing(
                 .count(),
         )?;
 
-        let fuzzing_run_summary: TestCaseSummary<Fuzzing> = TestCaseSummary::from(results);
+        let fuzzing_run_summary: TestCaseSummary<Fuzzing> =
+            TestCaseSummary::from(results, fuzzer_seed, unminimized_arguments);
 
         if let TestCaseSummary::Passed { .. } = fuzzing_run_summary {
             // Because we execute tests parallel, it's possible to
             // get Passed after Skipped. To treat fuzzing a test as Passed
             // we have to ensure that all fuzzing subtests Passed
-            if runs != fuzzer_runs.get() {
+            if runs != expected_runs {
                 return Ok(TestCaseSummary::Skipped {});
             };
         };
 
+        if let TestCaseSummary::Failed { arguments, .. } = &fuzzing_run_summary {
+            corpus.save(&case.name, arguments)?;
+        }
+
         Ok(fuzzing_run_summary)
     })
 }
@@ -1,5 +1,8 @@
+use crate::BUILTINS;
 use cairo_lang_sierra::ids::GenericTypeId;
 use cairo_lang_sierra::program::ProgramArtifact;
+use camino::Utf8PathBuf;
+use std::collections::HashMap;
 use std::sync::Arc;
 use universal_sierra_compiler_api::AssembledProgramWithDebugInfo;
 
@@ -15,11 +18,43 @@ pub enum TestTargetLocation {
     Tests,
 }
 
+/// Determines how the test function is invoked by the runner
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum TestKind {
+    /// Test invoked as an external entry point
+    #[default]
+    External,
+    /// Test invoked as an L1 handler entry point, with `from_address` passed as the first argument
+    L1Handler,
+}
+
 #[derive(Debug, PartialEq, Clone, Default)]
 pub struct TestDetails {
     pub sierra_entry_point_statement_idx: usize,
     pub parameter_types: Vec<(GenericTypeId, i16)>,
     pub return_types: Vec<(GenericTypeId, i16)>,
+    /// Path and line of the test function, available when the package is compiled
+    /// with statements code locations debug info
+    pub source_location: Option<(Utf8PathBuf, u32)>,
+    /// Paths and lines of Sierra statements of the test target by statement index, shared by all its tests.
+    /// Empty when the package is compiled without statements code locations debug info
+    pub statement_locations: Arc<HashMap<usize, (Utf8PathBuf, u32)>>,
+}
+
+impl TestDetails {
+    /// Whether the test takes arguments generated by the fuzzer
+    #[must_use]
+    pub fn is_fuzzed(&self, test_kind: TestKind) -> bool {
+        let arguments = self
+            .parameter_types
+            .iter()
+            .filter(|(ty, _)| !BUILTINS.contains(&ty.0.as_str()))
+            .count();
+        // `from_address` of L1 handler tests is passed by the runner
+        let runner_arguments = usize::from(test_kind == TestKind::L1Handler);
+
+        arguments > runner_arguments
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -1,9 +1,10 @@
-use super::{TestCase, TestTarget};
+use super::{TestCase, TestKind, TestTarget};
 use crate::expected_result::{ExpectedPanicValue, ExpectedTestResult};
 use cheatnet::runtime_extensions::forge_config_extension::config::{
     Expected, RawForgeConfig, RawForkConfig, RawFuzzerConfig, RawShouldPanicConfig,
 };
 use conversions::serde::serialize::SerializeToFeltVec;
+use std::time::Duration;
 
 pub type TestTargetWithConfig = TestTarget<TestCaseConfig>;
 
@@ -17,7 +18,11 @@ pub struct TestCaseConfig {
     pub ignored: bool,
     pub expected_result: ExpectedTestResult,
     pub fork_config: Option<RawForkConfig>,
+    pub parallel_fork_config: Option<RawForkConfig>,
     pub fuzzer_config: Option<RawFuzzerConfig>,
+    pub tags: Vec<String>,
+    pub test_kind: TestKind,
+    pub timeout: Option<Duration>,
 }
 
 impl From<RawForgeConfig> for TestCaseConfig {
@@ -27,7 +32,18 @@ impl From<RawForgeConfig> for TestCaseConfig {
             ignored: value.ignore.is_some_and(|v| v.is_ignored),
             expected_result: value.should_panic.into(),
             fork_config: value.fork,
+            parallel_fork_config: value.parallel_fork,
             fuzzer_config: value.fuzzer,
+            tags: value
+                .tag
+                .map(|v| v.tags.into_iter().map(Into::into).collect())
+                .unwrap_or_default(),
+            test_kind: if value.l1_handler.is_some_and(|v| v.is_l1_handler) {
+                TestKind::L1Handler
+            } else {
+                TestKind::External
+            },
+            timeout: value.timeout.map(|v| Duration::from_millis(v.ms)),
         }
     }
 }
@@ -1,7 +1,8 @@
-use super::{TestCase, TestTarget};
+use super::{TestCase, TestKind, TestTarget};
 use crate::expected_result::ExpectedTestResult;
 use cheatnet::runtime_extensions::forge_config_extension::config::RawFuzzerConfig;
 use starknet_api::block::BlockNumber;
+use std::time::Duration;
 use url::Url;
 
 pub type TestTargetWithResolvedConfig = TestTarget<TestCaseResolvedConfig>;
@@ -12,10 +13,14 @@ pub type TestCaseWithResolvedConfig = TestCase<TestCaseResolvedConfig>;
 pub struct ResolvedForkConfig {
     pub url: Url,
     pub block_number: BlockNumber,
+    /// Whether the block number was resolved from a tag, e.g. `latest`.
+    /// Only cached data of such forks expires, data of fixed blocks never changes
+    pub block_from_tag: bool,
 }
 
 /// Test case with config that has been resolved, that is
 ///     `#[fork("name")]` -> url and block id
+///     `#[parallel_fork("name")]` -> url and block id of the forked run
 ///     fetches block number
 #[derive(Debug, Clone, PartialEq)]
 pub struct TestCaseResolvedConfig {
@@ -23,5 +28,10 @@ pub struct TestCaseResolvedConfig {
     pub ignored: bool,
     pub expected_result: ExpectedTestResult,
     pub fork_config: Option<ResolvedForkConfig>,
+    /// Whether the test is run both with and without the fork, see `#[parallel_fork]`
+    pub parallel_fork: bool,
     pub fuzzer_config: Option<RawFuzzerConfig>,
+    pub tags: Vec<String>,
+    pub test_kind: TestKind,
+    pub timeout: Option<Duration>,
 }
@@ -1,8 +1,13 @@
 use crate::test_case_summary::{AnyTestCaseSummary, FuzzingStatistics, TestCaseSummary};
+use camino::Utf8PathBuf;
 use cheatnet::runtime_extensions::call_to_blockifier_runtime_extension::rpc::UsedResources;
 use console::style;
 
-pub fn print_test_result(any_test_result: &AnyTestCaseSummary, print_detailed_resources: bool) {
+pub fn print_test_result(
+    any_test_result: &AnyTestCaseSummary,
+    print_detailed_resources: bool,
+    source_location: Option<&(Utf8PathBuf, u32)>,
+) {
     if any_test_result.is_skipped() {
         return;
     }
@@ -15,7 +20,7 @@ pub fn print_test_result(any_test_result: &AnyTestCaseSummary, print_detailed_re
     if let AnyTestCaseSummary::Fuzzing(test_result) = any_test_result {
         fuzzer_report = match test_result {
             TestCaseSummary::Passed {
-                test_statistics: FuzzingStatistics { runs },
+                test_statistics: FuzzingStatistics { runs, .. },
                 gas_info,
                 ..
             } => Some(format!(
@@ -24,9 +29,23 @@ pub fn print_test_result(any_test_result: &AnyTestCaseSummary, print_detailed_re
             )),
             TestCaseSummary::Failed {
                 arguments,
-                test_statistics: FuzzingStatistics { runs },
+                test_statistics:
+                    FuzzingStatistics {
+                        runs,
+                        seed,
+                        unminimized_arguments,
+                    },
                 ..
-            } => Some(format!(" (runs: {runs}, arguments: {arguments:?})")),
+            } => {
+                // the seed reproduces the arguments from before minimizing
+                let unminimized_arguments = unminimized_arguments
+                    .as_ref()
+                    .map(|arguments| format!(", minimized from: {arguments:?}"))
+                    .unwrap_or_default();
+                Some(format!(
+                    " (runs: {runs}, arguments: {arguments:?}{unminimized_arguments}, seed: {seed})"
+                ))
+            }
             _ => None,
         };
     }
@@ -46,7 +65,14 @@ pub fn print_test_result(any_test_result: &AnyTestCaseSummary, print_detailed_re
         _ => String::new(),
     };
 
-    println!("{result_header} {result_name}{fuzzer_report}{gas_usage}{used_resources}{result_msg}");
+    let failure_location = match source_location {
+        Some((path, line)) if any_test_result.is_failed() => {
+            format!("\n\ntest failed at {path}:{line}")
+        }
+        _ => String::new(),
+    };
+
+    println!("{result_header} {result_name}{fuzzer_report}{gas_usage}{used_resources}{result_msg}{failure_location}");
 }
 
 fn format_detailed_resources(used_resources: &UsedResources) -> String {
@@ -107,6 +133,9 @@ fn result_header(any_test_result: &AnyTestCaseSummary) -> String {
     if any_test_result.is_passed() {
         return format!("[{}]", style("PASS").green());
     }
+    if any_test_result.is_paired_divergence() {
+        return format!("[{}]", style("DIVERGED").red());
+    }
     if any_test_result.is_failed() {
         return format!("[{}]", style("FAIL").red());
     }
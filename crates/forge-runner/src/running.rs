@@ -1,8 +1,14 @@
 use crate::build_trace_data::test_sierra_program_path::VersionedProgramPath;
+use crate::execution_trace::{build_execution_trace, save_execution_trace, ExecutionTraceEntry};
 use crate::forge_config::{RuntimeConfig, TestRunnerConfig};
+use crate::fuzzer::shrink;
 use crate::gas::calculate_used_gas;
-use crate::package_tests::with_config_resolved::{ResolvedForkConfig, TestCaseWithResolvedConfig};
-use crate::test_case_summary::{Single, TestCaseSummary};
+use crate::package_tests::with_config_resolved::{
+    ResolvedForkConfig, TestCaseResolvedConfig, TestCaseWithResolvedConfig,
+};
+use crate::package_tests::TestKind;
+use crate::saved_state::SavedState;
+use crate::test_case_summary::{GasUsage, Single, TestCaseSummary};
 use anyhow::{bail, ensure, Result};
 use blockifier::execution::entry_point::EntryPointExecutionContext;
 use blockifier::state::cached_state::CachedState;
@@ -26,14 +32,17 @@ use entry_code::create_entry_code;
 use hints::{hints_by_representation, hints_to_params};
 use runtime::starknet::context::{build_context, set_max_steps};
 use runtime::{ExtendedRuntime, StarknetRuntime};
+use shared::rpc::RetryConfig;
 use std::cell::RefCell;
 use std::default::Default;
 use std::marker::PhantomData;
 use std::rc::Rc;
 use std::sync::Arc;
+use std::time::Duration;
 use syscall_handler::build_syscall_handler;
-use tokio::sync::mpsc::Sender;
+use tokio::sync::Semaphore;
 use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
 use universal_sierra_compiler_api::AssembledProgramWithDebugInfo;
 
 mod casm;
@@ -49,85 +58,300 @@ pub fn run_test(
     casm_program: Arc<AssembledProgramWithDebugInfo>,
     test_runner_config: Arc<TestRunnerConfig>,
     maybe_versioned_program_path: Arc<Option<VersionedProgramPath>>,
-    send: Sender<()>,
+    cancellation_token: CancellationToken,
+    test_slots: Arc<Semaphore>,
 ) -> JoinHandle<Result<TestCaseSummary<Single>>> {
-    tokio::task::spawn_blocking(move || {
+    let timeout = case.config.timeout.or(test_runner_config.test_timeout);
+    let name = case.name.clone();
+    let cancellation_token = cancellation_token.child_token();
+    let test_cancellation_token = cancellation_token.clone();
+
+    let run = move || {
         // Due to the inability of spawn_blocking to be abruptly cancelled,
-        // a channel is used to receive information indicating
-        // that the execution of the task is no longer necessary.
-        if send.is_closed() {
+        // the token is checked before and after running the test case
+        // to find out whether its result is still needed.
+        if cancellation_token.is_cancelled() {
             return Ok(TestCaseSummary::Skipped {});
         }
-        let run_result = run_test_case(
+
+        let mut run_result = run_test_case(
             vec![],
             &case,
             &casm_program,
             &RuntimeConfig::from(&test_runner_config),
         );
 
-        // TODO: code below is added to fix snforge tests
-        // remove it after improve exit-first tests
-        // issue #1043
-        if send.is_closed() {
+        if cancellation_token.is_cancelled() {
             return Ok(TestCaseSummary::Skipped {});
         }
 
-        extract_test_case_summary(
+        if let Some(execution_trace) = take_execution_trace(&mut run_result) {
+            save_execution_trace(&test_runner_config.cache_dir, &case.name, &execution_trace)?;
+        }
+        let saved_state = take_saved_state(&mut run_result);
+
+        let summary = extract_test_case_summary(
             run_result,
             &case,
             vec![],
             &test_runner_config.contracts_data,
             &maybe_versioned_program_path,
-        )
+        )?;
+
+        // state of failed tests is not saved, as their setup did not finish
+        if let (Some(path), Some(saved_state), TestCaseSummary::Passed { .. }) =
+            (&test_runner_config.save_state, saved_state, &summary)
+        {
+            saved_state.save(path)?;
+        }
+
+        if !case.config.parallel_fork {
+            return Ok(summary);
+        }
+
+        let local_summary = run_test_without_fork(
+            &case,
+            &casm_program,
+            &test_runner_config,
+            &maybe_versioned_program_path,
+        )?;
+
+        if cancellation_token.is_cancelled() {
+            return Ok(TestCaseSummary::Skipped {});
+        }
+
+        Ok(summary.compare_with_local_run(local_summary))
+    };
+
+    tokio::task::spawn(run_in_slot_within(
+        run,
+        test_slots,
+        timeout,
+        name,
+        vec![],
+        test_cancellation_token,
+    ))
+}
+
+/// Runs a `#[parallel_fork]` test without the fork, so its result can be compared with the forked run
+fn run_test_without_fork(
+    case: &TestCaseWithResolvedConfig,
+    casm_program: &AssembledProgramWithDebugInfo,
+    test_runner_config: &TestRunnerConfig,
+    maybe_versioned_program_path: &Option<VersionedProgramPath>,
+) -> Result<TestCaseSummary<Single>> {
+    let local_case = TestCaseWithResolvedConfig {
+        config: TestCaseResolvedConfig {
+            fork_config: None,
+            ..case.config.clone()
+        },
+        ..case.clone()
+    };
+
+    let run_result = run_test_case(
+        vec![],
+        &local_case,
+        casm_program,
+        &RuntimeConfig::from(test_runner_config),
+    );
+
+    extract_test_case_summary(
+        run_result,
+        &local_case,
+        vec![],
+        &test_runner_config.contracts_data,
+        maybe_versioned_program_path,
+    )
+}
+
+const TIMED_OUT_MSG: &str = "Test timed out after";
+
+/// Runs the test on a blocking thread once one of the test slots is free,
+/// reports it as failed if it does not finish in the given time.
+/// `spawn_blocking` tasks can't be stopped, so a test which timed out keeps running on its blocking
+/// thread until it finishes or snforge exits. Its slot is freed right away, so it does not hold up
+/// the remaining tests, and its result is discarded thanks to the cancelled token
+async fn run_in_slot_within(
+    run: impl FnOnce() -> Result<TestCaseSummary<Single>> + Send + 'static,
+    test_slots: Arc<Semaphore>,
+    timeout: Option<Duration>,
+    name: String,
+    arguments: Vec<Felt252>,
+    cancellation_token: CancellationToken,
+) -> Result<TestCaseSummary<Single>> {
+    let _slot = test_slots.acquire_owned().await?;
+    let handle = tokio::task::spawn_blocking(run);
+
+    let Some(timeout) = timeout else {
+        return handle.await?;
+    };
+
+    if let Ok(result) = tokio::time::timeout(timeout, handle).await {
+        return result?;
+    }
+
+    cancellation_token.cancel();
+
+    Ok(TestCaseSummary::Failed {
+        name,
+        msg: Some(format!("\n\t{TIMED_OUT_MSG} {}ms", timeout.as_millis())),
+        arguments,
+        test_statistics: (),
     })
 }
 
+/// Whether the test failed because it did not finish in time
+#[must_use]
+pub(crate) fn is_timed_out(summary: &TestCaseSummary<Single>) -> bool {
+    match summary {
+        TestCaseSummary::Failed { msg: Some(msg), .. } => {
+            msg.trim_start().starts_with(TIMED_OUT_MSG)
+        }
+        _ => false,
+    }
+}
+
 pub(crate) fn run_fuzz_test(
     args: Vec<Felt252>,
     case: Arc<TestCaseWithResolvedConfig>,
     casm_program: Arc<AssembledProgramWithDebugInfo>,
     test_runner_config: Arc<TestRunnerConfig>,
     maybe_versioned_program_path: Arc<Option<VersionedProgramPath>>,
-    send: Sender<()>,
-    fuzzing_send: Sender<()>,
+    cancellation_token: CancellationToken,
+    test_slots: Arc<Semaphore>,
 ) -> JoinHandle<Result<TestCaseSummary<Single>>> {
-    tokio::task::spawn_blocking(move || {
+    // timeout applies to every fuzzer run separately
+    let timeout = case.config.timeout.or(test_runner_config.test_timeout);
+    let name = case.name.clone();
+    let arguments = args.clone();
+    let cancellation_token = cancellation_token.child_token();
+    let run_cancellation_token = cancellation_token.clone();
+
+    let run = move || {
         // Due to the inability of spawn_blocking to be abruptly cancelled,
-        // a channel is used to receive information indicating
-        // that the execution of the task is no longer necessary.
-        if send.is_closed() | fuzzing_send.is_closed() {
+        // the token is checked before and after running the test case
+        // to find out whether its result is still needed.
+        if cancellation_token.is_cancelled() {
             return Ok(TestCaseSummary::Skipped {});
         }
 
-        let run_result = run_test_case(
+        let mut run_result = run_test_case(
             args.clone(),
             &case,
             &casm_program,
             &Arc::new(RuntimeConfig::from(&test_runner_config)),
         );
 
-        // TODO: code below is added to fix snforge tests
-        // remove it after improve exit-first tests
-        // issue #1043
-        if send.is_closed() {
+        if cancellation_token.is_cancelled() {
             return Ok(TestCaseSummary::Skipped {});
         }
 
-        extract_test_case_summary(
+        let execution_trace = take_execution_trace(&mut run_result);
+
+        let summary = extract_test_case_summary(
             run_result,
             &case,
             args,
             &test_runner_config.contracts_data,
             &maybe_versioned_program_path,
-        )
+        )?;
+
+        // only traces of failing inputs are saved, as fuzz tests run many times
+        if let (Some(execution_trace), TestCaseSummary::Failed { .. }) = (execution_trace, &summary)
+        {
+            save_execution_trace(&test_runner_config.cache_dir, &case.name, &execution_trace)?;
+        }
+
+        Ok(summary)
+    };
+
+    tokio::task::spawn(run_in_slot_within(
+        run,
+        test_slots,
+        timeout,
+        name,
+        arguments,
+        run_cancellation_token,
+    ))
+}
+
+/// Reruns a failed fuzz test with simpler arguments.
+/// Returns the failure for minimized arguments if any of them still made the test fail
+pub(crate) fn shrink_fuzz_test_failure(
+    arguments: Vec<Felt252>,
+    case: Arc<TestCaseWithResolvedConfig>,
+    casm_program: Arc<AssembledProgramWithDebugInfo>,
+    test_runner_config: Arc<TestRunnerConfig>,
+    maybe_versioned_program_path: Arc<Option<VersionedProgramPath>>,
+    test_slots: Arc<Semaphore>,
+) -> JoinHandle<Result<Option<TestCaseSummary<Single>>>> {
+    tokio::task::spawn(async move {
+        let _slot = test_slots.acquire_owned().await?;
+        tokio::task::spawn_blocking(move || {
+            let runtime_config = RuntimeConfig::from(&test_runner_config);
+            let mut minimized_failure = None;
+
+            shrink(
+                arguments,
+                test_runner_config.fuzzer_shrink_iterations,
+                |args| {
+                    let mut run_result =
+                        run_test_case(args.to_vec(), &case, &casm_program, &runtime_config);
+                    let execution_trace = take_execution_trace(&mut run_result);
+
+                    match extract_test_case_summary(
+                        run_result,
+                        &case,
+                        args.to_vec(),
+                        &test_runner_config.contracts_data,
+                        &maybe_versioned_program_path,
+                    ) {
+                        Ok(summary @ TestCaseSummary::Failed { .. }) => {
+                            minimized_failure = Some((summary, execution_trace));
+                            true
+                        }
+                        _ => false,
+                    }
+                },
+            );
+
+            let Some((summary, execution_trace)) = minimized_failure else {
+                return Ok(None);
+            };
+            if let Some(execution_trace) = execution_trace {
+                save_execution_trace(&test_runner_config.cache_dir, &case.name, &execution_trace)?;
+            }
+
+            Ok(Some(summary))
+        })
+        .await?
     })
 }
 
 pub struct RunResultWithInfo {
     pub(crate) run_result: Result<RunResult, RunnerError>,
     pub(crate) call_trace: Rc<RefCell<CallTrace>>,
-    pub(crate) gas_used: u128,
+    pub(crate) gas_used: GasUsage,
     pub(crate) used_resources: UsedResources,
+    pub(crate) execution_trace: Option<Vec<ExecutionTraceEntry>>,
+    /// State left by the test, only collected with `--save-state`
+    pub(crate) saved_state: Option<SavedState>,
+}
+
+fn take_execution_trace(
+    run_result: &mut Result<RunResultWithInfo>,
+) -> Option<Vec<ExecutionTraceEntry>> {
+    run_result
+        .as_mut()
+        .ok()
+        .and_then(|result| result.execution_trace.take())
+}
+
+fn take_saved_state(run_result: &mut Result<RunResultWithInfo>) -> Option<SavedState> {
+    run_result
+        .as_mut()
+        .ok()
+        .and_then(|result| result.saved_state.take())
 }
 
 #[allow(clippy::too_many_lines)]
@@ -142,7 +366,20 @@ pub fn run_test_case(
         "\n\t`available_gas` attribute was incorrectly configured. Make sure you use scarb >= 2.4.4\n"
     );
 
+    let args = match case.config.test_kind {
+        TestKind::External => args,
+        TestKind::L1Handler => {
+            let from_address =
+                Felt252::from_hex(cheatnet_constants::TEST_L1_HANDLER_FROM_ADDRESS).unwrap();
+            [vec![from_address], args].concat()
+        }
+    };
+
     let (entry_code, builtins) = create_entry_code(args, &case.test_details, casm_program);
+    let header_size: usize = entry_code
+        .iter()
+        .map(|instruction| instruction.body.op_size())
+        .sum();
 
     let assembled_program = get_assembled_program(casm_program, entry_code);
 
@@ -153,9 +390,17 @@ pub fn run_test_case(
         dict_state_reader: cheatnet_constants::build_testing_state(),
         fork_state_reader: get_fork_state_reader(
             runtime_config.cache_dir,
+            runtime_config.fork_cache_ttl,
+            runtime_config.rpc_retry_config,
             &case.config.fork_config,
         )?,
     };
+    if let Some(initial_state) = runtime_config.initial_state {
+        initial_state.apply(
+            &mut state_reader.dict_state_reader,
+            runtime_config.contracts_data,
+        )?;
+    }
     let block_info = state_reader.get_block_info()?;
     let chain_id = state_reader.get_chain_id()?;
 
@@ -172,12 +417,20 @@ pub fn run_test_case(
         &mut execution_resources,
         &mut context,
         &case.test_details.parameter_types,
+        case.config.test_kind,
     );
 
     let mut cheatnet_state = CheatnetState {
         block_info,
         ..Default::default()
     };
+    cheatnet_state
+        .trace_data
+        .current_call_stack
+        .top()
+        .borrow_mut()
+        .entry_point
+        .entry_point_type = syscall_handler.call.entry_point_type;
     cheatnet_state.trace_data.is_vm_trace_needed = runtime_config.is_vm_trace_needed;
 
     let cheatable_runtime = ExtendedRuntime {
@@ -240,11 +493,28 @@ pub fn run_test_case(
 
                 update_top_call_vm_trace(&mut forge_runtime, &runner);
 
-                Ok((gas_counter, runner.relocated_memory, value))
+                let execution_trace = runtime_config.save_execution_trace.then(|| {
+                    build_execution_trace(
+                        runner.relocated_trace.as_ref().unwrap(),
+                        &runner.relocated_memory,
+                        casm_program,
+                        header_size,
+                        &case.test_details.statement_locations,
+                    )
+                });
+
+                Ok((gas_counter, runner.relocated_memory, value, execution_trace))
             }
             Err(err) => Err(RunnerError::CairoRunError(err)),
         };
 
+    let (run_result, execution_trace) = match run_result {
+        Ok((gas_counter, memory, value, execution_trace)) => {
+            (Ok((gas_counter, memory, value)), execution_trace)
+        }
+        Err(err) => (Err(err), None),
+    };
+
     let call_trace_ref = get_call_trace_ref(&mut forge_runtime);
 
     update_top_call_execution_resources(&mut forge_runtime);
@@ -256,6 +526,11 @@ pub fn run_test_case(
         &mut cached_state,
         used_resources.clone(),
     )?;
+    let saved_state = if runtime_config.save_state {
+        Some(SavedState::from_state_diff(cached_state.to_state_diff()?))
+    } else {
+        None
+    };
 
     Ok(RunResultWithInfo {
         run_result: run_result.map(|(gas_counter, memory, value)| RunResult {
@@ -268,6 +543,8 @@ pub fn run_test_case(
         gas_used: gas,
         used_resources,
         call_trace: call_trace_ref,
+        execution_trace,
+        saved_state,
     })
 }
 
@@ -317,13 +594,27 @@ fn extract_test_case_summary(
 
 fn get_fork_state_reader(
     cache_dir: &Utf8Path,
+    cache_ttl: Option<Duration>,
+    retry_config: RetryConfig,
     fork_config: &Option<ResolvedForkConfig>,
 ) -> Result<Option<ForkStateReader>> {
     fork_config
         .as_ref()
-        .map(|ResolvedForkConfig { url, block_number }| {
-            ForkStateReader::new(url.clone(), *block_number, cache_dir)
-        })
+        .map(
+            |ResolvedForkConfig {
+                 url,
+                 block_number,
+                 block_from_tag,
+             }| {
+                ForkStateReader::new(
+                    url.clone(),
+                    *block_number,
+                    cache_dir,
+                    cache_ttl.filter(|_| *block_from_tag),
+                    retry_config,
+                )
+            },
+        )
         .transpose()
 }
 
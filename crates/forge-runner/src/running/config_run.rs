@@ -3,7 +3,10 @@ use super::{
     entry_code::create_entry_code,
     hints::{hints_by_representation, hints_to_params},
 };
-use crate::{package_tests::TestDetails, running::build_syscall_handler};
+use crate::{
+    package_tests::{TestDetails, TestKind},
+    running::build_syscall_handler,
+};
 use anyhow::Result;
 use blockifier::{
     blockifier::block::{BlockInfo, GasPrices},
@@ -92,6 +95,7 @@ pub fn run_config_pass(
         &mut execution_resources,
         &mut context,
         &test_details.parameter_types,
+        TestKind::External,
     );
 
     let mut config = RawForgeConfig::default();
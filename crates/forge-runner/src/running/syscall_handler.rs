@@ -1,3 +1,4 @@
+use crate::package_tests::TestKind;
 use blockifier::{
     execution::{
         entry_point::EntryPointExecutionContext, execution_utils::ReadOnlySegments,
@@ -10,6 +11,7 @@ use cairo_lang_sierra::extensions::NoGenericArgsGenericType;
 use cairo_lang_sierra::{extensions::segment_arena::SegmentArenaType, ids::GenericTypeId};
 use cairo_vm::{types::relocatable::Relocatable, vm::runners::cairo_runner::ExecutionResources};
 use cheatnet::constants::build_test_entry_point;
+use starknet_api::deprecated_contract_class::EntryPointType;
 use std::collections::HashMap;
 use std::default::Default;
 
@@ -19,6 +21,7 @@ pub fn build_syscall_handler<'a>(
     execution_resources: &'a mut ExecutionResources,
     context: &'a mut EntryPointExecutionContext,
     test_param_types: &[(GenericTypeId, i16)],
+    test_kind: TestKind,
 ) -> SyscallHintProcessor<'a> {
     // Segment arena is allocated conditionally, so segment index is automatically moved (+2 segments)
     let segment_index = if test_param_types
@@ -30,7 +33,11 @@ pub fn build_syscall_handler<'a>(
         14
     };
 
-    let entry_point = build_test_entry_point();
+    let mut entry_point = build_test_entry_point();
+    entry_point.entry_point_type = match test_kind {
+        TestKind::External => EntryPointType::External,
+        TestKind::L1Handler => EntryPointType::L1Handler,
+    };
 
     SyscallHintProcessor::new(
         blockifier_state,
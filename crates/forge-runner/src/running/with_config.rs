@@ -2,13 +2,14 @@ use crate::{
     function_args,
     package_tests::{
         raw::TestTargetRaw,
-        with_config::{TestCaseWithConfig, TestTargetWithConfig},
-        TestDetails,
+        with_config::{TestCaseConfig, TestCaseWithConfig, TestTargetWithConfig},
+        TestDetails, TestKind,
     },
     running::config_run::run_config_pass,
 };
-use anyhow::{anyhow, Result};
+use anyhow::{anyhow, ensure, Result};
 use cairo_lang_sierra::{
+    debug_info::Annotations,
     extensions::core::{CoreLibfunc, CoreType},
     ids::ConcreteTypeId,
     program::{GenFunction, StatementIdx, TypeDeclaration},
@@ -17,9 +18,12 @@ use cairo_lang_sierra::{
 use cairo_lang_sierra_type_size::get_type_size_map;
 use cairo_lang_utils::unordered_hash_map::UnorderedHashMap;
 use cairo_vm::Felt252;
+use camino::Utf8PathBuf;
 use std::{collections::HashMap, sync::Arc};
 use universal_sierra_compiler_api::compile_sierra_to_casm;
 
+const CODE_LOCATIONS_NAMESPACE: &str = "github.com/software-mansion/cairo-coverage";
+
 pub fn test_target_with_config(test_target_raw: TestTargetRaw) -> Result<TestTargetWithConfig> {
     macro_rules! by_id {
         ($field:ident) => {{
@@ -55,20 +59,34 @@ pub fn test_target_with_config(test_target_raw: TestTargetRaw) -> Result<TestTar
         .as_ref()
         .and_then(|info| info.executables.get("snforge_internal_test_executable"))
         .unwrap_or(&default_executables);
+    let statement_locations = Arc::new(
+        debug_info
+            .as_ref()
+            .map(|info| find_statement_locations(&info.annotations))
+            .unwrap_or_default(),
+    );
 
     let test_cases = executables
         .iter()
         .map(|case| -> Result<TestCaseWithConfig> {
             let func = funcs[&case.id];
 
-            let test_details = build_test_details(func, &type_declarations, &type_size_map);
+            let mut test_details = build_test_details(func, &type_declarations, &type_size_map);
+            test_details.source_location = statement_locations.get(&func.entry_point.0).cloned();
+            test_details.statement_locations = statement_locations.clone();
             let args = prepare_args(func, &type_declarations);
 
             let raw_config = run_config_pass(args, &test_details, &casm_program)?;
+            let config: TestCaseConfig = raw_config.into();
+            let name: String = case.debug_name.clone().unwrap().into();
+
+            if config.test_kind == TestKind::L1Handler {
+                ensure_l1_handler_signature(&name, func, &type_declarations)?;
+            }
 
             Ok(TestCaseWithConfig {
-                config: raw_config.into(),
-                name: case.debug_name.clone().unwrap().into(),
+                config,
+                name,
                 test_details,
             })
         })
@@ -102,9 +120,64 @@ fn build_test_details(
         sierra_entry_point_statement_idx: func.entry_point.0,
         parameter_types: map_types(&func.signature.param_types),
         return_types: map_types(&func.signature.ret_types),
+        source_location: None,
+        statement_locations: Arc::default(),
     }
 }
 
+/// Reads locations of statements from the code locations debug info emitted by the compiler
+/// when `unstable-add-statements-code-locations-debug-info` is enabled
+fn find_statement_locations(annotations: &Annotations) -> HashMap<usize, (Utf8PathBuf, u32)> {
+    let Some(locations) = annotations
+        .get(CODE_LOCATIONS_NAMESPACE)
+        .and_then(|namespace| namespace.get("statements_code_locations"))
+        .and_then(serde_json::Value::as_object)
+    else {
+        return HashMap::new();
+    };
+    let current_dir = std::env::current_dir()
+        .ok()
+        .and_then(|current_dir| Utf8PathBuf::try_from(current_dir).ok());
+
+    locations
+        .iter()
+        .filter_map(|(statement_idx, locations)| {
+            let location = locations.as_array()?.first()?;
+
+            let path = Utf8PathBuf::from(location.get(0)?.as_str()?);
+            // Lines in debug info are 0-based
+            let line =
+                u32::try_from(location.get(1)?.get("start")?.get("line")?.as_u64()?).ok()? + 1;
+
+            let path = current_dir
+                .as_ref()
+                .and_then(|current_dir| path.strip_prefix(current_dir).ok().map(Into::into))
+                .unwrap_or(path);
+
+            Some((statement_idx.parse().ok()?, (path, line)))
+        })
+        .collect()
+}
+
+/// L1 handlers receive the address of the L1 sender as the first argument
+fn ensure_l1_handler_signature(
+    name: &str,
+    func: &GenFunction<StatementIdx>,
+    type_declarations: &HashMap<u64, &TypeDeclaration>,
+) -> Result<()> {
+    let first_arg = function_args(func, type_declarations)
+        .into_iter()
+        .next()
+        .map(|arg| arg.generic_id.0);
+
+    ensure!(
+        first_arg.as_deref() == Some("felt252"),
+        "Test {name} marked with #[as_l1_handler] must take `from_address: felt252` as the first parameter"
+    );
+
+    Ok(())
+}
+
 fn prepare_args(
     func: &GenFunction<StatementIdx>,
     type_declarations: &HashMap<u64, &TypeDeclaration>,
@@ -0,0 +1,137 @@
+use anyhow::{anyhow, Context, Result};
+use blockifier::execution::contract_class::{ContractClass, ContractClassV1};
+use blockifier::state::cached_state::CommitmentStateDiff;
+use cairo_vm::Felt252;
+use camino::Utf8Path;
+use cheatnet::runtime_extensions::forge_runtime_extension::contracts_data::ContractsData;
+use runtime::starknet::state::DictStateReader;
+use serde::{Deserialize, Serialize};
+use starknet_api::core::{ClassHash, ContractAddress, Nonce};
+use starknet_api::state::StorageKey;
+use std::fs;
+
+/// Changes of the state made by a test, saved with `--save-state`
+/// and used as the initial state of tests run with `--load-state`
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SavedState {
+    pub storage: Vec<(ContractAddress, StorageKey, Felt252)>,
+    pub nonces: Vec<(ContractAddress, Nonce)>,
+    pub deployed_contracts: Vec<(ContractAddress, ClassHash)>,
+    /// Classes are not saved, they are loaded from artifacts of the package by their hashes
+    pub declared_classes: Vec<ClassHash>,
+}
+
+impl SavedState {
+    #[must_use]
+    pub fn from_state_diff(state_diff: CommitmentStateDiff) -> Self {
+        Self {
+            storage: state_diff
+                .storage_updates
+                .into_iter()
+                .flat_map(|(contract_address, updates)| {
+                    updates
+                        .into_iter()
+                        .map(move |(key, value)| (contract_address, key, value))
+                })
+                .collect(),
+            nonces: state_diff.address_to_nonce.into_iter().collect(),
+            deployed_contracts: state_diff.address_to_class_hash.into_iter().collect(),
+            declared_classes: state_diff
+                .class_hash_to_compiled_class_hash
+                .into_keys()
+                .collect(),
+        }
+    }
+
+    pub fn load(path: &Utf8Path) -> Result<Self> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read state from {path}"))?;
+
+        serde_json::from_str(&content).with_context(|| format!("Failed to parse state from {path}"))
+    }
+
+    pub fn save(&self, path: &Utf8Path) -> Result<()> {
+        fs::write(path, serde_json::to_string(self)?)
+            .with_context(|| format!("Failed to write state to {path}"))
+    }
+
+    /// Writes the saved changes to the state the test starts with
+    pub fn apply(
+        &self,
+        state_reader: &mut DictStateReader,
+        contracts_data: &ContractsData,
+    ) -> Result<()> {
+        for (contract_address, key, value) in &self.storage {
+            state_reader
+                .storage_view
+                .insert((*contract_address, *key), *value);
+        }
+        for (contract_address, nonce) in &self.nonces {
+            state_reader
+                .address_to_nonce
+                .insert(*contract_address, *nonce);
+        }
+        for (contract_address, class_hash) in &self.deployed_contracts {
+            state_reader
+                .address_to_class_hash
+                .insert(*contract_address, *class_hash);
+        }
+
+        for class_hash in &self.declared_classes {
+            let artifacts = contracts_data
+                .get_contract_name(class_hash)
+                .and_then(|contract_name| contracts_data.get_artifacts(contract_name))
+                .ok_or_else(|| {
+                    anyhow!(
+                        "Class with hash {:#x} declared in the loaded state is not a contract of the package",
+                        class_hash.0
+                    )
+                })?;
+            let contract_class = ContractClassV1::try_from_json_string(&artifacts.casm)
+                .context("Failed to read contract class from json")?;
+
+            state_reader
+                .class_hash_to_class
+                .insert(*class_hash, ContractClass::V1(contract_class));
+            // Compiled class hashes of declared classes are always set to 0, see `declare` cheatcode
+            state_reader
+                .class_hash_to_compiled_class_hash
+                .insert(*class_hash, Default::default());
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SavedState;
+    use cairo_vm::Felt252;
+    use camino::Utf8PathBuf;
+    use starknet_api::core::{ClassHash, ContractAddress, Nonce, PatriciaKey};
+    use starknet_api::state::StorageKey;
+    use tempfile::TempDir;
+
+    #[test]
+    fn save_and_load() {
+        let contract_address =
+            ContractAddress(PatriciaKey::try_from(Felt252::from(0x123_u16)).unwrap());
+        let state = SavedState {
+            storage: vec![(
+                contract_address,
+                StorageKey(PatriciaKey::try_from(Felt252::from(0x1_u8)).unwrap()),
+                Felt252::from(100_u8),
+            )],
+            nonces: vec![(contract_address, Nonce(Felt252::ONE))],
+            deployed_contracts: vec![(contract_address, ClassHash(Felt252::from(0x456_u16)))],
+            declared_classes: vec![ClassHash(Felt252::from(0x456_u16))],
+        };
+
+        let temp_dir = TempDir::new().unwrap();
+        let path = Utf8PathBuf::try_from(temp_dir.path().join("state.json")).unwrap();
+
+        state.save(&path).unwrap();
+
+        assert_eq!(SavedState::load(&path).unwrap(), state);
+    }
+}
@@ -54,9 +54,36 @@ impl GasStatistics {
     }
 }
 
+/// Gas used by a single test run, split by the resource it is charged for
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct GasUsage {
+    pub l1_gas: u128,
+    pub l1_data_gas: u128,
+    /// The blockifier version used by forge charges computation in L1 gas, so this is always 0 for now
+    pub l2_gas: u128,
+}
+
+impl GasUsage {
+    #[must_use]
+    pub fn total(&self) -> u128 {
+        self.l1_gas + self.l1_data_gas + self.l2_gas
+    }
+}
+
+impl std::fmt::Display for GasUsage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.total())
+    }
+}
+
 #[derive(Debug, PartialEq, Clone)]
 pub struct FuzzingStatistics {
     pub runs: usize,
+    /// Seed used by the fuzzer, allows reproducing the run with `#[fuzzer(seed: ...)]`
+    pub seed: u64,
+    /// Failing arguments generated with the seed, before they were minimized.
+    /// `None` if the arguments were not minimized
+    pub unminimized_arguments: Option<Vec<Felt252>>,
 }
 
 pub trait TestType {
@@ -76,7 +103,7 @@ impl TestType for Fuzzing {
 #[derive(Debug, PartialEq, Clone)]
 pub struct Single;
 impl TestType for Single {
-    type GasInfo = u128;
+    type GasInfo = GasUsage;
     type TestStatistics = ();
     type TraceData = ProfilerCallTrace;
 }
@@ -112,6 +139,13 @@ pub enum TestCaseSummary<T: TestType> {
         /// Statistics of the test run
         test_statistics: <T as TestType>::TestStatistics,
     },
+    /// Test case marked with `#[parallel_fork]` had different results when run with and without the fork
+    PairedDivergence {
+        /// Name of the test case
+        name: String,
+        /// Message describing results of both runs
+        msg: String,
+    },
     /// Test case ignored due to `#[ignored]` attribute or `--ignored` flag
     Ignored {
         /// Name of the test case
@@ -134,6 +168,7 @@ impl<T: TestType> TestCaseSummary<T> {
         match self {
             TestCaseSummary::Failed { name, .. }
             | TestCaseSummary::Passed { name, .. }
+            | TestCaseSummary::PairedDivergence { name, .. }
             | TestCaseSummary::Ignored { name, .. } => Some(name),
             TestCaseSummary::Skipped { .. } => None,
         }
@@ -143,7 +178,8 @@ impl<T: TestType> TestCaseSummary<T> {
     pub fn msg(&self) -> Option<&str> {
         match self {
             TestCaseSummary::Failed { msg: Some(msg), .. }
-            | TestCaseSummary::Passed { msg: Some(msg), .. } => Some(msg),
+            | TestCaseSummary::Passed { msg: Some(msg), .. }
+            | TestCaseSummary::PairedDivergence { msg, .. } => Some(msg),
             _ => None,
         }
     }
@@ -151,7 +187,11 @@ impl<T: TestType> TestCaseSummary<T> {
 
 impl TestCaseSummary<Fuzzing> {
     #[must_use]
-    pub fn from(results: Vec<TestCaseSummary<Single>>) -> Self {
+    pub fn from(
+        results: Vec<TestCaseSummary<Single>>,
+        seed: u64,
+        unminimized_arguments: Option<Vec<Felt252>>,
+    ) -> Self {
         let last: TestCaseSummary<Single> = results
             .iter()
             .last()
@@ -172,7 +212,7 @@ impl TestCaseSummary<Fuzzing> {
                 let gas_usages: Vec<u128> = results
                     .into_iter()
                     .map(|a| match a {
-                        TestCaseSummary::Passed { gas_info, .. } => gas_info,
+                        TestCaseSummary::Passed { gas_info, .. } => gas_info.total(),
                         _ => unreachable!(),
                     })
                     .collect();
@@ -183,7 +223,11 @@ impl TestCaseSummary<Fuzzing> {
                     arguments,
                     gas_info: GasStatistics::new(&gas_usages),
                     used_resources: UsedResources::default(),
-                    test_statistics: FuzzingStatistics { runs },
+                    test_statistics: FuzzingStatistics {
+                        runs,
+                        seed,
+                        unminimized_arguments: None,
+                    },
                     trace_data: (),
                 }
             }
@@ -198,8 +242,13 @@ impl TestCaseSummary<Fuzzing> {
                 arguments,
                 test_statistics: FuzzingStatistics {
                     runs: results.len(),
+                    seed,
+                    unminimized_arguments,
                 },
             },
+            TestCaseSummary::PairedDivergence { name, msg } => {
+                TestCaseSummary::PairedDivergence { name, msg }
+            }
             TestCaseSummary::Ignored { name } => TestCaseSummary::Ignored { name: name.clone() },
             TestCaseSummary::Skipped {} => TestCaseSummary::Skipped {},
         }
@@ -213,7 +262,7 @@ impl TestCaseSummary<Single> {
         run_result: RunResult,
         test_case: &TestCaseWithResolvedConfig,
         arguments: Vec<Felt252>,
-        gas: u128,
+        gas: GasUsage,
         used_resources: UsedResources,
         call_trace: &Rc<RefCell<InternalCallTrace>>,
         contracts_data: &ContractsData,
@@ -281,6 +330,38 @@ impl TestCaseSummary<Single> {
     }
 }
 
+impl TestCaseSummary<Single> {
+    /// Compares the result of the forked run of a `#[parallel_fork]` test with the result of the run without the fork.
+    /// Returns the forked result if both runs passed or failed with the same data
+    #[must_use]
+    pub(crate) fn compare_with_local_run(self, local: Self) -> Self {
+        match (local.outcome(), self.outcome()) {
+            (Some(local_outcome), Some(forked_outcome)) if local_outcome != forked_outcome => {
+                TestCaseSummary::PairedDivergence {
+                    name: local.name().unwrap_or_default().to_string(),
+                    msg: format!(
+                        "\n    Results of runs without and with the fork differ\n    Local:   {local_outcome}\n    Forked:  {forked_outcome}\n"
+                    ),
+                }
+            }
+            _ => self,
+        }
+    }
+
+    fn outcome(&self) -> Option<String> {
+        let (status, msg) = match self {
+            TestCaseSummary::Passed { msg, .. } => ("passed", msg),
+            TestCaseSummary::Failed { msg, .. } => ("failed", msg),
+            _ => return None,
+        };
+
+        Some(match msg {
+            Some(msg) => format!("{status} with data: {}", msg.trim()),
+            None => status.to_string(),
+        })
+    }
+}
+
 fn join_short_strings(data: &[Felt252]) -> String {
     data.iter()
         .map(|felt| as_cairo_short_string(felt).unwrap_or_default())
@@ -376,12 +457,25 @@ impl AnyTestCaseSummary {
         )
     }
 
+    /// Whether the test failed, including tests with divergent results of runs with and without the fork
     #[must_use]
     pub fn is_failed(&self) -> bool {
         matches!(
             self,
-            AnyTestCaseSummary::Single(TestCaseSummary::Failed { .. })
-                | AnyTestCaseSummary::Fuzzing(TestCaseSummary::Failed { .. })
+            AnyTestCaseSummary::Single(
+                TestCaseSummary::Failed { .. } | TestCaseSummary::PairedDivergence { .. }
+            ) | AnyTestCaseSummary::Fuzzing(
+                TestCaseSummary::Failed { .. } | TestCaseSummary::PairedDivergence { .. }
+            )
+        )
+    }
+
+    #[must_use]
+    pub fn is_paired_divergence(&self) -> bool {
+        matches!(
+            self,
+            AnyTestCaseSummary::Single(TestCaseSummary::PairedDivergence { .. })
+                | AnyTestCaseSummary::Fuzzing(TestCaseSummary::PairedDivergence { .. })
         )
     }
 
@@ -403,3 +497,40 @@ impl AnyTestCaseSummary {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Single, TestCaseSummary};
+
+    fn failed(msg: &str) -> TestCaseSummary<Single> {
+        TestCaseSummary::Failed {
+            name: "tests::test_balance".to_string(),
+            msg: Some(msg.to_string()),
+            arguments: vec![],
+            test_statistics: (),
+        }
+    }
+
+    #[test]
+    fn same_results_of_paired_runs() {
+        let summary = failed("\n    0x0 ('Invalid balance')\n")
+            .compare_with_local_run(failed("\n    0x0 ('Invalid balance')\n"));
+
+        assert!(matches!(summary, TestCaseSummary::Failed { .. }));
+    }
+
+    #[test]
+    fn divergent_results_of_paired_runs() {
+        let summary = failed("\n    0x0 ('Invalid balance')\n")
+            .compare_with_local_run(failed("\n    0x0 ('Contract not deployed')\n"));
+
+        let TestCaseSummary::PairedDivergence { name, msg } = summary else {
+            panic!("Expected paired divergence, got {summary:?}");
+        };
+        assert_eq!(name, "tests::test_balance");
+        assert_eq!(
+            msg,
+            "\n    Results of runs without and with the fork differ\n    Local:   failed with data: 0x0 ('Contract not deployed')\n    Forked:  failed with data: 0x0 ('Invalid balance')\n"
+        );
+    }
+}
@@ -4,22 +4,59 @@ use cheatnet::runtime_extensions::forge_runtime_extension::contracts_data::Contr
 use forge_runner::forge_config::{
     ExecutionDataToSave, ForgeConfig, OutputConfig, TestRunnerConfig,
 };
+use forge_runner::saved_state::SavedState;
 use rand::{thread_rng, RngCore};
+use shared::rpc::{RetryConfig, DEFAULT_RPC_RETRIES, DEFAULT_RPC_RETRY_DELAY};
 use std::env;
 use std::num::NonZeroU32;
 use std::sync::Arc;
+use std::time::Duration;
+
+pub const DEFAULT_FUZZER_SHRINK_ITERATIONS: u32 = 100;
+
+/// Values of `snforge test` flags which override the config from `Scarb.toml`
+#[derive(Debug, Default)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct CliConfigOverrides {
+    pub exit_first: bool,
+    pub fuzzer_runs: Option<NonZeroU32>,
+    pub fuzzer_seed: Option<u64>,
+    pub replay_fuzzer_corpus: bool,
+    pub fuzzer_shrink_iterations: Option<u32>,
+    pub detailed_resources: bool,
+    pub save_trace_data: bool,
+    pub build_profile: bool,
+    pub coverage: bool,
+    pub trace: bool,
+    pub max_n_steps: Option<u32>,
+    pub fork_cache_ttl: Option<u64>,
+    pub test_timeout: Option<u64>,
+    pub rpc_retries: Option<u32>,
+    pub rpc_retry_delay_ms: Option<u64>,
+    pub save_state: Option<Utf8PathBuf>,
+}
 
-#[allow(clippy::too_many_arguments)]
-#[allow(clippy::fn_params_excessive_bools)]
 pub fn combine_configs(
-    exit_first: bool,
-    fuzzer_runs: Option<NonZeroU32>,
-    fuzzer_seed: Option<u64>,
-    detailed_resources: bool,
-    save_trace_data: bool,
-    build_profile: bool,
-    coverage: bool,
-    max_n_steps: Option<u32>,
+    CliConfigOverrides {
+        exit_first,
+        fuzzer_runs,
+        fuzzer_seed,
+        replay_fuzzer_corpus,
+        fuzzer_shrink_iterations,
+        detailed_resources,
+        save_trace_data,
+        build_profile,
+        coverage,
+        trace,
+        max_n_steps,
+        fork_cache_ttl,
+        test_timeout,
+        rpc_retries,
+        rpc_retry_delay_ms,
+        save_state,
+    }: CliConfigOverrides,
+    max_parallel_tests: usize,
+    initial_state: Option<SavedState>,
     contracts_data: ContractsData,
     cache_dir: Utf8PathBuf,
     versioned_programs_dir: Utf8PathBuf,
@@ -40,9 +77,23 @@ pub fn combine_configs(
             fuzzer_seed: fuzzer_seed
                 .or(forge_config_from_scarb.fuzzer_seed)
                 .unwrap_or_else(|| thread_rng().next_u64()),
+            replay_fuzzer_corpus,
+            fuzzer_shrink_iterations: fuzzer_shrink_iterations
+                .unwrap_or(DEFAULT_FUZZER_SHRINK_ITERATIONS),
             max_n_steps: max_n_steps.or(forge_config_from_scarb.max_n_steps),
             is_vm_trace_needed: execution_data_to_save.is_vm_trace_needed(),
+            save_execution_trace: trace,
             cache_dir,
+            fork_cache_ttl: fork_cache_ttl.map(Duration::from_secs),
+            test_timeout: test_timeout.map(Duration::from_millis),
+            max_parallel_tests,
+            save_state,
+            initial_state,
+            rpc_retry_config: RetryConfig {
+                retries: rpc_retries.unwrap_or(DEFAULT_RPC_RETRIES),
+                initial_delay: rpc_retry_delay_ms
+                    .map_or(DEFAULT_RPC_RETRY_DELAY, Duration::from_millis),
+            },
             contracts_data,
             environment_variables: env::vars().collect(),
         }),
@@ -61,13 +112,8 @@ mod tests {
     #[test]
     fn fuzzer_default_seed() {
         let config = combine_configs(
-            false,
-            None,
-            None,
-            false,
-            false,
-            false,
-            false,
+            CliConfigOverrides::default(),
+            1,
             None,
             Default::default(),
             Default::default(),
@@ -75,13 +121,8 @@ mod tests {
             &Default::default(),
         );
         let config2 = combine_configs(
-            false,
-            None,
-            None,
-            false,
-            false,
-            false,
-            false,
+            CliConfigOverrides::default(),
+            1,
             None,
             Default::default(),
             Default::default(),
@@ -100,13 +141,8 @@ mod tests {
     #[test]
     fn runner_config_default_arguments() {
         let config = combine_configs(
-            false,
-            None,
-            None,
-            false,
-            false,
-            false,
-            false,
+            CliConfigOverrides::default(),
+            1,
             None,
             Default::default(),
             Default::default(),
@@ -120,9 +156,18 @@ mod tests {
                     exit_first: false,
                     fuzzer_runs: NonZeroU32::new(256).unwrap(),
                     fuzzer_seed: config.test_runner_config.fuzzer_seed,
+                    replay_fuzzer_corpus: false,
+                    fuzzer_shrink_iterations: DEFAULT_FUZZER_SHRINK_ITERATIONS,
                     max_n_steps: None,
                     is_vm_trace_needed: false,
+                    save_execution_trace: false,
                     cache_dir: Default::default(),
+                    fork_cache_ttl: None,
+                    test_timeout: None,
+                    max_parallel_tests: 1,
+                    save_state: None,
+                    initial_state: None,
+                    rpc_retry_config: RetryConfig::default(),
                     contracts_data: Default::default(),
                     environment_variables: config.test_runner_config.environment_variables.clone(),
                 }),
@@ -150,13 +195,8 @@ mod tests {
         };
 
         let config = combine_configs(
-            false,
-            None,
-            None,
-            false,
-            false,
-            false,
-            false,
+            CliConfigOverrides::default(),
+            1,
             None,
             Default::default(),
             Default::default(),
@@ -170,9 +210,18 @@ mod tests {
                     exit_first: true,
                     fuzzer_runs: NonZeroU32::new(1234).unwrap(),
                     fuzzer_seed: 500,
+                    replay_fuzzer_corpus: false,
+                    fuzzer_shrink_iterations: DEFAULT_FUZZER_SHRINK_ITERATIONS,
                     max_n_steps: Some(1_000_000),
                     is_vm_trace_needed: true,
+                    save_execution_trace: false,
                     cache_dir: Default::default(),
+                    fork_cache_ttl: None,
+                    test_timeout: None,
+                    max_parallel_tests: 1,
+                    save_state: None,
+                    initial_state: None,
+                    rpc_retry_config: RetryConfig::default(),
                     contracts_data: Default::default(),
                     environment_variables: config.test_runner_config.environment_variables.clone(),
                 }),
@@ -203,14 +252,26 @@ mod tests {
             max_n_steps: Some(1234),
         };
         let config = combine_configs(
-            true,
-            Some(NonZeroU32::new(100).unwrap()),
-            Some(32),
-            true,
-            true,
-            true,
-            true,
-            Some(1_000_000),
+            CliConfigOverrides {
+                exit_first: true,
+                fuzzer_runs: Some(NonZeroU32::new(100).unwrap()),
+                fuzzer_seed: Some(32),
+                replay_fuzzer_corpus: true,
+                fuzzer_shrink_iterations: Some(10),
+                detailed_resources: true,
+                save_trace_data: true,
+                build_profile: true,
+                coverage: true,
+                trace: true,
+                max_n_steps: Some(1_000_000),
+                fork_cache_ttl: Some(60),
+                test_timeout: Some(500),
+                rpc_retries: Some(5),
+                rpc_retry_delay_ms: Some(100),
+                save_state: None,
+            },
+            4,
+            None,
             Default::default(),
             Default::default(),
             Default::default(),
@@ -224,9 +285,21 @@ mod tests {
                     exit_first: true,
                     fuzzer_runs: NonZeroU32::new(100).unwrap(),
                     fuzzer_seed: 32,
+                    replay_fuzzer_corpus: true,
+                    fuzzer_shrink_iterations: 10,
                     max_n_steps: Some(1_000_000),
                     is_vm_trace_needed: true,
+                    save_execution_trace: true,
                     cache_dir: Default::default(),
+                    fork_cache_ttl: Some(Duration::from_secs(60)),
+                    test_timeout: Some(Duration::from_millis(500)),
+                    max_parallel_tests: 4,
+                    save_state: None,
+                    initial_state: None,
+                    rpc_retry_config: RetryConfig {
+                        retries: 5,
+                        initial_delay: Duration::from_millis(100),
+                    },
                     contracts_data: Default::default(),
                     environment_variables: config.test_runner_config.environment_variables.clone(),
                 }),
@@ -1,6 +1,7 @@
 use crate::scarb::config::SCARB_MANIFEST_TEMPLATE_CONTENT;
 use crate::CAIRO_EDITION;
 use anyhow::{anyhow, bail, Context, Ok, Result};
+use clap::ValueEnum;
 use include_dir::{include_dir, Dir};
 use indoc::formatdoc;
 use scarb_api::ScarbCommand;
@@ -13,6 +14,27 @@ use toml_edit::{value, ArrayOfTables, DocumentMut, Item, Table};
 
 static TEMPLATE: Dir = include_dir!("starknet_forge_template");
 
+/// Sample contract and tests the project is created with
+#[derive(ValueEnum, Debug, Clone, Copy)]
+pub enum Template {
+    /// Contract with a single balance
+    Simple,
+    /// ERC20 token
+    Erc20,
+    /// NFT collection with minting and transfers
+    Nft,
+}
+
+impl Template {
+    fn dir_name(self) -> &'static str {
+        match self {
+            Template::Simple => "simple",
+            Template::Erc20 => "erc20",
+            Template::Nft => "nft",
+        }
+    }
+}
+
 const DEFAULT_ASSERT_MACROS: Version = Version::new(0, 1, 0);
 const MINIMAL_SCARB_FOR_CORRESPONDING_ASSERT_MACROS: Version = Version::new(2, 8, 0);
 
@@ -52,20 +74,25 @@ fn add_template_to_scarb_manifest(path: &PathBuf) -> Result<()> {
 }
 
 fn overwrite_files_from_scarb_template(
+    template: Template,
     dir_to_overwrite: &str,
     base_path: &Path,
     project_name: &str,
 ) -> Result<()> {
-    let copy_from_dir = TEMPLATE.get_dir(dir_to_overwrite).ok_or_else(|| {
-        anyhow!(
-            "Directory {} doesn't exist in the template.",
-            dir_to_overwrite
-        )
-    })?;
+    let template_dir = Path::new(template.dir_name());
+    let copy_from_dir = TEMPLATE
+        .get_dir(template_dir.join(dir_to_overwrite))
+        .ok_or_else(|| {
+            anyhow!(
+                "Directory {} doesn't exist in the {} template.",
+                dir_to_overwrite,
+                template.dir_name()
+            )
+        })?;
 
     for file in copy_from_dir.files() {
         fs::create_dir_all(base_path.join(Path::new(dir_to_overwrite)))?;
-        let path = base_path.join(file.path());
+        let path = base_path.join(file.path().strip_prefix(template_dir)?);
         let contents = file.contents();
         let contents = replace_project_name(contents, project_name)?;
 
@@ -146,7 +173,7 @@ fn extend_gitignore(path: &Path) -> Result<()> {
     Ok(())
 }
 
-pub fn run(project_name: &str) -> Result<()> {
+pub fn run(project_name: &str, template: Template) -> Result<()> {
     let current_dir = std::env::current_dir().context("Failed to get current directory")?;
     let project_path = current_dir.join(project_name);
     let scarb_manifest_path = project_path.join("Scarb.toml");
@@ -209,8 +236,8 @@ pub fn run(project_name: &str) -> Result<()> {
 
     update_config(&project_path.join("Scarb.toml"), &cairo_version)?;
     extend_gitignore(&project_path)?;
-    overwrite_files_from_scarb_template("src", &project_path, project_name)?;
-    overwrite_files_from_scarb_template("tests", &project_path, project_name)?;
+    overwrite_files_from_scarb_template(template, "src", &project_path, project_name)?;
+    overwrite_files_from_scarb_template(template, "tests", &project_path, project_name)?;
 
     // Fetch to create lock file.
     ScarbCommand::new_with_stdio()
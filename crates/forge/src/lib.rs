@@ -1,10 +1,15 @@
 use anyhow::Result;
+use camino::Utf8PathBuf;
 use clap::{Parser, Subcommand, ValueEnum};
 use forge_runner::CACHE_DIR;
+use init::Template;
 use run_tests::workspace::run_for_workspace;
 use scarb_api::{metadata::MetadataCommandExt, ScarbCommand};
 use scarb_ui::args::{FeaturesSpec, PackagesFilter};
-use std::{fs, num::NonZeroU32, thread::available_parallelism};
+use std::{
+    fs,
+    num::{NonZeroU32, NonZeroUsize},
+};
 use tokio::runtime::Builder;
 use universal_sierra_compiler_api::UniversalSierraCompilerCommand;
 
@@ -17,6 +22,7 @@ pub mod scarb;
 mod shared_cache;
 pub mod test_filter;
 mod warn;
+mod watch;
 
 pub const CAIRO_EDITION: &str = "2023_11";
 
@@ -68,9 +74,36 @@ enum ForgeSubcommand {
     Init {
         /// Name of a new project
         name: String,
+
+        /// Sample contract and tests to create the project with
+        #[arg(long, value_enum, default_value_t = Template::Simple)]
+        template: Template,
     },
     /// Clean Forge cache directory
     CleanCache {},
+    /// Run tests and generate a coverage report using cairo-coverage
+    Coverage {
+        #[command(flatten)]
+        args: TestArgs,
+    },
+    /// Manage inputs persisted by the fuzzer
+    Fuzz {
+        #[command(subcommand)]
+        command: FuzzCommand,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum FuzzCommand {
+    /// Rerun only the inputs saved in the fuzzer corpus for a given test
+    Replay {
+        /// Name used to filter fuzz tests to replay
+        #[arg(long, conflicts_with = "test_filter")]
+        test: String,
+
+        #[command(flatten)]
+        args: TestArgs,
+    },
 }
 
 #[derive(ValueEnum, Debug, Clone)]
@@ -80,7 +113,17 @@ enum ColorOption {
     Never,
 }
 
-#[derive(Parser, Debug)]
+#[derive(ValueEnum, Debug, Clone, Copy)]
+enum OrderOption {
+    /// Order in which tests are declared in source files
+    Definition,
+    /// Sorted by test names
+    Lexicographic,
+    /// Shuffled with a seed printed before running tests
+    Random,
+}
+
+#[derive(Parser, Debug, Clone)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct TestArgs {
     /// Name used to filter tests
@@ -102,6 +145,9 @@ pub struct TestArgs {
     /// Seed for the fuzzer
     #[arg(short = 's', long)]
     fuzzer_seed: Option<u64>,
+    /// Maximum number of runs used to minimize arguments of a failed fuzz test
+    #[arg(long)]
+    fuzzer_shrink_iterations: Option<u32>,
 
     /// Run only tests marked with `#[ignore]` attribute
     #[arg(long = "ignored")]
@@ -110,10 +156,25 @@ pub struct TestArgs {
     #[arg(long, conflicts_with = "only_ignored")]
     include_ignored: bool,
 
+    /// Run only tests marked with any of the given tags using `#[tag]` attribute
+    #[arg(long, value_delimiter = ',', value_name = "TAGS")]
+    filter_tag: Vec<String>,
+    /// Skip tests marked with any of the given tags using `#[tag]` attribute
+    #[arg(long, value_delimiter = ',', value_name = "TAGS")]
+    exclude_tag: Vec<String>,
+    /// Run only tests from the given Cairo module and its submodules, e.g. `tests::unit`.
+    /// The path can start with the package name or with a module at the package root
+    #[arg(long, value_name = "MODULE_PATH")]
+    filter_module: Option<String>,
+
     /// Display more detailed info about used resources
     #[arg(long)]
     detailed_resources: bool,
 
+    /// Print a table with gas used by each test and by contract functions called in tests
+    #[arg(long)]
+    gas_report: bool,
+
     /// Control when colored output is used
     #[arg(value_enum, long, default_value_t = ColorOption::Auto, value_name="WHEN")]
     color: ColorOption,
@@ -134,6 +195,11 @@ pub struct TestArgs {
     #[arg(long)]
     coverage: bool,
 
+    /// Save execution traces with executed Sierra statements and stack values to `.snfoundry_cache/traces`.
+    /// Only failing inputs are saved for fuzz tests
+    #[arg(long)]
+    trace: bool,
+
     /// Number of maximum steps during a single test. For fuzz tests this value is applied to each subtest separately.
     #[arg(long)]
     max_n_steps: Option<u32>,
@@ -145,6 +211,55 @@ pub struct TestArgs {
     /// Build contracts separately in the scarb starknet contract target
     #[arg(long)]
     no_optimization: bool,
+
+    /// Time in seconds after which cached data of forks at `latest` or `pending` block is fetched again.
+    /// If set to 0, it is fetched again once in every run
+    #[arg(long, value_name = "SECONDS")]
+    fork_cache_ttl: Option<u64>,
+
+    /// Time in milliseconds after which a test is stopped and reported as failed, can be overridden with `#[timeout]` attribute
+    #[arg(long, value_name = "MILLISECONDS")]
+    test_timeout: Option<u64>,
+
+    /// Save the state left by the test to the given file, e.g. after deploying and configuring contracts.
+    /// Exactly one non-fuzz test from a single package has to be selected to run, the state is saved only if it passes
+    #[arg(long, value_name = "PATH", conflicts_with = "load_state")]
+    save_state: Option<Utf8PathBuf>,
+    /// Start all tests from the state saved with `--save-state` instead of an empty state
+    #[arg(long, value_name = "PATH")]
+    load_state: Option<Utf8PathBuf>,
+
+    /// Number of times a failed RPC call to the fork node is retried [default: 3]
+    #[arg(long, value_name = "N")]
+    rpc_retries: Option<u32>,
+    /// Delay in milliseconds before the first retry of a failed RPC call, doubled with every subsequent retry [default: 500]
+    #[arg(long, value_name = "MILLISECONDS")]
+    rpc_retry_delay_ms: Option<u64>,
+
+    /// Order in which tests of every test target are run
+    #[arg(value_enum, long, default_value_t = OrderOption::Definition, value_name = "ORDER")]
+    order: OrderOption,
+    /// Seed used to shuffle tests with `--order random`
+    #[arg(long)]
+    order_seed: Option<u64>,
+    /// Run tests in a random order, shorthand for `--order random`
+    #[arg(long, conflicts_with = "order")]
+    randomize_order: bool,
+
+    /// Maximum number of tests run concurrently [default: number of logical CPUs]
+    #[arg(long, value_name = "N")]
+    parallel: Option<NonZeroUsize>,
+    /// Run tests one at a time, shorthand for `--parallel 1`. Only concurrency is affected, tests are isolated from each other either way
+    #[arg(long, conflicts_with = "parallel")]
+    serial: bool,
+
+    /// Re-run tests every time a `.cairo` file in `src` or `tests` directory of the tested packages changes
+    #[arg(long)]
+    watch: bool,
+
+    /// Run fuzz tests only with inputs saved in the fuzzer corpus
+    #[arg(skip)]
+    replay_fuzzer_corpus: bool,
 }
 
 pub enum ExitStatus {
@@ -159,8 +274,8 @@ pub fn main_execution() -> Result<ExitStatus> {
     UniversalSierraCompilerCommand::ensure_available()?;
 
     match cli.subcommand {
-        ForgeSubcommand::Init { name } => {
-            init::run(name.as_str())?;
+        ForgeSubcommand::Init { name, template } => {
+            init::run(name.as_str(), template)?;
             Ok(ExitStatus::Success)
         }
         ForgeSubcommand::CleanCache {} => {
@@ -173,20 +288,33 @@ pub fn main_execution() -> Result<ExitStatus> {
 
             Ok(ExitStatus::Success)
         }
-        ForgeSubcommand::Test { args } => {
-            let cores = if let Ok(available_cores) = available_parallelism() {
-                available_cores.get()
-            } else {
-                eprintln!("Failed to get the number of available cores, defaulting to 1");
-                1
-            };
-
-            let rt = Builder::new_multi_thread()
-                .max_blocking_threads(cores)
-                .enable_all()
-                .build()?;
-
-            rt.block_on(run_for_workspace(args))
+        ForgeSubcommand::Test { args } => run_tests(args),
+        ForgeSubcommand::Coverage { mut args } => {
+            args.coverage = true;
+
+            run_tests(args)
+        }
+        ForgeSubcommand::Fuzz {
+            command: FuzzCommand::Replay { test, mut args },
+        } => {
+            args.test_filter = Some(test);
+            args.replay_fuzzer_corpus = true;
+
+            run_tests(args)
         }
     }
 }
+
+fn run_tests(args: TestArgs) -> Result<ExitStatus> {
+    let rt = Builder::new_multi_thread().enable_all().build()?;
+
+    let result = if args.watch {
+        watch::run_in_watch_mode(&args, &rt).map(|()| ExitStatus::Success)
+    } else {
+        rt.block_on(run_for_workspace(args))
+    };
+    // tests that timed out can't be stopped, so the runtime doesn't wait for them
+    rt.shutdown_background();
+
+    result
+}
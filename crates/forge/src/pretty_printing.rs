@@ -1,5 +1,6 @@
 use anyhow::Error;
 use console::style;
+use forge_runner::gas_report::GasReport;
 use forge_runner::package_tests::TestTargetLocation;
 use forge_runner::{test_case_summary::AnyTestCaseSummary, test_target_summary::TestTargetSummary};
 use starknet_api::block::BlockNumber;
@@ -47,6 +48,10 @@ pub(crate) fn print_test_seed(seed: u64) {
     println!("{}: {seed}", style("Fuzzer seed").bold());
 }
 
+pub(crate) fn print_test_order_seed(seed: u64) {
+    println!("{}: {seed}", style("Test order seed").bold());
+}
+
 pub fn print_failures(all_failed_tests: &[AnyTestCaseSummary]) {
     if all_failed_tests.is_empty() {
         return;
@@ -70,3 +75,11 @@ pub fn print_latest_blocks_numbers(url_to_latest_block_number_map: &HashMap<Url,
         println!("Latest block number = {latest_block_number} for url = {url}");
     }
 }
+
+pub(crate) fn print_gas_report(gas_report: &GasReport) {
+    if gas_report.is_empty() {
+        return;
+    }
+    println!("\n{}\n", style("Gas report").bold());
+    print!("{gas_report}");
+}
@@ -4,21 +4,21 @@ use super::{
 };
 use crate::{
     block_number_map::BlockNumberMap,
-    combine_configs::combine_configs,
+    combine_configs::{combine_configs, CliConfigOverrides},
     pretty_printing,
     scarb::{
         config::{ForgeConfigFromScarb, ForkTarget},
         load_test_artifacts, should_compile_starknet_contract_target,
     },
     shared_cache::FailedTestsCache,
-    test_filter::TestsFilter,
+    test_filter::{TestsFilter, TestsFilterFlags},
     warn::{
         warn_if_available_gas_used_with_incompatible_scarb_version,
         warn_if_incompatible_rpc_version,
     },
     TestArgs,
 };
-use anyhow::Result;
+use anyhow::{ensure, Result};
 use camino::{Utf8Path, Utf8PathBuf};
 use cheatnet::runtime_extensions::forge_runtime_extension::contracts_data::ContractsData;
 use configuration::load_package_config;
@@ -26,9 +26,11 @@ use forge_runner::{
     forge_config::ForgeConfig,
     package_tests::{raw::TestTargetRaw, with_config_resolved::TestTargetWithResolvedConfig},
     running::with_config::test_target_with_config,
+    saved_state::SavedState,
     test_case_summary::AnyTestCaseSummary,
     test_target_summary::TestTargetSummary,
 };
+use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
 use scarb_api::get_contracts_artifacts_and_source_sierra_paths;
 use scarb_metadata::{Metadata, PackageMetadata};
 use std::sync::Arc;
@@ -39,6 +41,39 @@ pub struct RunForPackageArgs {
     pub forge_config: Arc<ForgeConfig>,
     pub fork_targets: Vec<ForkTarget>,
     pub package_name: String,
+    pub test_order: TestOrder,
+}
+
+/// Order in which test cases of every test target are run
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TestOrder {
+    /// Order in which tests are collected, i.e. declared in source files
+    Definition,
+    /// Sorted by test names
+    Lexicographic,
+    /// Shuffled with the given seed
+    Random { seed: u64 },
+}
+
+impl TestOrder {
+    fn apply(self, test_targets: &mut [TestTargetWithResolvedConfig]) {
+        match self {
+            TestOrder::Definition => {}
+            TestOrder::Lexicographic => {
+                for test_target in test_targets {
+                    test_target
+                        .test_cases
+                        .sort_by(|left, right| left.name.cmp(&right.name));
+                }
+            }
+            TestOrder::Random { seed } => {
+                let mut rng = StdRng::seed_from_u64(seed);
+                for test_target in test_targets {
+                    test_target.test_cases.shuffle(&mut rng);
+                }
+            }
+        }
+    }
 }
 
 impl RunForPackageArgs {
@@ -49,6 +84,8 @@ impl RunForPackageArgs {
         cache_dir: &Utf8PathBuf,
         snforge_target_dir_path: &Utf8Path,
         versioned_programs_dir: Utf8PathBuf,
+        test_order: TestOrder,
+        max_parallel_tests: usize,
     ) -> Result<RunForPackageArgs> {
         let raw_test_targets = load_test_artifacts(snforge_target_dir_path, &package)?;
 
@@ -65,15 +102,32 @@ impl RunForPackageArgs {
 
         let forge_config_from_scarb =
             load_package_config::<ForgeConfigFromScarb>(scarb_metadata, &package.id)?;
+        let initial_state = args
+            .load_state
+            .as_deref()
+            .map(SavedState::load)
+            .transpose()?;
         let forge_config = Arc::new(combine_configs(
-            args.exit_first,
-            args.fuzzer_runs,
-            args.fuzzer_seed,
-            args.detailed_resources,
-            args.save_trace_data,
-            args.build_profile,
-            args.coverage,
-            args.max_n_steps,
+            CliConfigOverrides {
+                exit_first: args.exit_first,
+                fuzzer_runs: args.fuzzer_runs,
+                fuzzer_seed: args.fuzzer_seed,
+                replay_fuzzer_corpus: args.replay_fuzzer_corpus,
+                fuzzer_shrink_iterations: args.fuzzer_shrink_iterations,
+                detailed_resources: args.detailed_resources,
+                save_trace_data: args.save_trace_data,
+                build_profile: args.build_profile,
+                coverage: args.coverage,
+                trace: args.trace,
+                max_n_steps: args.max_n_steps,
+                fork_cache_ttl: args.fork_cache_ttl,
+                test_timeout: args.test_timeout,
+                rpc_retries: args.rpc_retries,
+                rpc_retry_delay_ms: args.rpc_retry_delay_ms,
+                save_state: args.save_state.clone(),
+            },
+            max_parallel_tests,
+            initial_state,
             contracts_data,
             cache_dir.clone(),
             versioned_programs_dir,
@@ -81,11 +135,16 @@ impl RunForPackageArgs {
         ));
 
         let test_filter = TestsFilter::from_flags(
-            args.test_filter.clone(),
-            args.exact,
-            args.only_ignored,
-            args.include_ignored,
-            args.rerun_failed,
+            TestsFilterFlags {
+                test_name_filter: args.test_filter.clone(),
+                exact_match: args.exact,
+                only_ignored: args.only_ignored,
+                include_ignored: args.include_ignored,
+                rerun_failed: args.rerun_failed,
+                include_tags: args.filter_tag.clone(),
+                exclude_tags: args.exclude_tag.clone(),
+                module_filter: args.filter_module.clone(),
+            },
             FailedTestsCache::new(cache_dir),
         );
 
@@ -95,6 +154,7 @@ impl RunForPackageArgs {
             tests_filter: test_filter,
             fork_targets: forge_config_from_scarb.fork,
             package_name: package.name,
+            test_order,
         })
     }
 }
@@ -128,6 +188,7 @@ pub async fn run_for_package(
         tests_filter,
         fork_targets,
         package_name,
+        test_order,
     }: RunForPackageArgs,
     block_number_map: &mut BlockNumberMap,
 ) -> Result<Vec<TestTargetSummary>> {
@@ -139,10 +200,27 @@ pub async fn run_for_package(
         tests_filter.filter_tests(&mut test_target.test_cases)?;
     }
 
+    test_order.apply(&mut test_targets);
+
     warn_if_available_gas_used_with_incompatible_scarb_version(&test_targets)?;
     warn_if_incompatible_rpc_version(&test_targets).await?;
 
     let not_filtered = sum_test_cases(&test_targets);
+    ensure!(
+        forge_config.test_runner_config.save_state.is_none() || not_filtered == 1,
+        "--save-state requires exactly one test to run, got {not_filtered}. Select the test setting up the state with the test name filter and --exact flag"
+    );
+    ensure!(
+        forge_config.test_runner_config.save_state.is_none()
+            || !test_targets
+                .iter()
+                .flat_map(|test_target| &test_target.test_cases)
+                .any(|case| {
+                    case.config.fuzzer_config.is_some()
+                        || case.test_details.is_fuzzed(case.config.test_kind)
+                }),
+        "--save-state cannot be used with fuzz tests"
+    );
     pretty_printing::print_collected_tests_count(not_filtered, &package_name);
 
     let mut summaries = vec![];
@@ -1,7 +1,7 @@
 use crate::{block_number_map::BlockNumberMap, scarb::config::ForkTarget};
-use anyhow::{anyhow, Result};
+use anyhow::{anyhow, bail, Result};
 use cheatnet::runtime_extensions::forge_config_extension::config::{
-    BlockId, InlineForkConfig, OverriddenForkConfig, RawForkConfig,
+    BlockId, BlockTag, InlineForkConfig, OverriddenForkConfig, RawForkConfig,
 };
 use conversions::byte_array::ByteArray;
 use forge_runner::package_tests::{
@@ -21,6 +21,25 @@ pub async fn resolve_config(
     let mut test_cases = Vec::with_capacity(test_target.test_cases.len());
 
     for case in test_target.test_cases {
+        let (fork_config, parallel_fork) =
+            match (case.config.fork_config, case.config.parallel_fork_config) {
+                (Some(_), Some(_)) => bail!(
+                    "Test {} cannot be marked with both #[fork] and #[parallel_fork]",
+                    case.name
+                ),
+                (fork_config, None) => (fork_config, false),
+                (None, parallel_fork_config) => (parallel_fork_config, true),
+            };
+        if parallel_fork
+            && (case.config.fuzzer_config.is_some()
+                || case.test_details.is_fuzzed(case.config.test_kind))
+        {
+            bail!(
+                "Test {} cannot be marked with #[parallel_fork] as it is a fuzz test, use #[fork] instead",
+                case.name
+            );
+        }
+
         test_cases.push(TestCaseWithResolvedConfig {
             name: case.name,
             test_details: case.test_details,
@@ -28,13 +47,13 @@ pub async fn resolve_config(
                 available_gas: case.config.available_gas,
                 ignored: case.config.ignored,
                 expected_result: case.config.expected_result,
-                fork_config: resolve_fork_config(
-                    case.config.fork_config,
-                    block_number_map,
-                    fork_targets,
-                )
-                .await?,
+                fork_config: resolve_fork_config(fork_config, block_number_map, fork_targets)
+                    .await?,
+                parallel_fork,
                 fuzzer_config: case.config.fuzzer_config,
+                tags: case.config.tags,
+                test_kind: case.config.test_kind,
+                timeout: case.config.timeout,
             },
         });
     }
@@ -60,6 +79,7 @@ async fn resolve_fork_config(
 
     let url = raw_fork_params.url;
 
+    let block_from_tag = matches!(raw_fork_params.block, BlockId::BlockTag(_));
     let block_number = match raw_fork_params.block {
         BlockId::BlockNumber(block_number) => BlockNumber(block_number),
         BlockId::BlockHash(hash) => {
@@ -67,14 +87,20 @@ async fn resolve_fork_config(
                 .get_block_number_for_hash(url.clone(), hash)
                 .await?
         }
-        BlockId::BlockTag => {
+        // Pending block can't be referenced by its number,
+        // so it is resolved to the latest block it is built upon
+        BlockId::BlockTag(BlockTag::Latest | BlockTag::Pending) => {
             block_number_map
                 .get_latest_block_number(url.clone())
                 .await?
         }
     };
 
-    Ok(Some(ResolvedForkConfig { url, block_number }))
+    Ok(Some(ResolvedForkConfig {
+        url,
+        block_number,
+        block_from_tag,
+    }))
 }
 
 fn get_fork_target_from_runner_config<'a>(
@@ -124,7 +150,7 @@ mod tests {
     use cairo_lang_sierra::program::ProgramArtifact;
     use cairo_lang_sierra::{ids::GenericTypeId, program::Program};
     use forge_runner::package_tests::with_config::{TestCaseConfig, TestCaseWithConfig};
-    use forge_runner::package_tests::TestTargetLocation;
+    use forge_runner::package_tests::{TestKind, TestTargetLocation};
     use forge_runner::{expected_result::ExpectedTestResult, package_tests::TestDetails};
     use std::sync::Arc;
     use universal_sierra_compiler_api::compile_sierra_to_casm;
@@ -153,7 +179,11 @@ mod tests {
                     ignored: false,
                     expected_result: ExpectedTestResult::Success,
                     fork_config: Some(RawForkConfig::Named("non_existent".into())),
+                    parallel_fork_config: None,
                     fuzzer_config: None,
+                    tags: vec![],
+                    test_kind: TestKind::External,
+                    timeout: None,
                 },
                 test_details: TestDetails {
                     sierra_entry_point_statement_idx: 100,
@@ -166,6 +196,8 @@ mod tests {
                         (GenericTypeId("GasBuiltin".into()), 1),
                         (GenericTypeId("Enum".into()), 3),
                     ],
+                    source_location: None,
+                    statement_locations: Default::default(),
                 },
             }],
             tests_location: TestTargetLocation::Lib,
@@ -185,4 +217,84 @@ mod tests {
         .await
         .is_err());
     }
+
+    #[tokio::test]
+    async fn to_runnable_fork_and_parallel_fork() {
+        let mocked_tests = TestTargetWithConfig {
+            sierra_program: program_for_testing(),
+            casm_program: Arc::new(compile_sierra_to_casm(&program_for_testing().program).unwrap()),
+            test_cases: vec![TestCaseWithConfig {
+                name: "crate1::do_thing".to_string(),
+                config: TestCaseConfig {
+                    available_gas: None,
+                    ignored: false,
+                    expected_result: ExpectedTestResult::Success,
+                    fork_config: Some(RawForkConfig::Named("mainnet".into())),
+                    parallel_fork_config: Some(RawForkConfig::Named("mainnet".into())),
+                    fuzzer_config: None,
+                    tags: vec![],
+                    test_kind: TestKind::External,
+                    timeout: None,
+                },
+                test_details: TestDetails::default(),
+            }],
+            tests_location: TestTargetLocation::Lib,
+        };
+
+        let error = resolve_config(
+            mocked_tests,
+            &[ForkTarget::new("mainnet", "https://not_taken.com", "number", "120").unwrap()],
+            &mut BlockNumberMap::default(),
+        )
+        .await
+        .unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "Test crate1::do_thing cannot be marked with both #[fork] and #[parallel_fork]"
+        );
+    }
+
+    #[tokio::test]
+    async fn to_runnable_parallel_fork_fuzz_test() {
+        let mocked_tests = TestTargetWithConfig {
+            sierra_program: program_for_testing(),
+            casm_program: Arc::new(compile_sierra_to_casm(&program_for_testing().program).unwrap()),
+            test_cases: vec![TestCaseWithConfig {
+                name: "crate1::do_thing".to_string(),
+                config: TestCaseConfig {
+                    available_gas: None,
+                    ignored: false,
+                    expected_result: ExpectedTestResult::Success,
+                    fork_config: None,
+                    parallel_fork_config: Some(RawForkConfig::Named("mainnet".into())),
+                    fuzzer_config: None,
+                    tags: vec![],
+                    test_kind: TestKind::External,
+                    timeout: None,
+                },
+                test_details: TestDetails {
+                    parameter_types: vec![
+                        (GenericTypeId("RangeCheck".into()), 1),
+                        (GenericTypeId("felt252".into()), 1),
+                    ],
+                    ..TestDetails::default()
+                },
+            }],
+            tests_location: TestTargetLocation::Lib,
+        };
+
+        let error = resolve_config(
+            mocked_tests,
+            &[ForkTarget::new("mainnet", "https://not_taken.com", "number", "120").unwrap()],
+            &mut BlockNumberMap::default(),
+        )
+        .await
+        .unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "Test crate1::do_thing cannot be marked with #[parallel_fork] as it is a fuzz test, use #[fork] instead"
+        );
+    }
 }
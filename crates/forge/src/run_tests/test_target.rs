@@ -4,7 +4,7 @@ use forge_runner::{
     forge_config::ForgeConfig,
     function_args, maybe_generate_coverage, maybe_save_trace_and_profile,
     maybe_save_versioned_program,
-    package_tests::with_config_resolved::TestTargetWithResolvedConfig,
+    package_tests::{with_config_resolved::TestTargetWithResolvedConfig, TestKind},
     printing::print_test_result,
     run_for_test_case,
     test_case_summary::{AnyTestCaseSummary, TestCaseSummary},
@@ -13,7 +13,8 @@ use forge_runner::{
 };
 use futures::{stream::FuturesUnordered, StreamExt};
 use std::{collections::HashMap, sync::Arc};
-use tokio::sync::mpsc::channel;
+use tokio::sync::Semaphore;
+use tokio_util::sync::CancellationToken;
 
 #[non_exhaustive]
 pub enum TestTargetRunResult {
@@ -31,12 +32,17 @@ pub async fn run_for_test_target(
     let casm_program = tests.casm_program.clone();
 
     let mut tasks = FuturesUnordered::new();
-    // Initiate two channels to manage the `--exit-first` flag.
     // Owing to `cheatnet` fork's utilization of its own Tokio runtime for RPC requests,
     // test execution must occur within a `tokio::spawn_blocking`.
     // As `spawn_blocking` can't be prematurely cancelled (refer: https://dtantsur.github.io/rust-openstack/tokio/task/fn.spawn_blocking.html),
-    // a channel is used to signal the task that test processing is no longer necessary.
-    let (send, mut rec) = channel(1);
+    // a cancellation token is used to signal the tasks that test processing is no longer necessary
+    // when `--exit-first` flag is used.
+    let cancellation_token = CancellationToken::new();
+    // Tests which timed out can't be stopped either, so instead of limiting the number of
+    // blocking threads, each running test holds a slot which is freed when it times out.
+    let test_slots = Arc::new(Semaphore::new(
+        forge_config.test_runner_config.max_parallel_tests,
+    ));
 
     let maybe_versioned_program_path = Arc::new(maybe_save_versioned_program(
         forge_config.output_config.execution_data_to_save,
@@ -51,6 +57,17 @@ pub async fn run_for_test_target(
         .map(|f| (f.id.id, f))
         .collect();
 
+    let source_locations: HashMap<_, _> = tests
+        .test_cases
+        .iter()
+        .filter_map(|case| {
+            case.test_details
+                .source_location
+                .clone()
+                .map(|location| (case.name.clone(), location))
+        })
+        .collect();
+
     for case in tests.test_cases {
         let case_name = case.name.clone();
 
@@ -70,7 +87,11 @@ pub async fn run_for_test_target(
             .find(|f| f.id.debug_name.as_ref().unwrap().ends_with(&case_name))
             .ok_or(RunnerError::MissingFunction { suffix: case_name })?;
 
-        let args = function_args(function, &type_declarations);
+        let mut args = function_args(function, &type_declarations);
+        // `from_address` of L1 handler tests is passed by the runner, so it is not fuzzed
+        if case.config.test_kind == TestKind::L1Handler {
+            args.remove(0);
+        }
 
         let case = Arc::new(case);
 
@@ -80,7 +101,8 @@ pub async fn run_for_test_target(
             casm_program.clone(),
             forge_config.clone(),
             maybe_versioned_program_path.clone(),
-            send.clone(),
+            cancellation_token.clone(),
+            test_slots.clone(),
         ));
     }
 
@@ -91,7 +113,12 @@ pub async fn run_for_test_target(
     while let Some(task) = tasks.next().await {
         let result = task??;
 
-        print_test_result(&result, forge_config.output_config.detailed_resources);
+        let source_location = result.name().and_then(|name| source_locations.get(name));
+        print_test_result(
+            &result,
+            forge_config.output_config.detailed_resources,
+            source_location,
+        );
 
         let trace_path = maybe_save_trace_and_profile(
             &result,
@@ -103,7 +130,7 @@ pub async fn run_for_test_target(
 
         if result.is_failed() && forge_config.test_runner_config.exit_first {
             interrupted = true;
-            rec.close();
+            cancellation_token.cancel();
         }
 
         results.push(result);
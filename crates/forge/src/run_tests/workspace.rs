@@ -1,22 +1,24 @@
-use super::package::RunForPackageArgs;
+use super::package::{RunForPackageArgs, TestOrder};
 use crate::{
     block_number_map::BlockNumberMap, pretty_printing, run_tests::package::run_for_package,
     scarb::build_artifacts_with_scarb, shared_cache::FailedTestsCache,
-    warn::warn_if_snforge_std_not_compatible, ColorOption, ExitStatus, TestArgs,
+    warn::warn_if_snforge_std_not_compatible, ColorOption, ExitStatus, OrderOption, TestArgs,
 };
-use anyhow::{Context, Result};
+use anyhow::{ensure, Context, Result};
 use forge_runner::{
     build_trace_data::test_sierra_program_path::VERSIONED_PROGRAMS_DIR,
-    coverage_api::can_coverage_be_generated,
-    test_case_summary::{AnyTestCaseSummary, TestCaseSummary},
+    coverage_api::can_coverage_be_generated, gas_report::GasReport,
+    test_case_summary::AnyTestCaseSummary,
 };
 use forge_runner::{test_target_summary::TestTargetSummary, CACHE_DIR};
+use rand::{thread_rng, RngCore};
 use scarb_api::{
     metadata::{Metadata, MetadataCommandExt, PackageMetadata},
     target_dir_for_workspace, ScarbCommand,
 };
 use scarb_ui::args::PackagesFilter;
 use std::env;
+use std::thread::available_parallelism;
 
 #[allow(clippy::too_many_lines)]
 pub async fn run_for_workspace(args: TestArgs) -> Result<ExitStatus> {
@@ -26,6 +28,17 @@ pub async fn run_for_workspace(args: TestArgs) -> Result<ExitStatus> {
         ColorOption::Auto => (),
     }
 
+    let max_parallel_tests = if args.serial {
+        1
+    } else if let Some(parallel) = args.parallel {
+        parallel.get()
+    } else if let Ok(available_cores) = available_parallelism() {
+        available_cores.get()
+    } else {
+        eprintln!("Failed to get the number of available cores, defaulting to 1");
+        1
+    };
+
     let scarb_metadata = ScarbCommand::metadata().inherit_stderr().run()?;
 
     if args.coverage {
@@ -42,6 +55,12 @@ pub async fn run_for_workspace(args: TestArgs) -> Result<ExitStatus> {
         .match_many(&scarb_metadata)
         .context("Failed to find any packages matching the specified filter")?;
 
+    ensure!(
+        args.save_state.is_none() || packages.len() == 1,
+        "--save-state requires exactly one package to run, got {}. Select the package with the --package flag",
+        packages.len()
+    );
+
     let filter = PackagesFilter::generate_for::<Metadata>(packages.iter());
 
     build_artifacts_with_scarb(
@@ -51,8 +70,24 @@ pub async fn run_for_workspace(args: TestArgs) -> Result<ExitStatus> {
         args.no_optimization,
     )?;
 
+    let test_order = match (args.order, args.randomize_order) {
+        (OrderOption::Random, _) | (_, true) => TestOrder::Random {
+            seed: args.order_seed.unwrap_or_else(|| thread_rng().next_u64()),
+        },
+        (OrderOption::Lexicographic, false) => TestOrder::Lexicographic,
+        (OrderOption::Definition, false) => TestOrder::Definition,
+    };
+    ensure!(
+        args.order_seed.is_none() || matches!(test_order, TestOrder::Random { .. }),
+        "--order-seed can only be used with `--order random`"
+    );
+    if let TestOrder::Random { seed } = test_order {
+        pretty_printing::print_test_order_seed(seed);
+    }
+
     let mut block_number_map = BlockNumberMap::default();
     let mut all_failed_tests = vec![];
+    let mut gas_report = GasReport::default();
 
     let workspace_root = &scarb_metadata.workspace.root;
     let cache_dir = workspace_root.join(CACHE_DIR);
@@ -68,10 +103,18 @@ pub async fn run_for_workspace(args: TestArgs) -> Result<ExitStatus> {
             &cache_dir,
             &snforge_target_dir_path,
             versioned_programs_dir.clone(),
+            test_order,
+            max_parallel_tests,
         )?;
 
         let tests_file_summaries = run_for_package(args, &mut block_number_map).await?;
 
+        for test_case_summary in tests_file_summaries
+            .iter()
+            .flat_map(|test_file_summary| &test_file_summary.test_case_summaries)
+        {
+            gas_report.add(test_case_summary)?;
+        }
         all_failed_tests.extend(extract_failed_tests(tests_file_summaries));
     }
 
@@ -80,6 +123,10 @@ pub async fn run_for_workspace(args: TestArgs) -> Result<ExitStatus> {
     pretty_printing::print_latest_blocks_numbers(block_number_map.get_url_to_latest_block_number());
     pretty_printing::print_failures(&all_failed_tests);
 
+    if args.gas_report {
+        pretty_printing::print_gas_report(&gas_report);
+    }
+
     Ok(if all_failed_tests.is_empty() {
         ExitStatus::Success
     } else {
@@ -93,11 +140,5 @@ fn extract_failed_tests(
     tests_summaries
         .into_iter()
         .flat_map(|test_file_summary| test_file_summary.test_case_summaries)
-        .filter(|test_case_summary| {
-            matches!(
-                test_case_summary,
-                AnyTestCaseSummary::Fuzzing(TestCaseSummary::Failed { .. })
-                    | AnyTestCaseSummary::Single(TestCaseSummary::Failed { .. })
-            )
-        })
+        .filter(AnyTestCaseSummary::is_failed)
 }
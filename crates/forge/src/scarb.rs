@@ -145,7 +145,7 @@ mod tests {
     use assert_fs::fixture::{FileWriteStr, PathChild, PathCopy};
     use assert_fs::TempDir;
     use camino::Utf8PathBuf;
-    use cheatnet::runtime_extensions::forge_config_extension::config::BlockId;
+    use cheatnet::runtime_extensions::forge_config_extension::config::{BlockId, BlockTag};
     use configuration::load_package_config;
     use indoc::{formatdoc, indoc};
     use scarb_api::metadata::MetadataCommandExt;
@@ -424,7 +424,7 @@ mod tests {
             &scarb_metadata.workspace.members[0],
         )
         .unwrap_err();
-        assert!(format!("{err:?}").contains("block_id.tag can only be equal to latest"));
+        assert!(format!("{err:?}").contains("block_id.tag can only be equal to latest or pending"));
     }
 
     #[test]
@@ -455,7 +455,10 @@ mod tests {
             &scarb_metadata.workspace.members[0],
         )
         .unwrap();
-        assert_eq!(forge_config.fork[0].block_id, BlockId::BlockTag);
+        assert_eq!(
+            forge_config.fork[0].block_id,
+            BlockId::BlockTag(BlockTag::Latest)
+        );
     }
 
     #[test]
@@ -1,5 +1,5 @@
 use anyhow::{anyhow, bail, Result};
-use cheatnet::runtime_extensions::forge_config_extension::config::BlockId;
+use cheatnet::runtime_extensions::forge_config_extension::config::{BlockId, BlockTag};
 use itertools::Itertools;
 use serde::Deserialize;
 use std::{
@@ -87,8 +87,9 @@ impl ForkTarget {
                     .map_err(|_| anyhow!("Failed to parse block hash"))?,
             ),
             "tag" => match block_id_value {
-                "latest" => BlockId::BlockTag,
-                _ => bail!("block_id.tag can only be equal to latest"),
+                "latest" => BlockId::BlockTag(BlockTag::Latest),
+                "pending" => BlockId::BlockTag(BlockTag::Pending),
+                _ => bail!("block_id.tag can only be equal to latest or pending"),
             },
             block_id_key => bail!("block_id = {block_id_key} is not valid. Possible values are = \"number\", \"hash\" and \"tag\""),
         };
@@ -244,7 +245,25 @@ mod tests {
 
         assert_eq!(fork_target.name, name);
         assert_eq!(fork_target.url, Url::parse(url).unwrap());
-        if let BlockId::BlockTag = fork_target.block_id {
+        if let BlockId::BlockTag(BlockTag::Latest) = fork_target.block_id {
+            // Expected variant
+        } else {
+            panic!("Expected BlockId::BlockTag");
+        }
+    }
+
+    #[test]
+    fn test_fork_target_new_valid_pending_tag() {
+        let name = "TestFork";
+        let url = "http://example.com";
+        let block_id_type = "tag";
+        let block_id_value = "pending";
+
+        let fork_target = ForkTarget::new(name, url, block_id_type, block_id_value).unwrap();
+
+        assert_eq!(fork_target.name, name);
+        assert_eq!(fork_target.url, Url::parse(url).unwrap());
+        if let BlockId::BlockTag(BlockTag::Pending) = fork_target.block_id {
             // Expected variant
         } else {
             panic!("Expected BlockId::BlockTag");
@@ -12,6 +12,12 @@ pub struct TestsFilter {
     ignored_filter: IgnoredFilter,
     // based on rerun_failed flag
     last_failed_filter: bool,
+    // based on `#[tag]` attribute, tests with any of these tags are included
+    include_tags: Vec<String>,
+    // based on `#[tag]` attribute, tests with any of these tags are excluded
+    exclude_tags: Vec<String>,
+    // based on module path, only tests from this module and its submodules are included
+    module_filter: Option<String>,
 
     failed_tests_cache: FailedTestsCache,
 }
@@ -30,17 +36,34 @@ pub(crate) enum IgnoredFilter {
     All,
 }
 
+/// Filtering flags passed to `snforge test`
+#[derive(Debug, Default, Clone)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct TestsFilterFlags {
+    pub test_name_filter: Option<String>,
+    pub exact_match: bool,
+    pub only_ignored: bool,
+    pub include_ignored: bool,
+    pub rerun_failed: bool,
+    pub include_tags: Vec<String>,
+    pub exclude_tags: Vec<String>,
+    pub module_filter: Option<String>,
+}
+
 impl TestsFilter {
     #[must_use]
-    #[allow(clippy::fn_params_excessive_bools)]
-    pub fn from_flags(
-        test_name_filter: Option<String>,
-        exact_match: bool,
-        only_ignored: bool,
-        include_ignored: bool,
-        rerun_failed: bool,
-        failed_tests_cache: FailedTestsCache,
-    ) -> Self {
+    pub fn from_flags(flags: TestsFilterFlags, failed_tests_cache: FailedTestsCache) -> Self {
+        let TestsFilterFlags {
+            test_name_filter,
+            exact_match,
+            only_ignored,
+            include_ignored,
+            rerun_failed,
+            include_tags,
+            exclude_tags,
+            module_filter,
+        } = flags;
+
         assert!(
             !(only_ignored && include_ignored),
             "Arguments only_ignored and include_ignored cannot be both true"
@@ -69,6 +92,9 @@ impl TestsFilter {
             name_filter,
             ignored_filter,
             last_failed_filter: rerun_failed,
+            include_tags,
+            exclude_tags,
+            module_filter,
             failed_tests_cache,
         }
     }
@@ -88,6 +114,28 @@ impl TestsFilter {
             }
         };
 
+        if !self.include_tags.is_empty() {
+            test_cases.retain(|tc| {
+                tc.config
+                    .tags
+                    .iter()
+                    .any(|tag| self.include_tags.contains(tag))
+            });
+        }
+
+        if !self.exclude_tags.is_empty() {
+            test_cases.retain(|tc| {
+                !tc.config
+                    .tags
+                    .iter()
+                    .any(|tag| self.exclude_tags.contains(tag))
+            });
+        }
+
+        if let Some(module) = &self.module_filter {
+            test_cases.retain(|tc| is_in_module(&tc.name, module));
+        }
+
         if self.last_failed_filter {
             match self.failed_tests_cache.load()?.as_slice() {
                 [] => {}
@@ -109,6 +157,19 @@ impl TestsFilter {
     }
 }
 
+/// Checks if the test is defined in the module or its submodules.
+/// Test names start with the package name, so the module path is matched
+/// both from the package root and from the first module in it
+fn is_in_module(test_name: &str, module: &str) -> bool {
+    let module = module.trim_end_matches("::");
+    let prefix = format!("{module}::");
+
+    test_name.starts_with(&prefix)
+        || test_name
+            .split_once("::")
+            .is_some_and(|(_, path)| path.starts_with(&prefix))
+}
+
 impl TestCaseFilter for TestsFilter {
     fn should_be_run(&self, test_case: &TestCaseWithResolvedConfig) -> bool {
         let ignored = test_case.config.ignored;
@@ -123,14 +184,14 @@ impl TestCaseFilter for TestsFilter {
 
 #[cfg(test)]
 mod tests {
-    use crate::test_filter::TestsFilter;
+    use crate::test_filter::{TestsFilter, TestsFilterFlags};
     use cairo_lang_sierra::program::Program;
     use cairo_lang_sierra::program::ProgramArtifact;
     use forge_runner::expected_result::ExpectedTestResult;
     use forge_runner::package_tests::with_config_resolved::{
         TestCaseResolvedConfig, TestCaseWithResolvedConfig, TestTargetWithResolvedConfig,
     };
-    use forge_runner::package_tests::{TestDetails, TestTargetLocation};
+    use forge_runner::package_tests::{TestDetails, TestKind, TestTargetLocation};
     use std::sync::Arc;
     use universal_sierra_compiler_api::compile_sierra_to_casm;
 
@@ -149,13 +210,26 @@ mod tests {
     #[test]
     #[should_panic(expected = "Arguments only_ignored and include_ignored cannot be both true")]
     fn from_flags_only_ignored_and_include_ignored_both_true() {
-        let _ = TestsFilter::from_flags(None, false, true, true, false, Default::default());
+        let _ = TestsFilter::from_flags(
+            TestsFilterFlags {
+                only_ignored: true,
+                include_ignored: true,
+                ..Default::default()
+            },
+            Default::default(),
+        );
     }
 
     #[test]
     #[should_panic(expected = "Argument test_name_filter cannot be None with exact_match")]
     fn from_flags_exact_match_true_without_test_filter_name() {
-        let _ = TestsFilter::from_flags(None, true, false, false, false, Default::default());
+        let _ = TestsFilter::from_flags(
+            TestsFilterFlags {
+                exact_match: true,
+                ..Default::default()
+            },
+            Default::default(),
+        );
     }
 
     #[test]
@@ -174,7 +248,11 @@ mod tests {
                         ignored: false,
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
+                        parallel_fork: false,
                         fuzzer_config: None,
+                        tags: vec![],
+                        test_kind: TestKind::External,
+                        timeout: None,
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -186,7 +264,11 @@ mod tests {
                         ignored: true,
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
+                        parallel_fork: false,
                         fuzzer_config: None,
+                        tags: vec![],
+                        test_kind: TestKind::External,
+                        timeout: None,
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -198,7 +280,11 @@ mod tests {
                         ignored: true,
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
+                        parallel_fork: false,
                         fuzzer_config: None,
+                        tags: vec![],
+                        test_kind: TestKind::External,
+                        timeout: None,
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -210,7 +296,11 @@ mod tests {
                         ignored: false,
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
+                        parallel_fork: false,
                         fuzzer_config: None,
+                        tags: vec![],
+                        test_kind: TestKind::External,
+                        timeout: None,
                     },
                 },
             ],
@@ -218,11 +308,10 @@ mod tests {
         };
 
         let tests_filter = TestsFilter::from_flags(
-            Some("do".to_string()),
-            false,
-            false,
-            false,
-            false,
+            TestsFilterFlags {
+                test_name_filter: Some("do".to_string()),
+                ..Default::default()
+            },
             Default::default(),
         );
 
@@ -241,17 +330,20 @@ mod tests {
                     ignored: false,
                     expected_result: ExpectedTestResult::Success,
                     fork_config: None,
+                    parallel_fork: false,
                     fuzzer_config: None,
+                    tags: vec![],
+                    test_kind: TestKind::External,
+                    timeout: None,
                 },
             },]
         );
 
         let tests_filter = TestsFilter::from_flags(
-            Some("te2::run".to_string()),
-            false,
-            false,
-            false,
-            false,
+            TestsFilterFlags {
+                test_name_filter: Some("te2::run".to_string()),
+                ..Default::default()
+            },
             Default::default(),
         );
 
@@ -269,17 +361,20 @@ mod tests {
                     ignored: true,
                     expected_result: ExpectedTestResult::Success,
                     fork_config: None,
+                    parallel_fork: false,
                     fuzzer_config: None,
+                    tags: vec![],
+                    test_kind: TestKind::External,
+                    timeout: None,
                 },
             },]
         );
 
         let tests_filter = TestsFilter::from_flags(
-            Some("thing".to_string()),
-            false,
-            false,
-            false,
-            false,
+            TestsFilterFlags {
+                test_name_filter: Some("thing".to_string()),
+                ..Default::default()
+            },
             Default::default(),
         );
 
@@ -298,7 +393,11 @@ mod tests {
                         ignored: false,
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
+                        parallel_fork: false,
                         fuzzer_config: None,
+                        tags: vec![],
+                        test_kind: TestKind::External,
+                        timeout: None,
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -310,7 +409,11 @@ mod tests {
                         ignored: true,
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
+                        parallel_fork: false,
                         fuzzer_config: None,
+                        tags: vec![],
+                        test_kind: TestKind::External,
+                        timeout: None,
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -322,7 +425,11 @@ mod tests {
                         ignored: true,
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
+                        parallel_fork: false,
                         fuzzer_config: None,
+                        tags: vec![],
+                        test_kind: TestKind::External,
+                        timeout: None,
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -334,18 +441,21 @@ mod tests {
                         ignored: false,
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
+                        parallel_fork: false,
                         fuzzer_config: None,
+                        tags: vec![],
+                        test_kind: TestKind::External,
+                        timeout: None,
                     },
                 },
             ]
         );
 
         let tests_filter = TestsFilter::from_flags(
-            Some("nonexistent".to_string()),
-            false,
-            false,
-            false,
-            false,
+            TestsFilterFlags {
+                test_name_filter: Some("nonexistent".to_string()),
+                ..Default::default()
+            },
             Default::default(),
         );
 
@@ -355,11 +465,10 @@ mod tests {
         assert_eq!(filtered.test_cases, vec![]);
 
         let tests_filter = TestsFilter::from_flags(
-            Some(String::new()),
-            false,
-            false,
-            false,
-            false,
+            TestsFilterFlags {
+                test_name_filter: Some(String::new()),
+                ..Default::default()
+            },
             Default::default(),
         );
 
@@ -378,7 +487,11 @@ mod tests {
                         ignored: false,
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
+                        parallel_fork: false,
                         fuzzer_config: None,
+                        tags: vec![],
+                        test_kind: TestKind::External,
+                        timeout: None,
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -390,7 +503,11 @@ mod tests {
                         ignored: true,
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
+                        parallel_fork: false,
                         fuzzer_config: None,
+                        tags: vec![],
+                        test_kind: TestKind::External,
+                        timeout: None,
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -402,7 +519,11 @@ mod tests {
                         ignored: true,
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
+                        parallel_fork: false,
                         fuzzer_config: None,
+                        tags: vec![],
+                        test_kind: TestKind::External,
+                        timeout: None,
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -414,7 +535,11 @@ mod tests {
                         ignored: false,
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
+                        parallel_fork: false,
                         fuzzer_config: None,
+                        tags: vec![],
+                        test_kind: TestKind::External,
+                        timeout: None,
                     },
                 },
             ]
@@ -431,11 +556,10 @@ mod tests {
         };
 
         let tests_filter = TestsFilter::from_flags(
-            Some(String::new()),
-            false,
-            false,
-            false,
-            false,
+            TestsFilterFlags {
+                test_name_filter: Some(String::new()),
+                ..Default::default()
+            },
             Default::default(),
         );
 
@@ -445,11 +569,10 @@ mod tests {
         assert_eq!(filtered.test_cases, vec![]);
 
         let tests_filter = TestsFilter::from_flags(
-            Some("thing".to_string()),
-            false,
-            false,
-            false,
-            false,
+            TestsFilterFlags {
+                test_name_filter: Some("thing".to_string()),
+                ..Default::default()
+            },
             Default::default(),
         );
 
@@ -475,7 +598,11 @@ mod tests {
                         ignored: false,
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
+                        parallel_fork: false,
                         fuzzer_config: None,
+                        tags: vec![],
+                        test_kind: TestKind::External,
+                        timeout: None,
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -487,7 +614,11 @@ mod tests {
                         ignored: true,
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
+                        parallel_fork: false,
                         fuzzer_config: None,
+                        tags: vec![],
+                        test_kind: TestKind::External,
+                        timeout: None,
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -499,7 +630,11 @@ mod tests {
                         ignored: true,
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
+                        parallel_fork: false,
                         fuzzer_config: None,
+                        tags: vec![],
+                        test_kind: TestKind::External,
+                        timeout: None,
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -511,7 +646,11 @@ mod tests {
                         ignored: false,
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
+                        parallel_fork: false,
                         fuzzer_config: None,
+                        tags: vec![],
+                        test_kind: TestKind::External,
+                        timeout: None,
                     },
                 },
             ],
@@ -519,11 +658,11 @@ mod tests {
         };
 
         let tests_filter = TestsFilter::from_flags(
-            Some(String::new()),
-            true,
-            false,
-            false,
-            false,
+            TestsFilterFlags {
+                test_name_filter: Some(String::new()),
+                exact_match: true,
+                ..Default::default()
+            },
             Default::default(),
         );
 
@@ -533,11 +672,11 @@ mod tests {
         assert_eq!(filtered.test_cases, vec![]);
 
         let tests_filter = TestsFilter::from_flags(
-            Some("thing".to_string()),
-            true,
-            false,
-            false,
-            false,
+            TestsFilterFlags {
+                test_name_filter: Some("thing".to_string()),
+                exact_match: true,
+                ..Default::default()
+            },
             Default::default(),
         );
 
@@ -547,11 +686,11 @@ mod tests {
         assert_eq!(filtered.test_cases, vec![]);
 
         let tests_filter = TestsFilter::from_flags(
-            Some("do_thing".to_string()),
-            true,
-            false,
-            false,
-            false,
+            TestsFilterFlags {
+                test_name_filter: Some("do_thing".to_string()),
+                exact_match: true,
+                ..Default::default()
+            },
             Default::default(),
         );
 
@@ -569,17 +708,21 @@ mod tests {
                     ignored: false,
                     expected_result: ExpectedTestResult::Success,
                     fork_config: None,
+                    parallel_fork: false,
                     fuzzer_config: None,
+                    tags: vec![],
+                    test_kind: TestKind::External,
+                    timeout: None,
                 },
             },]
         );
 
         let tests_filter = TestsFilter::from_flags(
-            Some("crate1::do_thing".to_string()),
-            true,
-            false,
-            false,
-            false,
+            TestsFilterFlags {
+                test_name_filter: Some("crate1::do_thing".to_string()),
+                exact_match: true,
+                ..Default::default()
+            },
             Default::default(),
         );
 
@@ -597,17 +740,21 @@ mod tests {
                     ignored: false,
                     expected_result: ExpectedTestResult::Success,
                     fork_config: None,
+                    parallel_fork: false,
                     fuzzer_config: None,
+                    tags: vec![],
+                    test_kind: TestKind::External,
+                    timeout: None,
                 },
             },]
         );
 
         let tests_filter = TestsFilter::from_flags(
-            Some("crate3::run_other_thing".to_string()),
-            true,
-            false,
-            false,
-            false,
+            TestsFilterFlags {
+                test_name_filter: Some("crate3::run_other_thing".to_string()),
+                exact_match: true,
+                ..Default::default()
+            },
             Default::default(),
         );
 
@@ -617,11 +764,11 @@ mod tests {
         assert_eq!(filtered.test_cases, vec![]);
 
         let tests_filter = TestsFilter::from_flags(
-            Some("outer::crate3::run_other_thing".to_string()),
-            true,
-            false,
-            false,
-            false,
+            TestsFilterFlags {
+                test_name_filter: Some("outer::crate3::run_other_thing".to_string()),
+                exact_match: true,
+                ..Default::default()
+            },
             Default::default(),
         );
 
@@ -639,7 +786,11 @@ mod tests {
                     ignored: true,
                     expected_result: ExpectedTestResult::Success,
                     fork_config: None,
+                    parallel_fork: false,
                     fuzzer_config: None,
+                    tags: vec![],
+                    test_kind: TestKind::External,
+                    timeout: None,
                 },
             },]
         );
@@ -660,7 +811,11 @@ mod tests {
                         ignored: false,
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
+                        parallel_fork: false,
                         fuzzer_config: None,
+                        tags: vec![],
+                        test_kind: TestKind::External,
+                        timeout: None,
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -672,7 +827,11 @@ mod tests {
                         ignored: true,
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
+                        parallel_fork: false,
                         fuzzer_config: None,
+                        tags: vec![],
+                        test_kind: TestKind::External,
+                        timeout: None,
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -684,7 +843,11 @@ mod tests {
                         ignored: true,
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
+                        parallel_fork: false,
                         fuzzer_config: None,
+                        tags: vec![],
+                        test_kind: TestKind::External,
+                        timeout: None,
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -696,15 +859,24 @@ mod tests {
                         ignored: false,
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
+                        parallel_fork: false,
                         fuzzer_config: None,
+                        tags: vec![],
+                        test_kind: TestKind::External,
+                        timeout: None,
                     },
                 },
             ],
             tests_location: TestTargetLocation::Tests,
         };
 
-        let tests_filter =
-            TestsFilter::from_flags(None, false, true, false, false, Default::default());
+        let tests_filter = TestsFilter::from_flags(
+            TestsFilterFlags {
+                only_ignored: true,
+                ..Default::default()
+            },
+            Default::default(),
+        );
         let mut filtered = mocked_tests;
         tests_filter.filter_tests(&mut filtered.test_cases).unwrap();
 
@@ -720,7 +892,11 @@ mod tests {
                         ignored: true,
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
+                        parallel_fork: false,
                         fuzzer_config: None,
+                        tags: vec![],
+                        test_kind: TestKind::External,
+                        timeout: None,
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -732,7 +908,11 @@ mod tests {
                         ignored: true,
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
+                        parallel_fork: false,
                         fuzzer_config: None,
+                        tags: vec![],
+                        test_kind: TestKind::External,
+                        timeout: None,
                     },
                 },
             ]
@@ -755,7 +935,11 @@ mod tests {
                         ignored: false,
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
+                        parallel_fork: false,
                         fuzzer_config: None,
+                        tags: vec![],
+                        test_kind: TestKind::External,
+                        timeout: None,
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -767,7 +951,11 @@ mod tests {
                         ignored: true,
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
+                        parallel_fork: false,
                         fuzzer_config: None,
+                        tags: vec![],
+                        test_kind: TestKind::External,
+                        timeout: None,
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -779,7 +967,11 @@ mod tests {
                         ignored: true,
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
+                        parallel_fork: false,
                         fuzzer_config: None,
+                        tags: vec![],
+                        test_kind: TestKind::External,
+                        timeout: None,
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -791,15 +983,24 @@ mod tests {
                         ignored: false,
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
+                        parallel_fork: false,
                         fuzzer_config: None,
+                        tags: vec![],
+                        test_kind: TestKind::External,
+                        timeout: None,
                     },
                 },
             ],
             tests_location: TestTargetLocation::Tests,
         };
 
-        let tests_filter =
-            TestsFilter::from_flags(None, false, false, true, false, Default::default());
+        let tests_filter = TestsFilter::from_flags(
+            TestsFilterFlags {
+                include_ignored: true,
+                ..Default::default()
+            },
+            Default::default(),
+        );
         let mut filtered = mocked_tests;
         tests_filter.filter_tests(&mut filtered.test_cases).unwrap();
 
@@ -815,7 +1016,11 @@ mod tests {
                         ignored: false,
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
+                        parallel_fork: false,
                         fuzzer_config: None,
+                        tags: vec![],
+                        test_kind: TestKind::External,
+                        timeout: None,
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -827,7 +1032,11 @@ mod tests {
                         ignored: true,
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
+                        parallel_fork: false,
                         fuzzer_config: None,
+                        tags: vec![],
+                        test_kind: TestKind::External,
+                        timeout: None,
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -839,7 +1048,11 @@ mod tests {
                         ignored: true,
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
+                        parallel_fork: false,
                         fuzzer_config: None,
+                        tags: vec![],
+                        test_kind: TestKind::External,
+                        timeout: None,
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -851,10 +1064,133 @@ mod tests {
                         ignored: false,
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
+                        parallel_fork: false,
                         fuzzer_config: None,
+                        tags: vec![],
+                        test_kind: TestKind::External,
+                        timeout: None,
                     },
                 },
             ]
         );
     }
+
+    #[test]
+    fn filtering_with_tags() {
+        let test_case = |name: &str, tags: &[&str]| TestCaseWithResolvedConfig {
+            name: name.to_string(),
+            test_details: TestDetails::default(),
+
+            config: TestCaseResolvedConfig {
+                available_gas: None,
+                ignored: false,
+                expected_result: ExpectedTestResult::Success,
+                fork_config: None,
+                parallel_fork: false,
+                fuzzer_config: None,
+                tags: tags.iter().map(ToString::to_string).collect(),
+                test_kind: TestKind::External,
+                timeout: None,
+            },
+        };
+        let mocked_tests = vec![
+            test_case("crate1::untagged", &[]),
+            test_case("crate1::slow", &["slow"]),
+            test_case("crate1::integration", &["integration"]),
+            test_case("crate1::slow_integration", &["slow", "integration"]),
+        ];
+
+        let tests_filter = TestsFilter::from_flags(
+            TestsFilterFlags {
+                include_tags: vec!["slow".to_string(), "integration".to_string()],
+                ..Default::default()
+            },
+            Default::default(),
+        );
+        let mut filtered = mocked_tests.clone();
+        tests_filter.filter_tests(&mut filtered).unwrap();
+
+        assert_eq!(filtered, mocked_tests[1..].to_vec());
+
+        let tests_filter = TestsFilter::from_flags(
+            TestsFilterFlags {
+                exclude_tags: vec!["slow".to_string()],
+                ..Default::default()
+            },
+            Default::default(),
+        );
+        let mut filtered = mocked_tests.clone();
+        tests_filter.filter_tests(&mut filtered).unwrap();
+
+        assert_eq!(
+            filtered,
+            vec![mocked_tests[0].clone(), mocked_tests[2].clone()]
+        );
+
+        let tests_filter = TestsFilter::from_flags(
+            TestsFilterFlags {
+                include_tags: vec!["integration".to_string()],
+                exclude_tags: vec!["slow".to_string()],
+                ..Default::default()
+            },
+            Default::default(),
+        );
+        let mut filtered = mocked_tests.clone();
+        tests_filter.filter_tests(&mut filtered).unwrap();
+
+        assert_eq!(filtered, vec![mocked_tests[2].clone()]);
+    }
+
+    #[test]
+    fn filtering_with_module() {
+        let test_case = |name: &str| TestCaseWithResolvedConfig {
+            name: name.to_string(),
+            test_details: TestDetails::default(),
+
+            config: TestCaseResolvedConfig {
+                available_gas: None,
+                ignored: false,
+                expected_result: ExpectedTestResult::Success,
+                fork_config: None,
+                parallel_fork: false,
+                fuzzer_config: None,
+                tags: vec![],
+                test_kind: TestKind::External,
+                timeout: None,
+            },
+        };
+        let mocked_tests = vec![
+            test_case("crate1::tests::unit::add"),
+            test_case("crate1::tests::unit::nested::sub"),
+            test_case("crate1::tests::unit_other::mul"),
+            test_case("crate1::tests::integration::div"),
+            test_case("crate1::other::tests::unit::pow"),
+        ];
+
+        for module in ["tests::unit", "crate1::tests::unit", "tests::unit::"] {
+            let tests_filter = TestsFilter::from_flags(
+                TestsFilterFlags {
+                    module_filter: Some(module.to_string()),
+                    ..Default::default()
+                },
+                Default::default(),
+            );
+            let mut filtered = mocked_tests.clone();
+            tests_filter.filter_tests(&mut filtered).unwrap();
+
+            assert_eq!(filtered, mocked_tests[..2].to_vec());
+        }
+
+        let tests_filter = TestsFilter::from_flags(
+            TestsFilterFlags {
+                module_filter: Some("crate1::other".to_string()),
+                ..Default::default()
+            },
+            Default::default(),
+        );
+        let mut filtered = mocked_tests.clone();
+        tests_filter.filter_tests(&mut filtered).unwrap();
+
+        assert_eq!(filtered, vec![mocked_tests[4].clone()]);
+    }
 }
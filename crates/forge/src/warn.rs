@@ -4,7 +4,7 @@ use scarb_api::{package_matches_version_requirement, ScarbCommand};
 use scarb_metadata::Metadata;
 use semver::{Comparator, Op, Version, VersionReq};
 use shared::print::print_as_warning;
-use shared::rpc::create_rpc_client;
+use shared::rpc::{create_rpc_client, RetryConfig};
 use shared::verify_and_warn_if_incompatible_rpc_version;
 use std::collections::HashSet;
 use url::Url;
@@ -50,7 +50,7 @@ pub(crate) async fn warn_if_incompatible_rpc_version(
         handles.push(tokio::spawn(async move {
             let client = create_rpc_client(url.as_ref())?;
 
-            verify_and_warn_if_incompatible_rpc_version(&client, &url).await
+            verify_and_warn_if_incompatible_rpc_version(&client, &url, RetryConfig::default()).await
         }));
     }
 
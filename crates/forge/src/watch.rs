@@ -0,0 +1,119 @@
+use crate::{pretty_printing, run_tests::workspace::run_for_workspace, TestArgs};
+use anyhow::{Context, Result};
+use camino::Utf8PathBuf;
+use console::style;
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+use scarb_api::{metadata::MetadataCommandExt, ScarbCommand};
+use std::path::Path;
+use std::sync::mpsc::{channel, Receiver};
+use std::time::Duration;
+use tokio::runtime::Runtime;
+
+/// Directories of a package which are watched for changes
+const WATCHED_DIRS: [&str; 2] = ["src", "tests"];
+
+/// Time to wait for more events after a change is detected, so that saving several files
+/// at once (e.g. by an editor or `git checkout`) triggers a single run
+const DEBOUNCE_TIME: Duration = Duration::from_millis(300);
+
+/// Runs tests, then re-runs them every time a `.cairo` file in `src` or `tests` directory
+/// of any of the selected packages changes. Returns only if watching files fails
+pub fn run_in_watch_mode(args: &TestArgs, rt: &Runtime) -> Result<()> {
+    let scarb_metadata = ScarbCommand::metadata().inherit_stderr().run()?;
+    let packages = args
+        .packages_filter
+        .match_many(&scarb_metadata)
+        .context("Failed to find any packages matching the specified filter")?;
+
+    let (sender, receiver) = channel();
+    let mut watcher =
+        notify::recommended_watcher(sender).context("Failed to create a file watcher")?;
+
+    let watched_dirs: Vec<Utf8PathBuf> = packages
+        .iter()
+        .flat_map(|package| WATCHED_DIRS.map(|dir| package.root.join(dir)))
+        .filter(|dir| dir.is_dir())
+        .collect();
+    for dir in &watched_dirs {
+        watcher
+            .watch(dir.as_std_path(), RecursiveMode::Recursive)
+            .with_context(|| format!("Failed to watch directory {dir}"))?;
+    }
+
+    loop {
+        // Failures are only reported, the next change of files may fix them
+        if let Err(error) = rt.block_on(run_for_workspace(args.clone())) {
+            pretty_printing::print_error_message(&error);
+        }
+
+        println!(
+            "\n{}",
+            style("Waiting for changes in .cairo files, press Ctrl+C to exit").bold()
+        );
+        wait_for_change(&receiver)?;
+        println!("{}", style("Files changed, re-running tests").bold());
+    }
+}
+
+fn wait_for_change(receiver: &Receiver<notify::Result<Event>>) -> Result<()> {
+    loop {
+        let event = receiver
+            .recv()
+            .context("File watcher stopped unexpectedly")?
+            .context("Failed to watch files")?;
+
+        if is_cairo_file_change(&event) {
+            break;
+        }
+    }
+
+    while receiver.recv_timeout(DEBOUNCE_TIME).is_ok() {}
+
+    Ok(())
+}
+
+fn is_cairo_file_change(event: &Event) -> bool {
+    let is_change = matches!(
+        event.kind,
+        EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+    );
+
+    is_change && event.paths.iter().any(|path| is_cairo_file(path))
+}
+
+fn is_cairo_file(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|extension| extension == "cairo")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_cairo_file_change;
+    use notify::event::{AccessKind, CreateKind, ModifyKind};
+    use notify::{Event, EventKind};
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_cairo_file_modified() {
+        let event = Event::new(EventKind::Modify(ModifyKind::Any))
+            .add_path(PathBuf::from("/project/src/lib.cairo"));
+
+        assert!(is_cairo_file_change(&event));
+    }
+
+    #[test]
+    fn test_other_file_created() {
+        let event = Event::new(EventKind::Create(CreateKind::File))
+            .add_path(PathBuf::from("/project/src/notes.md"));
+
+        assert!(!is_cairo_file_change(&event));
+    }
+
+    #[test]
+    fn test_cairo_file_accessed() {
+        let event = Event::new(EventKind::Access(AccessKind::Any))
+            .add_path(PathBuf::from("/project/tests/test_contract.cairo"));
+
+        assert!(!is_cairo_file_change(&event));
+    }
+}
@@ -3,9 +3,9 @@ use camino::Utf8PathBuf;
 use cheatnet::runtime_extensions::forge_runtime_extension::contracts_data::ContractsData;
 use forge::{
     block_number_map::BlockNumberMap,
-    run_tests::package::{run_for_package, RunForPackageArgs},
+    run_tests::package::{run_for_package, RunForPackageArgs, TestOrder},
     scarb::load_test_artifacts,
-    test_filter::TestsFilter,
+    test_filter::{TestsFilter, TestsFilterFlags},
 };
 use forge_runner::build_trace_data::test_sierra_program_path::VERSIONED_PROGRAMS_DIR;
 use forge_runner::forge_config::{
@@ -14,10 +14,12 @@ use forge_runner::forge_config::{
 use forge_runner::test_target_summary::TestTargetSummary;
 use forge_runner::CACHE_DIR;
 use scarb_api::{metadata::MetadataCommandExt, ScarbCommand};
+use shared::rpc::RetryConfig;
 use std::num::NonZeroU32;
 use std::sync::Arc;
 use tempfile::tempdir;
 use tokio::runtime::Runtime;
+use tokio::sync::Semaphore;
 
 #[must_use]
 pub fn run_test_case(test: &TestCase) -> Vec<TestTargetSummary> {
@@ -47,12 +49,11 @@ pub fn run_test_case(test: &TestCase) -> Vec<TestTargetSummary> {
         RunForPackageArgs {
             test_targets: raw_test_targets,
             package_name: "test_package".to_string(),
+            test_order: TestOrder::Definition,
             tests_filter: TestsFilter::from_flags(
-                None,
-                false,
-                false,
-                false,
-                false,
+                TestsFilterFlags {
+                    ..Default::default()
+                },
                 Default::default(),
             ),
             forge_config: Arc::new(ForgeConfig {
@@ -60,11 +61,20 @@ pub fn run_test_case(test: &TestCase) -> Vec<TestTargetSummary> {
                     exit_first: false,
                     fuzzer_runs: NonZeroU32::new(256).unwrap(),
                     fuzzer_seed: 12345,
+                    replay_fuzzer_corpus: false,
+                    fuzzer_shrink_iterations: 100,
                     max_n_steps: None,
                     is_vm_trace_needed: false,
+                    save_execution_trace: false,
                     cache_dir: Utf8PathBuf::from_path_buf(tempdir().unwrap().into_path())
                         .unwrap()
                         .join(CACHE_DIR),
+                    fork_cache_ttl: None,
+                    test_timeout: None,
+                    max_parallel_tests: Semaphore::MAX_PERMITS,
+                    save_state: None,
+                    initial_state: None,
+                    rpc_retry_config: RetryConfig::default(),
                     contracts_data: ContractsData::try_from(test.contracts().unwrap()).unwrap(),
                     environment_variables: test.env().clone(),
                 }),
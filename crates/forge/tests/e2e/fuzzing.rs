@@ -1,4 +1,4 @@
-use super::common::runner::{setup_package, test_runner};
+use super::common::runner::{runner, setup_package, test_runner};
 use indoc::indoc;
 use shared::test_utils::output_assert::{assert_stderr_contains, assert_stdout_contains};
 
@@ -21,7 +21,7 @@ fn fuzzing() {
         [PASS] fuzzing::tests::fuzzed_argument (runs: 256, [..]
         [PASS] fuzzing::tests::fuzzed_both_arguments (runs: 256, [..]
         [PASS] fuzzing::tests::passing [..]
-        [FAIL] fuzzing::tests::failing_fuzz (runs: 1, arguments: [[..], [..]])
+        [FAIL] fuzzing::tests::failing_fuzz (runs: 1, arguments: [[..], [..]], seed: [..])
 
         Failure data:
             0x726573756c74203d3d2061202b2062 ('result == a + b')
@@ -66,7 +66,7 @@ fn fuzzing_set_runs() {
         [PASS] fuzzing::tests::fuzzed_argument (runs: 10, [..]
         [PASS] fuzzing::tests::fuzzed_both_arguments (runs: 10, [..]
         [PASS] fuzzing::tests::passing [..]
-        [FAIL] fuzzing::tests::failing_fuzz (runs: 1, arguments: [[..], [..]])
+        [FAIL] fuzzing::tests::failing_fuzz (runs: 1, arguments: [[..], [..]], seed: [..])
 
         Failure data:
             0x726573756c74203d3d2061202b2062 ('result == a + b')
@@ -111,7 +111,7 @@ fn fuzzing_set_seed() {
         [PASS] fuzzing::tests::fuzzed_argument (runs: 256, [..]
         [PASS] fuzzing::tests::fuzzed_both_arguments (runs: 256, [..]
         [PASS] fuzzing::tests::passing [..]
-        [FAIL] fuzzing::tests::failing_fuzz (runs: 1, arguments: [[..], [..]])
+        [FAIL] fuzzing::tests::failing_fuzz (runs: 1, arguments: [[..], [..]], minimized from: [[..], [..]], seed: 1234)
 
         Failure data:
             0x726573756c74203d3d2061202b2062 ('result == a + b')
@@ -191,7 +191,7 @@ fn fuzzing_exit_first() {
 
         Collected 2 test(s) from fuzzing package
         Running 2 test(s) from tests/
-        [FAIL] fuzzing_integrationtest::exit_first_fuzz::exit_first_fails_test (runs: 1, arguments: [..])
+        [FAIL] fuzzing_integrationtest::exit_first_fuzz::exit_first_fails_test (runs: 1, arguments: [..], seed: [..])
 
         Failure data:
             0x32202b2062203d3d2032202b2062 ('2 + b == 2 + b')
@@ -235,3 +235,33 @@ fn fuzzing_exit_first_single_fail() {
         "},
     );
 }
+
+#[test]
+fn fuzz_replay_accepts_test_flags() {
+    let temp = setup_package("fuzzing");
+
+    let output = runner(&temp)
+        .args([
+            "fuzz",
+            "replay",
+            "--test",
+            "fuzzing::tests::fuzzed_argument",
+            "--exact",
+        ])
+        .assert()
+        .success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        [..]Compiling[..]
+        [..]Finished[..]
+
+
+        Collected 1 test(s) from fuzzing package
+        Running 1 test(s) from src/
+        Running 0 test(s) from tests/
+        Tests: 0 passed, 0 failed, 1 skipped, 0 ignored, [..] filtered out
+        "},
+    );
+}
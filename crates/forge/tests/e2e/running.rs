@@ -736,27 +736,7 @@ fn validate_init(temp: &TempDir, validate_snforge_std: bool) {
 
     assert_matches(&expected, &scarb_toml);
 
-    let mut scarb_toml = DocumentMut::from_str(&scarb_toml).unwrap();
-
-    let dependencies = scarb_toml
-        .get_mut("dev-dependencies")
-        .unwrap()
-        .as_table_mut()
-        .unwrap();
-
-    let local_snforge_std = get_local_snforge_std_absolute_path()
-        .unwrap()
-        .to_str()
-        .unwrap()
-        .to_string();
-
-    let mut snforge_std = InlineTable::new();
-    snforge_std.insert("path", Value::String(Formatted::new(local_snforge_std)));
-
-    dependencies.remove("snforge_std");
-    dependencies.insert("snforge_std", Item::Value(Value::InlineTable(snforge_std)));
-
-    std::fs::write(manifest_path, scarb_toml.to_string()).unwrap();
+    use_local_snforge_std(&manifest_path);
 
     let output = test_runner(temp)
         .current_dir(temp.child(Path::new("test_name")))
@@ -780,6 +760,70 @@ fn validate_init(temp: &TempDir, validate_snforge_std: bool) {
     assert_stdout_contains(output, expected);
 }
 
+#[test]
+fn init_new_project_with_erc20_template() {
+    let temp = tempdir_with_tool_versions().unwrap();
+
+    runner(&temp)
+        .args(["init", "test_name", "--template", "erc20"])
+        .env("DEV_DISABLE_SNFORGE_STD_DEPENDENCY", "true")
+        .assert()
+        .success();
+
+    let lib = fs::read_to_string(temp.join("test_name/src/lib.cairo")).unwrap();
+    assert!(lib.contains("pub mod ERC20 {"));
+
+    use_local_snforge_std(&temp.join("test_name/Scarb.toml"));
+
+    let output = test_runner(&temp)
+        .current_dir(temp.child(Path::new("test_name")))
+        .assert()
+        .success();
+
+    assert_stdout_contains(
+        output,
+        indoc!(
+            r"
+            [..]Compiling test_name v0.1.0[..]
+            [..]Finished[..]
+
+            Collected 3 test(s) from test_name package
+            Running 0 test(s) from src/
+            Running 3 test(s) from tests/
+            [..]
+            [..]
+            [..]
+            Tests: 3 passed, 0 failed, 0 skipped, 0 ignored, 0 filtered out
+            "
+        ),
+    );
+}
+
+fn use_local_snforge_std(manifest_path: &Path) {
+    let scarb_toml = fs::read_to_string(manifest_path).unwrap();
+    let mut scarb_toml = DocumentMut::from_str(&scarb_toml).unwrap();
+
+    let dependencies = scarb_toml
+        .get_mut("dev-dependencies")
+        .unwrap()
+        .as_table_mut()
+        .unwrap();
+
+    let local_snforge_std = get_local_snforge_std_absolute_path()
+        .unwrap()
+        .to_str()
+        .unwrap()
+        .to_string();
+
+    let mut snforge_std = InlineTable::new();
+    snforge_std.insert("path", Value::String(Formatted::new(local_snforge_std)));
+
+    dependencies.remove("snforge_std");
+    dependencies.insert("snforge_std", Item::Value(Value::InlineTable(snforge_std)));
+
+    std::fs::write(manifest_path, scarb_toml.to_string()).unwrap();
+}
+
 #[test]
 #[cfg(feature = "smoke")]
 fn test_init_project_with_custom_snforge_dependency_git() {
@@ -1041,6 +1085,43 @@ fn detailed_resources_flag() {
     );
 }
 
+#[test]
+fn gas_report_flag() {
+    let temp = setup_package("erc20_package");
+    let output = test_runner(&temp).arg("--gas-report").assert().success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        [..]Compiling[..]
+        [..]Finished[..]
+
+
+        Collected 1 test(s) from erc20_package package
+        Running 0 test(s) from src/
+        Running 1 test(s) from tests/
+        [PASS] erc20_package_integrationtest::test_complex::complex (gas: ~[..])
+        Tests: 1 passed, 0 failed, 0 skipped, 0 ignored, 0 filtered out
+
+        Gas report
+
+        | Test[..]| L1 gas[..]| L1 data gas[..]| L2 gas[..]| Total[..]|
+        |[..]|
+        | erc20_package_integrationtest::test_complex::complex | [..] | [..] | 0 [..] | [..] |
+
+        | ERC20 contract     |[..]|
+        |[..]|
+        | Function Name      | min[..]| avg[..]| median[..]| max[..]| # calls |
+        | allowance          | [..] | 2       |
+        | balance_of         | [..] |
+        | constructor        | [..] | 1       |
+        | increase_allowance | [..] | 1       |
+        | transfer           | [..] | 1       |
+        | transfer_from      | [..] | 1       |
+        "},
+    );
+}
+
 #[test]
 fn catch_runtime_errors() {
     let temp = setup_package("simple_package");
@@ -1075,3 +1156,375 @@ fn catch_runtime_errors() {
         ),
     );
 }
+
+#[test]
+fn test_timeout() {
+    let temp = setup_package("empty");
+
+    temp.child("tests/test.cairo")
+        .write_str(indoc!(
+            r"
+                #[test]
+                #[timeout(500)]
+                fn infinite_loop() {
+                    let mut i: felt252 = 0;
+                    loop {
+                        i += 1;
+                    }
+                }
+
+                #[test]
+                fn infinite_loop_with_global_timeout() {
+                    let mut i: felt252 = 0;
+                    loop {
+                        i += 1;
+                    }
+                }
+
+                #[test]
+                #[timeout(60000)]
+                fn finishing() {
+                    assert(1 + 1 == 2, 'simple check');
+                }
+            "
+        ))
+        .unwrap();
+
+    let output = test_runner(&temp)
+        .arg("--test-timeout")
+        .arg("1000")
+        .assert()
+        .code(1);
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+            [..]Compiling[..]
+            [..]Finished[..]
+
+
+            Collected 3 test(s) from empty package
+            Running 0 test(s) from src/
+            Running 3 test(s) from tests/
+            [PASS] empty_integrationtest::test::finishing [..]
+            [FAIL] empty_integrationtest::test::infinite_loop
+
+            Failure data:
+            [..]Test timed out after 500ms
+            [FAIL] empty_integrationtest::test::infinite_loop_with_global_timeout
+
+            Failure data:
+            [..]Test timed out after 1000ms
+            Tests: 1 passed, 2 failed, 0 skipped, 0 ignored, 0 filtered out
+        "},
+    );
+}
+
+#[test]
+fn test_timeout_does_not_block_serial_run() {
+    let temp = setup_package("empty");
+
+    temp.child("tests/test.cairo")
+        .write_str(indoc!(
+            r"
+                #[test]
+                #[timeout(500)]
+                fn infinite_loop() {
+                    let mut i: felt252 = 0;
+                    loop {
+                        i += 1;
+                    }
+                }
+
+                #[test]
+                fn finishing() {
+                    assert(1 + 1 == 2, 'simple check');
+                }
+            "
+        ))
+        .unwrap();
+
+    let output = test_runner(&temp).arg("--serial").assert().code(1);
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+            [..]Compiling[..]
+            [..]Finished[..]
+
+
+            Collected 2 test(s) from empty package
+            Running 0 test(s) from src/
+            Running 2 test(s) from tests/
+            [FAIL] empty_integrationtest::test::infinite_loop
+
+            Failure data:
+            [..]Test timed out after 500ms
+            [PASS] empty_integrationtest::test::finishing [..]
+            Tests: 1 passed, 1 failed, 0 skipped, 0 ignored, 0 filtered out
+        "},
+    );
+}
+
+#[test]
+fn test_timeout_fuzz_test() {
+    let temp = setup_package("empty");
+
+    temp.child("tests/test.cairo")
+        .write_str(indoc!(
+            r"
+                #[test]
+                #[fuzzer(runs: 10, seed: 100)]
+                #[timeout(500)]
+                fn infinite_loop_fuzz(a: u8) {
+                    let mut i: felt252 = a.into();
+                    loop {
+                        i += 1;
+                    }
+                }
+            "
+        ))
+        .unwrap();
+
+    let output = test_runner(&temp).assert().code(1);
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+            [..]Compiling[..]
+            [..]Finished[..]
+
+
+            Collected 1 test(s) from empty package
+            Running 0 test(s) from src/
+            Running 1 test(s) from tests/
+            [FAIL] empty_integrationtest::test::infinite_loop_fuzz (runs: 1, arguments: [[..]], seed: 100)
+
+            Failure data:
+            [..]Test timed out after 500ms
+            Tests: 0 passed, 1 failed, 0 skipped, 0 ignored, 0 filtered out
+        "},
+    );
+}
+
+#[test]
+fn test_randomize_order_with_seed() {
+    let temp = setup_package("empty");
+
+    temp.child("tests/test.cairo")
+        .write_str(indoc!(
+            r"
+                #[test]
+                fn first() {
+                    assert(1 + 1 == 2, 'simple check');
+                }
+
+                #[test]
+                fn second() {
+                    assert(2 + 2 == 4, 'simple check');
+                }
+            "
+        ))
+        .unwrap();
+
+    let output = test_runner(&temp)
+        .arg("--order")
+        .arg("random")
+        .arg("--order-seed")
+        .arg("100")
+        .assert()
+        .success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+            [..]Compiling[..]
+            [..]Finished[..]
+            Test order seed: 100
+
+
+            Collected 2 test(s) from empty package
+            Running 0 test(s) from src/
+            Running 2 test(s) from tests/
+            [PASS] [..]
+            [PASS] [..]
+            Tests: 2 passed, 0 failed, 0 skipped, 0 ignored, 0 filtered out
+        "},
+    );
+}
+
+#[test]
+fn test_lexicographic_order() {
+    let temp = setup_package("empty");
+
+    temp.child("tests/test.cairo")
+        .write_str(indoc!(
+            r"
+                #[test]
+                fn b_second() {
+                    assert(2 + 2 == 4, 'simple check');
+                }
+
+                #[test]
+                fn a_first() {
+                    assert(1 + 1 == 2, 'simple check');
+                }
+            "
+        ))
+        .unwrap();
+
+    let output = test_runner(&temp)
+        .arg("--order")
+        .arg("lexicographic")
+        .arg("--serial")
+        .assert()
+        .success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+            [..]Compiling[..]
+            [..]Finished[..]
+
+
+            Collected 2 test(s) from empty package
+            Running 0 test(s) from src/
+            Running 2 test(s) from tests/
+            [PASS] empty_integrationtest::test::a_first [..]
+            [PASS] empty_integrationtest::test::b_second [..]
+            Tests: 2 passed, 0 failed, 0 skipped, 0 ignored, 0 filtered out
+        "},
+    );
+}
+
+#[test]
+fn save_and_load_state() {
+    let temp = setup_package("simple_package");
+
+    temp.child("tests/state.cairo")
+        .write_str(indoc!(
+            r#"
+                use snforge_std::{declare, ContractClassTrait};
+                use snforge_std::cheatcodes::contract_class::DeclareResultTrait;
+                use simple_package::hello_starknet::{IHelloStarknetDispatcher, IHelloStarknetDispatcherTrait};
+
+                #[test]
+                fn setup_state() {
+                    let contract = declare("HelloStarknet").unwrap().contract_class();
+                    let (contract_address, _) = contract
+                        .deploy_at(@array![], 0x123.try_into().unwrap())
+                        .unwrap();
+                    IHelloStarknetDispatcher { contract_address }.increase_balance(100);
+                }
+
+                #[test]
+                fn check_state() {
+                    let dispatcher = IHelloStarknetDispatcher {
+                        contract_address: 0x123.try_into().unwrap()
+                    };
+                    assert(dispatcher.get_balance() == 100, 'state not loaded');
+
+                    dispatcher.increase_balance(50);
+                    assert(dispatcher.get_balance() == 150, 'state not modified');
+                }
+            "#
+        ))
+        .unwrap();
+
+    test_runner(&temp)
+        .arg("simple_package_integrationtest::state::setup_state")
+        .arg("--exact")
+        .arg("--save-state")
+        .arg("state.json")
+        .assert()
+        .success();
+
+    let output = test_runner(&temp)
+        .arg("check_state")
+        .arg("--load-state")
+        .arg("state.json")
+        .assert()
+        .success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+            [..]Compiling[..]
+            [..]Finished[..]
+
+
+            Collected 1 test(s) from simple_package package
+            Running 0 test(s) from src/
+            Running 1 test(s) from tests/
+            [PASS] simple_package_integrationtest::state::check_state [..]
+            Tests: 1 passed, 0 failed, 0 skipped, 0 ignored, [..] filtered out
+        "},
+    );
+}
+
+#[test]
+fn save_state_requires_single_test() {
+    let temp = setup_package("empty");
+
+    temp.child("tests/test.cairo")
+        .write_str(indoc!(
+            r"
+                #[test]
+                fn first() {
+                    assert(1 + 1 == 2, 'simple check');
+                }
+
+                #[test]
+                fn second() {
+                    assert(2 + 2 == 4, 'simple check');
+                }
+            "
+        ))
+        .unwrap();
+
+    let output = test_runner(&temp)
+        .arg("--save-state")
+        .arg("state.json")
+        .assert()
+        .code(2);
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+            [..]Compiling[..]
+            [..]Finished[..]
+            [ERROR] --save-state requires exactly one test to run, got 2. Select the test setting up the state with the test name filter and --exact flag
+        "},
+    );
+}
+
+#[test]
+fn save_state_rejects_fuzz_test() {
+    let temp = setup_package("empty");
+
+    temp.child("tests/test.cairo")
+        .write_str(indoc!(
+            r"
+                #[test]
+                fn fuzzed(a: felt252) {
+                    assert(a == a, 'simple check');
+                }
+            "
+        ))
+        .unwrap();
+
+    let output = test_runner(&temp)
+        .arg("--save-state")
+        .arg("state.json")
+        .assert()
+        .code(2);
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+            [..]Compiling[..]
+            [..]Finished[..]
+            [ERROR] --save-state cannot be used with fuzz tests
+        "},
+    );
+}
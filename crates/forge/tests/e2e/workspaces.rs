@@ -653,3 +653,19 @@ fn virtual_workspace_missing_package() {
 
     assert!(stdout.contains("Failed to find any packages matching the specified filter"));
 }
+
+#[test]
+fn save_state_requires_single_package() {
+    let temp = setup_hello_workspace();
+
+    let result = test_runner(&temp)
+        .args(["--workspace", "--save-state", "state.json"])
+        .assert()
+        .code(2);
+
+    let stdout = String::from_utf8_lossy(&result.get_output().stdout);
+
+    assert!(stdout.contains(
+        "--save-state requires exactly one package to run, got 3. Select the package with the --package flag"
+    ));
+}
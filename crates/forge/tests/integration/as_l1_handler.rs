@@ -0,0 +1,57 @@
+use indoc::indoc;
+use test_utils::runner::{assert_case_output_contains, assert_failed, assert_passed};
+use test_utils::running_tests::run_test_case;
+
+#[test]
+fn as_l1_handler_receives_from_address() {
+    let test = test_utils::test_case!(indoc!(
+        r"
+            #[test]
+            #[as_l1_handler]
+            fn handle_message(from_address: felt252) {
+                assert(from_address == 0x0123456789abcdef0123456789abcdef01234567, 'wrong from_address');
+            }
+        "
+    ));
+
+    let result = run_test_case(&test);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn as_l1_handler_with_fuzzed_payload() {
+    let test = test_utils::test_case!(indoc!(
+        r"
+            #[test]
+            #[as_l1_handler]
+            #[fuzzer(runs: 10, seed: 100)]
+            fn handle_message(from_address: felt252, payload: u64) {
+                assert(from_address == 0x0123456789abcdef0123456789abcdef01234567, 'wrong from_address');
+                assert(payload >= 0, 'invalid payload');
+            }
+        "
+    ));
+
+    let result = run_test_case(&test);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn as_l1_handler_failing() {
+    let test = test_utils::test_case!(indoc!(
+        r"
+            #[test]
+            #[as_l1_handler]
+            fn handle_message(from_address: felt252) {
+                assert(from_address == 0, 'wrong from_address');
+            }
+        "
+    ));
+
+    let result = run_test_case(&test);
+
+    assert_failed(&result);
+    assert_case_output_contains(&result, "handle_message", "wrong from_address");
+}
@@ -1,3 +1,4 @@
+mod as_l1_handler;
 mod available_gas;
 mod cheat_block_number;
 mod cheat_block_timestamp;
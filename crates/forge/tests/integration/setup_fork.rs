@@ -7,12 +7,13 @@ use camino::Utf8PathBuf;
 use forge::block_number_map::BlockNumberMap;
 use forge::run_tests::package::run_for_package;
 use forge::scarb::config::ForkTarget;
-use forge::test_filter::TestsFilter;
+use forge::test_filter::{TestsFilter, TestsFilterFlags};
 use tempfile::tempdir;
 use tokio::runtime::Runtime;
+use tokio::sync::Semaphore;
 
 use cheatnet::runtime_extensions::forge_runtime_extension::contracts_data::ContractsData;
-use forge::run_tests::package::RunForPackageArgs;
+use forge::run_tests::package::{RunForPackageArgs, TestOrder};
 use forge::scarb::load_test_artifacts;
 use forge_runner::build_trace_data::test_sierra_program_path::VERSIONED_PROGRAMS_DIR;
 use forge_runner::forge_config::{
@@ -21,6 +22,7 @@ use forge_runner::forge_config::{
 use forge_runner::CACHE_DIR;
 use scarb_api::metadata::MetadataCommandExt;
 use scarb_api::ScarbCommand;
+use shared::rpc::RetryConfig;
 use shared::test_utils::node_url::node_rpc_url;
 use test_utils::runner::{assert_case_output_contains, assert_failed, assert_passed, Contract};
 use test_utils::running_tests::run_test_case;
@@ -133,12 +135,11 @@ fn fork_aliased_decorator() {
             RunForPackageArgs {
                 test_targets: raw_test_targets,
                 package_name: "test_package".to_string(),
+                test_order: TestOrder::Definition,
                 tests_filter: TestsFilter::from_flags(
-                    None,
-                    false,
-                    false,
-                    false,
-                    false,
+                    TestsFilterFlags {
+                        ..Default::default()
+                    },
                     Default::default(),
                 ),
                 forge_config: Arc::new(ForgeConfig {
@@ -146,11 +147,20 @@ fn fork_aliased_decorator() {
                         exit_first: false,
                         fuzzer_runs: NonZeroU32::new(256).unwrap(),
                         fuzzer_seed: 12345,
+                        replay_fuzzer_corpus: false,
+                        fuzzer_shrink_iterations: 100,
                         max_n_steps: None,
                         is_vm_trace_needed: false,
+                        save_execution_trace: false,
                         cache_dir: Utf8PathBuf::from_path_buf(tempdir().unwrap().into_path())
                             .unwrap()
                             .join(CACHE_DIR),
+                        fork_cache_ttl: None,
+                        test_timeout: None,
+                        max_parallel_tests: Semaphore::MAX_PERMITS,
+                        save_state: None,
+                        initial_state: None,
+                        rpc_retry_config: RetryConfig::default(),
                         contracts_data: ContractsData::try_from(test.contracts().unwrap()).unwrap(),
                         environment_variables: test.env().clone(),
                     }),
@@ -225,12 +235,11 @@ fn fork_aliased_decorator_overrding() {
             RunForPackageArgs {
                 test_targets: raw_test_targets,
                 package_name: "test_package".to_string(),
+                test_order: TestOrder::Definition,
                 tests_filter: TestsFilter::from_flags(
-                    None,
-                    false,
-                    false,
-                    false,
-                    false,
+                    TestsFilterFlags {
+                        ..Default::default()
+                    },
                     Default::default(),
                 ),
                 forge_config: Arc::new(ForgeConfig {
@@ -238,11 +247,20 @@ fn fork_aliased_decorator_overrding() {
                         exit_first: false,
                         fuzzer_runs: NonZeroU32::new(256).unwrap(),
                         fuzzer_seed: 12345,
+                        replay_fuzzer_corpus: false,
+                        fuzzer_shrink_iterations: 100,
                         max_n_steps: None,
                         is_vm_trace_needed: false,
+                        save_execution_trace: false,
                         cache_dir: Utf8PathBuf::from_path_buf(tempdir().unwrap().into_path())
                             .unwrap()
                             .join(CACHE_DIR),
+                        fork_cache_ttl: None,
+                        test_timeout: None,
+                        max_parallel_tests: Semaphore::MAX_PERMITS,
+                        save_state: None,
+                        initial_state: None,
+                        rpc_retry_config: RetryConfig::default(),
                         contracts_data: ContractsData::try_from(test.contracts().unwrap()).unwrap(),
                         environment_variables: test.env().clone(),
                     }),
@@ -369,6 +387,14 @@ fn get_block_info_in_forked_block() {
                 assert(block_info.block_number > 54060, block_info.block_number.into());
             }}
 
+            #[test]
+            #[fork(url: "{node_rpc_url}", block_tag: pending)]
+            fn test_fork_get_block_info_pending_block() {{
+                let block_info = starknet::get_block_info().unbox();
+                assert(block_info.block_timestamp > 1711645884, block_info.block_timestamp.into());
+                assert(block_info.block_number > 54060, block_info.block_number.into());
+            }}
+
             #[test]
             #[fork(url: "{node_rpc_url}", block_hash: 0x06ae121e46f5375f93b00475fb130348ae38148e121f84b0865e17542e9485de)]
             fn test_fork_get_block_info_block_hash() {{
@@ -458,3 +484,59 @@ fn incompatible_abi() {
 
     assert_passed(&result);
 }
+
+#[test]
+fn parallel_fork_same_results() {
+    let test = test_case!(formatdoc!(
+        r#"
+            #[test]
+            #[parallel_fork(url: "{}", block_number: 54060)]
+            fn parallel_fork_same_results() {{
+                let sum = 2 + 3;
+                assert(sum == 5, 'Sum should be 5');
+            }}
+        "#,
+        node_rpc_url()
+    )
+    .as_str());
+
+    let result = run_test_case(&test);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn parallel_fork_divergent_results() {
+    let test = test_case!(formatdoc!(
+        r#"
+            use starknet::contract_address_const;
+
+            #[starknet::interface]
+            trait IHelloStarknet<TContractState> {{
+                fn get_balance(self: @TContractState) -> felt252;
+            }}
+
+            #[test]
+            #[parallel_fork(url: "{}", block_number: 54060)]
+            fn parallel_fork_divergent_results() {{
+                let dispatcher = IHelloStarknetDispatcher {{
+                    contract_address: contract_address_const::<0x202de98471a4fae6bcbabb96cab00437d381abc58b02509043778074d6781e9>()
+                }};
+
+                let balance = dispatcher.get_balance();
+                assert(balance == 0, 'Balance should be 0');
+            }}
+        "#,
+        node_rpc_url()
+    )
+    .as_str());
+
+    let result = run_test_case(&test);
+
+    assert_failed(&result);
+    assert_case_output_contains(
+        &result,
+        "parallel_fork_divergent_results",
+        "Results of runs without and with the fork differ",
+    );
+}
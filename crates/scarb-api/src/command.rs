@@ -30,6 +30,7 @@ pub struct ScarbCommand {
     env: HashMap<OsString, Option<OsString>>,
     inherit_stderr: bool,
     inherit_stdout: bool,
+    stdout_to_stderr: bool,
     json: bool,
     offline: bool,
     manifest_path: Option<PathBuf>,
@@ -165,6 +166,12 @@ impl ScarbCommand {
         self
     }
 
+    /// Redirect standard output to this process's standard error, i.e. show Scarb output without polluting standard output.
+    pub fn stdout_to_stderr(&mut self) -> &mut Self {
+        self.stdout_to_stderr = true;
+        self
+    }
+
     /// Set output format to JSON.
     pub fn json(&mut self) -> &mut Self {
         self.json = true;
@@ -218,6 +225,10 @@ impl ScarbCommand {
             cmd.stdout(Stdio::inherit());
         }
 
+        if self.stdout_to_stderr {
+            cmd.stdout(io::stderr());
+        }
+
         cmd
     }
 
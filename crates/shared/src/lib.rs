@@ -1,6 +1,6 @@
 use crate::consts::EXPECTED_RPC_VERSION;
 use crate::print::print_as_warning;
-use crate::rpc::{get_rpc_version, is_expected_version};
+use crate::rpc::{get_rpc_version, is_expected_version, RetryConfig};
 use anyhow::{anyhow, Result};
 use starknet::providers::jsonrpc::HttpTransport;
 use starknet::providers::JsonRpcClient;
@@ -16,8 +16,9 @@ pub mod utils;
 pub async fn verify_and_warn_if_incompatible_rpc_version(
     client: &JsonRpcClient<HttpTransport>,
     url: impl Display,
+    retry_config: RetryConfig,
 ) -> Result<()> {
-    let node_spec_version = get_rpc_version(client).await?;
+    let node_spec_version = get_rpc_version(client, retry_config).await?;
     if !is_expected_version(&node_spec_version) {
         print_as_warning(&anyhow!(
             "RPC node with the url {url} uses incompatible version {node_spec_version}. Expected version: {EXPECTED_RPC_VERSION}"
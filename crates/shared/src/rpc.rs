@@ -1,11 +1,35 @@
 use crate::consts::EXPECTED_RPC_VERSION;
 use anyhow::{Context, Result};
+use rand::Rng;
+use reqwest::StatusCode;
 use semver::{Version, VersionReq};
-use starknet::providers::jsonrpc::HttpTransport;
-use starknet::providers::{JsonRpcClient, Provider};
+use starknet::providers::jsonrpc::{HttpTransport, HttpTransportError, JsonRpcClientError};
+use starknet::providers::{JsonRpcClient, Provider, ProviderError};
+use std::future::Future;
 use std::str::FromStr;
+use std::time::Duration;
 use url::Url;
 
+pub const DEFAULT_RPC_RETRIES: u32 = 3;
+pub const DEFAULT_RPC_RETRY_DELAY: Duration = Duration::from_millis(500);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryConfig {
+    /// Number of times a failed call is retried
+    pub retries: u32,
+    /// Delay before the first retry, doubled with every subsequent one
+    pub initial_delay: Duration,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            retries: DEFAULT_RPC_RETRIES,
+            initial_delay: DEFAULT_RPC_RETRY_DELAY,
+        }
+    }
+}
+
 pub fn create_rpc_client(url: &str) -> Result<JsonRpcClient<HttpTransport>> {
     let parsed_url = Url::parse(url).with_context(|| format!("Failed to parse URL: {url}"))?;
     let client = JsonRpcClient::new(HttpTransport::new(parsed_url));
@@ -19,11 +43,156 @@ pub fn is_expected_version(version: &Version) -> bool {
         .matches(version)
 }
 
-pub async fn get_rpc_version(client: &JsonRpcClient<HttpTransport>) -> Result<Version> {
-    client
-        .spec_version()
+pub async fn get_rpc_version(
+    client: &JsonRpcClient<HttpTransport>,
+    retry_config: RetryConfig,
+) -> Result<Version> {
+    retry_with_backoff(retry_config, || client.spec_version())
         .await
         .context("Error while calling RPC method spec_version")?
         .parse::<Version>()
         .context("Failed to parse RPC spec version")
 }
+
+/// Calls `call` until it succeeds, a non-transient error is returned or `config.retries` is exhausted.
+/// Delays between attempts grow exponentially and are randomized to avoid retrying in lockstep.
+pub async fn retry_with_backoff<F, Fut, T>(
+    config: RetryConfig,
+    mut call: F,
+) -> Result<T, ProviderError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, ProviderError>>,
+{
+    let mut attempt = 0;
+
+    loop {
+        match call().await {
+            Err(err) if attempt < config.retries && is_transient_error(&err) => {
+                tokio::time::sleep(backoff_delay(config.initial_delay, attempt)).await;
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+fn backoff_delay(initial_delay: Duration, attempt: u32) -> Duration {
+    let delay = initial_delay.saturating_mul(2_u32.saturating_pow(attempt));
+    let jitter = rand::thread_rng().gen_range(0.5..=1.0);
+
+    delay.mul_f64(jitter)
+}
+
+/// Errors worth retrying are rate limiting and failures of the HTTP request itself:
+/// timeouts, refused or dropped connections and 429 or 5xx responses
+#[must_use]
+pub fn is_transient_error(error: &ProviderError) -> bool {
+    match error {
+        ProviderError::RateLimited => true,
+        ProviderError::Other(error) => error
+            .as_any()
+            .downcast_ref::<JsonRpcClientError<HttpTransportError>>()
+            .is_some_and(|error| match error {
+                JsonRpcClientError::TransportError(HttpTransportError::Reqwest(error)) => {
+                    is_transient_reqwest_error(error)
+                }
+                _ => false,
+            }),
+        _ => false,
+    }
+}
+
+fn is_transient_reqwest_error(error: &reqwest::Error) -> bool {
+    if error.is_timeout() || error.is_connect() || error.is_request() {
+        return true;
+    }
+
+    error
+        .status()
+        .is_some_and(|status| status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use starknet::core::types::StarknetError;
+    use std::cell::Cell;
+
+    fn config(retries: u32) -> RetryConfig {
+        RetryConfig {
+            retries,
+            initial_delay: Duration::from_millis(1),
+        }
+    }
+
+    #[tokio::test]
+    async fn retries_transient_errors() {
+        let calls = Cell::new(0);
+
+        let result = retry_with_backoff(config(3), || async {
+            calls.set(calls.get() + 1);
+            if calls.get() < 3 {
+                Err(ProviderError::RateLimited)
+            } else {
+                Ok(calls.get())
+            }
+        })
+        .await;
+
+        assert_eq!(result.unwrap(), 3);
+    }
+
+    #[tokio::test]
+    async fn stops_after_retries_are_exhausted() {
+        let calls = Cell::new(0);
+
+        let result: Result<(), _> = retry_with_backoff(config(2), || async {
+            calls.set(calls.get() + 1);
+            Err(ProviderError::RateLimited)
+        })
+        .await;
+
+        assert!(matches!(result, Err(ProviderError::RateLimited)));
+        assert_eq!(calls.get(), 3);
+    }
+
+    #[tokio::test]
+    async fn does_not_retry_other_errors() {
+        let calls = Cell::new(0);
+
+        let result: Result<(), _> = retry_with_backoff(config(3), || async {
+            calls.set(calls.get() + 1);
+            Err(ProviderError::StarknetError(
+                StarknetError::ContractNotFound,
+            ))
+        })
+        .await;
+
+        assert!(result.is_err());
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[tokio::test]
+    async fn connection_errors_are_transient() {
+        let error = reqwest::Client::new()
+            .get("http://127.0.0.1:1")
+            .send()
+            .await
+            .unwrap_err();
+        let error = ProviderError::Other(Box::new(JsonRpcClientError::TransportError(
+            HttpTransportError::Reqwest(error),
+        )));
+
+        assert!(is_transient_error(&error));
+    }
+
+    #[test]
+    fn backoff_grows_exponentially() {
+        let initial_delay = Duration::from_millis(100);
+
+        assert!(backoff_delay(initial_delay, 0) <= Duration::from_millis(100));
+        assert!(backoff_delay(initial_delay, 3) >= Duration::from_millis(400));
+        assert!(backoff_delay(initial_delay, 3) <= Duration::from_millis(800));
+    }
+}
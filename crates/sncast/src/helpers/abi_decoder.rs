@@ -0,0 +1,446 @@
+use anyhow::{bail, ensure, Context, Result};
+use serde_json::Value;
+use starknet::core::types::contract::{AbiEntry, AbiEnum, AbiStruct};
+use starknet::core::types::Felt;
+use starknet::core::utils::get_selector_from_name;
+
+const ARRAY_PREFIXES: [&str; 2] = ["core::array::Array::<", "core::array::Span::<"];
+const UNSIGNED_INTEGERS: [&str; 6] = [
+    "core::integer::u8",
+    "core::integer::u16",
+    "core::integer::u32",
+    "core::integer::u64",
+    "core::integer::u128",
+    "core::integer::usize",
+];
+const SIGNED_INTEGERS: [&str; 5] = [
+    "core::integer::i8",
+    "core::integer::i16",
+    "core::integer::i32",
+    "core::integer::i64",
+    "core::integer::i128",
+];
+const HEX_TYPES: [&str; 5] = [
+    "core::felt252",
+    "core::starknet::contract_address::ContractAddress",
+    "core::starknet::class_hash::ClassHash",
+    "core::starknet::eth_address::EthAddress",
+    "core::starknet::storage_access::StorageAddress",
+];
+
+/// Parses an ABI from `contents`, which can contain the ABI itself or a contract class with `abi` field
+pub fn parse_abi(contents: &str) -> Result<Vec<AbiEntry>> {
+    let mut abi: Value = serde_json::from_str(contents).context("Failed to parse ABI")?;
+    if let Some(class_abi) = abi.get_mut("abi") {
+        abi = match class_abi.take() {
+            Value::String(abi) => {
+                serde_json::from_str(&abi).context("Failed to parse ABI of contract class")?
+            }
+            abi => abi,
+        };
+    }
+    serde_json::from_value(abi).context("Failed to parse ABI")
+}
+
+/// Decodes values returned by `function` into Cairo-like representation using types from the contract ABI,
+/// e.g. `MyStruct { id: 1, owner: 0x123 }, [1, 2]`
+pub fn decode_return_value(abi: &[AbiEntry], function: &str, values: &[Felt]) -> Result<String> {
+    let entries = flatten_abi(abi);
+    let output_types = entries
+        .iter()
+        .find_map(|entry| match entry {
+            AbiEntry::Function(abi_function) if abi_function.name == function => Some(
+                abi_function
+                    .outputs
+                    .iter()
+                    .map(|output| output.r#type.as_str())
+                    .collect::<Vec<_>>(),
+            ),
+            _ => None,
+        })
+        .with_context(|| format!("Function `{function}` not found in the contract ABI"))?;
+
+    let mut decoder = Decoder {
+        abi: &entries,
+        values,
+        position: 0,
+    };
+    let decoded = output_types
+        .iter()
+        .map(|ty| decoder.decode(ty))
+        .collect::<Result<Vec<_>>>()?;
+
+    ensure!(
+        decoder.position == values.len(),
+        "Returned {} values, but the function outputs take {}",
+        values.len(),
+        decoder.position
+    );
+
+    Ok(decoded.join(", "))
+}
+
+/// Decodes calldata of the function with the given selector into Cairo-like representation,
+/// e.g. `transfer(recipient: 0x123, amount: 100)`
+pub fn decode_function_call(abi: &[AbiEntry], selector: Felt, calldata: &[Felt]) -> Result<String> {
+    let (function, arguments) = decode_function_arguments(abi, selector, calldata)?;
+    let arguments = arguments
+        .iter()
+        .map(|(name, value)| format!("{name}: {value}"))
+        .collect::<Vec<_>>();
+
+    Ok(format!("{function}({})", arguments.join(", ")))
+}
+
+/// Decodes calldata of the function with the given selector into names and Cairo-like values of its inputs.
+/// Returns the name of the function along with the arguments
+pub fn decode_function_arguments(
+    abi: &[AbiEntry],
+    selector: Felt,
+    calldata: &[Felt],
+) -> Result<(String, Vec<(String, String)>)> {
+    let entries = flatten_abi(abi);
+    let function = entries
+        .iter()
+        .find_map(|entry| match entry {
+            AbiEntry::Function(abi_function)
+                if get_selector_from_name(&abi_function.name).ok() == Some(selector) =>
+            {
+                Some(abi_function)
+            }
+            _ => None,
+        })
+        .with_context(|| {
+            format!("Function with selector {selector:#x} not found in the contract ABI")
+        })?;
+
+    let mut decoder = Decoder {
+        abi: &entries,
+        values: calldata,
+        position: 0,
+    };
+    let arguments = function
+        .inputs
+        .iter()
+        .map(|input| Ok((input.name.clone(), decoder.decode(&input.r#type)?)))
+        .collect::<Result<Vec<_>>>()?;
+
+    ensure!(
+        decoder.position == calldata.len(),
+        "Passed {} values, but the function inputs take {}",
+        calldata.len(),
+        decoder.position
+    );
+
+    Ok((function.name.clone(), arguments))
+}
+
+pub fn flatten_abi(abi: &[AbiEntry]) -> Vec<&AbiEntry> {
+    abi.iter()
+        .flat_map(|entry| match entry {
+            AbiEntry::Interface(interface) => interface.items.iter().collect(),
+            entry => vec![entry],
+        })
+        .collect()
+}
+
+struct Decoder<'a> {
+    abi: &'a [&'a AbiEntry],
+    values: &'a [Felt],
+    position: usize,
+}
+
+impl Decoder<'_> {
+    fn next(&mut self) -> Result<Felt> {
+        let value = self
+            .values
+            .get(self.position)
+            .copied()
+            .context("Returned values are shorter than the function outputs")?;
+        self.position += 1;
+        Ok(value)
+    }
+
+    fn next_usize(&mut self) -> Result<usize> {
+        let value = self.next()?;
+        usize::try_from(value.to_biguint()).with_context(|| format!("Invalid length {value:#x}"))
+    }
+
+    fn decode(&mut self, ty: &str) -> Result<String> {
+        if HEX_TYPES.contains(&ty) {
+            return Ok(format!("{:#x}", self.next()?));
+        }
+        if UNSIGNED_INTEGERS.contains(&ty) {
+            return Ok(self.next()?.to_biguint().to_string());
+        }
+        if SIGNED_INTEGERS.contains(&ty) {
+            return Ok(decode_signed(self.next()?));
+        }
+        if let Some(inner) = ARRAY_PREFIXES
+            .iter()
+            .find_map(|prefix| ty.strip_prefix(*prefix)?.strip_suffix('>'))
+        {
+            let length = self.next_usize()?;
+            let items = (0..length)
+                .map(|_| self.decode(inner))
+                .collect::<Result<Vec<_>>>()?;
+            return Ok(format!("[{}]", items.join(", ")));
+        }
+        if let Some(items) = ty.strip_prefix('(').and_then(|ty| ty.strip_suffix(')')) {
+            let items = split_tuple(items)
+                .into_iter()
+                .map(|item| self.decode(item))
+                .collect::<Result<Vec<_>>>()?;
+            return Ok(format!("({})", items.join(", ")));
+        }
+
+        match ty {
+            "core::integer::u256" => {
+                let low = self.next()?.to_biguint();
+                let high = self.next()?.to_biguint();
+                Ok(((high << 128) + low).to_string())
+            }
+            "core::bool" => Ok((self.next()? != Felt::ZERO).to_string()),
+            "core::byte_array::ByteArray" => self.decode_byte_array(),
+            _ => self.decode_user_type(ty),
+        }
+    }
+
+    fn decode_user_type(&mut self, ty: &str) -> Result<String> {
+        let abi = self.abi;
+        let entry = abi.iter().find_map(|entry| match entry {
+            AbiEntry::Struct(abi_struct) if abi_struct.name == ty => {
+                Some(UserType::Struct(abi_struct))
+            }
+            AbiEntry::Enum(abi_enum) if abi_enum.name == ty => Some(UserType::Enum(abi_enum)),
+            _ => None,
+        });
+
+        match entry {
+            Some(UserType::Struct(abi_struct)) => {
+                let members = abi_struct
+                    .members
+                    .iter()
+                    .map(|member| {
+                        let value = self.decode(&member.r#type)?;
+                        Ok(format!("{}: {value}", member.name))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(format!("{} {{ {} }}", short_name(ty), members.join(", ")))
+            }
+            Some(UserType::Enum(abi_enum)) => {
+                let index = self.next_usize()?;
+                let variant = abi_enum
+                    .variants
+                    .get(index)
+                    .with_context(|| format!("Invalid variant index {index} of enum {ty}"))?;
+                let name = format!("{}::{}", short_name(ty), variant.name);
+                if variant.r#type == "()" {
+                    Ok(name)
+                } else {
+                    Ok(format!("{name}({})", self.decode(&variant.r#type)?))
+                }
+            }
+            None => bail!("Type {ty} is not supported"),
+        }
+    }
+
+    fn decode_byte_array(&mut self) -> Result<String> {
+        let full_words = self.next_usize()?;
+        let mut bytes = vec![];
+        for _ in 0..full_words {
+            bytes.extend_from_slice(&self.next()?.to_bytes_be()[1..]);
+        }
+        let pending_word = self.next()?.to_bytes_be();
+        let pending_word_len = self.next_usize()?;
+        ensure!(
+            pending_word_len < 31,
+            "Invalid ByteArray pending word length {pending_word_len}"
+        );
+        bytes.extend_from_slice(&pending_word[32 - pending_word_len..]);
+
+        Ok(format!("{:?}", String::from_utf8_lossy(&bytes)))
+    }
+}
+
+enum UserType<'a> {
+    Struct(&'a AbiStruct),
+    Enum(&'a AbiEnum),
+}
+
+fn decode_signed(value: Felt) -> String {
+    let negated = -value;
+    if negated < value {
+        format!("-{}", negated.to_biguint())
+    } else {
+        value.to_biguint().to_string()
+    }
+}
+
+/// Name without the module path, keeping generic arguments, e.g. `Option::<core::integer::u8>`
+fn short_name(ty: &str) -> &str {
+    let path = ty[..ty.find('<').unwrap_or(ty.len())].trim_end_matches("::");
+    path.rfind("::").map_or(ty, |index| &ty[index + 2..])
+}
+
+/// Splits tuple items on top level commas
+fn split_tuple(items: &str) -> Vec<&str> {
+    let mut result = vec![];
+    let mut depth = 0;
+    let mut start = 0;
+    for (index, char) in items.char_indices() {
+        match char {
+            '<' | '(' => depth += 1,
+            '>' | ')' => depth -= 1,
+            ',' if depth == 0 => {
+                result.push(items[start..index].trim());
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    let last = items[start..].trim();
+    if !last.is_empty() {
+        result.push(last);
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use starknet::core::types::contract::{
+        AbiFunction, AbiNamedMember, AbiOutput, StateMutability,
+    };
+
+    fn member(name: &str, ty: &str) -> AbiNamedMember {
+        AbiNamedMember {
+            name: name.to_string(),
+            r#type: ty.to_string(),
+        }
+    }
+
+    fn abi(outputs: &[&str]) -> Vec<AbiEntry> {
+        vec![
+            AbiEntry::Function(AbiFunction {
+                name: "get".to_string(),
+                inputs: vec![
+                    member("position", "contract::Position"),
+                    member("flag", "core::bool"),
+                ],
+                outputs: outputs
+                    .iter()
+                    .map(|ty| AbiOutput {
+                        r#type: (*ty).to_string(),
+                    })
+                    .collect(),
+                state_mutability: StateMutability::View,
+            }),
+            AbiEntry::Struct(AbiStruct {
+                name: "contract::Position".to_string(),
+                members: vec![
+                    member("x", "core::integer::i32"),
+                    member("owner", "core::starknet::contract_address::ContractAddress"),
+                ],
+            }),
+            AbiEntry::Enum(AbiEnum {
+                name: "core::option::Option::<core::integer::u8>".to_string(),
+                variants: vec![member("Some", "core::integer::u8"), member("None", "()")],
+            }),
+        ]
+    }
+
+    #[test]
+    fn decodes_struct_and_array() {
+        let values = [
+            Felt::from(-5),
+            Felt::from(0x123),
+            Felt::TWO,
+            Felt::ONE,
+            Felt::from(300),
+        ]
+        .to_vec();
+
+        let decoded = decode_return_value(
+            &abi(&[
+                "contract::Position",
+                "core::array::Array::<core::integer::u16>",
+            ]),
+            "get",
+            &values,
+        )
+        .unwrap();
+
+        assert_eq!(decoded, "Position { x: -5, owner: 0x123 }, [1, 300]");
+    }
+
+    #[test]
+    fn decodes_u256_enum_and_tuple() {
+        let values = [
+            Felt::ONE,
+            Felt::ONE,
+            Felt::ZERO,
+            Felt::from(7),
+            Felt::ONE,
+            Felt::ONE,
+        ]
+        .to_vec();
+
+        let decoded = decode_return_value(
+            &abi(&[
+                "core::integer::u256",
+                "core::option::Option::<core::integer::u8>",
+                "(core::option::Option::<core::integer::u8>, core::bool)",
+            ]),
+            "get",
+            &values,
+        )
+        .unwrap();
+
+        assert_eq!(
+            decoded,
+            "340282366920938463463374607431768211457, Option::<core::integer::u8>::Some(7), (Option::<core::integer::u8>::None, true)"
+        );
+    }
+
+    #[test]
+    fn decodes_byte_array() {
+        let values = [
+            Felt::ZERO,
+            Felt::from_bytes_be_slice(b"hello"),
+            Felt::from(5),
+        ]
+        .to_vec();
+
+        let decoded =
+            decode_return_value(&abi(&["core::byte_array::ByteArray"]), "get", &values).unwrap();
+
+        assert_eq!(decoded, "\"hello\"");
+    }
+
+    #[test]
+    fn decodes_function_call() {
+        let values = [Felt::from(-5), Felt::from(0x123), Felt::ONE].to_vec();
+
+        let decoded =
+            decode_function_call(&abi(&[]), get_selector_from_name("get").unwrap(), &values)
+                .unwrap();
+
+        assert_eq!(
+            decoded,
+            "get(position: Position { x: -5, owner: 0x123 }, flag: true)"
+        );
+    }
+
+    #[test]
+    fn fails_on_unknown_selector() {
+        assert!(decode_function_call(&abi(&[]), Felt::ONE, &[]).is_err());
+    }
+
+    #[test]
+    fn fails_on_too_many_values() {
+        let values = [Felt::ONE, Felt::TWO].to_vec();
+
+        assert!(decode_return_value(&abi(&["core::felt252"]), "get", &values).is_err());
+    }
+}
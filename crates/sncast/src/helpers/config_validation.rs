@@ -0,0 +1,257 @@
+use crate::helpers::configuration::CastConfig;
+use anyhow::{ensure, Context, Result};
+use camino::{Utf8Path, Utf8PathBuf};
+use configuration::{find_config_file, load_global_config, GlobalConfig};
+use serde::Serialize;
+use std::fs;
+use url::Url;
+
+/// Keys which can be set in a `[sncast.<profile>]` table of snfoundry.toml
+const KNOWN_KEYS: [&str; 8] = [
+    "url",
+    "account",
+    "accounts-file",
+    "keystore",
+    "wait-params",
+    "block-explorer",
+    "show-explorer-links",
+    "fee-token",
+];
+
+/// Keys which can be set in `wait-params` of a profile
+const KNOWN_WAIT_PARAMS_KEYS: [&str; 2] = ["timeout", "retry-interval"];
+
+#[derive(Serialize, Debug, Default)]
+pub struct ConfigValidationReport {
+    pub config_file: Option<Utf8PathBuf>,
+    pub profiles: Vec<String>,
+    pub errors: Vec<String>,
+}
+
+impl ConfigValidationReport {
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+/// Validates all profiles of snfoundry.toml found in the current directory or its parents.
+/// `update_config` is applied to every loaded profile, so that values passed in the CLI are validated too
+pub fn validate_config(update_config: impl Fn(&mut CastConfig)) -> Result<ConfigValidationReport> {
+    let mut report = ConfigValidationReport::default();
+
+    let profiles = match find_config_file() {
+        Ok(config_file) => {
+            let raw_config = fs::read_to_string(&config_file)
+                .context("Failed to read snfoundry.toml config file")?;
+            report.config_file = Some(config_file);
+
+            match raw_config.parse::<toml::Table>() {
+                Ok(raw_config) => check_raw_config(&raw_config, &mut report.errors),
+                Err(error) => {
+                    report.errors.push(format!(
+                        "Failed to parse snfoundry.toml config file: {error}"
+                    ));
+                    return Ok(report);
+                }
+            }
+        }
+        Err(_) => vec!["default".to_string()],
+    };
+
+    for profile in profiles {
+        match load_global_config::<CastConfig>(&None, &Some(profile.clone())) {
+            Ok(mut config) => {
+                update_config(&mut config);
+                check_config(&profile, &config, &mut report.errors);
+            }
+            Err(error) => report.errors.push(format!("[{profile}] {error:#}")),
+        }
+        report.profiles.push(profile);
+    }
+
+    Ok(report)
+}
+
+/// Reports unknown keys of all profiles and returns their names
+fn check_raw_config(raw_config: &toml::Table, errors: &mut Vec<String>) -> Vec<String> {
+    let Some(tool_config) = raw_config.get(CastConfig::tool_name()) else {
+        return vec!["default".to_string()];
+    };
+    let Some(tool_config) = tool_config.as_table() else {
+        errors.push(format!("`{}` must be a table", CastConfig::tool_name()));
+        return vec![];
+    };
+
+    let mut profiles = vec![];
+    for (profile, profile_config) in tool_config {
+        let Some(profile_config) = profile_config.as_table() else {
+            errors.push(format!("[{profile}] profile must be a table"));
+            continue;
+        };
+
+        check_keys(profile, profile_config, &KNOWN_KEYS, errors);
+        if let Some(wait_params) = profile_config
+            .get("wait-params")
+            .and_then(toml::Value::as_table)
+        {
+            check_keys(profile, wait_params, &KNOWN_WAIT_PARAMS_KEYS, errors);
+        }
+        profiles.push(profile.clone());
+    }
+
+    profiles
+}
+
+fn check_keys(profile: &str, table: &toml::Table, known_keys: &[&str], errors: &mut Vec<String>) {
+    for key in table.keys() {
+        if known_keys.contains(&key.as_str()) {
+            continue;
+        }
+
+        let kebab_case_key = key.replace('_', "-");
+        if known_keys.contains(&kebab_case_key.as_str()) {
+            errors.push(format!(
+                "[{profile}] Unknown key `{key}`, did you mean `{kebab_case_key}`?"
+            ));
+        } else {
+            errors.push(format!("[{profile}] Unknown key `{key}`"));
+        }
+    }
+}
+
+fn check_config(profile: &str, config: &CastConfig, errors: &mut Vec<String>) {
+    if !config.url.is_empty() {
+        if let Err(error) = Url::parse(&config.url) {
+            errors.push(format!("[{profile}] Invalid url `{}`: {error}", config.url));
+        }
+    }
+
+    if config.account.is_empty() {
+        return;
+    }
+
+    match &config.keystore {
+        Some(keystore) => {
+            if !keystore.exists() {
+                errors.push(format!(
+                    "[{profile}] Keystore file {keystore} does not exist"
+                ));
+            }
+            if !Utf8Path::new(&config.account).exists() {
+                errors.push(format!(
+                    "[{profile}] Account file {} does not exist",
+                    config.account
+                ));
+            }
+        }
+        None => {
+            if let Err(error) = check_account_exists(&config.account, &config.accounts_file) {
+                errors.push(format!("[{profile}] {error:#}"));
+            }
+        }
+    }
+}
+
+/// Checks whether the account is defined for any network in the accounts file,
+/// the network can't be checked without connecting to the RPC node
+fn check_account_exists(account: &str, accounts_file: &Utf8Path) -> Result<()> {
+    ensure!(
+        accounts_file.exists(),
+        "Accounts file {accounts_file} does not exist"
+    );
+
+    let accounts: serde_json::Value = serde_json::from_str(
+        &fs::read_to_string(accounts_file)
+            .with_context(|| format!("Failed to read accounts file {accounts_file}"))?,
+    )
+    .with_context(|| format!("Failed to parse accounts file {accounts_file}"))?;
+
+    let exists = accounts.as_object().is_some_and(|networks| {
+        networks
+            .values()
+            .any(|network| network.get(account).is_some())
+    });
+    ensure!(
+        exists,
+        "Account {account} not found in accounts file {accounts_file}"
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{check_raw_config, KNOWN_KEYS, KNOWN_WAIT_PARAMS_KEYS};
+    use crate::helpers::configuration::CastConfig;
+    use crate::helpers::fee::FeeToken;
+    use camino::Utf8PathBuf;
+
+    fn sorted_keys(table: &toml::Table) -> Vec<&str> {
+        let mut keys: Vec<_> = table.keys().map(String::as_str).collect();
+        keys.sort_unstable();
+        keys
+    }
+
+    #[test]
+    fn test_known_keys_match_cast_config() {
+        // Optional fields are set, so that all keys are serialized
+        let config = CastConfig {
+            keystore: Some(Utf8PathBuf::from("keystore.json")),
+            fee_token: Some(FeeToken::Strk),
+            ..CastConfig::default()
+        };
+        let serialized = toml::Table::try_from(&config).unwrap();
+
+        let mut known_keys = KNOWN_KEYS.to_vec();
+        known_keys.sort_unstable();
+        assert_eq!(sorted_keys(&serialized), known_keys);
+
+        let mut known_wait_params_keys = KNOWN_WAIT_PARAMS_KEYS.to_vec();
+        known_wait_params_keys.sort_unstable();
+        assert_eq!(
+            sorted_keys(serialized["wait-params"].as_table().unwrap()),
+            known_wait_params_keys
+        );
+    }
+
+    #[test]
+    fn test_unknown_keys() {
+        let raw_config = r#"
+            [sncast.default]
+            url = "http://127.0.0.1:5050/rpc"
+            accounts_file = "accounts.json"
+            colour = "red"
+            wait-params = { timeout = 300, retry_interval = 5 }
+
+            [sncast.other]
+            account = "user"
+        "#
+        .parse::<toml::Table>()
+        .unwrap();
+        let mut errors = vec![];
+
+        let profiles = check_raw_config(&raw_config, &mut errors);
+
+        assert_eq!(profiles, vec!["default", "other"]);
+        assert_eq!(
+            errors,
+            vec![
+                "[default] Unknown key `accounts_file`, did you mean `accounts-file`?",
+                "[default] Unknown key `colour`",
+                "[default] Unknown key `retry_interval`, did you mean `retry-interval`?",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_no_sncast_table() {
+        let raw_config = "[other-tool]\nkey = 1".parse::<toml::Table>().unwrap();
+        let mut errors = vec![];
+
+        let profiles = check_raw_config(&raw_config, &mut errors);
+
+        assert_eq!(profiles, vec!["default"]);
+        assert!(errors.is_empty());
+    }
+}
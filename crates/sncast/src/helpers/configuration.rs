@@ -5,6 +5,7 @@ use configuration::GlobalConfig;
 use serde::{Deserialize, Serialize};
 
 use super::block_explorer;
+use super::fee::FeeToken;
 
 const fn show_explorer_links_default() -> bool {
     true
@@ -46,6 +47,14 @@ pub struct CastConfig {
     )]
     /// Print links pointing to pages with transaction details in the chosen block explorer
     pub show_explorer_links: bool,
+
+    #[serde(default, rename(serialize = "fee-token", deserialize = "fee-token"))]
+    /// Token used for fee payment by `declare`, `deploy` and `invoke` when neither `--fee-token` nor `--version` is passed
+    pub fee_token: Option<FeeToken>,
+
+    #[serde(skip)]
+    /// Account passed inline with `--account-json`, never read from or written to the config file
+    pub account_json: Option<String>,
 }
 
 impl Default for CastConfig {
@@ -58,6 +67,8 @@ impl Default for CastConfig {
             wait_params: ValidatedWaitParams::default(),
             block_explorer: Some(block_explorer::Service::default()),
             show_explorer_links: true,
+            fee_token: None,
+            account_json: None,
         }
     }
 }
@@ -28,6 +28,11 @@ pub const BRAAVOS_CLASS_HASH: Felt =
 pub const BRAAVOS_BASE_ACCOUNT_CLASS_HASH: Felt =
     felt!("0x013bfe114fb1cf405bfc3a7f8dbe2d91db146c17521d40dcf57e16d6b59fa8e6");
 
+pub const ETH_ADDRESS: Felt =
+    felt!("0x049d36570d4e46f48e99674bd3fcc84644ddd6b96f7c741b1562b82f9e004dc7");
+pub const STRK_ADDRESS: Felt =
+    felt!("0x04718f5a0fc34cc1af16a1cdee98ffb20c31f5cd61d6ab07201858f4287c938d");
+
 // used in wait_for_tx. Txs will be fetched every 5s with timeout of 300s - so 60 attempts
 #[allow(dead_code)]
 pub const WAIT_TIMEOUT: u16 = 300;
@@ -39,6 +44,12 @@ pub const DEFAULT_ACCOUNTS_FILE: &str = "~/.starknet_accounts/starknet_open_zepp
 
 pub const KEYSTORE_PASSWORD_ENV_VAR: &str = "KEYSTORE_PASSWORD";
 pub const CREATE_KEYSTORE_PASSWORD_ENV_VAR: &str = "CREATE_KEYSTORE_PASSWORD";
+pub const SNCAST_PROFILE_ENV_VAR: &str = "SNCAST_PROFILE";
+/// Set for commands run by `--profile-matrix`, makes them exit with status 1 when the command fails
+pub const SNCAST_PROFILE_MATRIX_RUN_ENV_VAR: &str = "SNCAST_PROFILE_MATRIX_RUN";
+/// Internal, only read together with [`SNCAST_PROFILE_MATRIX_RUN_ENV_VAR`].
+/// Url of a `--profile-matrix` run, replaces the url from the profile of the command
+pub const SNCAST_PROFILE_MATRIX_URL_ENV_VAR: &str = "SNCAST_PROFILE_MATRIX_URL";
 
 pub const SCRIPT_LIB_ARTIFACT_NAME: &str = "__sncast_script_lib";
 
@@ -2,9 +2,12 @@ use anyhow::{bail, ensure, Result};
 use clap::{Args, ValueEnum};
 use conversions::serde::deserialize::CairoDeserialize;
 use conversions::TryIntoConv;
-use starknet::core::types::{BlockId, Felt};
+use serde::{Deserialize, Serialize};
+use starknet::core::types::{BlockId, FeeEstimate, Felt};
 use starknet::providers::Provider;
 use starknet_types_core::felt::NonZeroFelt;
+use std::future::Future;
+use std::str::FromStr;
 
 #[derive(Args, Debug, Clone)]
 pub struct FeeArgs {
@@ -16,12 +19,16 @@ pub struct FeeArgs {
     #[clap(short, long)]
     pub max_fee: Option<Felt>,
 
+    /// Gas price in Wei. Max fee is calculated from the estimated gas usage and this price instead of the current network gas price. (Only for ETH fee payment)
+    #[clap(long, conflicts_with = "max_fee")]
+    pub gas_price: Option<Felt>,
+
     /// Max gas amount. If not provided, will be automatically estimated. (Only for STRK fee payment)
     #[clap(long)]
     pub max_gas: Option<Felt>,
 
     /// Max gas price in Fri. If not provided, will be automatically estimated. (Only for STRK fee payment)
-    #[clap(long)]
+    #[clap(long, alias = "l1-gas-price")]
     pub max_gas_unit_price: Option<Felt>,
 }
 
@@ -31,6 +38,7 @@ impl From<ScriptFeeSettings> for FeeArgs {
             ScriptFeeSettings::Eth { max_fee } => Self {
                 fee_token: Some(FeeToken::Eth),
                 max_fee,
+                gas_price: None,
                 max_gas: None,
                 max_gas_unit_price: None,
             },
@@ -41,6 +49,7 @@ impl From<ScriptFeeSettings> for FeeArgs {
             } => Self {
                 fee_token: Some(FeeToken::Strk),
                 max_fee,
+                gas_price: None,
                 max_gas: max_gas.map(Into::into),
                 max_gas_unit_price: max_gas_unit_price.map(Into::into),
             },
@@ -74,9 +83,14 @@ impl FeeArgs {
                 );
                 Ok(FeeSettings::Eth {
                     max_fee: self.max_fee,
+                    gas_price: self.gas_price,
                 })
             }
             FeeToken::Strk => {
+                ensure!(
+                    self.gas_price.is_none(),
+                    "--gas-price is not supported for STRK fee payment, use --max-gas-unit-price instead"
+                );
                 let settings = match (self.max_fee, self.max_gas, self.max_gas_unit_price) {
                     (Some(_), Some(_), Some(_)) => {
                         bail!("Passing all --max-fee, --max-gas and --max-gas-unit-price is conflicting. Please pass only two of them or less")
@@ -138,7 +152,39 @@ impl FeeArgs {
     }
 }
 
-#[derive(ValueEnum, Debug, Clone, PartialEq)]
+/// Bounds of a single resource, passed as `<MAX_AMOUNT>,<MAX_PRICE_PER_UNIT>`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ResourceBounds {
+    pub max_amount: u64,
+    pub max_price_per_unit: u128,
+}
+
+impl FromStr for ResourceBounds {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (max_amount, max_price_per_unit) = s.split_once(',').ok_or_else(|| {
+            "Expected resource bounds in <MAX_AMOUNT>,<MAX_PRICE_PER_UNIT> format".to_string()
+        })?;
+
+        let max_amount = Felt::from_str(max_amount.trim())
+            .ok()
+            .and_then(|amount| amount.try_into_().ok())
+            .ok_or_else(|| format!("Invalid max amount: {max_amount}"))?;
+        let max_price_per_unit = Felt::from_str(max_price_per_unit.trim())
+            .ok()
+            .and_then(|price| price.try_into_().ok())
+            .ok_or_else(|| format!("Invalid max price per unit: {max_price_per_unit}"))?;
+
+        Ok(Self {
+            max_amount,
+            max_price_per_unit,
+        })
+    }
+}
+
+#[derive(ValueEnum, Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
 pub enum FeeToken {
     Eth,
     Strk,
@@ -162,6 +208,7 @@ pub enum ScriptFeeSettings {
 pub enum FeeSettings {
     Eth {
         max_fee: Option<Felt>,
+        gas_price: Option<Felt>,
     },
     Strk {
         max_gas: Option<u64>,
@@ -169,10 +216,34 @@ pub enum FeeSettings {
     },
 }
 
+/// Calculates max fee from the estimated gas usage, paying `gas_price` for each unit of L1 gas
+#[must_use]
+pub fn max_fee_from_gas_price(fee_estimate: &FeeEstimate, gas_price: Felt) -> Felt {
+    fee_estimate.gas_consumed * gas_price
+        + fee_estimate.data_gas_consumed * fee_estimate.data_gas_price
+}
+
+/// Resolves max fee of an ETH transaction, awaiting `estimate_fee` only when `gas_price` was passed
+pub async fn resolve_max_fee<E>(
+    max_fee: Option<Felt>,
+    gas_price: Option<Felt>,
+    estimate_fee: impl Future<Output = Result<FeeEstimate, E>>,
+) -> Result<Option<Felt>, E> {
+    match gas_price {
+        Some(gas_price) => Ok(Some(max_fee_from_gas_price(
+            &estimate_fee.await?,
+            gas_price,
+        ))),
+        None => Ok(max_fee),
+    }
+}
+
 pub trait PayableTransaction {
     fn error_message(&self, token: &str, version: &str) -> String;
     fn validate(&self) -> Result<()>;
     fn token_from_version(&self) -> Option<FeeToken>;
+    /// Sets the fee token used when neither `--fee-token` nor `--version` was passed
+    fn default_fee_token(&mut self, fee_token: FeeToken);
 }
 
 #[macro_export]
@@ -206,6 +277,12 @@ macro_rules! impl_payable_transaction {
                     $($version => $token),+
                 })
             }
+
+            fn default_fee_token(&mut self, fee_token: FeeToken) {
+                if self.fee_args.fee_token.is_none() && self.version.is_none() {
+                    self.fee_args.fee_token = Some(fee_token);
+                }
+            }
         }
     };
 }
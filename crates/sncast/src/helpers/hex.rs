@@ -0,0 +1,11 @@
+use starknet::core::types::Felt;
+
+/// Formats felts as `0x`-prefixed hex strings joined with `separator`
+#[must_use]
+pub fn join_hex(felts: &[Felt], separator: &str) -> String {
+    felts
+        .iter()
+        .map(|felt| format!("{felt:#x}"))
+        .collect::<Vec<_>>()
+        .join(separator)
+}
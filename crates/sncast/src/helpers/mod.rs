@@ -1,8 +1,16 @@
+pub mod abi_decoder;
 pub mod block_explorer;
 pub mod braavos;
+pub mod config_validation;
 pub mod configuration;
 pub mod constants;
 pub mod error;
 pub mod fee;
+pub mod hex;
+pub mod profile_matrix;
 pub mod rpc;
 pub mod scarb_utils;
+pub mod simulation;
+pub mod storage;
+pub mod tx_log;
+pub mod udc;
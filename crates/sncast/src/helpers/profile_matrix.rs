@@ -0,0 +1,233 @@
+use crate::helpers::constants::{
+    SNCAST_PROFILE_MATRIX_RUN_ENV_VAR, SNCAST_PROFILE_MATRIX_URL_ENV_VAR,
+};
+use anyhow::{anyhow, bail, ensure, Context, Result};
+use std::ffi::OsString;
+use std::process::{Command, Output};
+use std::str::FromStr;
+use std::thread;
+
+const PROFILE_MATRIX_FLAG: &str = "--profile-matrix";
+
+/// Profiles and RPC urls passed as `[profile1,profile2] x [url1,url2]`
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProfileMatrix {
+    pub profiles: Vec<String>,
+    pub urls: Vec<String>,
+}
+
+impl FromStr for ProfileMatrix {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (profiles, urls) = s
+            .split_once(" x ")
+            .ok_or_else(|| "Expected profile matrix in [profiles] x [urls] format".to_string())?;
+
+        Ok(Self {
+            profiles: parse_list(profiles)?,
+            urls: parse_list(urls)?,
+        })
+    }
+}
+
+fn parse_list(list: &str) -> Result<Vec<String>, String> {
+    let items = list
+        .trim()
+        .strip_prefix('[')
+        .and_then(|list| list.strip_suffix(']'))
+        .ok_or_else(|| format!("Expected a list in [item1,item2] format, got {list}"))?
+        .split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .map(String::from)
+        .collect::<Vec<_>>();
+
+    if items.is_empty() {
+        return Err(format!("List {list} can't be empty"));
+    }
+    Ok(items)
+}
+
+impl ProfileMatrix {
+    /// All pairs of profiles and urls, ordered by profile
+    #[must_use]
+    pub fn combinations(&self) -> Vec<(String, String)> {
+        self.profiles
+            .iter()
+            .flat_map(|profile| {
+                self.urls
+                    .iter()
+                    .map(move |url| (profile.clone(), url.clone()))
+            })
+            .collect()
+    }
+}
+
+/// Removes the `--profile-matrix` flag and its value from the command line arguments
+#[must_use]
+pub fn strip_profile_matrix_arg(args: Vec<OsString>) -> Vec<OsString> {
+    let mut stripped = vec![];
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        if arg == PROFILE_MATRIX_FLAG {
+            args.next();
+        } else if !arg
+            .to_string_lossy()
+            .starts_with(&format!("{PROFILE_MATRIX_FLAG}="))
+        {
+            stripped.push(arg);
+        }
+    }
+
+    stripped
+}
+
+struct MatrixRun {
+    profile: String,
+    url: String,
+    output: Output,
+}
+
+impl MatrixRun {
+    fn succeeded(&self) -> bool {
+        self.output.status.success()
+    }
+}
+
+fn is_url_arg(arg: &OsString) -> bool {
+    let arg = arg.to_string_lossy();
+    arg == "--url" || arg == "-u" || arg.starts_with("--url=")
+}
+
+/// Runs sncast with the given arguments for every combination of profiles and urls in parallel,
+/// then prints outputs of all runs followed by a summary grid.
+/// Urls are passed with an internal environment variable, so they replace the url from the profile
+pub fn run_profile_matrix(matrix: &ProfileMatrix, args: &[OsString]) -> Result<()> {
+    ensure!(
+        !args.iter().any(is_url_arg),
+        "--url cannot be used with --profile-matrix, urls are taken from the matrix"
+    );
+    let executable = std::env::current_exe().context("Failed to find sncast executable")?;
+
+    let handles: Vec<_> = matrix
+        .combinations()
+        .into_iter()
+        .map(|(profile, url)| {
+            let mut command = Command::new(&executable);
+            command
+                .arg("--profile")
+                .arg(&profile)
+                .args(args)
+                .env(SNCAST_PROFILE_MATRIX_URL_ENV_VAR, &url)
+                .env(SNCAST_PROFILE_MATRIX_RUN_ENV_VAR, "1");
+
+            thread::spawn(move || {
+                command
+                    .output()
+                    .map(|output| MatrixRun {
+                        profile,
+                        url,
+                        output,
+                    })
+                    .context("Failed to run sncast")
+            })
+        })
+        .collect();
+
+    let mut runs = vec![];
+    for handle in handles {
+        let run = handle
+            .join()
+            .map_err(|_| anyhow!("Profile matrix run panicked"))??;
+        runs.push(run);
+    }
+
+    for run in &runs {
+        println!("profile: {}, url: {}", run.profile, run.url);
+        print!("{}", String::from_utf8_lossy(&run.output.stdout));
+        eprint!("{}", String::from_utf8_lossy(&run.output.stderr));
+        println!();
+    }
+
+    print_summary(&runs);
+
+    let failed = runs.iter().filter(|run| !run.succeeded()).count();
+    if failed > 0 {
+        bail!("{failed} of {} profile matrix runs failed", runs.len());
+    }
+    Ok(())
+}
+
+fn print_summary(runs: &[MatrixRun]) {
+    let profile_width = runs
+        .iter()
+        .map(|run| run.profile.len())
+        .chain(["PROFILE".len()])
+        .max()
+        .unwrap_or_default();
+    let url_width = runs
+        .iter()
+        .map(|run| run.url.len())
+        .chain(["URL".len()])
+        .max()
+        .unwrap_or_default();
+
+    println!("{:profile_width$}  {:url_width$}  RESULT", "PROFILE", "URL");
+    for run in runs {
+        let result = if run.succeeded() { "success" } else { "failed" };
+        println!(
+            "{:profile_width$}  {:url_width$}  {result}",
+            run.profile, run.url
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_matrix() {
+        let matrix =
+            ProfileMatrix::from_str("[profile1, profile2] x [http://a:5050,http://b:5050]")
+                .unwrap();
+
+        assert_eq!(
+            matrix.combinations(),
+            vec![
+                ("profile1".to_string(), "http://a:5050".to_string()),
+                ("profile1".to_string(), "http://b:5050".to_string()),
+                ("profile2".to_string(), "http://a:5050".to_string()),
+                ("profile2".to_string(), "http://b:5050".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_matrix() {
+        assert!(ProfileMatrix::from_str("[profile1]").is_err());
+        assert!(ProfileMatrix::from_str("profile1 x [http://a:5050]").is_err());
+        assert!(ProfileMatrix::from_str("[] x [http://a:5050]").is_err());
+    }
+
+    #[test]
+    fn strips_profile_matrix_arg() {
+        let args = ["--profile-matrix", "[a] x [b]", "--json", "invoke"]
+            .map(OsString::from)
+            .to_vec();
+        assert_eq!(
+            strip_profile_matrix_arg(args),
+            ["--json", "invoke"].map(OsString::from).to_vec()
+        );
+
+        let args = ["--profile-matrix=[a] x [b]", "invoke"]
+            .map(OsString::from)
+            .to_vec();
+        assert_eq!(
+            strip_profile_matrix_arg(args),
+            ["invoke"].map(OsString::from).to_vec()
+        );
+    }
+}
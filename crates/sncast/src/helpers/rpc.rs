@@ -1,13 +1,39 @@
-use crate::{get_provider, helpers::configuration::CastConfig};
+use crate::{get_provider_with_http_client, helpers::configuration::CastConfig};
 use clap::Args;
+use shared::rpc::RetryConfig;
 use shared::verify_and_warn_if_incompatible_rpc_version;
 use starknet::providers::{jsonrpc::HttpTransport, JsonRpcClient};
 
-#[derive(Args, Clone, Debug, Default)]
+pub const DEFAULT_CONNECTION_POOL_SIZE: usize = 10;
+
+#[derive(Args, Clone, Debug)]
 pub struct RpcArgs {
     /// RPC provider url address; overrides url from snfoundry.toml
     #[clap(short, long)]
     pub url: Option<String>,
+
+    /// Ignore proxies set with `HTTP_PROXY`, `HTTPS_PROXY` and `ALL_PROXY` environment variables
+    #[clap(long)]
+    pub no_proxy: bool,
+
+    /// Maximum number of idle connections kept open to the RPC node and reused across requests
+    #[clap(long, value_name = "N", default_value_t = DEFAULT_CONNECTION_POOL_SIZE)]
+    pub connection_pool_size: usize,
+
+    /// Value appended to the default `sncast/<version>` User-Agent header of RPC requests
+    #[clap(long, value_name = "USER_AGENT")]
+    pub user_agent: Option<String>,
+}
+
+impl Default for RpcArgs {
+    fn default() -> Self {
+        Self {
+            url: None,
+            no_proxy: false,
+            connection_pool_size: DEFAULT_CONNECTION_POOL_SIZE,
+            user_agent: None,
+        }
+    }
 }
 
 impl RpcArgs {
@@ -16,9 +42,15 @@ impl RpcArgs {
         config: &CastConfig,
     ) -> anyhow::Result<JsonRpcClient<HttpTransport>> {
         let url = self.url.as_ref().unwrap_or(&config.url);
-        let provider = get_provider(url)?;
+        let provider = get_provider_with_http_client(
+            url,
+            self.no_proxy,
+            self.connection_pool_size,
+            self.user_agent.as_deref(),
+        )?;
 
-        verify_and_warn_if_incompatible_rpc_version(&provider, &url).await?;
+        verify_and_warn_if_incompatible_rpc_version(&provider, &url, RetryConfig::default())
+            .await?;
 
         Ok(provider)
     }
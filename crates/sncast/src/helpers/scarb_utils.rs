@@ -121,6 +121,8 @@ pub struct BuildConfig {
     pub scarb_toml_path: Utf8PathBuf,
     pub json: bool,
     pub profile: String,
+    /// Print Scarb output to stderr, so stdout contains only the command result
+    pub stdout_to_stderr: bool,
 }
 
 pub fn build(
@@ -147,6 +149,9 @@ pub fn build(
     if config.json {
         cmd.json();
     }
+    if config.stdout_to_stderr {
+        cmd.stdout_to_stderr();
+    }
     cmd.run()
 }
 
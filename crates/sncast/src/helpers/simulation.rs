@@ -0,0 +1,161 @@
+use crate::helpers::hex::join_hex;
+use crate::response::errors::StarknetCommandError;
+use crate::response::structs::{Decimal, ExecutionStatus, SimulateResponse};
+use anyhow::{anyhow, Context};
+use starknet::accounts::AccountError;
+use starknet::core::types::{
+    ExecuteInvocation, Felt, FunctionInvocation, PriceUnit, SimulatedTransaction, StateDiff,
+    TransactionTrace,
+};
+
+/// Converts the result of transaction simulation into a response printed to the user
+pub fn handle_simulation_result<S>(
+    result: Result<SimulatedTransaction, AccountError<S>>,
+) -> Result<SimulateResponse, StarknetCommandError> {
+    match result {
+        Ok(simulated) => simulate_response(&simulated),
+        Err(AccountError::Provider(error)) => {
+            Err(StarknetCommandError::ProviderError(error.into()))
+        }
+        _ => Err(anyhow!("Unknown RPC error").into()),
+    }
+}
+
+fn simulate_response(
+    simulated: &SimulatedTransaction,
+) -> Result<SimulateResponse, StarknetCommandError> {
+    let trace = &simulated.transaction_trace;
+
+    let (invocations, revert_reason, state_diff) = match trace {
+        TransactionTrace::Invoke(trace) => {
+            let (execute_invocation, revert_reason) = match &trace.execute_invocation {
+                ExecuteInvocation::Success(invocation) => (Some(invocation), None),
+                ExecuteInvocation::Reverted(reverted) => {
+                    (None, Some(reverted.revert_reason.clone()))
+                }
+            };
+            let invocations = [
+                trace.validate_invocation.as_ref(),
+                execute_invocation,
+                trace.fee_transfer_invocation.as_ref(),
+            ];
+            (invocations, revert_reason, trace.state_diff.as_ref())
+        }
+        TransactionTrace::Declare(trace) => (
+            [
+                trace.validate_invocation.as_ref(),
+                None,
+                trace.fee_transfer_invocation.as_ref(),
+            ],
+            None,
+            trace.state_diff.as_ref(),
+        ),
+        TransactionTrace::DeployAccount(trace) => (
+            [
+                trace.validate_invocation.as_ref(),
+                Some(&trace.constructor_invocation),
+                trace.fee_transfer_invocation.as_ref(),
+            ],
+            None,
+            trace.state_diff.as_ref(),
+        ),
+        TransactionTrace::L1Handler(_) => {
+            return Err(anyhow!("Simulation of L1 handler transactions is not supported").into())
+        }
+    };
+
+    // invocation of the transaction itself, without validation and fee transfer
+    let call_stack_depth = invocations[1].map_or(0, call_stack_depth);
+
+    let mut events = vec![];
+    for invocation in invocations.into_iter().flatten() {
+        collect_events(invocation, &mut events);
+    }
+
+    let fee_estimation = &simulated.fee_estimation;
+
+    Ok(SimulateResponse {
+        overall_fee: fee_estimation.overall_fee,
+        gas_consumed: fee_estimation.gas_consumed,
+        gas_price: fee_estimation.gas_price,
+        fee_unit: match fee_estimation.unit {
+            PriceUnit::Wei => "WEI",
+            PriceUnit::Fri => "FRI",
+        }
+        .to_string(),
+        execution_status: if revert_reason.is_some() {
+            ExecutionStatus::Reverted
+        } else {
+            ExecutionStatus::Succeeded
+        },
+        revert_reason,
+        call_stack_depth: Decimal(call_stack_depth),
+        events,
+        storage_diffs: state_diff.map(storage_diffs).unwrap_or_default(),
+        deployed_contracts: state_diff.map(deployed_contracts).unwrap_or_default(),
+        declared_classes: state_diff.map(declared_classes).unwrap_or_default(),
+        transaction_trace: serde_json::to_string(trace)
+            .context("Failed to serialize transaction trace")?,
+    })
+}
+
+fn call_stack_depth(invocation: &FunctionInvocation) -> u64 {
+    1 + invocation
+        .calls
+        .iter()
+        .map(call_stack_depth)
+        .max()
+        .unwrap_or(0)
+}
+
+fn collect_events(invocation: &FunctionInvocation, events: &mut Vec<String>) {
+    for event in &invocation.events {
+        events.push(format!(
+            "from_address: {:#x}, keys: [{}], data: [{}]",
+            invocation.contract_address,
+            join_hex(&event.keys, ", "),
+            join_hex(&event.data, ", ")
+        ));
+    }
+
+    for call in &invocation.calls {
+        collect_events(call, events);
+    }
+}
+
+fn storage_diffs(state_diff: &StateDiff) -> Vec<String> {
+    state_diff
+        .storage_diffs
+        .iter()
+        .flat_map(|diff| {
+            diff.storage_entries.iter().map(|entry| {
+                format!(
+                    "address: {:#x}, key: {:#x}, value: {:#x}",
+                    diff.address, entry.key, entry.value
+                )
+            })
+        })
+        .collect()
+}
+
+fn deployed_contracts(state_diff: &StateDiff) -> Vec<String> {
+    state_diff
+        .deployed_contracts
+        .iter()
+        .map(|contract| {
+            format!(
+                "address: {:#x}, class_hash: {:#x}",
+                contract.address, contract.class_hash
+            )
+        })
+        .collect()
+}
+
+fn declared_classes(state_diff: &StateDiff) -> Vec<Felt> {
+    state_diff
+        .declared_classes
+        .iter()
+        .map(|class| class.class_hash)
+        .chain(state_diff.deprecated_declared_classes.iter().copied())
+        .collect()
+}
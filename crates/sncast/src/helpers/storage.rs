@@ -0,0 +1,51 @@
+use anyhow::{ensure, Context, Result};
+use starknet::core::types::Felt;
+use starknet::core::utils::{get_storage_var_address, starknet_keccak};
+
+/// Computes the storage address of a named storage variable, i.e. `sn_keccak` of its name.
+/// With `field`, computes the address of the field of a storage node stored in the variable,
+/// i.e. Pedersen hash of the variable address and `sn_keccak` of the field name
+pub fn storage_key(variable: &str, field: Option<&str>) -> Result<Felt> {
+    let mut field_selectors = vec![];
+    if let Some(field) = field {
+        ensure!(
+            field.is_ascii(),
+            "Field name {field} must be an ASCII string"
+        );
+        field_selectors.push(starknet_keccak(field.as_bytes()));
+    }
+
+    get_storage_var_address(variable, &field_selectors)
+        .with_context(|| format!("Variable name {variable} must be an ASCII string"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::storage_key;
+    use starknet::core::utils::{normalize_address, pedersen_hash, starknet_keccak};
+
+    #[test]
+    fn test_storage_key_of_variable() {
+        assert_eq!(
+            storage_key("balance", None).unwrap(),
+            normalize_address(starknet_keccak(b"balance"))
+        );
+    }
+
+    #[test]
+    fn test_storage_key_of_field() {
+        assert_eq!(
+            storage_key("config", Some("owner")).unwrap(),
+            normalize_address(pedersen_hash(
+                &starknet_keccak(b"config"),
+                &starknet_keccak(b"owner")
+            ))
+        );
+    }
+
+    #[test]
+    fn test_storage_key_non_ascii() {
+        assert!(storage_key("bałance", None).is_err());
+        assert!(storage_key("config", Some("ówner")).is_err());
+    }
+}
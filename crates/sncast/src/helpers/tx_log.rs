@@ -0,0 +1,81 @@
+use crate::read_and_parse_json_file;
+use anyhow::{Context, Result};
+use camino::{Utf8Path, Utf8PathBuf};
+use serde::{Deserialize, Serialize};
+use starknet::core::types::Felt;
+use std::fs;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub const TX_LOG_FILE_PATH: &str = ".snfoundry_cache/tx_log.json";
+
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct TxLogEntry {
+    pub transaction_hash: Felt,
+    pub memo: String,
+    /// Unix timestamp in seconds
+    pub timestamp: u64,
+}
+
+/// Reads entries of the transaction log, returns no entries if the log doesn't exist yet
+pub fn read_tx_log(path: &Utf8Path) -> Result<Vec<TxLogEntry>> {
+    if !path.exists() {
+        return Ok(vec![]);
+    }
+    read_and_parse_json_file(&path.to_path_buf())
+}
+
+/// Appends the transaction with its memo to the transaction log, creating the log if needed.
+/// The memo is only stored locally, it is never sent on-chain
+pub fn append_to_tx_log(path: &Utf8Path, transaction_hash: Felt, memo: &str) -> Result<()> {
+    let mut entries = read_tx_log(path)?;
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .context("System time is before Unix epoch")?
+        .as_secs();
+    entries.push(TxLogEntry {
+        transaction_hash,
+        memo: memo.to_string(),
+        timestamp,
+    });
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create directory = {parent}"))?;
+    }
+    fs::write(path, serde_json::to_string_pretty(&entries)?)
+        .with_context(|| format!("Failed to write transaction log = {path}"))
+}
+
+#[must_use]
+pub fn default_tx_log_path() -> Utf8PathBuf {
+    Utf8PathBuf::from(TX_LOG_FILE_PATH)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn appends_entries() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = Utf8PathBuf::from_path_buf(temp_dir.path().join(TX_LOG_FILE_PATH)).unwrap();
+
+        assert!(read_tx_log(&path).unwrap().is_empty());
+
+        append_to_tx_log(&path, Felt::ONE, "deployed MyToken").unwrap();
+        append_to_tx_log(&path, Felt::TWO, "minted tokens").unwrap();
+
+        let entries = read_tx_log(&path).unwrap();
+        assert_eq!(
+            entries
+                .iter()
+                .map(|entry| (entry.transaction_hash, entry.memo.as_str()))
+                .collect::<Vec<_>>(),
+            vec![
+                (Felt::ONE, "deployed MyToken"),
+                (Felt::TWO, "minted tokens")
+            ]
+        );
+    }
+}
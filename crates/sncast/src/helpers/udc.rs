@@ -0,0 +1,59 @@
+use crate::udc_uniqueness;
+use starknet::core::types::Felt;
+use starknet::core::utils::get_udc_deployed_address;
+
+/// Computes the address of a contract deployed through the Universal Deployer Contract
+#[must_use]
+pub fn compute_udc_deployed_address(
+    salt: Felt,
+    class_hash: Felt,
+    unique: bool,
+    deployer_address: Felt,
+    constructor_calldata: &[Felt],
+) -> Felt {
+    get_udc_deployed_address(
+        salt,
+        class_hash,
+        &udc_uniqueness(unique, deployer_address),
+        constructor_calldata,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::compute_udc_deployed_address;
+    use crate::helpers::constants::UDC_ADDRESS;
+    use starknet::core::types::Felt;
+    use starknet::core::utils::{get_contract_address, pedersen_hash};
+
+    #[test]
+    fn test_compute_address_not_unique() {
+        let calldata = [Felt::ONE, Felt::TWO];
+
+        let address =
+            compute_udc_deployed_address(Felt::THREE, Felt::TWO, false, Felt::ONE, &calldata);
+
+        assert_eq!(
+            address,
+            get_contract_address(Felt::THREE, Felt::TWO, &calldata, Felt::ZERO)
+        );
+    }
+
+    #[test]
+    fn test_compute_address_unique() {
+        let calldata = [Felt::ONE, Felt::TWO];
+
+        let address =
+            compute_udc_deployed_address(Felt::THREE, Felt::TWO, true, Felt::ONE, &calldata);
+
+        assert_eq!(
+            address,
+            get_contract_address(
+                pedersen_hash(&Felt::ONE, &Felt::THREE),
+                Felt::TWO,
+                &calldata,
+                UDC_ADDRESS
+            )
+        );
+    }
+}
@@ -1,7 +1,8 @@
+use crate::helpers::configuration::CastConfig;
 use crate::helpers::constants::{DEFAULT_STATE_FILE_SUFFIX, WAIT_RETRY_INTERVAL, WAIT_TIMEOUT};
 use crate::response::errors::SNCastProviderError;
 use anyhow::{anyhow, bail, Context, Error, Result};
-use camino::Utf8PathBuf;
+use camino::{Utf8Path, Utf8PathBuf};
 use clap::ValueEnum;
 use conversions::serde::serialize::CairoSerialize;
 use helpers::constants::{KEYSTORE_PASSWORD_ENV_VAR, UDC_ADDRESS};
@@ -10,6 +11,8 @@ use rand::RngCore;
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use serde_json::{Deserializer, Value};
+use sha3::{Digest, Keccak256};
+use shared::print::print_as_warning;
 use shared::rpc::create_rpc_client;
 use starknet::accounts::{AccountFactory, AccountFactoryError};
 use starknet::core::types::{
@@ -30,11 +33,13 @@ use starknet::{
     signers::{LocalWallet, SigningKey},
 };
 use std::str::FromStr;
+use std::sync::OnceLock;
 use std::thread::sleep;
 use std::time::Duration;
 use std::{collections::HashMap, fmt::Display};
 use std::{env, fs};
 use thiserror::Error;
+use url::Url;
 
 pub mod helpers;
 pub mod response;
@@ -132,10 +137,66 @@ impl NumbersFormat {
     }
 }
 
+#[derive(ValueEnum, Debug, PartialEq, Eq, Copy, Clone, Default)]
+pub enum AddressFormat {
+    /// Lowercase hex, e.g. 0x4a3f
+    #[default]
+    Lower,
+    /// Uppercase hex, e.g. 0x4A3F
+    Upper,
+    /// Padded hex with checksum capitalization
+    Checksum,
+}
+
+impl AddressFormat {
+    #[must_use]
+    pub fn format(self, address: Felt) -> String {
+        match self {
+            AddressFormat::Lower => format!("{address:#x}"),
+            AddressFormat::Upper => format!("0x{}", format!("{address:x}").to_uppercase()),
+            AddressFormat::Checksum => checksum_address(address),
+        }
+    }
+}
+
+/// Encodes the address as padded hex where a letter is capitalized
+/// if the corresponding nibble of the keccak hash of the address is at least 8
+#[must_use]
+pub fn checksum_address(address: Felt) -> String {
+    let bytes = address.to_bytes_be();
+    let first_non_zero = bytes.iter().position(|byte| *byte != 0).unwrap_or(31);
+    let hash = Keccak256::digest(&bytes[first_non_zero..]);
+
+    let checksummed: String = bytes
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect::<String>()
+        .chars()
+        .enumerate()
+        .map(|(index, char)| {
+            let byte = hash[index / 2];
+            let nibble = if index % 2 == 0 {
+                byte >> 4
+            } else {
+                byte & 0x0f
+            };
+            if nibble >= 8 {
+                char.to_ascii_uppercase()
+            } else {
+                char
+            }
+        })
+        .collect();
+
+    format!("0x{checksummed}")
+}
+
 #[derive(Clone, Copy)]
 pub struct WaitForTx {
     pub wait: bool,
     pub wait_params: ValidatedWaitParams,
+    /// Print waiting status to stderr instead of stdout
+    pub status_to_stderr: bool,
 }
 
 #[derive(Deserialize, Serialize, Clone, Debug, Copy, PartialEq)]
@@ -196,6 +257,38 @@ pub fn get_provider(url: &str) -> Result<JsonRpcClient<HttpTransport>> {
     create_rpc_client(url)
 }
 
+/// Creates a provider keeping at most `connection_pool_size` idle connections to the RPC node.
+/// With `no_proxy` set, proxies from `HTTP_PROXY`, `HTTPS_PROXY` and `ALL_PROXY` environment variables are ignored.
+/// Requests are sent with `sncast/<version>` User-Agent header, followed by `user_agent` if passed
+pub fn get_provider_with_http_client(
+    url: &str,
+    no_proxy: bool,
+    connection_pool_size: usize,
+    user_agent: Option<&str>,
+) -> Result<JsonRpcClient<HttpTransport>> {
+    raise_if_empty(url, "RPC url")?;
+    let parsed_url = Url::parse(url).with_context(|| format!("Failed to parse URL: {url}"))?;
+    let mut builder = reqwest::Client::builder()
+        .pool_max_idle_per_host(connection_pool_size)
+        .user_agent(user_agent_header(user_agent));
+    if no_proxy {
+        builder = builder.no_proxy();
+    }
+    let client = builder.build().context("Failed to create HTTP client")?;
+
+    Ok(JsonRpcClient::new(HttpTransport::new_with_client(
+        parsed_url, client,
+    )))
+}
+
+fn user_agent_header(user_agent: Option<&str>) -> String {
+    let default_user_agent = concat!("sncast/", env!("CARGO_PKG_VERSION"));
+    match user_agent {
+        Some(user_agent) => format!("{default_user_agent} {user_agent}"),
+        None => default_user_agent.to_string(),
+    }
+}
+
 pub async fn get_chain_id(provider: &JsonRpcClient<HttpTransport>) -> Result<Felt> {
     provider
         .chain_id()
@@ -203,7 +296,55 @@ pub async fn get_chain_id(provider: &JsonRpcClient<HttpTransport>) -> Result<Fel
         .context("Failed to fetch chain_id")
 }
 
+/// Password read from `--keystore-password-file`, takes precedence over environment variables
+static KEYSTORE_PASSWORD_FROM_FILE: OnceLock<String> = OnceLock::new();
+
+/// Reads the keystore password from the first line of the file, so that it is used instead of prompting for it
+pub fn set_keystore_password_file(path: &Utf8Path) -> Result<()> {
+    let password = read_keystore_password_file(path)?;
+    KEYSTORE_PASSWORD_FROM_FILE
+        .set(password)
+        .map_err(|_| anyhow!("Keystore password file can only be set once"))
+}
+
+fn read_keystore_password_file(path: &Utf8Path) -> Result<String> {
+    let contents = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read keystore password file {path}"))?;
+    warn_if_readable_by_others(path)?;
+
+    let password = contents.lines().next().unwrap_or_default();
+    Ok(password.trim().to_string())
+}
+
+#[cfg(unix)]
+fn warn_if_readable_by_others(path: &Utf8Path) -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let mode = fs::metadata(path)
+        .with_context(|| format!("Failed to read permissions of {path}"))?
+        .permissions()
+        .mode();
+    if mode & 0o004 != 0 {
+        print_as_warning(&anyhow!(
+            "Keystore password file {path} is readable by all users, restrict its permissions with `chmod 600 {path}`"
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn warn_if_readable_by_others(_path: &Utf8Path) -> Result<()> {
+    Ok(())
+}
+
+/// Returns the keystore password from `--keystore-password-file`, `env_var` environment variable
+/// or prompts for it, in this order
 pub fn get_keystore_password(env_var: &str) -> std::io::Result<String> {
+    if let Some(password) = KEYSTORE_PASSWORD_FROM_FILE.get() {
+        return Ok(password.clone());
+    }
+
     match env::var(env_var) {
         Ok(password) => Ok(password),
         _ => rpassword::prompt_password("Enter password: "),
@@ -266,6 +407,31 @@ pub async fn get_account<'a>(
     Ok(account)
 }
 
+/// Builds the account from `--account-json` if it was passed, otherwise from the account
+/// set with `--account` in the accounts file or keystore
+pub async fn get_account_from_config<'a>(
+    config: &CastConfig,
+    provider: &'a JsonRpcClient<HttpTransport>,
+) -> Result<SingleOwnerAccount<&'a JsonRpcClient<HttpTransport>, LocalWallet>> {
+    match &config.account_json {
+        Some(account_json) => {
+            let chain_id = get_chain_id(provider).await?;
+            let account_data = get_account_data_from_json(account_json)?;
+
+            build_account(account_data, chain_id, provider).await
+        }
+        None => {
+            get_account(
+                &config.account,
+                &config.accounts_file,
+                provider,
+                config.keystore.clone(),
+            )
+            .await
+        }
+    }
+}
+
 async fn build_account(
     account_data: AccountData,
     chain_id: Felt,
@@ -411,6 +577,55 @@ pub fn get_account_data_from_accounts_file(
         .ok_or_else(|| anyhow!("Account = {name} not found under network = {network_name}"))
 }
 
+#[derive(Deserialize)]
+struct InlineAccountData {
+    private_key: Felt,
+    public_key: Option<Felt>,
+    address: Felt,
+    salt: Option<Felt>,
+    deployed: Option<bool>,
+    class_hash: Option<Felt>,
+    legacy: Option<bool>,
+    #[serde(default, rename = "type")]
+    account_type: Option<String>,
+}
+
+/// Parses account passed inline with `--account-json`, using the same fields as an entry of the accounts file.
+/// `public_key` is derived from `private_key` when omitted
+pub fn get_account_data_from_json(account_json: &str) -> Result<AccountData> {
+    let deserializer = &mut Deserializer::from_str(account_json);
+    let account: InlineAccountData =
+        serde_path_to_error::deserialize(deserializer).map_err(|err| {
+            let path_to_field = err.path().to_string();
+            anyhow!(
+                "Failed to parse field `{path_to_field}` of --account-json: {}",
+                err.into_inner()
+            )
+        })?;
+
+    let public_key = account.public_key.unwrap_or_else(|| {
+        SigningKey::from_secret_scalar(account.private_key)
+            .verifying_key()
+            .scalar()
+    });
+    let account_type = account
+        .account_type
+        .as_deref()
+        .map(AccountType::from_str)
+        .transpose()?;
+
+    Ok(AccountData {
+        private_key: account.private_key,
+        public_key,
+        address: Some(account.address),
+        salt: account.salt,
+        deployed: account.deployed,
+        class_hash: account.class_hash,
+        legacy: account.legacy,
+        account_type,
+    })
+}
+
 pub fn read_and_parse_json_file<T: DeserializeOwned>(path: &Utf8PathBuf) -> Result<T> {
     let file_content =
         fs::read_to_string(path).with_context(|| format!("Failed to read a file = {path}"))?;
@@ -539,7 +754,24 @@ pub async fn wait_for_tx(
     tx_hash: Felt,
     wait_params: ValidatedWaitParams,
 ) -> Result<&str, WaitForTransactionError> {
-    println!("Transaction hash = {tx_hash:#x}");
+    wait_for_tx_with_status(provider, tx_hash, wait_params, false).await
+}
+
+async fn wait_for_tx_with_status(
+    provider: &JsonRpcClient<HttpTransport>,
+    tx_hash: Felt,
+    wait_params: ValidatedWaitParams,
+    status_to_stderr: bool,
+) -> Result<&str, WaitForTransactionError> {
+    let print_status = |status: String| {
+        if status_to_stderr {
+            eprintln!("{status}");
+        } else {
+            println!("{status}");
+        }
+    };
+
+    print_status(format!("Transaction hash = {tx_hash:#x}"));
 
     let retries = wait_params.get_retries();
     for i in (1..retries).rev() {
@@ -563,10 +795,12 @@ pub async fn wait_for_tx(
             Ok(starknet::core::types::TransactionStatus::Received)
             | Err(StarknetError(TransactionHashNotFound)) => {
                 let remaining_time = wait_params.remaining_time(i);
-                println!("Waiting for transaction to be accepted ({i} retries / {remaining_time}s left until timeout)");
+                print_status(format!("Waiting for transaction to be accepted ({i} retries / {remaining_time}s left until timeout)"));
             }
             Err(ProviderError::RateLimited) => {
-                println!("Request rate limited while waiting for transaction to be accepted");
+                print_status(
+                    "Request rate limited while waiting for transaction to be accepted".to_string(),
+                );
                 sleep(Duration::from_secs(wait_params.get_retry_interval().into()));
             }
             Err(err) => return Err(WaitForTransactionError::ProviderError(err.into())),
@@ -624,7 +858,14 @@ pub async fn handle_wait_for_tx<T>(
     wait_config: WaitForTx,
 ) -> Result<T, WaitForTransactionError> {
     if wait_config.wait {
-        return match wait_for_tx(provider, transaction_hash, wait_config.wait_params).await {
+        return match wait_for_tx_with_status(
+            provider,
+            transaction_hash,
+            wait_config.wait_params,
+            wait_config.status_to_stderr,
+        )
+        .await
+        {
             Ok(_) => Ok(return_value),
             Err(error) => Err(error),
         };
@@ -699,7 +940,8 @@ mod tests {
     use crate::helpers::constants::KEYSTORE_PASSWORD_ENV_VAR;
     use crate::{
         chain_id_to_network_name, extract_or_generate_salt, get_account_data_from_accounts_file,
-        get_account_data_from_keystore, get_block_id, udc_uniqueness, AccountType,
+        get_account_data_from_json, get_account_data_from_keystore, get_block_id,
+        read_keystore_password_file, udc_uniqueness, user_agent_header, AccountType, AddressFormat,
     };
     use camino::Utf8PathBuf;
     use conversions::string::IntoHexStr;
@@ -712,6 +954,17 @@ mod tests {
     use starknet::core::utils::UdcUniqueness::{NotUnique, Unique};
     use std::env;
 
+    #[test]
+    fn test_user_agent_header() {
+        let default_user_agent = format!("sncast/{}", env!("CARGO_PKG_VERSION"));
+
+        assert_eq!(user_agent_header(None), default_user_agent);
+        assert_eq!(
+            user_agent_header(Some("myapp/1.0")),
+            format!("{default_user_agent} myapp/1.0")
+        );
+    }
+
     #[test]
     fn test_get_block_id() {
         let pending_block = get_block_id("pending").unwrap();
@@ -751,6 +1004,45 @@ mod tests {
             .contains("Incorrect value passed for block_id = mariusz. Possible values are pending, latest, block hash (hex) and block number (u64)"));
     }
 
+    #[test]
+    fn test_get_account_data_from_json() {
+        let account = get_account_data_from_json(
+            r#"{"private_key": "0xffd33878eed7767e7c546ce3fc026295", "address": "0xf6ecd22832b7c3713cfa7826ee309ce96a2769833f093795fafa1b8f20c48b", "type": "oz"}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            account.public_key,
+            Felt::from_hex("0x17b62d16ee2b9b5ccd3320e2c0b234dfbdd1d01d09d0aa29ce164827cddf46a")
+                .unwrap()
+        );
+        assert_eq!(
+            account.address,
+            Some(
+                Felt::from_hex("0xf6ecd22832b7c3713cfa7826ee309ce96a2769833f093795fafa1b8f20c48b")
+                    .unwrap()
+            )
+        );
+        assert_eq!(account.account_type, Some(AccountType::OpenZeppelin));
+    }
+
+    #[test]
+    fn test_get_account_data_from_json_missing_address() {
+        let err = get_account_data_from_json(r#"{"private_key": "0x1"}"#).unwrap_err();
+
+        assert!(err.to_string().contains("missing field `address`"));
+    }
+
+    #[test]
+    fn test_get_account_data_from_json_invalid_type() {
+        let err = get_account_data_from_json(
+            r#"{"private_key": "0x1", "address": "0x2", "type": "unknown"}"#,
+        )
+        .unwrap_err();
+
+        assert!(err.to_string().contains("Invalid account type = unknown"));
+    }
+
     #[test]
     fn test_generate_salt() {
         let salt = extract_or_generate_salt(None);
@@ -779,6 +1071,26 @@ mod tests {
         assert!(matches!(uniqueness, NotUnique));
     }
 
+    #[test]
+    fn test_address_format() {
+        let address = Felt::from_hex_unchecked(
+            "0x2fd23d9182193775423497fc0c472e156c57c69e4089a1967fb288a2d84e914",
+        );
+
+        assert_eq!(
+            AddressFormat::Lower.format(address),
+            "0x2fd23d9182193775423497fc0c472e156c57c69e4089a1967fb288a2d84e914"
+        );
+        assert_eq!(
+            AddressFormat::Upper.format(address),
+            "0x2FD23D9182193775423497FC0C472E156C57C69E4089A1967FB288A2D84E914"
+        );
+        assert_eq!(
+            AddressFormat::Checksum.format(address),
+            "0x02Fd23d9182193775423497fc0c472E156C57C69E4089A1967fb288A2d84e914"
+        );
+    }
+
     #[test]
     fn test_chain_id_to_network_name() {
         let network_name_katana =
@@ -886,4 +1198,15 @@ mod tests {
             .to_string()
             .contains("Account = user1 not found under network = CUSTOM_CHAIN_ID"));
     }
+
+    #[test]
+    fn test_read_keystore_password_file() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let path = Utf8PathBuf::try_from(tempdir.path().join("password")).unwrap();
+        std::fs::write(&path, "  secret password \nsecond line\n").unwrap();
+
+        let password = read_keystore_password_file(&path).unwrap();
+
+        assert_eq!(password, "secret password");
+    }
 }
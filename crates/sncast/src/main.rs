@@ -1,32 +1,60 @@
 use crate::starknet_commands::account::Account;
 use crate::starknet_commands::show_config::ShowConfig;
 use crate::starknet_commands::{
-    account, call::Call, declare::Declare, deploy::Deploy, invoke::Invoke, multicall::Multicall,
-    script::Script, tx_status::TxStatus,
+    account, block::Block, call::Call, class::Class, class_hash::ClassHash, console::Console,
+    contract::Contract, declare::Declare, deploy::Deploy, estimate_fee::EstimateFee,
+    get_abi::GetAbi, get_block::GetBlock, get_nonce::GetNonce, get_transaction::GetTransaction,
+    invoke::Invoke, multicall::Multicall, predict_fee::PredictFee, script::Script,
+    storage_read::StorageRead, tx::Tx, tx_log::TxLog, tx_status::TxStatus,
 };
-use anyhow::{Context, Result};
+use anyhow::{anyhow, bail, ensure, Context, Result};
 use configuration::load_global_config;
 use sncast::response::explorer_link::print_block_explorer_link_if_allowed;
-use sncast::response::print::{print_command_result, OutputFormat};
+use sncast::response::print::{command_failed, print_command_result, OutputFormat};
 
 use camino::Utf8PathBuf;
-use clap::{Parser, Subcommand};
+use clap::error::ErrorKind;
+use clap::{CommandFactory, Parser, Subcommand};
+use shared::print::print_as_warning;
+use sncast::helpers::config_validation;
 use sncast::helpers::configuration::CastConfig;
-use sncast::helpers::constants::{DEFAULT_ACCOUNTS_FILE, DEFAULT_MULTICALL_CONTENTS};
-use sncast::helpers::fee::PayableTransaction;
+use sncast::helpers::constants::{
+    DEFAULT_ACCOUNTS_FILE, DEFAULT_MULTICALL_CONTENTS, SNCAST_PROFILE_ENV_VAR,
+    SNCAST_PROFILE_MATRIX_RUN_ENV_VAR, SNCAST_PROFILE_MATRIX_URL_ENV_VAR,
+};
+use sncast::helpers::fee::{FeeToken, PayableTransaction};
+use sncast::helpers::profile_matrix::{
+    run_profile_matrix, strip_profile_matrix_arg, ProfileMatrix,
+};
+use sncast::helpers::rpc::RpcArgs;
 use sncast::helpers::scarb_utils::{
     assert_manifest_path_exists, build, build_and_load_artifacts, get_package_metadata,
     get_scarb_metadata_with_deps, BuildConfig,
 };
+use sncast::helpers::tx_log::{append_to_tx_log, default_tx_log_path};
 use sncast::response::errors::handle_starknet_command_error;
+use sncast::response::structs::{ClassSierraResponse, TransactionStatusResponse};
 use sncast::{
-    chain_id_to_network_name, get_account, get_block_id, get_chain_id, get_default_state_file_name,
+    chain_id_to_network_name, extract_or_generate_salt, get_account_from_config, get_block_id,
+    get_chain_id, get_default_state_file_name, set_keystore_password_file, AddressFormat,
     NumbersFormat, ValidatedWaitParams, WaitForTx,
 };
+use starknet::accounts::Account as _;
+use starknet::core::types::{BlockId, BlockTag, Felt};
 use starknet::core::utils::get_selector_from_name;
 use starknet::providers::Provider;
 use starknet_commands::account::list::print_account_list;
+use starknet_commands::class::sierra::SierraOutput;
+use starknet_commands::console::{fetch_function_names, run_console, CONSOLE_COMMANDS};
+use starknet_commands::script::progress::{spawn_progress_printer, ProgressReporter};
+use starknet_commands::script::run::CompletedTransactions;
+use starknet_commands::tx_log::print_tx_log;
 use starknet_commands::verify::Verify;
+use std::collections::HashMap;
+use std::env;
+use std::ffi::OsString;
+use std::process;
+use std::time::Duration;
 use tokio::runtime::Runtime;
 
 mod starknet_commands;
@@ -65,16 +93,27 @@ Report bugs: https://github.com/foundry-rs/starknet-foundry/issues/new/choose\
 #[clap(name = "sncast")]
 #[allow(clippy::struct_excessive_bools)]
 struct Cli {
-    /// Profile name in snfoundry.toml config file
+    /// Profile name in snfoundry.toml config file, used by all commands.
+    /// Can also be set with SNCAST_PROFILE environment variable
     #[clap(short, long)]
     profile: Option<String>,
 
+    /// Run the command for every combination of profiles and RPC urls in parallel,
+    /// e.g. "[profile1,profile2] x [url1,url2]"
+    #[clap(long, conflicts_with = "profile")]
+    profile_matrix: Option<ProfileMatrix>,
+
     /// Account to be used for contract declaration;
     /// When using keystore (`--keystore`), this should be a path to account file
     /// When using accounts file, this should be an account name
     #[clap(short = 'a', long)]
     account: Option<String>,
 
+    /// Account passed inline as JSON with the same fields as an entry of the accounts file,
+    /// e.g. '{"private_key": "0x1", "address": "0x2", "type": "oz"}'. Nothing is written to the filesystem
+    #[clap(long, conflicts_with_all = ["account", "keystore"])]
+    account_json: Option<String>,
+
     /// Path to the file holding accounts info
     #[clap(long = "accounts-file")]
     accounts_file_path: Option<Utf8PathBuf>,
@@ -83,6 +122,10 @@ struct Cli {
     #[clap(short, long)]
     keystore: Option<Utf8PathBuf>,
 
+    /// Path to a file with the keystore password in its first line, used instead of prompting for the password
+    #[clap(long)]
+    keystore_password_file: Option<Utf8PathBuf>,
+
     /// If passed, values will be displayed as integers
     #[clap(long, conflicts_with = "hex_format")]
     int_format: bool,
@@ -95,10 +138,19 @@ struct Cli {
     #[clap(short, long)]
     json: bool,
 
+    /// Capitalization of hex addresses in the output
+    #[clap(long, value_enum, default_value_t = AddressFormat::Lower)]
+    format_addresses: AddressFormat,
+
     /// If passed, command will wait until transaction is accepted or rejected
     #[clap(short = 'w', long)]
     wait: bool,
 
+    /// Assert that the command does not block on its transactions being accepted.
+    /// Fails for commands which wait for acceptance regardless of --wait, e.g. `tx cancel`
+    #[clap(long, conflicts_with_all = ["wait", "wait_timeout", "wait_retry_interval"])]
+    no_wait: bool,
+
     /// Adjusts the time after which --wait assumes transaction was not received or rejected
     #[clap(long)]
     wait_timeout: Option<u16>,
@@ -107,8 +159,23 @@ struct Cli {
     #[clap(long)]
     wait_retry_interval: Option<u8>,
 
+    /// Note stored with the transaction hash in the local transaction log (`.snfoundry_cache/tx_log.json`),
+    /// it is never sent on-chain
+    #[clap(long)]
+    memo: Option<String>,
+
+    /// Simulate the transaction of `declare`, `deploy` or `invoke` and print the result without sending it.
+    /// Same as `--simulate-only` flag of these commands
+    #[clap(long)]
+    dry_run: bool,
+
+    /// Validate snfoundry.toml, values passed in the CLI and the accounts file without running any command.
+    /// Exits with status 1 if any errors are found
+    #[clap(long, conflicts_with = "profile_matrix")]
+    config_validate: bool,
+
     #[command(subcommand)]
-    command: Commands,
+    command: Option<Commands>,
 }
 
 #[derive(Subcommand)]
@@ -137,61 +204,159 @@ enum Commands {
     /// Run or initialize a deployment script
     Script(Script),
 
+    /// Start an interactive console
+    Console(Console),
+
     /// Get the status of a transaction
     TxStatus(TxStatus),
 
+    /// List transactions annotated with `--memo`
+    TxLog(TxLog),
+
     /// Verify a contract
     Verify(Verify),
+
+    /// Predict max fee based on fees paid in recent blocks
+    PredictFee(PredictFee),
+
+    /// Estimate the fee of a transaction without sending it
+    EstimateFee(EstimateFee),
+
+    /// Inspect declared classes or clone them to another network
+    Class(Class),
+
+    /// Compute the class hash of a compiled contract
+    ClassHash(ClassHash),
+
+    /// Read a raw storage slot of a deployed contract
+    StorageRead(StorageRead),
+
+    /// Inspect blocks
+    Block(Block),
+
+    /// Get the nonce of an account
+    GetNonce(GetNonce),
+
+    /// Get details of a transaction with decoded calls
+    GetTransaction(GetTransaction),
+
+    /// Get information about a block
+    GetBlock(GetBlock),
+
+    /// Print the ABI of a deployed contract
+    GetAbi(GetAbi),
+
+    /// Inspect deployed contracts
+    Contract(Contract),
+
+    /// Manage sent transactions
+    Tx(Tx),
 }
 
 fn main() -> Result<()> {
-    let cli = Cli::parse();
+    let mut cli = Cli::parse();
+
+    if let Some(profile_matrix) = &cli.profile_matrix {
+        let args = strip_profile_matrix_arg(std::env::args_os().skip(1).collect());
+        return run_profile_matrix(profile_matrix, &args);
+    }
+    resolve_profile(&mut cli);
 
     let numbers_format = NumbersFormat::from_flags(cli.hex_format, cli.int_format);
+    let address_format = cli.format_addresses;
     let output_format = OutputFormat::from_flag(cli.json);
 
+    if cli.config_validate {
+        if cli.command.is_some() {
+            Cli::command()
+                .error(
+                    ErrorKind::ArgumentConflict,
+                    "--config-validate cannot be used with a subcommand",
+                )
+                .exit();
+        }
+        return validate_config(&cli, output_format);
+    }
+    let Some(mut command) = cli.command.take() else {
+        Cli::command()
+            .error(
+                ErrorKind::MissingSubcommand,
+                "A subcommand is required unless --config-validate is passed",
+            )
+            .exit();
+    };
+
+    if cli.dry_run {
+        apply_dry_run(&mut command)?;
+    }
+    if cli.no_wait {
+        check_no_wait(&command)?;
+    }
+
+    if let Some(keystore_password_file) = &cli.keystore_password_file {
+        set_keystore_password_file(keystore_password_file)?;
+    }
+
     let runtime = Runtime::new().expect("Failed to instantiate Runtime");
 
-    if let Commands::Script(script) = &cli.command {
-        run_script_command(&cli, runtime, script, numbers_format, output_format)
+    let result = if let Commands::Script(script) = &command {
+        run_script_command(
+            &cli,
+            runtime,
+            script,
+            numbers_format,
+            address_format,
+            output_format,
+        )
+    } else if let Commands::Console(_) = &command {
+        run_console_command(&runtime)
     } else {
         let mut config = load_global_config::<CastConfig>(&None, &cli.profile)?;
         update_cast_config(&mut config, &cli);
 
         runtime.block_on(run_async_command(
             cli,
+            command,
             config,
             numbers_format,
+            address_format,
             output_format,
         ))
+    };
+
+    // runs of `--profile-matrix` are reported as failed based on their exit status
+    if result.is_ok()
+        && command_failed()
+        && env::var_os(SNCAST_PROFILE_MATRIX_RUN_ENV_VAR).is_some()
+    {
+        process::exit(1);
     }
+    result
 }
 
 #[allow(clippy::too_many_lines)]
 async fn run_async_command(
     cli: Cli,
+    command: Commands,
     config: CastConfig,
     numbers_format: NumbersFormat,
+    address_format: AddressFormat,
     output_format: OutputFormat,
 ) -> Result<()> {
     let wait_config = WaitForTx {
         wait: cli.wait,
         wait_params: config.wait_params,
+        status_to_stderr: false,
     };
 
-    match cli.command {
-        Commands::Declare(declare) => {
+    match command {
+        Commands::Declare(mut declare) => {
             let provider = declare.rpc.get_provider(&config).await?;
 
+            declare.default_fee_token(config.fee_token.clone().unwrap_or(FeeToken::Eth));
             declare.validate()?;
 
-            let account = get_account(
-                &config.account,
-                &config.accounts_file,
-                &provider,
-                config.keystore,
-            )
-            .await?;
+            let account = get_account_from_config(&config, &provider).await?;
             let manifest_path = assert_manifest_path_exists()?;
             let package_metadata = get_package_metadata(&manifest_path, &declare.package)?;
             let artifacts = build_and_load_artifacts(
@@ -200,16 +365,69 @@ async fn run_async_command(
                     scarb_toml_path: manifest_path,
                     json: cli.json,
                     profile: cli.profile.unwrap_or("release".to_string()),
+                    stdout_to_stderr: declare.class_hash_only,
                 },
                 false,
             )
             .expect("Failed to build contract");
+
+            if declare.class_hash_only {
+                let wait_config = WaitForTx {
+                    status_to_stderr: true,
+                    ..wait_config
+                };
+                let result =
+                    starknet_commands::declare::declare(declare, &account, &artifacts, wait_config)
+                        .await
+                        .map_err(handle_starknet_command_error);
+
+                match &result {
+                    Ok(response) => {
+                        log_transaction(cli.memo.as_deref(), response.transaction_hash);
+                        println!("{:#x}", response.class_hash);
+                    }
+                    Err(_) => print_command_result(
+                        "declare",
+                        &result,
+                        numbers_format,
+                        address_format,
+                        output_format,
+                    )?,
+                }
+                return Ok(());
+            }
+
+            if declare.simulate_only {
+                let result =
+                    starknet_commands::declare::simulate_declare(declare, &account, &artifacts)
+                        .await
+                        .map_err(handle_starknet_command_error);
+
+                print_command_result(
+                    "declare",
+                    &result,
+                    numbers_format,
+                    address_format,
+                    output_format,
+                )?;
+                return Ok(());
+            }
+
             let result =
                 starknet_commands::declare::declare(declare, &account, &artifacts, wait_config)
                     .await
                     .map_err(handle_starknet_command_error);
 
-            print_command_result("declare", &result, numbers_format, output_format)?;
+            if let Ok(response) = &result {
+                log_transaction(cli.memo.as_deref(), response.transaction_hash);
+            }
+            print_command_result(
+                "declare",
+                &result,
+                numbers_format,
+                address_format,
+                output_format,
+            )?;
             print_block_explorer_link_if_allowed(
                 &result,
                 output_format,
@@ -220,23 +438,78 @@ async fn run_async_command(
             Ok(())
         }
 
-        Commands::Deploy(deploy) => {
+        Commands::Deploy(mut deploy) => {
             let provider = deploy.rpc.get_provider(&config).await?;
 
+            deploy.default_fee_token(config.fee_token.clone().unwrap_or(FeeToken::Eth));
             deploy.validate()?;
-            let account = get_account(
-                &config.account,
-                &config.accounts_file,
-                &provider,
-                config.keystore,
-            )
-            .await?;
+            let account = get_account_from_config(&config, &provider).await?;
+
+            if deploy.simulate_only {
+                let result = starknet_commands::deploy::simulate_deploy(deploy, &account)
+                    .await
+                    .map_err(handle_starknet_command_error);
+
+                print_command_result(
+                    "deploy",
+                    &result,
+                    numbers_format,
+                    address_format,
+                    output_format,
+                )?;
+                return Ok(());
+            }
+
+            if deploy.address_only {
+                let wait_config = WaitForTx {
+                    status_to_stderr: true,
+                    ..wait_config
+                };
+                let result = starknet_commands::deploy::deploy(deploy, &account, wait_config)
+                    .await
+                    .map_err(handle_starknet_command_error);
+
+                match &result {
+                    Ok(response) => {
+                        log_transaction(cli.memo.as_deref(), response.transaction_hash);
+                        println!("{}", address_format.format(response.contract_address));
+                    }
+                    Err(_) => print_command_result(
+                        "deploy",
+                        &result,
+                        numbers_format,
+                        address_format,
+                        output_format,
+                    )?,
+                }
+                return Ok(());
+            }
+
+            let salt = extract_or_generate_salt(deploy.salt);
+            deploy.salt = Some(salt);
+            if output_format == OutputFormat::Human {
+                if let Ok(contract_address) = deploy.contract_address(salt, account.address()) {
+                    println!(
+                        "Predicted contract address: {}",
+                        address_format.format(contract_address)
+                    );
+                }
+            }
 
             let result = starknet_commands::deploy::deploy(deploy, &account, wait_config)
                 .await
                 .map_err(handle_starknet_command_error);
 
-            print_command_result("deploy", &result, numbers_format, output_format)?;
+            if let Ok(response) = &result {
+                log_transaction(cli.memo.as_deref(), response.transaction_hash);
+            }
+            print_command_result(
+                "deploy",
+                &result,
+                numbers_format,
+                address_format,
+                output_format,
+            )?;
             print_block_explorer_link_if_allowed(
                 &result,
                 output_format,
@@ -250,7 +523,42 @@ async fn run_async_command(
         Commands::Call(call) => {
             let provider = call.rpc.get_provider(&config).await?;
 
-            let block_id = get_block_id(&call.block_id)?;
+            if call.call_trace {
+                let account = get_account_from_config(&config, &provider).await?;
+
+                let result = starknet_commands::call::call_trace(
+                    call.contract_address,
+                    get_selector_from_name(&call.function)
+                        .context("Failed to convert entry point selector to FieldElement")?,
+                    call.calldata,
+                    &account,
+                    call.abi_dir.as_ref(),
+                )
+                .await
+                .map_err(handle_starknet_command_error);
+
+                print_command_result(
+                    "call",
+                    &result,
+                    numbers_format,
+                    address_format,
+                    output_format,
+                )?;
+                return Ok(());
+            }
+
+            let block_id = match &call.block_id {
+                Some(block_id) => {
+                    let block_id = get_block_id(block_id)?;
+                    if matches!(block_id, BlockId::Tag(BlockTag::Pending)) {
+                        print_as_warning(&anyhow!(
+                            "The pending block can still change, so results of calls on it may not be reproducible. Pass a block number or hash to call on a specific block"
+                        ));
+                    }
+                    block_id
+                }
+                None => BlockId::Tag(BlockTag::Pending),
+            };
 
             let result = starknet_commands::call::call(
                 call.contract_address,
@@ -263,33 +571,125 @@ async fn run_async_command(
             .await
             .map_err(handle_starknet_command_error);
 
-            print_command_result("call", &result, numbers_format, output_format)?;
+            if let (true, Ok(response)) = (call.decode, &result) {
+                match starknet_commands::call::decode_call_response(
+                    response,
+                    call.contract_address,
+                    &call.function,
+                    &provider,
+                    block_id.as_ref(),
+                )
+                .await
+                {
+                    Ok(decoded) => {
+                        print_command_result(
+                            "call",
+                            &Ok(decoded),
+                            numbers_format,
+                            address_format,
+                            output_format,
+                        )?;
+                        return Ok(());
+                    }
+                    Err(error) => print_as_warning(&anyhow!(
+                        "Failed to decode the response, showing raw values: {error}"
+                    )),
+                }
+            }
+
+            print_command_result(
+                "call",
+                &result,
+                numbers_format,
+                address_format,
+                output_format,
+            )?;
             Ok(())
         }
 
-        Commands::Invoke(invoke) => {
+        Commands::Invoke(mut invoke) => {
             let provider = invoke.rpc.get_provider(&config).await?;
 
+            invoke.default_fee_token(config.fee_token.clone().unwrap_or(FeeToken::Eth));
             invoke.validate()?;
 
-            let account = get_account(
-                &config.account,
-                &config.accounts_file,
-                &provider,
-                config.keystore,
-            )
-            .await?;
+            let account = get_account_from_config(&config, &provider).await?;
+
+            if let Some(batch_file) = &invoke.batch_file {
+                let entries = starknet_commands::invoke_batch::read_batch_file(batch_file)?;
+                let chain_id = provider.chain_id().await?;
+
+                return starknet_commands::invoke_batch::invoke_batch(
+                    &invoke,
+                    &entries,
+                    &account,
+                    wait_config,
+                    |entry, result| {
+                        if let Ok(response) = &result {
+                            log_transaction(
+                                entry.memo.as_deref().or(cli.memo.as_deref()),
+                                response.transaction_hash,
+                            );
+                        }
+                        print_command_result(
+                            "invoke",
+                            &result,
+                            numbers_format,
+                            address_format,
+                            output_format,
+                        )?;
+                        print_block_explorer_link_if_allowed(
+                            &result,
+                            output_format,
+                            chain_id,
+                            config.show_explorer_links,
+                            config.block_explorer,
+                        );
+                        Ok(())
+                    },
+                )
+                .await;
+            }
+
+            let function_selector =
+                get_selector_from_name(invoke.function.as_deref().unwrap_or_default())
+                    .context("Failed to convert entry point selector to FieldElement")?;
+
+            if invoke.simulate_only {
+                let result =
+                    starknet_commands::invoke::simulate_invoke(invoke, function_selector, &account)
+                        .await
+                        .map_err(handle_starknet_command_error);
+
+                print_command_result(
+                    "invoke",
+                    &result,
+                    numbers_format,
+                    address_format,
+                    output_format,
+                )?;
+                return Ok(());
+            }
+
             let result = starknet_commands::invoke::invoke(
                 invoke.clone(),
-                get_selector_from_name(&invoke.function)
-                    .context("Failed to convert entry point selector to FieldElement")?,
+                function_selector,
                 &account,
                 wait_config,
             )
             .await
             .map_err(handle_starknet_command_error);
 
-            print_command_result("invoke", &result, numbers_format, output_format)?;
+            if let Ok(response) = &result {
+                log_transaction(cli.memo.as_deref(), response.transaction_hash);
+            }
+            print_command_result(
+                "invoke",
+                &result,
+                numbers_format,
+                address_format,
+                output_format,
+            )?;
             print_block_explorer_link_if_allowed(
                 &result,
                 output_format,
@@ -313,6 +713,7 @@ async fn run_async_command(
                             "multicall new",
                             &result,
                             numbers_format,
+                            address_format,
                             output_format,
                         )?;
                     } else {
@@ -324,18 +725,36 @@ async fn run_async_command(
 
                     run.validate()?;
 
-                    let account = get_account(
-                        &config.account,
-                        &config.accounts_file,
-                        &provider,
-                        config.keystore,
-                    )
-                    .await?;
+                    let account = get_account_from_config(&config, &provider).await?;
+
+                    if run.dry_run {
+                        let result =
+                            starknet_commands::multicall::run::dry_run(run.clone(), &account).await;
+
+                        print_command_result(
+                            "multicall run",
+                            &result,
+                            numbers_format,
+                            address_format,
+                            output_format,
+                        )?;
+                        return Ok(());
+                    }
+
                     let result =
                         starknet_commands::multicall::run::run(run.clone(), &account, wait_config)
                             .await;
 
-                    print_command_result("multicall run", &result, numbers_format, output_format)?;
+                    if let Ok(response) = &result {
+                        log_transaction(cli.memo.as_deref(), response.transaction_hash);
+                    }
+                    print_command_result(
+                        "multicall run",
+                        &result,
+                        numbers_format,
+                        address_format,
+                        output_format,
+                    )?;
                     print_block_explorer_link_if_allowed(
                         &result,
                         output_format,
@@ -359,7 +778,13 @@ async fn run_async_command(
                 )
                 .await;
 
-                print_command_result("account import", &result, numbers_format, output_format)?;
+                print_command_result(
+                    "account import",
+                    &result,
+                    numbers_format,
+                    address_format,
+                    output_format,
+                )?;
                 Ok(())
             }
 
@@ -385,7 +810,13 @@ async fn run_async_command(
                 )
                 .await;
 
-                print_command_result("account create", &result, numbers_format, output_format)?;
+                print_command_result(
+                    "account create",
+                    &result,
+                    numbers_format,
+                    address_format,
+                    output_format,
+                )?;
                 print_block_explorer_link_if_allowed(
                     &result,
                     output_format,
@@ -414,7 +845,16 @@ async fn run_async_command(
                 )
                 .await;
 
-                print_command_result("account deploy", &result, numbers_format, output_format)?;
+                if let Ok(response) = &result {
+                    log_transaction(cli.memo.as_deref(), response.transaction_hash);
+                }
+                print_command_result(
+                    "account deploy",
+                    &result,
+                    numbers_format,
+                    address_format,
+                    output_format,
+                )?;
                 print_block_explorer_link_if_allowed(
                     &result,
                     output_format,
@@ -434,9 +874,16 @@ async fn run_async_command(
                     &config.accounts_file,
                     &network_name,
                     delete.yes,
+                    delete.force,
                 );
 
-                print_command_result("account delete", &result, numbers_format, output_format)?;
+                print_command_result(
+                    "account delete",
+                    &result,
+                    numbers_format,
+                    address_format,
+                    output_format,
+                )?;
                 Ok(())
             }
 
@@ -444,8 +891,127 @@ async fn run_async_command(
                 &config.accounts_file,
                 options.display_private_keys,
                 numbers_format,
+                address_format,
                 output_format,
             ),
+
+            account::Commands::Watch(watch) => {
+                let provider = watch.rpc.get_provider(&config).await?;
+
+                let account = get_account_from_config(&config, &provider).await?;
+
+                account::watch::watch(
+                    &watch,
+                    account.address(),
+                    &provider,
+                    numbers_format,
+                    address_format,
+                    output_format,
+                )
+                .await
+            }
+
+            account::Commands::Guardian(guardian) => match guardian.command {
+                account::guardian::Commands::Get(get) => {
+                    let provider = get.rpc.get_provider(&config).await?;
+
+                    account::ensure_argent_account(
+                        &config.account,
+                        &config.accounts_file,
+                        &provider,
+                        config.keystore.as_ref(),
+                        "Guardian",
+                    )
+                    .await?;
+                    let account = get_account_from_config(&config, &provider).await?;
+
+                    let result = account::guardian::get::get(&account)
+                        .await
+                        .map_err(handle_starknet_command_error);
+
+                    print_command_result(
+                        "account guardian get",
+                        &result,
+                        numbers_format,
+                        address_format,
+                        output_format,
+                    )?;
+                    Ok(())
+                }
+
+                account::guardian::Commands::Set(set) => {
+                    let provider = set.rpc.get_provider(&config).await?;
+
+                    set.validate()?;
+
+                    account::ensure_argent_account(
+                        &config.account,
+                        &config.accounts_file,
+                        &provider,
+                        config.keystore.as_ref(),
+                        "Guardian",
+                    )
+                    .await?;
+                    let account = get_account_from_config(&config, &provider).await?;
+
+                    let result = account::guardian::set::set(&set, &account, wait_config)
+                        .await
+                        .map_err(handle_starknet_command_error);
+
+                    print_command_result(
+                        "account guardian set",
+                        &result,
+                        numbers_format,
+                        address_format,
+                        output_format,
+                    )?;
+                    print_block_explorer_link_if_allowed(
+                        &result,
+                        output_format,
+                        provider.chain_id().await?,
+                        config.show_explorer_links,
+                        config.block_explorer,
+                    );
+                    Ok(())
+                }
+            },
+
+            account::Commands::RevokeSession(revoke_session) => {
+                let provider = revoke_session.rpc.get_provider(&config).await?;
+
+                revoke_session.validate()?;
+
+                account::ensure_argent_account(
+                    &config.account,
+                    &config.accounts_file,
+                    &provider,
+                    config.keystore.as_ref(),
+                    "Sessions",
+                )
+                .await?;
+                let account = get_account_from_config(&config, &provider).await?;
+
+                let result =
+                    account::revoke_session::revoke_session(&revoke_session, &account, wait_config)
+                        .await
+                        .map_err(handle_starknet_command_error);
+
+                print_command_result(
+                    "account revoke-session",
+                    &result,
+                    numbers_format,
+                    address_format,
+                    output_format,
+                )?;
+                print_block_explorer_link_if_allowed(
+                    &result,
+                    output_format,
+                    provider.chain_id().await?,
+                    config.show_explorer_links,
+                    config.block_explorer,
+                );
+                Ok(())
+            }
         },
 
         Commands::ShowConfig(show) => {
@@ -455,7 +1021,13 @@ async fn run_async_command(
                 starknet_commands::show_config::show_config(&show, &provider, config, cli.profile)
                     .await;
 
-            print_command_result("show-config", &result, numbers_format, output_format)?;
+            print_command_result(
+                "show-config",
+                &result,
+                numbers_format,
+                address_format,
+                output_format,
+            )?;
 
             Ok(())
         }
@@ -463,15 +1035,39 @@ async fn run_async_command(
         Commands::TxStatus(tx_status) => {
             let provider = tx_status.rpc.get_provider(&config).await?;
 
-            let result =
-                starknet_commands::tx_status::tx_status(&provider, tx_status.transaction_hash)
-                    .await
-                    .context("Failed to get transaction status");
+            let result = if tx_status.wait {
+                starknet_commands::tx_status::wait_for_tx_status(
+                    &provider,
+                    tx_status.transaction_hash,
+                    Duration::from_secs(tx_status.poll_interval),
+                    tx_status.timeout.map(Duration::from_secs),
+                )
+                .await
+            } else {
+                starknet_commands::tx_status::tx_status(&provider, tx_status.transaction_hash).await
+            }
+            .context("Failed to get transaction status");
 
-            print_command_result("tx-status", &result, numbers_format, output_format)?;
-            Ok(())
+            print_command_result(
+                "tx-status",
+                &result,
+                numbers_format,
+                address_format,
+                output_format,
+            )?;
+
+            if tx_status.wait
+                && !result
+                    .as_ref()
+                    .is_ok_and(TransactionStatusResponse::is_accepted)
+            {
+                process::exit(1);
+            }
+            Ok(())
         }
 
+        Commands::TxLog(_) => print_tx_log(numbers_format, output_format),
+
         Commands::Verify(verify) => {
             let manifest_path = assert_manifest_path_exists()?;
             let package_metadata = get_package_metadata(&manifest_path, &verify.package)?;
@@ -481,6 +1077,7 @@ async fn run_async_command(
                     scarb_toml_path: manifest_path.clone(),
                     json: cli.json,
                     profile: cli.profile.unwrap_or("release".to_string()),
+                    stdout_to_stderr: false,
                 },
                 false,
             )
@@ -496,11 +1093,371 @@ async fn run_async_command(
             )
             .await;
 
-            print_command_result("verify", &result, numbers_format, output_format)?;
+            print_command_result(
+                "verify",
+                &result,
+                numbers_format,
+                address_format,
+                output_format,
+            )?;
+            Ok(())
+        }
+
+        Commands::PredictFee(predict_fee) => {
+            let provider = predict_fee.rpc.get_provider(&config).await?;
+
+            let result = starknet_commands::predict_fee::predict_fee(&predict_fee, &provider).await;
+
+            print_command_result(
+                "predict-fee",
+                &result,
+                numbers_format,
+                address_format,
+                output_format,
+            )?;
+            Ok(())
+        }
+
+        Commands::EstimateFee(estimate_fee) => {
+            let provider = estimate_fee.rpc.get_provider(&config).await?;
+
+            let account = get_account_from_config(&config, &provider).await?;
+            let artifacts = match &estimate_fee.contract_name {
+                Some(_) => {
+                    let manifest_path = assert_manifest_path_exists()?;
+                    let package_metadata =
+                        get_package_metadata(&manifest_path, &estimate_fee.package)?;
+                    build_and_load_artifacts(
+                        &package_metadata,
+                        &BuildConfig {
+                            scarb_toml_path: manifest_path,
+                            json: cli.json,
+                            profile: cli.profile.unwrap_or("release".to_string()),
+                            stdout_to_stderr: false,
+                        },
+                        false,
+                    )
+                    .expect("Failed to build contract")
+                }
+                None => HashMap::new(),
+            };
+            let fee_token = estimate_fee
+                .fee_token
+                .clone()
+                .or_else(|| config.fee_token.clone())
+                .unwrap_or(FeeToken::Eth);
+
+            let result = starknet_commands::estimate_fee::estimate_fee(
+                &estimate_fee,
+                fee_token,
+                &account,
+                &artifacts,
+            )
+            .await
+            .map_err(handle_starknet_command_error);
+
+            print_command_result(
+                "estimate-fee",
+                &result,
+                numbers_format,
+                address_format,
+                output_format,
+            )?;
+            Ok(())
+        }
+
+        Commands::Class(class) => match class.command {
+            starknet_commands::class::Commands::Checksum(checksum) => {
+                let result = starknet_commands::class::checksum::checksum(&checksum);
+
+                print_command_result(
+                    "class checksum",
+                    &result,
+                    numbers_format,
+                    address_format,
+                    output_format,
+                )?;
+
+                if !result.as_ref().is_ok_and(|response| response.matches) {
+                    process::exit(1);
+                }
+                Ok(())
+            }
+            starknet_commands::class::Commands::Clone(mut clone) => {
+                let provider = clone.rpc.get_provider(&config).await?;
+                let source_provider = RpcArgs {
+                    url: Some(clone.from_url.clone()),
+                    ..clone.rpc.clone()
+                }
+                .get_provider(&config)
+                .await?;
+
+                clone.default_fee_token(config.fee_token.clone().unwrap_or(FeeToken::Eth));
+                clone.validate()?;
+
+                let account = get_account_from_config(&config, &provider).await?;
+                let result = starknet_commands::class::clone::clone_class(
+                    &clone,
+                    &source_provider,
+                    &account,
+                    wait_config,
+                )
+                .await
+                .map_err(handle_starknet_command_error);
+
+                if let Ok(response) = &result {
+                    log_transaction(cli.memo.as_deref(), response.transaction_hash);
+                }
+                print_command_result(
+                    "class clone",
+                    &result,
+                    numbers_format,
+                    address_format,
+                    output_format,
+                )?;
+                print_block_explorer_link_if_allowed(
+                    &result,
+                    output_format,
+                    provider.chain_id().await?,
+                    config.show_explorer_links,
+                    config.block_explorer,
+                );
+                Ok(())
+            }
+            starknet_commands::class::Commands::Dependencies(dependencies) => {
+                let provider = dependencies.rpc.get_provider(&config).await?;
+
+                let result =
+                    starknet_commands::class::dependencies::dependencies(&dependencies, &provider)
+                        .await;
+
+                print_command_result(
+                    "class dependencies",
+                    &result,
+                    numbers_format,
+                    address_format,
+                    output_format,
+                )?;
+                Ok(())
+            }
+            starknet_commands::class::Commands::History(history) => {
+                let provider = history.rpc.get_provider(&config).await?;
+
+                let result = starknet_commands::class::history::history(&history, &provider).await;
+
+                print_command_result(
+                    "class history",
+                    &result,
+                    numbers_format,
+                    address_format,
+                    output_format,
+                )?;
+                Ok(())
+            }
+            starknet_commands::class::Commands::Sierra(sierra) => {
+                let provider = sierra.rpc.get_provider(&config).await?;
+
+                let result = starknet_commands::class::sierra::sierra(&sierra, &provider).await;
+
+                match result {
+                    Ok(SierraOutput::Text(program)) => print!("{program}"),
+                    Ok(SierraOutput::File(response)) => print_command_result(
+                        "class sierra",
+                        &Ok(response),
+                        numbers_format,
+                        address_format,
+                        output_format,
+                    )?,
+                    Err(error) => print_command_result(
+                        "class sierra",
+                        &Err::<ClassSierraResponse, _>(error),
+                        numbers_format,
+                        address_format,
+                        output_format,
+                    )?,
+                }
+                Ok(())
+            }
+        },
+
+        Commands::ClassHash(class_hash) => {
+            let result = starknet_commands::class_hash::class_hash(&class_hash);
+
+            print_command_result(
+                "class-hash",
+                &result,
+                numbers_format,
+                address_format,
+                output_format,
+            )?;
             Ok(())
         }
 
-        Commands::Script(_) => unreachable!(),
+        Commands::StorageRead(storage_read) => {
+            let provider = storage_read.rpc.get_provider(&config).await?;
+
+            let result =
+                starknet_commands::storage_read::storage_read(&storage_read, &provider).await;
+
+            print_command_result(
+                "storage-read",
+                &result,
+                numbers_format,
+                address_format,
+                output_format,
+            )?;
+            Ok(())
+        }
+
+        Commands::GetNonce(get_nonce) => {
+            let provider = get_nonce.rpc.get_provider(&config).await?;
+
+            let result =
+                starknet_commands::get_nonce::get_nonce(&get_nonce, &config, &provider).await;
+
+            print_command_result(
+                "get-nonce",
+                &result,
+                numbers_format,
+                address_format,
+                output_format,
+            )?;
+            Ok(())
+        }
+
+        Commands::GetTransaction(get_transaction) => {
+            let provider = get_transaction.rpc.get_provider(&config).await?;
+
+            let result =
+                starknet_commands::get_transaction::get_transaction(&get_transaction, &provider)
+                    .await;
+
+            print_command_result(
+                "get-transaction",
+                &result,
+                numbers_format,
+                address_format,
+                output_format,
+            )?;
+            Ok(())
+        }
+
+        Commands::GetBlock(get_block) => {
+            let provider = get_block.rpc.get_provider(&config).await?;
+
+            let result = starknet_commands::get_block::get_block(&get_block, &provider).await;
+
+            print_command_result(
+                "get-block",
+                &result,
+                numbers_format,
+                address_format,
+                output_format,
+            )?;
+            Ok(())
+        }
+
+        Commands::GetAbi(get_abi) => {
+            let provider = get_abi.rpc.get_provider(&config).await?;
+
+            let result = starknet_commands::get_abi::get_abi(&get_abi, &provider).await;
+
+            print_command_result(
+                "get-abi",
+                &result,
+                numbers_format,
+                address_format,
+                output_format,
+            )?;
+            Ok(())
+        }
+
+        Commands::Block(block) => match &block.command {
+            starknet_commands::block::Commands::Time(time) => {
+                let provider = time.rpc.get_provider(&config).await?;
+
+                let result = starknet_commands::block::time::time(time, &provider).await;
+
+                print_command_result(
+                    "block time",
+                    &result,
+                    numbers_format,
+                    address_format,
+                    output_format,
+                )?;
+                Ok(())
+            }
+        },
+
+        Commands::Contract(contract) => match &contract.command {
+            starknet_commands::contract::Commands::CallLog(call_log) => {
+                let provider = call_log.rpc.get_provider(&config).await?;
+
+                starknet_commands::contract::call_log::call_log(call_log, &provider, output_format)
+                    .await
+            }
+            starknet_commands::contract::Commands::GasEstimateHistory(history) => {
+                let provider = history.rpc.get_provider(&config).await?;
+
+                starknet_commands::contract::gas_estimate_history::gas_estimate_history(
+                    history,
+                    &provider,
+                    numbers_format,
+                    output_format,
+                )
+                .await
+            }
+            starknet_commands::contract::Commands::StorageDiff(storage_diff) => {
+                let provider = storage_diff.rpc.get_provider(&config).await?;
+
+                starknet_commands::contract::storage_diff::storage_diff(
+                    storage_diff,
+                    &provider,
+                    output_format,
+                )
+                .await
+            }
+        },
+
+        Commands::Tx(tx) => match &tx.command {
+            starknet_commands::tx::Commands::Cancel(cancel) => {
+                let provider = cancel.rpc.get_provider(&config).await?;
+                let account = get_account_from_config(&config, &provider).await?;
+
+                let result =
+                    starknet_commands::tx::cancel::cancel(cancel, &account, wait_config).await;
+
+                if let Ok(response) = &result {
+                    log_transaction(cli.memo.as_deref(), response.transaction_hash);
+                }
+                print_command_result(
+                    "tx cancel",
+                    &result,
+                    numbers_format,
+                    address_format,
+                    output_format,
+                )?;
+                Ok(())
+            }
+            starknet_commands::tx::Commands::DecodeTrace(decode_trace) => {
+                let provider = decode_trace.rpc.get_provider(&config).await?;
+
+                let result =
+                    starknet_commands::tx::decode_trace::decode_trace(decode_trace, &provider)
+                        .await;
+
+                print_command_result(
+                    "tx decode-trace",
+                    &result,
+                    numbers_format,
+                    address_format,
+                    output_format,
+                )?;
+                Ok(())
+            }
+        },
+
+        Commands::Script(_) | Commands::Console(_) => unreachable!(),
     }
 }
 
@@ -509,12 +1466,19 @@ fn run_script_command(
     runtime: Runtime,
     script: &Script,
     numbers_format: NumbersFormat,
+    address_format: AddressFormat,
     output_format: OutputFormat,
 ) -> Result<()> {
     match &script.command {
         starknet_commands::script::Commands::Init(init) => {
             let result = starknet_commands::script::init::init(init);
-            print_command_result("script init", &result, numbers_format, output_format)?;
+            print_command_result(
+                "script init",
+                &result,
+                numbers_format,
+                address_format,
+                output_format,
+            )?;
         }
         starknet_commands::script::Commands::Run(run) => {
             let manifest_path = assert_manifest_path_exists()?;
@@ -533,6 +1497,7 @@ fn run_script_command(
                     scarb_toml_path: manifest_path.clone(),
                     json: cli.json,
                     profile: cli.profile.clone().unwrap_or("dev".to_string()),
+                    stdout_to_stderr: false,
                 },
                 true,
             )
@@ -544,6 +1509,7 @@ fn run_script_command(
                     scarb_toml_path: manifest_path.clone(),
                     json: cli.json,
                     profile: "dev".to_string(),
+                    stdout_to_stderr: false,
                 },
                 "dev",
             )
@@ -560,6 +1526,25 @@ fn run_script_command(
                 )))
             };
 
+            let completed_transactions = CompletedTransactions::default();
+            if let Some(script_timeout) = run.script_timeout {
+                starknet_commands::script::run::exit_on_script_timeout(
+                    &runtime,
+                    script_timeout,
+                    completed_transactions.clone(),
+                    numbers_format,
+                    address_format,
+                    output_format,
+                );
+            }
+
+            let (progress, progress_printer) = if run.quiet {
+                (ProgressReporter::disabled(), None)
+            } else {
+                let (progress, progress_printer) = spawn_progress_printer();
+                (progress, Some(progress_printer))
+            };
+
             let result = starknet_commands::script::run::run(
                 &run.script_name,
                 &metadata_with_deps,
@@ -569,15 +1554,206 @@ fn run_script_command(
                 runtime,
                 &config,
                 state_file_path,
+                completed_transactions,
+                &run.args,
+                progress,
             );
 
-            print_command_result("script run", &result, numbers_format, output_format)?;
+            // all progress is printed before the result, as the reporter is dropped once the script finishes
+            if let Some(progress_printer) = progress_printer {
+                progress_printer
+                    .join()
+                    .expect("Failed to print the progress of the script");
+            }
+
+            print_command_result(
+                "script run",
+                &result,
+                numbers_format,
+                address_format,
+                output_format,
+            )?;
         }
     }
 
     Ok(())
 }
 
+fn validate_config(cli: &Cli, output_format: OutputFormat) -> Result<()> {
+    let report = config_validation::validate_config(|config| update_cast_config(config, cli))?;
+
+    match output_format {
+        OutputFormat::Json => println!("{}", serde_json::to_string(&report)?),
+        OutputFormat::Human => {
+            match &report.config_file {
+                Some(config_file) => println!("Validated {config_file}"),
+                None => println!("No snfoundry.toml found, validated the default configuration"),
+            }
+            println!("Profiles: {}", report.profiles.join(", "));
+            for error in &report.errors {
+                println!("error: {error}");
+            }
+            if report.is_valid() {
+                println!("Configuration is valid");
+            } else {
+                println!("Found {} error(s)", report.errors.len());
+            }
+        }
+    }
+
+    if !report.is_valid() {
+        process::exit(1);
+    }
+    Ok(())
+}
+
+/// Makes `--dry-run` simulate the transaction of the command instead of sending it
+fn apply_dry_run(command: &mut Commands) -> Result<()> {
+    match command {
+        Commands::Declare(declare) => {
+            ensure!(
+                !declare.class_hash_only,
+                "--dry-run cannot be used with --class-hash-only"
+            );
+            declare.simulate_only = true;
+        }
+        Commands::Deploy(deploy) => {
+            ensure!(
+                !deploy.address_only,
+                "--dry-run cannot be used with --address-only"
+            );
+            deploy.simulate_only = true;
+        }
+        Commands::Invoke(invoke) => {
+            ensure!(
+                invoke.batch_file.is_none(),
+                "--dry-run cannot be used with --batch-file"
+            );
+            invoke.simulate_only = true;
+        }
+        // applied to every command run in the console
+        Commands::Console(_) => {}
+        _ => bail!("--dry-run can only be used with declare, deploy and invoke commands"),
+    }
+    Ok(())
+}
+
+/// Fails for commands which wait for their transactions to be accepted regardless of `--wait`
+fn check_no_wait(command: &Commands) -> Result<()> {
+    match command {
+        Commands::Invoke(invoke) => {
+            ensure!(
+                invoke.retry_on_revert.is_none(),
+                "--no-wait cannot be used with --retry-on-revert, the transaction has to be accepted to know whether it reverted"
+            );
+            ensure!(
+                invoke.batch_file.is_none() || invoke.parallel,
+                "--no-wait can be used with --batch-file only together with --parallel, otherwise every transaction is sent after the previous one is accepted"
+            );
+        }
+        Commands::Tx(tx) => {
+            if let starknet_commands::tx::Commands::Cancel(_) = tx.command {
+                bail!("--no-wait cannot be used with tx cancel, the replacement transaction has to be accepted to know whether the cancellation succeeded");
+            }
+        }
+        Commands::Script(script) => {
+            if let starknet_commands::script::Commands::Run(_) = script.command {
+                bail!("--no-wait cannot be used with script run, every transaction of the script has to be accepted before the next one is sent");
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Falls back to the profile from `SNCAST_PROFILE` environment variable if `--profile` is not passed
+fn resolve_profile(cli: &mut Cli) {
+    if cli.profile.is_none() {
+        cli.profile = env::var(SNCAST_PROFILE_ENV_VAR)
+            .ok()
+            .filter(|profile| !profile.is_empty());
+    }
+}
+
+/// Runs every line typed in the console as `sncast` with the global flags passed before `console`
+fn run_console_command(runtime: &Runtime) -> Result<()> {
+    let args: Vec<OsString> = std::env::args_os().collect();
+    let global_args_end = args
+        .iter()
+        .position(|arg| arg == "console")
+        .unwrap_or(args.len());
+    let global_args = &args[..global_args_end];
+
+    run_console(|words, session| {
+        let parsed = Cli::try_parse_from(
+            global_args
+                .iter()
+                .cloned()
+                .chain(words.into_iter().map(OsString::from)),
+        );
+        let mut cli = match parsed {
+            Ok(cli) => cli,
+            Err(error) => {
+                error.print()?;
+                return Ok(());
+            }
+        };
+        resolve_profile(&mut cli);
+        let Some(mut command) = cli.command.take() else {
+            bail!("No command passed");
+        };
+        if cli.dry_run {
+            apply_dry_run(&mut command)?;
+        }
+        if cli.no_wait {
+            check_no_wait(&command)?;
+        }
+
+        let used_contract = match &command {
+            Commands::Call(call) => Some((call.contract_address, call.rpc.clone())),
+            Commands::Invoke(invoke) => invoke
+                .contract_address
+                .map(|contract_address| (contract_address, invoke.rpc.clone())),
+            Commands::Deploy(_) | Commands::Declare(_) => None,
+            _ => bail!(
+                "Only {} commands can be run in the console",
+                CONSOLE_COMMANDS.join(", ")
+            ),
+        };
+
+        let numbers_format = NumbersFormat::from_flags(cli.hex_format, cli.int_format);
+        let address_format = cli.format_addresses;
+        let output_format = OutputFormat::from_flag(cli.json);
+        let mut config = load_global_config::<CastConfig>(&None, &cli.profile)?;
+        update_cast_config(&mut config, &cli);
+
+        runtime.block_on(run_async_command(
+            cli,
+            command,
+            config.clone(),
+            numbers_format,
+            address_format,
+            output_format,
+        ))?;
+
+        if let Some((contract_address, rpc)) = used_contract {
+            session.add_address(contract_address);
+            if !session.has_abi(contract_address) {
+                let functions = runtime.block_on(async {
+                    let provider = rpc.get_provider(&config).await?;
+                    fetch_function_names(&provider, contract_address).await
+                });
+                // Failing to fetch the ABI only disables completion of function names
+                if let Ok(functions) = functions {
+                    session.cache_abi(contract_address, functions);
+                }
+            }
+        }
+
+        Ok(())
+    })
+}
+
 fn update_cast_config(config: &mut CastConfig, cli: &Cli) {
     macro_rules! clone_or_else {
         ($field:expr, $config_field:expr) => {
@@ -587,6 +1763,13 @@ fn update_cast_config(config: &mut CastConfig, cli: &Cli) {
 
     config.account = clone_or_else!(cli.account, config.account);
     config.keystore = cli.keystore.clone().or(config.keystore.clone());
+    config.account_json.clone_from(&cli.account_json);
+
+    if env::var_os(SNCAST_PROFILE_MATRIX_RUN_ENV_VAR).is_some() {
+        if let Ok(url) = env::var(SNCAST_PROFILE_MATRIX_URL_ENV_VAR) {
+            config.url = url;
+        }
+    }
 
     if config.accounts_file == Utf8PathBuf::default() {
         config.accounts_file = Utf8PathBuf::from(DEFAULT_ACCOUNTS_FILE);
@@ -603,3 +1786,13 @@ fn update_cast_config(config: &mut CastConfig, cli: &Cli) {
         clone_or_else!(cli.wait_timeout, config.wait_params.get_timeout()),
     );
 }
+
+fn log_transaction(memo: Option<&str>, transaction_hash: Felt) {
+    if let Some(memo) = memo {
+        if let Err(error) = append_to_tx_log(&default_tx_log_path(), transaction_hash, memo) {
+            print_as_warning(&anyhow!(
+                "Failed to save the transaction in the transaction log: {error:#}"
+            ));
+        }
+    }
+}
@@ -1,12 +1,15 @@
 use super::structs::CommandResponse;
-use crate::NumbersFormat;
+use crate::{AddressFormat, NumbersFormat};
 use anyhow::Result;
 use itertools::Itertools;
 use serde::{Serialize, Serializer};
 use serde_json::Value;
 use starknet::core::types::Felt;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::{collections::HashMap, fmt::Display, str::FromStr};
 
+static COMMAND_FAILED: AtomicBool = AtomicBool::new(false);
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum OutputFormat {
     Json,
@@ -104,6 +107,24 @@ impl Format for OutputValue {
     }
 }
 
+impl OutputValue {
+    fn format_address_with(self, addresses: AddressFormat) -> Self {
+        match self {
+            OutputValue::String(input) => match Felt::from_hex(&input) {
+                Ok(address) if input.starts_with("0x") => {
+                    OutputValue::String(addresses.format(address))
+                }
+                _ => OutputValue::String(input),
+            },
+            OutputValue::Array(arr) => OutputValue::Array(
+                arr.into_iter()
+                    .map(|item| item.format_address_with(addresses))
+                    .collect(),
+            ),
+        }
+    }
+}
+
 /// Constrained subset of `serde::json`. No nested maps allowed.
 #[derive(Debug, PartialEq, Eq, Serialize)]
 struct OutputData(Vec<(String, OutputValue)>);
@@ -119,6 +140,24 @@ impl Format for OutputData {
     }
 }
 
+impl OutputData {
+    /// Applies `addresses` format to hex values of fields holding addresses
+    fn format_addresses_with(self, addresses: AddressFormat) -> Self {
+        Self(
+            self.0
+                .into_iter()
+                .map(|(k, v)| {
+                    if k.contains("address") {
+                        (k, v.format_address_with(addresses))
+                    } else {
+                        (k, v)
+                    }
+                })
+                .collect(),
+        )
+    }
+}
+
 impl<T: CommandResponse> From<&Result<T, anyhow::Error>> for OutputData {
     fn from(value: &Result<T>) -> Self {
         match value {
@@ -164,7 +203,13 @@ impl OutputData {
         let fields = self
             .0
             .iter()
-            .map(|(key, val)| format!("{key}: {val}"))
+            .map(|(key, val)| match val {
+                // keeps lines of multi-line values, e.g. tables, aligned
+                OutputValue::String(text) if text.contains('\n') && key != "error" => {
+                    format!("{key}:\n{text}")
+                }
+                _ => format!("{key}: {val}"),
+            })
             .join("\n");
 
         format!("command: {command}\n{fields}")
@@ -182,25 +227,36 @@ pub fn print_command_result<T: CommandResponse>(
     command: &str,
     result: &Result<T>,
     numbers_format: NumbersFormat,
+    address_format: AddressFormat,
     output_format: OutputFormat,
 ) -> Result<()> {
     let output: OutputData = result.into();
     let repr = output
         .format_with(numbers_format)
+        .format_addresses_with(address_format)
         .to_string_pretty(command, output_format)?;
 
     match result {
         Ok(_) => println!("{repr}"),
-        Err(_) => eprintln!("{repr}"),
+        Err(_) => {
+            COMMAND_FAILED.store(true, Ordering::Relaxed);
+            eprintln!("{repr}");
+        }
     }
 
     Ok(())
 }
 
+/// Whether an error of the command was printed with `print_command_result`
+#[must_use]
+pub fn command_failed() -> bool {
+    COMMAND_FAILED.load(Ordering::Relaxed)
+}
+
 #[cfg(test)]
 mod tests {
     use super::{OutputData, OutputValue};
-    use crate::{response::print::Format, NumbersFormat};
+    use crate::{response::print::Format, AddressFormat, NumbersFormat};
     use serde_json::{Map, Value};
 
     #[test]
@@ -272,4 +328,52 @@ mod tests {
 
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn test_multi_line_values_to_lines() {
+        let output_data = OutputData(vec![
+            (
+                String::from("abi"),
+                OutputValue::String(String::from("KIND  NAME\nview  get")),
+            ),
+            (
+                String::from("error"),
+                OutputValue::String(String::from("first\nsecond")),
+            ),
+        ]);
+
+        assert_eq!(
+            output_data.to_lines("get-abi"),
+            "command: get-abi\nabi:\nKIND  NAME\nview  get\nerror: first\nsecond"
+        );
+    }
+
+    #[test]
+    fn test_format_addresses_only_in_address_fields() {
+        let value = "0x49d36570d4e46f48e99674bd3fcc84644ddd6b96f7c741b1562b82f9e004dc7";
+        let output_data = OutputData(vec![
+            (
+                String::from("contract_address"),
+                OutputValue::String(String::from(value)),
+            ),
+            (
+                String::from("transaction_hash"),
+                OutputValue::String(String::from(value)),
+            ),
+        ]);
+
+        let actual = output_data.format_addresses_with(AddressFormat::Upper);
+
+        let expected = OutputData(vec![
+            (
+                String::from("contract_address"),
+                OutputValue::String(value.to_uppercase().replacen("0X", "0x", 1)),
+            ),
+            (
+                String::from("transaction_hash"),
+                OutputValue::String(String::from(value)),
+            ),
+        ]);
+        assert_eq!(actual, expected);
+    }
 }
@@ -32,6 +32,20 @@ pub struct CallResponse {
 }
 impl CommandResponse for CallResponse {}
 
+#[derive(Serialize)]
+pub struct DecodedCallResponse {
+    pub response: Vec<Felt>,
+    pub decoded_response: String,
+}
+impl CommandResponse for DecodedCallResponse {}
+
+#[derive(Serialize)]
+pub struct CallTraceResponse {
+    pub response: Vec<Felt>,
+    pub call_trace: String,
+}
+impl CommandResponse for CallTraceResponse {}
+
 #[derive(Serialize, Deserialize, CairoSerialize, Clone, Debug, PartialEq)]
 pub struct InvokeResponse {
     pub transaction_hash: Felt,
@@ -84,6 +98,12 @@ pub struct MulticallNewResponse {
 }
 impl CommandResponse for MulticallNewResponse {}
 
+#[derive(Serialize)]
+pub struct MulticallDryRunResponse {
+    pub calldata: Vec<Felt>,
+}
+impl CommandResponse for MulticallDryRunResponse {}
+
 #[derive(Serialize)]
 pub struct ShowConfigResponse {
     pub profile: Option<String>,
@@ -105,6 +125,13 @@ pub struct ScriptRunResponse {
 
 impl CommandResponse for ScriptRunResponse {}
 
+#[derive(Serialize)]
+pub struct ScriptTimeoutResponse {
+    pub completed_transactions: Vec<Felt>,
+}
+
+impl CommandResponse for ScriptTimeoutResponse {}
+
 #[derive(Serialize)]
 pub struct ScriptInitResponse {
     pub message: String,
@@ -134,6 +161,16 @@ pub struct TransactionStatusResponse {
 
 impl CommandResponse for TransactionStatusResponse {}
 
+impl TransactionStatusResponse {
+    #[must_use]
+    pub fn is_accepted(&self) -> bool {
+        matches!(
+            self.finality_status,
+            FinalityStatus::AcceptedOnL2 | FinalityStatus::AcceptedOnL1
+        ) && matches!(self.execution_status, Some(ExecutionStatus::Succeeded))
+    }
+}
+
 #[derive(Serialize)]
 pub struct VerifyResponse {
     pub message: String,
@@ -141,6 +178,207 @@ pub struct VerifyResponse {
 
 impl CommandResponse for VerifyResponse {}
 
+#[derive(Serialize)]
+pub struct PredictFeeResponse {
+    #[serde(serialize_with = "crate::response::structs::serialize_as_decimal")]
+    pub max_fee: Felt,
+    pub samples: Decimal,
+    pub blocks_scanned: Decimal,
+}
+
+impl CommandResponse for PredictFeeResponse {}
+
+#[derive(Serialize)]
+pub struct EstimateFeeResponse {
+    #[serde(serialize_with = "crate::response::structs::serialize_as_decimal")]
+    pub overall_fee: Felt,
+    pub overall_fee_in_token: String,
+    pub fee_unit: String,
+    #[serde(serialize_with = "crate::response::structs::serialize_as_decimal")]
+    pub l1_gas_consumed: Felt,
+    #[serde(serialize_with = "crate::response::structs::serialize_as_decimal")]
+    pub l1_gas_price: Felt,
+    #[serde(serialize_with = "crate::response::structs::serialize_as_decimal")]
+    pub l1_data_gas_consumed: Felt,
+    #[serde(serialize_with = "crate::response::structs::serialize_as_decimal")]
+    pub l1_data_gas_price: Felt,
+    #[serde(serialize_with = "crate::response::structs::serialize_as_decimal")]
+    pub block_l1_gas_price: Felt,
+    #[serde(serialize_with = "crate::response::structs::serialize_as_decimal")]
+    pub block_l1_data_gas_price: Felt,
+}
+
+impl CommandResponse for EstimateFeeResponse {}
+
+#[derive(Serialize)]
+pub struct BlockTimeResponse {
+    pub average_block_time: String,
+    pub samples: Decimal,
+}
+
+impl CommandResponse for BlockTimeResponse {}
+
+#[derive(Serialize)]
+pub struct GuardianResponse {
+    pub guardian: Felt,
+}
+
+impl CommandResponse for GuardianResponse {}
+
+#[derive(Serialize)]
+pub struct ClassDependenciesResponse {
+    pub class_hash: Felt,
+    pub dependencies: Vec<Felt>,
+    pub transitive_dependencies: Option<Vec<Felt>>,
+}
+
+impl CommandResponse for ClassDependenciesResponse {}
+
+#[derive(Serialize)]
+pub struct AccountWatchResponse {
+    pub block_number: Decimal,
+    pub transaction_hash: Felt,
+    pub transaction_type: String,
+    pub sender_address: Option<Felt>,
+    pub calls: Vec<String>,
+}
+
+impl CommandResponse for AccountWatchResponse {}
+
+#[derive(Serialize)]
+pub struct StorageReadResponse {
+    pub key: Felt,
+    pub value: Felt,
+}
+
+impl CommandResponse for StorageReadResponse {}
+
+#[derive(Serialize)]
+pub struct GetNonceResponse {
+    pub address: Felt,
+    #[serde(serialize_with = "crate::response::structs::serialize_as_decimal")]
+    pub nonce: Felt,
+    pub nonce_hex: String,
+}
+
+impl CommandResponse for GetNonceResponse {}
+
+#[derive(Serialize)]
+pub struct GetAbiResponse {
+    pub abi: String,
+}
+
+impl CommandResponse for GetAbiResponse {}
+
+#[derive(Serialize)]
+pub struct TxCancelResponse {
+    pub transaction_hash: Felt,
+    pub cancelled_transaction_hash: Felt,
+    #[serde(serialize_with = "crate::response::structs::serialize_as_decimal")]
+    pub nonce: Felt,
+}
+
+impl CommandResponse for TxCancelResponse {}
+
+#[derive(Serialize)]
+pub struct TxDecodeTraceResponse {
+    pub trace: String,
+}
+
+impl CommandResponse for TxDecodeTraceResponse {}
+
+#[derive(Serialize, Default)]
+pub struct GetTransactionResponse {
+    pub transaction_hash: Felt,
+    pub transaction_type: String,
+    pub version: String,
+    pub sender_address: Option<Felt>,
+    pub contract_address: Option<Felt>,
+    pub class_hash: Option<Felt>,
+    pub nonce: Option<Felt>,
+    pub calls: Option<Vec<String>>,
+    pub constructor_calldata: Option<Vec<Felt>>,
+    pub max_fee: Option<String>,
+    pub resource_bounds: Option<Vec<String>>,
+    pub tip: Option<String>,
+    pub signature: Vec<Felt>,
+    pub actual_fee: Option<String>,
+    pub execution_status: Option<ExecutionStatus>,
+    pub revert_reason: Option<String>,
+    pub events: Option<Vec<String>>,
+}
+
+impl CommandResponse for GetTransactionResponse {}
+
+#[derive(Serialize)]
+pub struct GetBlockResponse {
+    pub block_number: Option<Decimal>,
+    pub block_hash: Option<Felt>,
+    pub timestamp: Decimal,
+    pub sequencer_address: Felt,
+    pub transaction_count: Decimal,
+    pub state_root: Option<Felt>,
+    pub parent_hash: Felt,
+    pub transactions: Option<Vec<Felt>>,
+}
+
+impl CommandResponse for GetBlockResponse {}
+
+#[derive(Serialize)]
+pub struct ClassSierraResponse {
+    pub output_path: Utf8PathBuf,
+}
+
+impl CommandResponse for ClassSierraResponse {}
+
+#[derive(Serialize)]
+pub struct ClassHashResponse {
+    pub class_hash: Felt,
+    pub compiled_class_hash: Option<Felt>,
+}
+
+impl CommandResponse for ClassHashResponse {}
+
+#[derive(Serialize)]
+pub struct ClassChecksumResponse {
+    pub class_hash: Felt,
+    pub expected_class_hash: Felt,
+    #[serde(skip)]
+    pub matches: bool,
+    pub message: String,
+}
+
+impl CommandResponse for ClassChecksumResponse {}
+
+#[derive(Serialize)]
+pub struct ClassHistoryResponse {
+    pub contract_address: Felt,
+    pub class_changes: Vec<String>,
+}
+
+impl CommandResponse for ClassHistoryResponse {}
+
+#[derive(Serialize)]
+pub struct SimulateResponse {
+    #[serde(serialize_with = "crate::response::structs::serialize_as_decimal")]
+    pub overall_fee: Felt,
+    #[serde(serialize_with = "crate::response::structs::serialize_as_decimal")]
+    pub gas_consumed: Felt,
+    #[serde(serialize_with = "crate::response::structs::serialize_as_decimal")]
+    pub gas_price: Felt,
+    pub fee_unit: String,
+    pub execution_status: ExecutionStatus,
+    pub revert_reason: Option<String>,
+    pub call_stack_depth: Decimal,
+    pub events: Vec<String>,
+    pub storage_diffs: Vec<String>,
+    pub deployed_contracts: Vec<String>,
+    pub declared_classes: Vec<Felt>,
+    pub transaction_trace: String,
+}
+
+impl CommandResponse for SimulateResponse {}
+
 impl OutputLink for InvokeResponse {
     const TITLE: &'static str = "invocation";
 
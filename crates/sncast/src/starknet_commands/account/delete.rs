@@ -1,5 +1,5 @@
 use anyhow::{anyhow, bail, Context, Result};
-use camino::Utf8PathBuf;
+use camino::{Utf8Path, Utf8PathBuf};
 use clap::{ArgGroup, Args};
 use promptly::prompt;
 use serde_json::Map;
@@ -7,6 +7,8 @@ use sncast::helpers::configuration::CastConfig;
 use sncast::helpers::rpc::RpcArgs;
 use sncast::response::structs::AccountDeleteResponse;
 use sncast::{chain_id_to_network_name, get_chain_id};
+use std::io::Write;
+use tempfile::NamedTempFile;
 
 #[derive(Args, Debug)]
 #[command(about = "Delete account information from the accounts file")]
@@ -27,6 +29,10 @@ pub struct Delete {
     #[clap(long, default_value = "false")]
     pub yes: bool,
 
+    /// Delete the account even if it is marked as deployed
+    #[clap(long)]
+    pub force: bool,
+
     #[clap(flatten)]
     pub rpc: Option<RpcArgs>,
 }
@@ -37,6 +43,7 @@ pub fn delete(
     path: &Utf8PathBuf,
     network_name: &str,
     yes: bool,
+    force: bool,
 ) -> Result<AccountDeleteResponse> {
     let contents = std::fs::read_to_string(path.clone()).context("Failed to read accounts file")?;
     let items: serde_json::Value = serde_json::from_str(&contents)
@@ -48,6 +55,9 @@ pub fn delete(
     if items[&network_name][&name].is_null() {
         bail!("Account with name {name} does not exist")
     }
+    if items[&network_name][&name]["deployed"] == true && !force {
+        bail!("Account {name} is marked as deployed, pass --force to delete it anyway");
+    }
 
     let mut items: Map<String, serde_json::Value> = serde_json::from_str(&contents)
         .unwrap_or_else(|_| panic!("Failed to read file at path = {path}"));
@@ -72,12 +82,29 @@ pub fn delete(
 
     // now remove the child from there
     nested.remove(name);
+    if nested.is_empty() {
+        items.remove(network_name);
+    }
 
-    std::fs::write(path.clone(), serde_json::to_string_pretty(&items).unwrap())?;
+    write_atomically(path, &serde_json::to_string_pretty(&items).unwrap())?;
     let result = "Account successfully removed".to_string();
     Ok(AccountDeleteResponse { result })
 }
 
+/// Writes to a temporary file first and then renames it, so the accounts file is never left partially written
+fn write_atomically(path: &Utf8PathBuf, contents: &str) -> Result<()> {
+    let dir = match path.parent() {
+        Some(dir) if !dir.as_str().is_empty() => dir,
+        _ => Utf8Path::new("."),
+    };
+    let mut file = NamedTempFile::new_in(dir).context("Failed to create a temporary file")?;
+    file.write_all(contents.as_bytes())
+        .context("Failed to write to a temporary file")?;
+    file.persist(path)
+        .with_context(|| format!("Failed to write accounts file at {path}"))?;
+    Ok(())
+}
+
 pub(crate) async fn get_network_name(delete: &Delete, config: &CastConfig) -> Result<String> {
     match (&delete.rpc, &delete.network) {
         (Some(rpc), None) => {
@@ -5,7 +5,9 @@ use serde_json::Map;
 use sncast::helpers::braavos::BraavosAccountFactory;
 use sncast::helpers::constants::{BRAAVOS_BASE_ACCOUNT_CLASS_HASH, KEYSTORE_PASSWORD_ENV_VAR};
 use sncast::helpers::error::token_not_supported_for_deployment;
-use sncast::helpers::fee::{FeeArgs, FeeSettings, FeeToken, PayableTransaction};
+use sncast::helpers::fee::{
+    max_fee_from_gas_price, FeeArgs, FeeSettings, FeeToken, PayableTransaction,
+};
 use sncast::helpers::rpc::RpcArgs;
 use sncast::response::structs::InvokeResponse;
 use sncast::{
@@ -18,7 +20,9 @@ use starknet::accounts::{
 };
 use starknet::accounts::{AccountFactoryError, ArgentAccountFactory};
 use starknet::core::types::BlockTag::Pending;
-use starknet::core::types::{BlockId, Felt, StarknetError::ClassHashNotFound};
+use starknet::core::types::{
+    BlockId, FeeEstimate, Felt, PriceUnit, StarknetError::ClassHashNotFound,
+};
 use starknet::core::utils::get_contract_address;
 use starknet::providers::jsonrpc::HttpTransport;
 use starknet::providers::ProviderError::StarknetError;
@@ -263,23 +267,40 @@ where
         .try_into_fee_settings(account_factory.provider(), account_factory.block_id())
         .await?;
     let result = match fee_settings {
-        FeeSettings::Eth { max_fee } => {
+        FeeSettings::Eth { max_fee, gas_price } => {
             let deployment = account_factory.deploy_v1(salt);
-            let deployment = apply_optional(deployment, max_fee, AccountDeploymentV1::max_fee);
-            deployment.send().await
+            match deployment.estimate_fee().await {
+                Ok(fee_estimate) => {
+                    print_estimated_fee(&fee_estimate);
+                    let max_fee = match gas_price {
+                        Some(gas_price) => Some(max_fee_from_gas_price(&fee_estimate, gas_price)),
+                        None => max_fee,
+                    };
+                    apply_optional(deployment, max_fee, AccountDeploymentV1::max_fee)
+                        .send()
+                        .await
+                }
+                Err(error) => Err(error),
+            }
         }
         FeeSettings::Strk {
             max_gas,
             max_gas_unit_price,
         } => {
             let deployment = account_factory.deploy_v3(salt);
-            let deployment = apply_optional(deployment, max_gas, AccountDeploymentV3::gas);
-            let deployment = apply_optional(
-                deployment,
-                max_gas_unit_price,
-                AccountDeploymentV3::gas_price,
-            );
-            deployment.send().await
+            match deployment.estimate_fee().await {
+                Ok(fee_estimate) => {
+                    print_estimated_fee(&fee_estimate);
+                    let deployment = apply_optional(deployment, max_gas, AccountDeploymentV3::gas);
+                    let deployment = apply_optional(
+                        deployment,
+                        max_gas_unit_price,
+                        AccountDeploymentV3::gas_price,
+                    );
+                    deployment.send().await
+                }
+                Err(error) => Err(error),
+            }
         }
     };
 
@@ -312,6 +333,15 @@ where
     }
 }
 
+/// Printed to stderr, so the output of the command itself is not affected
+fn print_estimated_fee(fee_estimate: &FeeEstimate) {
+    let unit = match fee_estimate.unit {
+        PriceUnit::Wei => "WEI",
+        PriceUnit::Fri => "FRI",
+    };
+    eprintln!("Estimated fee: {} {unit}", fee_estimate.overall_fee);
+}
+
 fn update_account_in_accounts_file(
     accounts_file: Utf8PathBuf,
     account_name: &str,
@@ -0,0 +1,39 @@
+use anyhow::Result;
+use clap::Args;
+use sncast::helpers::rpc::RpcArgs;
+use sncast::response::errors::StarknetCommandError;
+use sncast::response::structs::GuardianResponse;
+use starknet::accounts::{Account, ConnectedAccount, SingleOwnerAccount};
+use starknet::core::types::{BlockId, BlockTag, FunctionCall};
+use starknet::core::utils::get_selector_from_name;
+use starknet::providers::jsonrpc::HttpTransport;
+use starknet::providers::{JsonRpcClient, Provider};
+use starknet::signers::LocalWallet;
+
+#[derive(Args, Debug)]
+#[command(about = "Get guardian of an Argent account")]
+pub struct Get {
+    #[clap(flatten)]
+    pub rpc: RpcArgs,
+}
+
+pub async fn get(
+    account: &SingleOwnerAccount<&JsonRpcClient<HttpTransport>, LocalWallet>,
+) -> Result<GuardianResponse, StarknetCommandError> {
+    let function_call = FunctionCall {
+        contract_address: account.address(),
+        entry_point_selector: get_selector_from_name("get_guardian")
+            .expect("Failed to convert entry point selector"),
+        calldata: vec![],
+    };
+
+    let response = account
+        .provider()
+        .call(function_call, BlockId::Tag(BlockTag::Pending))
+        .await
+        .map_err(|error| StarknetCommandError::ProviderError(error.into()))?;
+
+    Ok(GuardianResponse {
+        guardian: response.first().copied().unwrap_or_default(),
+    })
+}
@@ -0,0 +1,20 @@
+use clap::{Args, Subcommand};
+
+pub mod get;
+pub mod set;
+
+use get::Get;
+use set::Set;
+
+#[derive(Args, Debug)]
+#[command(about = "Manage guardian of an Argent account")]
+pub struct Guardian {
+    #[clap(subcommand)]
+    pub command: Commands,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Commands {
+    Get(Get),
+    Set(Set),
+}
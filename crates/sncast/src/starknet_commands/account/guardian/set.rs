@@ -0,0 +1,59 @@
+use crate::starknet_commands::invoke::{execute_calls, InvokeVersion};
+use anyhow::{anyhow, Result};
+use clap::Args;
+use sncast::helpers::error::token_not_supported_for_invoke;
+use sncast::helpers::fee::{FeeArgs, FeeToken, PayableTransaction};
+use sncast::helpers::rpc::RpcArgs;
+use sncast::response::errors::StarknetCommandError;
+use sncast::response::structs::InvokeResponse;
+use sncast::{impl_payable_transaction, WaitForTx};
+use starknet::accounts::{Account, SingleOwnerAccount};
+use starknet::core::types::{Call, Felt};
+use starknet::core::utils::get_selector_from_name;
+use starknet::providers::jsonrpc::HttpTransport;
+use starknet::providers::JsonRpcClient;
+use starknet::signers::LocalWallet;
+
+#[derive(Args, Debug)]
+#[command(about = "Change guardian of an Argent account")]
+pub struct Set {
+    /// Address of the new guardian, 0 removes the guardian
+    #[clap(short, long)]
+    pub guardian: Felt,
+
+    #[clap(flatten)]
+    pub fee_args: FeeArgs,
+
+    /// Nonce of the transaction. If not provided, nonce will be set automatically
+    #[clap(short, long)]
+    pub nonce: Option<Felt>,
+
+    /// Version of invoke (can be inferred from fee token)
+    #[clap(short, long)]
+    pub version: Option<InvokeVersion>,
+
+    #[clap(flatten)]
+    pub rpc: RpcArgs,
+}
+
+impl_payable_transaction!(Set, token_not_supported_for_invoke,
+    InvokeVersion::V1 => FeeToken::Eth,
+    InvokeVersion::V3 => FeeToken::Strk
+);
+
+pub async fn set(
+    set: &Set,
+    account: &SingleOwnerAccount<&JsonRpcClient<HttpTransport>, LocalWallet>,
+    wait_config: WaitForTx,
+) -> Result<InvokeResponse, StarknetCommandError> {
+    let fee_args = set.fee_args.clone().fee_token(set.token_from_version());
+
+    let call = Call {
+        to: account.address(),
+        selector: get_selector_from_name("change_guardian")
+            .expect("Failed to convert entry point selector"),
+        calldata: vec![set.guardian],
+    };
+
+    execute_calls(account, vec![call], fee_args, set.nonce, wait_config).await
+}
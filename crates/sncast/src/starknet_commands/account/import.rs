@@ -8,9 +8,15 @@ use clap::Args;
 use conversions::string::{TryFromDecStr, TryFromHexStr};
 use regex::Regex;
 use sncast::helpers::configuration::CastConfig;
+use sncast::helpers::constants::{
+    ARGENT_CLASS_HASH, BRAAVOS_BASE_ACCOUNT_CLASS_HASH, BRAAVOS_CLASS_HASH,
+    KEYSTORE_PASSWORD_ENV_VAR, OZ_CLASS_HASH,
+};
 use sncast::helpers::rpc::RpcArgs;
 use sncast::response::structs::AccountImportResponse;
-use sncast::{check_class_hash_exists, get_chain_id, AccountType as SNCastAccountType};
+use sncast::{
+    check_class_hash_exists, get_chain_id, get_keystore_password, AccountType as SNCastAccountType,
+};
 use sncast::{check_if_legacy_contract, get_class_hash_by_address};
 use starknet::core::types::Felt;
 use starknet::providers::jsonrpc::{HttpTransport, JsonRpcClient};
@@ -29,9 +35,9 @@ pub struct Import {
     #[clap(short, long)]
     pub address: Felt,
 
-    /// Type of the account
+    /// Type of the account. If not provided, it is detected from the class hash of the account
     #[clap(short = 't', long = "type")]
-    pub account_type: AccountType,
+    pub account_type: Option<AccountType>,
 
     /// Class hash of the account
     #[clap(short, long)]
@@ -45,6 +51,10 @@ pub struct Import {
     #[clap(long = "private-key-file", group = "private_key_input")]
     pub private_key_file_path: Option<Utf8PathBuf>,
 
+    /// Path to the keystore file holding account private key
+    #[clap(long = "keystore", group = "private_key_input")]
+    pub keystore_path: Option<Utf8PathBuf>,
+
     /// Salt for the address
     #[clap(short, long)]
     pub salt: Option<Felt>,
@@ -70,6 +80,10 @@ pub async fn import(
         &get_private_key_from_file(passed_private_key_file_path).with_context(|| {
             format!("Failed to obtain private key from the file {passed_private_key_file_path}")
         })?
+    } else if let Some(keystore_path) = &import.keystore_path {
+        &get_private_key_from_keystore(keystore_path).with_context(|| {
+            format!("Failed to obtain private key from the keystore {keystore_path}")
+        })?
     } else if import.private_key.is_none() && import.private_key_file_path.is_none() {
         &get_private_key_from_input()?
     } else {
@@ -101,10 +115,15 @@ pub async fn import(
         );
     };
 
+    let account_type = match &import.account_type {
+        Some(account_type) => account_type.clone(),
+        None => detect_account_type(class_hash)?,
+    };
+
     let chain_id = get_chain_id(provider).await?;
     if let Some(salt) = import.salt {
         // TODO(#2571)
-        let sncast_account_type = match import.account_type {
+        let sncast_account_type = match &account_type {
             AccountType::Argent => SNCastAccountType::Argent,
             AccountType::Braavos => SNCastAccountType::Braavos,
             AccountType::Oz => SNCastAccountType::OpenZeppelin,
@@ -126,7 +145,7 @@ pub async fn import(
         import.address,
         deployed,
         legacy,
-        &import.account_type,
+        &account_type,
         Some(class_hash),
         import.salt,
     );
@@ -163,6 +182,30 @@ fn get_private_key_from_file(file_path: &Utf8PathBuf) -> Result<Felt> {
     Ok(private_key_string.parse()?)
 }
 
+fn get_private_key_from_keystore(keystore_path: &Utf8PathBuf) -> Result<Felt> {
+    ensure!(keystore_path.exists(), "Keystore file does not exist");
+    let private_key = SigningKey::from_keystore(
+        keystore_path,
+        get_keystore_password(KEYSTORE_PASSWORD_ENV_VAR)?.as_str(),
+    )?;
+    Ok(private_key.secret_scalar())
+}
+
+fn detect_account_type(class_hash: Felt) -> Result<AccountType> {
+    if class_hash == OZ_CLASS_HASH {
+        Ok(AccountType::Oz)
+    } else if class_hash == ARGENT_CLASS_HASH {
+        Ok(AccountType::Argent)
+    } else if [BRAAVOS_CLASS_HASH, BRAAVOS_BASE_ACCOUNT_CLASS_HASH].contains(&class_hash) {
+        Ok(AccountType::Braavos)
+    } else {
+        bail!(
+            "Failed to detect account type for class hash {:#x}. Please provide the account type with --type",
+            class_hash
+        )
+    }
+}
+
 fn parse_input_to_felt(input: &String) -> Result<Felt> {
     // Regex is from spec https://github.com/starkware-libs/starknet-specs/blob/6d88b7399f56260ece3821c71f9ce53ec55f830b/api/starknet_api_openrpc.json#L1303
     let felt_re = Regex::new(r"^0x(0|[a-fA-F1-9]{1}[a-fA-F0-9]{0,62})$").unwrap();
@@ -187,10 +230,34 @@ fn get_private_key_from_input() -> Result<Felt> {
 
 #[cfg(test)]
 mod tests {
-    use crate::starknet_commands::account::import::parse_input_to_felt;
+    use crate::starknet_commands::account::import::{detect_account_type, parse_input_to_felt};
+    use crate::starknet_commands::account::AccountType;
     use conversions::string::TryFromHexStr;
+    use sncast::helpers::constants::{BRAAVOS_BASE_ACCOUNT_CLASS_HASH, OZ_CLASS_HASH};
     use starknet::core::types::Felt;
 
+    #[test]
+    fn test_detect_account_type() {
+        assert!(matches!(
+            detect_account_type(OZ_CLASS_HASH).unwrap(),
+            AccountType::Oz
+        ));
+        assert!(matches!(
+            detect_account_type(BRAAVOS_BASE_ACCOUNT_CLASS_HASH).unwrap(),
+            AccountType::Braavos
+        ));
+    }
+
+    #[test]
+    fn test_detect_account_type_unknown() {
+        let result = detect_account_type(Felt::ONE);
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Failed to detect account type for class hash 0x1. Please provide the account type with --type"
+        );
+    }
+
     #[test]
     fn test_parse_hex_str() {
         let hex_str = "0x1a2b3c";
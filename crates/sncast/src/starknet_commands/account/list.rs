@@ -8,8 +8,8 @@ use serde::Deserialize;
 use serde::Serialize;
 use sncast::AccountType;
 use sncast::{
-    check_account_file_exists, read_and_parse_json_file, response::print::OutputFormat,
-    AccountData, NumbersFormat,
+    read_and_parse_json_file, response::print::OutputFormat, AccountData, AddressFormat,
+    NumbersFormat,
 };
 use std::collections::HashMap;
 use std::fmt::Display;
@@ -53,13 +53,16 @@ impl AccountDataRepresentation {
         account: &AccountData,
         display_private_key: bool,
         numbers_format: NumbersFormat,
+        address_format: AddressFormat,
     ) -> Self {
         match numbers_format {
             NumbersFormat::Default | NumbersFormat::Hex => Self {
                 private_key: display_private_key.then(|| account.private_key.into_hex_string()),
                 public_key: account.public_key.into_hex_string(),
                 network: None,
-                address: account.address.map(IntoHexStr::into_hex_string),
+                address: account
+                    .address
+                    .map(|address| address_format.format(address)),
                 salt: account.salt.map(IntoHexStr::into_hex_string),
                 deployed: account.deployed,
                 class_hash: account.class_hash.map(IntoHexStr::into_hex_string),
@@ -91,14 +94,19 @@ fn read_and_flatten(
     accounts_file: &Utf8PathBuf,
     display_private_keys: bool,
     numbers_format: NumbersFormat,
+    address_format: AddressFormat,
 ) -> anyhow::Result<HashMap<String, AccountDataRepresentation>> {
     let networks: NestedMap<AccountData> = read_and_parse_json_file(accounts_file)?;
     let mut result = HashMap::new();
 
     for (network, accounts) in networks.iter().sorted_by_key(|(name, _)| *name) {
         for (name, data) in accounts.iter().sorted_by_key(|(name, _)| *name) {
-            let mut data_repr =
-                AccountDataRepresentation::new(data, display_private_keys, numbers_format);
+            let mut data_repr = AccountDataRepresentation::new(
+                data,
+                display_private_keys,
+                numbers_format,
+                address_format,
+            );
 
             data_repr.set_network(network);
             result.insert(name.to_owned(), data_repr);
@@ -160,16 +168,31 @@ pub fn print_account_list(
     accounts_file: &Utf8PathBuf,
     display_private_keys: bool,
     numbers_format: NumbersFormat,
+    address_format: AddressFormat,
     output_format: OutputFormat,
 ) -> anyhow::Result<()> {
-    check_account_file_exists(accounts_file)?;
+    if !accounts_file.exists() {
+        match output_format {
+            OutputFormat::Json => print_as_json(&HashMap::new())?,
+            OutputFormat::Human => println!(
+                "No accounts file found at {accounts_file}. If you do not have an account create one with `account create` command, \
+                or if you're using a custom accounts file, make sure to supply correct path to it with `--accounts-file` argument."
+            ),
+        }
+        return Ok(());
+    }
 
     let accounts_file_path = accounts_file.canonicalize()?;
     let accounts_file_path = accounts_file_path
         .to_str()
         .context("Failed to resolve an absolute path to the accounts file")?;
 
-    let networks = read_and_flatten(accounts_file, display_private_keys, numbers_format)?;
+    let networks = read_and_flatten(
+        accounts_file,
+        display_private_keys,
+        numbers_format,
+        address_format,
+    )?;
 
     match output_format {
         OutputFormat::Json => print_as_json(&networks)?,
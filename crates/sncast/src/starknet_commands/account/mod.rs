@@ -1,16 +1,24 @@
 use crate::starknet_commands::account::create::Create;
 use crate::starknet_commands::account::delete::Delete;
 use crate::starknet_commands::account::deploy::Deploy;
+use crate::starknet_commands::account::guardian::Guardian;
 use crate::starknet_commands::account::import::Import;
 use crate::starknet_commands::account::list::List;
-use anyhow::{anyhow, bail, Context, Result};
+use crate::starknet_commands::account::revoke_session::RevokeSession;
+use crate::starknet_commands::account::watch::Watch;
+use anyhow::{anyhow, bail, ensure, Context, Result};
 use camino::Utf8PathBuf;
 use clap::{Args, Subcommand, ValueEnum};
 use configuration::{
     find_config_file, load_global_config, search_config_upwards_relative_to, CONFIG_FILENAME,
 };
 use serde_json::json;
-use sncast::{chain_id_to_network_name, decode_chain_id, helpers::configuration::CastConfig};
+use sncast::{
+    chain_id_to_network_name, decode_chain_id, get_account_data_from_accounts_file,
+    get_account_data_from_keystore, get_chain_id, helpers::configuration::CastConfig,
+};
+use starknet::providers::jsonrpc::HttpTransport;
+use starknet::providers::JsonRpcClient;
 use starknet::{core::types::Felt, signers::SigningKey};
 use std::{fmt, fs::OpenOptions, io::Write};
 use toml::Value;
@@ -18,8 +26,11 @@ use toml::Value;
 pub mod create;
 pub mod delete;
 pub mod deploy;
+pub mod guardian;
 pub mod import;
 pub mod list;
+pub mod revoke_session;
+pub mod watch;
 
 #[derive(Args)]
 #[command(about = "Creates and deploys an account to the Starknet")]
@@ -35,6 +46,9 @@ pub enum Commands {
     Deploy(Deploy),
     Delete(Delete),
     List(List),
+    Guardian(Guardian),
+    RevokeSession(RevokeSession),
+    Watch(Watch),
 }
 
 #[allow(clippy::doc_markdown)]
@@ -183,6 +197,29 @@ pub fn add_created_profile_to_configuration(
     Ok(())
 }
 
+/// Fails if the account is not an Argent account, `managed` names what the command manages
+pub async fn ensure_argent_account(
+    account: &str,
+    accounts_file: &Utf8PathBuf,
+    provider: &JsonRpcClient<HttpTransport>,
+    keystore: Option<&Utf8PathBuf>,
+    managed: &str,
+) -> Result<()> {
+    let account_data = if let Some(keystore) = keystore {
+        get_account_data_from_keystore(account, keystore)?
+    } else {
+        let chain_id = get_chain_id(provider).await?;
+        get_account_data_from_accounts_file(account, chain_id, accounts_file)?
+    };
+
+    ensure!(
+        account_data.account_type == Some(sncast::AccountType::Argent),
+        "{managed} can only be managed for Argent accounts"
+    );
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use camino::Utf8PathBuf;
@@ -0,0 +1,69 @@
+use crate::starknet_commands::invoke::{execute_calls, InvokeVersion};
+use anyhow::{anyhow, Result};
+use clap::Args;
+use sncast::helpers::error::token_not_supported_for_invoke;
+use sncast::helpers::fee::{FeeArgs, FeeToken, PayableTransaction};
+use sncast::helpers::rpc::RpcArgs;
+use sncast::response::errors::StarknetCommandError;
+use sncast::response::structs::InvokeResponse;
+use sncast::{impl_payable_transaction, WaitForTx};
+use starknet::accounts::{Account, SingleOwnerAccount};
+use starknet::core::types::{Call, Felt};
+use starknet::core::utils::get_selector_from_name;
+use starknet::providers::jsonrpc::HttpTransport;
+use starknet::providers::JsonRpcClient;
+use starknet::signers::LocalWallet;
+
+#[derive(Args, Debug)]
+#[command(about = "Revoke a session of an Argent account")]
+pub struct RevokeSession {
+    /// Hash of the session to revoke, transactions signed with its session key are rejected afterwards
+    #[clap(long)]
+    pub session_hash: Felt,
+
+    #[clap(flatten)]
+    pub fee_args: FeeArgs,
+
+    /// Nonce of the transaction. If not provided, nonce will be set automatically
+    #[clap(short, long)]
+    pub nonce: Option<Felt>,
+
+    /// Version of invoke (can be inferred from fee token)
+    #[clap(short, long)]
+    pub version: Option<InvokeVersion>,
+
+    #[clap(flatten)]
+    pub rpc: RpcArgs,
+}
+
+impl_payable_transaction!(RevokeSession, token_not_supported_for_invoke,
+    InvokeVersion::V1 => FeeToken::Eth,
+    InvokeVersion::V3 => FeeToken::Strk
+);
+
+pub async fn revoke_session(
+    revoke_session: &RevokeSession,
+    account: &SingleOwnerAccount<&JsonRpcClient<HttpTransport>, LocalWallet>,
+    wait_config: WaitForTx,
+) -> Result<InvokeResponse, StarknetCommandError> {
+    let fee_args = revoke_session
+        .fee_args
+        .clone()
+        .fee_token(revoke_session.token_from_version());
+
+    let call = Call {
+        to: account.address(),
+        selector: get_selector_from_name("revoke_session")
+            .expect("Failed to convert entry point selector"),
+        calldata: vec![revoke_session.session_hash],
+    };
+
+    execute_calls(
+        account,
+        vec![call],
+        fee_args,
+        revoke_session.nonce,
+        wait_config,
+    )
+    .await
+}
@@ -0,0 +1,260 @@
+use anyhow::{bail, Result};
+use clap::Args;
+use sncast::handle_rpc_error;
+use sncast::helpers::abi_decoder::decode_function_call;
+use sncast::helpers::rpc::RpcArgs;
+use sncast::response::print::{print_command_result, OutputFormat};
+use sncast::response::structs::{AccountWatchResponse, Decimal};
+use sncast::{AddressFormat, NumbersFormat};
+use starknet::core::types::contract::AbiEntry;
+use starknet::core::types::{
+    BlockId, BlockTag, ContractClass, DeclareTransaction, Felt, InvokeTransaction,
+    MaybePendingBlockWithTxs, Transaction,
+};
+use starknet::providers::jsonrpc::HttpTransport;
+use starknet::providers::{JsonRpcClient, Provider};
+use std::collections::HashMap;
+use std::time::Duration;
+use tokio::time::sleep;
+
+#[derive(Args, Debug)]
+#[command(about = "Watch new blocks for transactions sent by or to the account")]
+pub struct Watch {
+    /// Exit after the first transaction involving the account is found
+    #[clap(long)]
+    pub once: bool,
+
+    /// Interval between checks for new blocks in seconds
+    #[clap(long, value_name = "SECONDS", default_value_t = 5)]
+    pub poll_interval: u64,
+
+    #[clap(flatten)]
+    pub rpc: RpcArgs,
+}
+
+pub struct FunctionCall {
+    pub to: Felt,
+    pub selector: Felt,
+    pub calldata: Vec<Felt>,
+}
+
+/// Polls new blocks and prints every transaction sent by the account or calling it,
+/// until interrupted or until the first match with `--once`
+pub async fn watch(
+    watch: &Watch,
+    address: Felt,
+    provider: &JsonRpcClient<HttpTransport>,
+    numbers_format: NumbersFormat,
+    address_format: AddressFormat,
+    output_format: OutputFormat,
+) -> Result<()> {
+    let mut next_block = provider.block_number().await.map_err(handle_rpc_error)? + 1;
+    let mut abis = HashMap::new();
+
+    loop {
+        let latest_block = provider.block_number().await.map_err(handle_rpc_error)?;
+
+        while next_block <= latest_block {
+            let block = provider
+                .get_block_with_txs(BlockId::Number(next_block))
+                .await
+                .map_err(handle_rpc_error)?;
+            let MaybePendingBlockWithTxs::Block(block) = block else {
+                bail!("Block {next_block} is still pending");
+            };
+
+            for transaction in &block.transactions {
+                let Some(response) =
+                    describe_transaction(transaction, address, next_block, provider, &mut abis)
+                        .await
+                else {
+                    continue;
+                };
+
+                print_command_result(
+                    "account watch",
+                    &Ok(response),
+                    numbers_format,
+                    address_format,
+                    output_format,
+                )?;
+                if watch.once {
+                    return Ok(());
+                }
+            }
+
+            next_block += 1;
+        }
+
+        sleep(Duration::from_secs(watch.poll_interval)).await;
+    }
+}
+
+/// Returns a description of the transaction if it was sent by the account or calls it
+async fn describe_transaction(
+    transaction: &Transaction,
+    address: Felt,
+    block_number: u64,
+    provider: &JsonRpcClient<HttpTransport>,
+    abis: &mut HashMap<Felt, Option<Vec<AbiEntry>>>,
+) -> Option<AccountWatchResponse> {
+    let (transaction_type, sender, calls) = match transaction {
+        Transaction::Invoke(InvokeTransaction::V0(tx)) => (
+            "INVOKE",
+            None,
+            vec![FunctionCall {
+                to: tx.contract_address,
+                selector: tx.entry_point_selector,
+                calldata: tx.calldata.clone(),
+            }],
+        ),
+        Transaction::Invoke(InvokeTransaction::V1(tx)) => {
+            ("INVOKE", Some(tx.sender_address), parse_calls(&tx.calldata))
+        }
+        Transaction::Invoke(InvokeTransaction::V3(tx)) => {
+            ("INVOKE", Some(tx.sender_address), parse_calls(&tx.calldata))
+        }
+        Transaction::L1Handler(tx) => (
+            "L1_HANDLER",
+            None,
+            vec![FunctionCall {
+                to: tx.contract_address,
+                selector: tx.entry_point_selector,
+                calldata: tx.calldata.clone(),
+            }],
+        ),
+        Transaction::Declare(tx) => {
+            let sender = match tx {
+                DeclareTransaction::V0(tx) => tx.sender_address,
+                DeclareTransaction::V1(tx) => tx.sender_address,
+                DeclareTransaction::V2(tx) => tx.sender_address,
+                DeclareTransaction::V3(tx) => tx.sender_address,
+            };
+            ("DECLARE", Some(sender), vec![])
+        }
+        Transaction::Deploy(_) | Transaction::DeployAccount(_) => return None,
+    };
+
+    if sender != Some(address) && !calls.iter().any(|call| call.to == address) {
+        return None;
+    }
+
+    let mut described_calls = vec![];
+    for call in &calls {
+        described_calls.push(describe_call(call, provider, abis).await);
+    }
+
+    Some(AccountWatchResponse {
+        block_number: Decimal(block_number),
+        transaction_hash: *transaction.transaction_hash(),
+        transaction_type: transaction_type.to_string(),
+        sender_address: sender,
+        calls: described_calls,
+    })
+}
+
+/// Splits calldata of `__execute__` into calls, assuming the Cairo 1 account calldata layout.
+/// Returns no calls if the calldata has a different layout
+pub fn parse_calls(calldata: &[Felt]) -> Vec<FunctionCall> {
+    let to_usize = |felt: &Felt| usize::try_from(felt.to_biguint()).ok();
+
+    let mut calls = vec![];
+    let Some((calls_count, mut rest)) = calldata.split_first() else {
+        return calls;
+    };
+    for _ in 0..to_usize(calls_count).unwrap_or_default() {
+        let [to, selector, length, tail @ ..] = rest else {
+            return vec![];
+        };
+        let Some(length) = to_usize(length).filter(|length| *length <= tail.len()) else {
+            return vec![];
+        };
+        calls.push(FunctionCall {
+            to: *to,
+            selector: *selector,
+            calldata: tail[..length].to_vec(),
+        });
+        rest = &tail[length..];
+    }
+
+    if rest.is_empty() {
+        calls
+    } else {
+        vec![]
+    }
+}
+
+/// Describes the call with arguments decoded using ABI of the called contract,
+/// or with raw calldata if the ABI is not available
+pub async fn describe_call(
+    call: &FunctionCall,
+    provider: &JsonRpcClient<HttpTransport>,
+    abis: &mut HashMap<Felt, Option<Vec<AbiEntry>>>,
+) -> String {
+    if !abis.contains_key(&call.to) {
+        let abi = get_abi(provider, call.to).await;
+        abis.insert(call.to, abi);
+    }
+
+    let decoded = abis[&call.to]
+        .as_ref()
+        .and_then(|abi| decode_function_call(abi, call.selector, &call.calldata).ok());
+
+    match decoded {
+        Some(decoded) => format!("{:#x}: {decoded}", call.to),
+        None => {
+            let calldata = call
+                .calldata
+                .iter()
+                .map(|felt| format!("{felt:#x}"))
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!(
+                "{:#x}: selector {:#x}, calldata [{calldata}]",
+                call.to, call.selector
+            )
+        }
+    }
+}
+
+async fn get_abi(provider: &JsonRpcClient<HttpTransport>, address: Felt) -> Option<Vec<AbiEntry>> {
+    let class = provider
+        .get_class_at(BlockId::Tag(BlockTag::Latest), address)
+        .await
+        .ok()?;
+    let ContractClass::Sierra(class) = class else {
+        return None;
+    };
+    serde_json::from_str(&class.abi).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_calls;
+    use starknet::core::types::Felt;
+
+    #[test]
+    fn test_parse_calls() {
+        let calldata = [2, 0x10, 0x20, 1, 5, 0x11, 0x21, 0].map(Felt::from);
+
+        let calls = parse_calls(&calldata);
+
+        assert_eq!(
+            calls
+                .iter()
+                .map(|call| (call.to, call.selector, call.calldata.clone()))
+                .collect::<Vec<_>>(),
+            vec![
+                (Felt::from(0x10), Felt::from(0x20), vec![Felt::from(5)]),
+                (Felt::from(0x11), Felt::from(0x21), vec![]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_calls_invalid_layout() {
+        let calldata = [1, 0x10, 0x20, 3, 5].map(Felt::from);
+
+        assert!(parse_calls(&calldata).is_empty());
+    }
+}
@@ -0,0 +1,17 @@
+use clap::{Args, Subcommand};
+
+pub mod time;
+
+use time::Time;
+
+#[derive(Args)]
+#[command(about = "Inspect blocks of the Starknet network", long_about = None)]
+pub struct Block {
+    #[clap(subcommand)]
+    pub command: Commands,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Commands {
+    Time(Time),
+}
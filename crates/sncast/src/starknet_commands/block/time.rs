@@ -0,0 +1,97 @@
+use anyhow::{ensure, Context, Result};
+use clap::Args;
+use sncast::handle_rpc_error;
+use sncast::helpers::rpc::RpcArgs;
+use sncast::response::structs::{BlockTimeResponse, Decimal};
+use starknet::core::types::{BlockId, MaybePendingBlockWithTxHashes};
+use starknet::providers::jsonrpc::HttpTransport;
+use starknet::providers::{JsonRpcClient, Provider};
+
+#[derive(Args, Debug)]
+#[command(about = "Show the average time between recent blocks")]
+pub struct Time {
+    /// Number of recent blocks taken into account
+    #[clap(long, default_value_t = 100)]
+    pub samples: u64,
+
+    #[clap(flatten)]
+    pub rpc: RpcArgs,
+}
+
+pub async fn time(
+    time: &Time,
+    provider: &JsonRpcClient<HttpTransport>,
+) -> Result<BlockTimeResponse> {
+    ensure!(time.samples > 1, "--samples must be greater than 1");
+
+    let latest_block_number = provider.block_number().await.map_err(handle_rpc_error)?;
+    let first_block_number = (latest_block_number + 1).saturating_sub(time.samples);
+
+    let mut timestamps = vec![];
+    for block_number in first_block_number..=latest_block_number {
+        let block = provider
+            .get_block_with_tx_hashes(BlockId::Number(block_number))
+            .await
+            .map_err(handle_rpc_error)?;
+
+        timestamps.push(match block {
+            MaybePendingBlockWithTxHashes::Block(block) => block.timestamp,
+            MaybePendingBlockWithTxHashes::PendingBlock(block) => block.timestamp,
+        });
+    }
+
+    let (mean, std_dev) = interval_statistics(&timestamps)
+        .context("At least 2 blocks are required to compute the block time")?;
+
+    Ok(BlockTimeResponse {
+        average_block_time: format!("{mean:.1}s ± {std_dev:.1}s"),
+        samples: Decimal(timestamps.len() as u64),
+    })
+}
+
+/// Mean and population standard deviation of intervals between consecutive timestamps
+#[allow(clippy::cast_precision_loss)]
+fn interval_statistics(timestamps: &[u64]) -> Option<(f64, f64)> {
+    let intervals: Vec<f64> = timestamps
+        .windows(2)
+        .map(|pair| pair[1].saturating_sub(pair[0]) as f64)
+        .collect();
+
+    if intervals.is_empty() {
+        return None;
+    }
+
+    let count = intervals.len() as f64;
+    let mean = intervals.iter().sum::<f64>() / count;
+    let variance = intervals
+        .iter()
+        .map(|interval| (interval - mean).powi(2))
+        .sum::<f64>()
+        / count;
+
+    Some((mean, variance.sqrt()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::interval_statistics;
+
+    #[test]
+    fn test_interval_statistics_too_few_blocks() {
+        assert_eq!(interval_statistics(&[]), None);
+        assert_eq!(interval_statistics(&[100]), None);
+    }
+
+    #[test]
+    fn test_interval_statistics_constant_intervals() {
+        assert_eq!(interval_statistics(&[0, 10, 20, 30]), Some((10.0, 0.0)));
+    }
+
+    #[test]
+    fn test_interval_statistics() {
+        let (mean, std_dev) = interval_statistics(&[0, 10, 30, 40, 60]).unwrap();
+
+        assert!((mean - 15.0).abs() < f64::EPSILON);
+        assert!((std_dev - 5.0).abs() < f64::EPSILON);
+    }
+}
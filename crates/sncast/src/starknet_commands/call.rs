@@ -1,11 +1,23 @@
-use anyhow::Result;
+use anyhow::{anyhow, bail, Context, Result};
+use camino::Utf8PathBuf;
 use clap::Args;
+use sncast::handle_rpc_error;
+use sncast::helpers::abi_decoder::{decode_return_value, flatten_abi, parse_abi};
+use sncast::helpers::hex::join_hex;
 use sncast::helpers::rpc::RpcArgs;
 use sncast::response::errors::StarknetCommandError;
-use sncast::response::structs::CallResponse;
-use starknet::core::types::{BlockId, Felt, FunctionCall};
+use sncast::response::structs::{CallResponse, CallTraceResponse, DecodedCallResponse};
+use starknet::accounts::{Account, AccountError, SingleOwnerAccount};
+use starknet::core::types::contract::AbiEntry;
+use starknet::core::types::{
+    BlockId, CallType, ContractClass, ExecuteInvocation, Felt, FunctionCall, FunctionInvocation,
+    TransactionTrace,
+};
+use starknet::core::utils::get_selector_from_name;
 use starknet::providers::jsonrpc::HttpTransport;
 use starknet::providers::{JsonRpcClient, Provider};
+use starknet::signers::LocalWallet;
+use std::fs;
 
 #[derive(Args)]
 #[command(about = "Call a contract instance on Starknet", long_about = None)]
@@ -24,9 +36,21 @@ pub struct Call {
 
     /// Block identifier on which call should be performed.
     /// Possible values: pending, latest, block hash (0x prefixed string)
-    /// and block number (u64)
-    #[clap(short, long, default_value = "pending")]
-    pub block_id: String,
+    /// and block number (u64) [default: pending]
+    #[clap(short, long, visible_alias = "block", conflicts_with = "call_trace")]
+    pub block_id: Option<String>,
+
+    /// Print a tree of all internal calls made by the function, simulated on the pending block using the account
+    #[clap(long)]
+    pub call_trace: bool,
+
+    /// Directory with ABIs of called classes, stored in files named `<class_hash>.json`, used to show function names in the call trace
+    #[clap(long, requires = "call_trace")]
+    pub abi_dir: Option<Utf8PathBuf>,
+
+    /// Decode the response using ABI of the called contract, fetched from the network
+    #[clap(long, conflicts_with = "call_trace")]
+    pub decode: bool,
 
     #[clap(flatten)]
     pub rpc: RpcArgs,
@@ -52,3 +76,138 @@ pub async fn call(
         Err(error) => Err(StarknetCommandError::ProviderError(error.into())),
     }
 }
+
+/// Decodes values returned by the function using ABI of the class of the called contract
+pub async fn decode_call_response(
+    response: &CallResponse,
+    contract_address: Felt,
+    function: &str,
+    provider: &JsonRpcClient<HttpTransport>,
+    block_id: &BlockId,
+) -> Result<DecodedCallResponse> {
+    let contract_class = provider
+        .get_class_at(block_id, contract_address)
+        .await
+        .map_err(handle_rpc_error)?;
+    let ContractClass::Sierra(class) = contract_class else {
+        bail!("Decoding responses of Cairo 0 contracts is not supported");
+    };
+    let abi: Vec<AbiEntry> =
+        serde_json::from_str(&class.abi).context("Failed to parse contract ABI")?;
+
+    Ok(DecodedCallResponse {
+        response: response.response.clone(),
+        decoded_response: decode_return_value(&abi, function, &response.response)?,
+    })
+}
+
+pub async fn call_trace(
+    contract_address: Felt,
+    entry_point_selector: Felt,
+    calldata: Vec<Felt>,
+    account: &SingleOwnerAccount<&JsonRpcClient<HttpTransport>, LocalWallet>,
+    abi_dir: Option<&Utf8PathBuf>,
+) -> Result<CallTraceResponse, StarknetCommandError> {
+    let call = starknet::core::types::Call {
+        to: contract_address,
+        selector: entry_point_selector,
+        calldata,
+    };
+
+    let simulated = match account.execute_v1(vec![call]).simulate(false, true).await {
+        Ok(simulated) => simulated,
+        Err(AccountError::Provider(error)) => {
+            return Err(StarknetCommandError::ProviderError(error.into()))
+        }
+        Err(_) => return Err(anyhow!("Unknown RPC error").into()),
+    };
+
+    let TransactionTrace::Invoke(trace) = simulated.transaction_trace else {
+        return Err(anyhow!("Expected trace of an invoke transaction").into());
+    };
+    let execute_invocation = match trace.execute_invocation {
+        ExecuteInvocation::Success(invocation) => invocation,
+        ExecuteInvocation::Reverted(reverted) => {
+            return Err(anyhow!("Call reverted: {}", reverted.revert_reason).into())
+        }
+    };
+
+    // The account's `__execute__` performs the called function as its only inner call
+    let invocation = execute_invocation
+        .calls
+        .into_iter()
+        .next()
+        .context("Failed to find the called function in the transaction trace")?;
+
+    let mut lines = vec![];
+    format_invocation(&invocation, 0, abi_dir, &mut lines)?;
+
+    Ok(CallTraceResponse {
+        response: invocation.result,
+        call_trace: lines.join("\n"),
+    })
+}
+
+fn format_invocation(
+    invocation: &FunctionInvocation,
+    depth: usize,
+    abi_dir: Option<&Utf8PathBuf>,
+    lines: &mut Vec<String>,
+) -> Result<()> {
+    let function = match function_name(abi_dir, invocation)? {
+        Some(name) => name,
+        None => format!("{:#x}", invocation.entry_point_selector),
+    };
+    let library_call = if matches!(invocation.call_type, CallType::LibraryCall) {
+        " (library call)"
+    } else {
+        ""
+    };
+
+    lines.push(format!(
+        "{}{:#x}::{function}({}) -> [{}]{library_call}",
+        "  ".repeat(depth),
+        invocation.contract_address,
+        join_hex(&invocation.calldata, ", "),
+        join_hex(&invocation.result, ", "),
+    ));
+
+    for call in &invocation.calls {
+        format_invocation(call, depth + 1, abi_dir, lines)?;
+    }
+
+    Ok(())
+}
+
+/// Looks up name of the invoked function in `<abi_dir>/<class_hash>.json`.
+/// The file can contain the ABI itself or a contract class with `abi` field.
+fn function_name(
+    abi_dir: Option<&Utf8PathBuf>,
+    invocation: &FunctionInvocation,
+) -> Result<Option<String>> {
+    let Some(abi_dir) = abi_dir else {
+        return Ok(None);
+    };
+    let abi_path = abi_dir.join(format!("{:#x}.json", invocation.class_hash));
+    if !abi_path.exists() {
+        return Ok(None);
+    }
+
+    let contents =
+        fs::read_to_string(&abi_path).with_context(|| format!("Failed to read {abi_path}"))?;
+    let abi = parse_abi(&contents).with_context(|| format!("Failed to parse ABI in {abi_path}"))?;
+
+    for entry in flatten_abi(&abi) {
+        let name = match entry {
+            AbiEntry::Function(function) => &function.name,
+            AbiEntry::Constructor(constructor) => &constructor.name,
+            AbiEntry::L1Handler(handler) => &handler.name,
+            _ => continue,
+        };
+        if get_selector_from_name(name)? == invocation.entry_point_selector {
+            return Ok(Some(name.clone()));
+        }
+    }
+
+    Ok(None)
+}
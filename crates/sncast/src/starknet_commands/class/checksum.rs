@@ -0,0 +1,44 @@
+use crate::starknet_commands::declare::parse_sierra_class;
+use anyhow::{Context, Result};
+use camino::Utf8PathBuf;
+use clap::Args;
+use sncast::response::structs::ClassChecksumResponse;
+use starknet::core::types::Felt;
+
+#[derive(Args, Debug)]
+#[command(about = "Verify that the class hash of a Sierra artifact matches the expected one")]
+pub struct Checksum {
+    /// Path to the Sierra contract class artifact
+    #[clap(long)]
+    pub artifact: Utf8PathBuf,
+
+    /// Expected class hash of the artifact
+    #[clap(long)]
+    pub expected: Felt,
+}
+
+pub fn checksum(checksum: &Checksum) -> Result<ClassChecksumResponse> {
+    let sierra = std::fs::read_to_string(&checksum.artifact)
+        .with_context(|| format!("Failed to read Sierra artifact from {}", checksum.artifact))?;
+
+    let sierra_class = parse_sierra_class(checksum.artifact.as_str(), &sierra)?;
+    let class_hash = sierra_class.class_hash().map_err(anyhow::Error::from)?;
+
+    let matches = class_hash == checksum.expected;
+    let message = if matches {
+        "Class hash of the artifact matches the expected one".to_string()
+    } else {
+        format!(
+            "Class hash of the artifact {:#x} does not match the expected {:#x}. \
+            The artifact may have been modified, built from different sources or with a different compiler version",
+            class_hash, checksum.expected
+        )
+    };
+
+    Ok(ClassChecksumResponse {
+        class_hash,
+        expected_class_hash: checksum.expected,
+        matches,
+        message,
+    })
+}
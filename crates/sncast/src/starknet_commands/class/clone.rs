@@ -0,0 +1,107 @@
+use crate::starknet_commands::declare::{declare_class, DeclareVersion};
+use anyhow::{anyhow, bail, ensure, Context, Result};
+use clap::Args;
+use sncast::helpers::error::token_not_supported_for_declaration;
+use sncast::helpers::fee::{FeeArgs, FeeToken, PayableTransaction};
+use sncast::helpers::rpc::RpcArgs;
+use sncast::response::errors::StarknetCommandError;
+use sncast::response::structs::DeclareResponse;
+use sncast::{handle_rpc_error, impl_payable_transaction, WaitForTx};
+use starknet::accounts::SingleOwnerAccount;
+use starknet::core::types::contract::CompiledClass;
+use starknet::core::types::{BlockId, BlockTag, ContractClass, Felt, FlattenedSierraClass};
+use starknet::providers::jsonrpc::HttpTransport;
+use starknet::providers::{JsonRpcClient, Provider};
+use starknet::signers::LocalWallet;
+use std::sync::Arc;
+use universal_sierra_compiler_api::{compile_sierra, SierraType};
+
+#[derive(Args, Debug)]
+#[command(about = "Declare a class declared on another network on the current network")]
+pub struct CloneClass {
+    /// Class hash of the class to clone
+    pub class_hash: Felt,
+
+    /// RPC url of the network the class is downloaded from
+    #[clap(long)]
+    pub from_url: String,
+
+    #[clap(flatten)]
+    pub fee_args: FeeArgs,
+
+    /// Nonce of the transaction. If not provided, nonce will be set automatically
+    #[clap(short, long)]
+    pub nonce: Option<Felt>,
+
+    /// Version of the declaration (can be inferred from fee token)
+    #[clap(short, long)]
+    pub version: Option<DeclareVersion>,
+
+    #[clap(flatten)]
+    pub rpc: RpcArgs,
+}
+
+impl_payable_transaction!(CloneClass, token_not_supported_for_declaration,
+    DeclareVersion::V2 => FeeToken::Eth,
+    DeclareVersion::V3 => FeeToken::Strk
+);
+
+/// Downloads the class from the source network and declares it with the account
+pub async fn clone_class(
+    clone: &CloneClass,
+    source_provider: &JsonRpcClient<HttpTransport>,
+    account: &SingleOwnerAccount<&JsonRpcClient<HttpTransport>, LocalWallet>,
+    wait_config: WaitForTx,
+) -> Result<DeclareResponse, StarknetCommandError> {
+    let contract_definition = download_class(source_provider, clone.class_hash).await?;
+    let casm_class_hash = compiled_class_hash(&contract_definition)?;
+
+    declare_class(
+        clone.fee_args.clone().fee_token(clone.token_from_version()),
+        clone.nonce,
+        Arc::new(contract_definition),
+        casm_class_hash,
+        account,
+        wait_config,
+    )
+    .await
+}
+
+async fn download_class(
+    provider: &JsonRpcClient<HttpTransport>,
+    class_hash: Felt,
+) -> Result<FlattenedSierraClass> {
+    let class = provider
+        .get_class(BlockId::Tag(BlockTag::Latest), class_hash)
+        .await
+        .map_err(handle_rpc_error)?;
+    let ContractClass::Sierra(class) = class else {
+        bail!(
+            "Class with hash {class_hash:#x} is a Cairo 0 class, only Sierra classes can be cloned"
+        );
+    };
+
+    let downloaded_class_hash = class.class_hash();
+    ensure!(
+        downloaded_class_hash == class_hash,
+        "Hash of the downloaded class {downloaded_class_hash:#x} does not match the requested class hash {class_hash:#x}"
+    );
+
+    Ok(class)
+}
+
+/// Compiles the class to CASM locally, as its compiled class hash is required by the declare transaction
+fn compiled_class_hash(class: &FlattenedSierraClass) -> Result<Felt> {
+    let mut sierra = serde_json::to_value(class).context("Failed to serialize Sierra class")?;
+    // The compiler expects the ABI as JSON instead of a string, it does not affect the compiled class
+    sierra
+        .as_object_mut()
+        .ok_or_else(|| anyhow!("Sierra class must be a JSON object"))?
+        .remove("abi");
+
+    let casm = compile_sierra(&sierra, None, &SierraType::Contract)?;
+    let casm_class: CompiledClass =
+        serde_json::from_str(&casm).context("Failed to parse casm artifact")?;
+
+    casm_class.class_hash().map_err(anyhow::Error::from)
+}
@@ -0,0 +1,167 @@
+use anyhow::{anyhow, bail, Result};
+use cairo_lang_sierra::program::{GenericArg, Program};
+use cairo_lang_starknet_classes::felt252_serde::sierra_from_felt252s;
+use cairo_lang_utils::bigint::BigUintAsHex;
+use clap::Args;
+use num_bigint::BigUint;
+use sncast::handle_rpc_error;
+use sncast::helpers::rpc::RpcArgs;
+use sncast::response::structs::ClassDependenciesResponse;
+use starknet::core::types::{BlockId, BlockTag, ContractClass, Felt, StarknetError};
+use starknet::providers::jsonrpc::HttpTransport;
+use starknet::providers::{JsonRpcClient, Provider, ProviderError};
+use std::collections::{HashSet, VecDeque};
+
+/// Name of the libfunc that puts a hardcoded class hash on the stack,
+/// e.g. when it is passed to `library_call_syscall`
+const CLASS_HASH_CONST_LIBFUNC: &str = "class_hash_const";
+
+#[derive(Args, Debug)]
+#[command(about = "Show class hashes that a class depends on")]
+pub struct Dependencies {
+    /// Class hash of the class to inspect
+    #[clap(short = 'g', long)]
+    pub class_hash: Felt,
+
+    /// Also inspect classes of found dependencies
+    #[clap(long)]
+    pub recursive: bool,
+
+    #[clap(flatten)]
+    pub rpc: RpcArgs,
+}
+
+pub async fn dependencies(
+    dependencies: &Dependencies,
+    provider: &JsonRpcClient<HttpTransport>,
+) -> Result<ClassDependenciesResponse> {
+    let direct = match get_sierra_program(provider, dependencies.class_hash).await? {
+        Some(program) => class_hash_constants(&program),
+        None => bail!(
+            "Class with hash {:#x} is a Cairo 0 class, only Sierra classes are supported",
+            dependencies.class_hash
+        ),
+    };
+
+    let mut transitive = vec![];
+
+    if dependencies.recursive {
+        let mut visited: HashSet<Felt> = direct.iter().copied().collect();
+        visited.insert(dependencies.class_hash);
+
+        let mut queue: VecDeque<Felt> = direct.iter().copied().collect();
+
+        while let Some(class_hash) = queue.pop_front() {
+            let Some(program) = get_sierra_program(provider, class_hash).await? else {
+                continue;
+            };
+
+            for dependency in class_hash_constants(&program) {
+                if visited.insert(dependency) {
+                    transitive.push(dependency);
+                    queue.push_back(dependency);
+                }
+            }
+        }
+    }
+
+    Ok(ClassDependenciesResponse {
+        class_hash: dependencies.class_hash,
+        dependencies: direct,
+        transitive_dependencies: dependencies.recursive.then_some(transitive),
+    })
+}
+
+/// Fetches the class and decompresses its Sierra program.
+/// Returns `None` for Cairo 0 classes and dependencies that are not declared.
+pub async fn get_sierra_program(
+    provider: &JsonRpcClient<HttpTransport>,
+    class_hash: Felt,
+) -> Result<Option<Program>> {
+    let class = match provider
+        .get_class(BlockId::Tag(BlockTag::Pending), class_hash)
+        .await
+    {
+        Ok(class) => class,
+        Err(ProviderError::StarknetError(StarknetError::ClassHashNotFound)) => return Ok(None),
+        Err(error) => return Err(handle_rpc_error(error)),
+    };
+
+    let ContractClass::Sierra(class) = class else {
+        return Ok(None);
+    };
+
+    let felts: Vec<BigUintAsHex> = class
+        .sierra_program
+        .iter()
+        .map(|felt| BigUintAsHex {
+            value: BigUint::from_bytes_be(&felt.to_bytes_be()),
+        })
+        .collect();
+
+    let (_, _, program) = sierra_from_felt252s(&felts).map_err(|err| {
+        anyhow!("Failed to decode Sierra program of class {class_hash:#x}: {err}")
+    })?;
+
+    Ok(Some(program))
+}
+
+/// Collects class hashes hardcoded in the program, in order of their declaration
+fn class_hash_constants(program: &Program) -> Vec<Felt> {
+    let mut class_hashes = vec![];
+
+    for declaration in &program.libfunc_declarations {
+        if declaration.long_id.generic_id.0 != CLASS_HASH_CONST_LIBFUNC {
+            continue;
+        }
+        if let [GenericArg::Value(value)] = declaration.long_id.generic_args.as_slice() {
+            let class_hash = Felt::from_bytes_be_slice(&value.to_bytes_be().1);
+            if !class_hashes.contains(&class_hash) {
+                class_hashes.push(class_hash);
+            }
+        }
+    }
+
+    class_hashes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::class_hash_constants;
+    use cairo_lang_sierra::ProgramParser;
+    use starknet::core::types::Felt;
+
+    #[test]
+    fn test_class_hash_constants() {
+        let program = ProgramParser::new()
+            .parse(
+                "
+                type felt252 = felt252;
+                libfunc class_hash_const_1 = class_hash_const<4660>;
+                libfunc felt252_const_1 = felt252_const<1>;
+                libfunc class_hash_const_2 = class_hash_const<22136>;
+                libfunc class_hash_const_3 = class_hash_const<4660>;
+                ",
+            )
+            .unwrap();
+
+        assert_eq!(
+            class_hash_constants(&program),
+            vec![Felt::from(0x1234), Felt::from(0x5678)]
+        );
+    }
+
+    #[test]
+    fn test_no_class_hash_constants() {
+        let program = ProgramParser::new()
+            .parse(
+                "
+                type felt252 = felt252;
+                libfunc felt252_const_1 = felt252_const<1>;
+                ",
+            )
+            .unwrap();
+
+        assert!(class_hash_constants(&program).is_empty());
+    }
+}
@@ -0,0 +1,142 @@
+use anyhow::{ensure, Result};
+use clap::Args;
+use sncast::handle_rpc_error;
+use sncast::helpers::rpc::RpcArgs;
+use sncast::response::structs::ClassHistoryResponse;
+use starknet::core::types::{
+    BlockId, Felt, MaybePendingStateUpdate, StarknetError, StateDiff, TransactionTrace,
+};
+use starknet::providers::jsonrpc::HttpTransport;
+use starknet::providers::{JsonRpcClient, Provider, ProviderError};
+
+#[derive(Args, Debug)]
+#[command(about = "Show changes of the class hash of a contract over time")]
+pub struct History {
+    /// Address of the contract to inspect
+    pub contract_address: Felt,
+
+    /// Number of the first block to inspect
+    #[clap(long)]
+    pub from_block: u64,
+
+    /// Number of the last block to inspect, defaults to the latest block
+    #[clap(long)]
+    pub to_block: Option<u64>,
+
+    #[clap(flatten)]
+    pub rpc: RpcArgs,
+}
+
+pub async fn history(
+    history: &History,
+    provider: &JsonRpcClient<HttpTransport>,
+) -> Result<ClassHistoryResponse> {
+    let to_block = match history.to_block {
+        Some(to_block) => to_block,
+        None => provider.block_number().await.map_err(handle_rpc_error)?,
+    };
+    ensure!(
+        history.from_block <= to_block,
+        "--from-block must not be greater than the last inspected block ({to_block})"
+    );
+
+    let mut current_class_hash = match history.from_block.checked_sub(1) {
+        Some(block_number) => {
+            class_hash_at(
+                provider,
+                BlockId::Number(block_number),
+                history.contract_address,
+            )
+            .await?
+        }
+        None => None,
+    };
+
+    let mut class_changes = vec![];
+    for block_number in history.from_block..=to_block {
+        let block_id = BlockId::Number(block_number);
+
+        let state_diff = match provider
+            .get_state_update(block_id)
+            .await
+            .map_err(handle_rpc_error)?
+        {
+            MaybePendingStateUpdate::Update(update) => update.state_diff,
+            MaybePendingStateUpdate::PendingUpdate(update) => update.state_diff,
+        };
+
+        if let Some(class_hash) = deployed_class_hash(&state_diff, history.contract_address) {
+            current_class_hash = Some(class_hash);
+        }
+        if replaced_class_hash(&state_diff, history.contract_address).is_none() {
+            continue;
+        }
+
+        // A block state diff only contains the final class hash, transaction traces are
+        // inspected to find every replacement and the transaction that made it
+        let traces = provider
+            .trace_block_transactions(block_id)
+            .await
+            .map_err(handle_rpc_error)?;
+
+        for trace in traces {
+            let Some(new_class_hash) = transaction_state_diff(&trace.trace_root)
+                .and_then(|state_diff| replaced_class_hash(state_diff, history.contract_address))
+            else {
+                continue;
+            };
+
+            let old_class_hash = current_class_hash.map_or_else(
+                || "unknown".to_string(),
+                |class_hash| format!("{class_hash:#x}"),
+            );
+            class_changes.push(format!(
+                "block: {block_number}, transaction_hash: {:#x}, old_class_hash: {old_class_hash}, new_class_hash: {new_class_hash:#x}",
+                trace.transaction_hash
+            ));
+            current_class_hash = Some(new_class_hash);
+        }
+    }
+
+    Ok(ClassHistoryResponse {
+        contract_address: history.contract_address,
+        class_changes,
+    })
+}
+
+async fn class_hash_at(
+    provider: &JsonRpcClient<HttpTransport>,
+    block_id: BlockId,
+    contract_address: Felt,
+) -> Result<Option<Felt>> {
+    match provider.get_class_hash_at(block_id, contract_address).await {
+        Ok(class_hash) => Ok(Some(class_hash)),
+        Err(ProviderError::StarknetError(StarknetError::ContractNotFound)) => Ok(None),
+        Err(error) => Err(handle_rpc_error(error)),
+    }
+}
+
+fn deployed_class_hash(state_diff: &StateDiff, contract_address: Felt) -> Option<Felt> {
+    state_diff
+        .deployed_contracts
+        .iter()
+        .find(|contract| contract.address == contract_address)
+        .map(|contract| contract.class_hash)
+}
+
+fn replaced_class_hash(state_diff: &StateDiff, contract_address: Felt) -> Option<Felt> {
+    state_diff
+        .replaced_classes
+        .iter()
+        .find(|replaced| replaced.contract_address == contract_address)
+        .map(|replaced| replaced.class_hash)
+}
+
+fn transaction_state_diff(trace: &TransactionTrace) -> Option<&StateDiff> {
+    match trace {
+        TransactionTrace::Invoke(trace) => trace.state_diff.as_ref(),
+        TransactionTrace::DeployAccount(trace) => trace.state_diff.as_ref(),
+        TransactionTrace::L1Handler(trace) => trace.state_diff.as_ref(),
+        TransactionTrace::Declare(trace) => trace.state_diff.as_ref(),
+    }
+}
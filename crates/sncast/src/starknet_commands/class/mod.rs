@@ -0,0 +1,29 @@
+use clap::{Args, Subcommand};
+
+pub mod checksum;
+pub mod clone;
+pub mod dependencies;
+pub mod history;
+pub mod sierra;
+
+use checksum::Checksum;
+use clone::CloneClass;
+use dependencies::Dependencies;
+use history::History;
+use sierra::Sierra;
+
+#[derive(Args)]
+#[command(about = "Inspect classes declared on Starknet or clone them to another network", long_about = None)]
+pub struct Class {
+    #[clap(subcommand)]
+    pub command: Commands,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Commands {
+    Checksum(Checksum),
+    Clone(CloneClass),
+    Dependencies(Dependencies),
+    History(History),
+    Sierra(Sierra),
+}
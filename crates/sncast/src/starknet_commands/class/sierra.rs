@@ -0,0 +1,55 @@
+use super::dependencies::get_sierra_program;
+use anyhow::{Context, Result};
+use camino::Utf8PathBuf;
+use clap::Args;
+use sncast::helpers::rpc::RpcArgs;
+use sncast::response::structs::ClassSierraResponse;
+use starknet::core::types::Felt;
+use starknet::providers::jsonrpc::HttpTransport;
+use starknet::providers::JsonRpcClient;
+use std::fs;
+
+#[derive(Args, Debug)]
+#[command(about = "Show the Sierra program of a declared class")]
+pub struct Sierra {
+    /// Class hash of the class to inspect
+    pub class_hash: Felt,
+
+    /// Write the Sierra program to the given file instead of printing it
+    #[clap(short, long)]
+    pub output: Option<Utf8PathBuf>,
+
+    #[clap(flatten)]
+    pub rpc: RpcArgs,
+}
+
+pub enum SierraOutput {
+    Text(String),
+    File(ClassSierraResponse),
+}
+
+pub async fn sierra(
+    sierra: &Sierra,
+    provider: &JsonRpcClient<HttpTransport>,
+) -> Result<SierraOutput> {
+    let program = get_sierra_program(provider, sierra.class_hash)
+        .await?
+        .with_context(|| {
+            format!(
+                "Class with hash {:#x} is not declared or is a Cairo 0 class, only Sierra classes are supported",
+                sierra.class_hash
+            )
+        })?;
+    let program = program.to_string();
+
+    match &sierra.output {
+        Some(output) => {
+            fs::write(output, program)
+                .with_context(|| format!("Failed to write Sierra program to {output}"))?;
+            Ok(SierraOutput::File(ClassSierraResponse {
+                output_path: output.clone(),
+            }))
+        }
+        None => Ok(SierraOutput::Text(program)),
+    }
+}
@@ -0,0 +1,51 @@
+use crate::starknet_commands::declare::parse_sierra_class;
+use anyhow::{Context, Result};
+use clap::Args;
+use serde_json::Value;
+use sncast::response::structs::ClassHashResponse;
+use starknet::core::types::contract::CompiledClass;
+use std::io::{self, Read};
+use universal_sierra_compiler_api::{compile_sierra, SierraType};
+
+#[derive(Args)]
+#[command(about = "Compute the class hash of a compiled contract", long_about = None)]
+pub struct ClassHash {
+    /// Path to the Sierra contract class artifact, use `-` to read it from stdin
+    pub path: String,
+
+    /// Compile the contract to CASM and compute its compiled class hash as well
+    #[clap(long)]
+    pub compiled_class_hash: bool,
+}
+
+pub fn class_hash(args: &ClassHash) -> Result<ClassHashResponse> {
+    let sierra = if args.path == "-" {
+        let mut sierra = String::new();
+        io::stdin()
+            .read_to_string(&mut sierra)
+            .context("Failed to read Sierra artifact from stdin")?;
+        sierra
+    } else {
+        std::fs::read_to_string(&args.path)
+            .with_context(|| format!("Failed to read Sierra artifact from {}", args.path))?
+    };
+
+    let sierra_class = parse_sierra_class(&args.path, &sierra)?;
+    let class_hash = sierra_class.class_hash().map_err(anyhow::Error::from)?;
+
+    let compiled_class_hash = if args.compiled_class_hash {
+        let sierra: Value =
+            serde_json::from_str(&sierra).context("Failed to parse sierra artifact")?;
+        let casm = compile_sierra(&sierra, None, &SierraType::Contract)?;
+        let casm_class: CompiledClass =
+            serde_json::from_str(&casm).context("Failed to parse casm artifact")?;
+        Some(casm_class.class_hash().map_err(anyhow::Error::from)?)
+    } else {
+        None
+    };
+
+    Ok(ClassHashResponse {
+        class_hash,
+        compiled_class_hash,
+    })
+}
@@ -0,0 +1,297 @@
+use anyhow::{bail, Context, Result};
+use camino::Utf8PathBuf;
+use clap::Args;
+use rustyline::completion::{Completer, Pair};
+use rustyline::error::ReadlineError;
+use rustyline::highlight::Highlighter;
+use rustyline::hint::Hinter;
+use rustyline::history::DefaultHistory;
+use rustyline::validate::Validator;
+use rustyline::{Editor, Helper};
+use sncast::handle_rpc_error;
+use sncast::helpers::abi_decoder::flatten_abi;
+use starknet::core::types::contract::AbiEntry;
+use starknet::core::types::{BlockId, BlockTag, ContractClass, Felt};
+use starknet::providers::jsonrpc::HttpTransport;
+use starknet::providers::{JsonRpcClient, Provider};
+use std::collections::{BTreeSet, HashMap};
+
+/// Commands which can be run in the console
+pub const CONSOLE_COMMANDS: [&str; 4] = ["call", "invoke", "deploy", "declare"];
+
+/// Commands controlling the console itself
+const BUILTIN_COMMANDS: [&str; 2] = ["help", "exit"];
+
+const HISTORY_FILE: &str = "~/.sncast_history";
+
+const PROMPT: &str = "sncast> ";
+
+#[derive(Args, Debug)]
+#[command(
+    about = "Start an interactive console running `call`, `invoke`, `deploy` and `declare` commands"
+)]
+pub struct Console {}
+
+/// State kept for the whole console session, used to complete contract addresses and function names
+#[derive(Default)]
+pub struct Session {
+    /// Addresses of contracts used in the session
+    addresses: BTreeSet<Felt>,
+    /// Names of functions from ABIs of contracts used in the session
+    functions: HashMap<Felt, Vec<String>>,
+}
+
+impl Session {
+    pub fn add_address(&mut self, address: Felt) {
+        self.addresses.insert(address);
+    }
+
+    #[must_use]
+    pub fn has_abi(&self, address: Felt) -> bool {
+        self.functions.contains_key(&address)
+    }
+
+    pub fn cache_abi(&mut self, address: Felt, functions: Vec<String>) {
+        self.functions.insert(address, functions);
+    }
+
+    fn candidates(&self, previous_word: Option<&str>, words: &[String]) -> Vec<String> {
+        match previous_word {
+            None => CONSOLE_COMMANDS
+                .iter()
+                .chain(BUILTIN_COMMANDS.iter())
+                .map(ToString::to_string)
+                .collect(),
+            Some("-d" | "--contract-address") => self
+                .addresses
+                .iter()
+                .map(|address| format!("{address:#x}"))
+                .collect(),
+            Some("-f" | "--function") => contract_address(words)
+                .and_then(|address| self.functions.get(&address))
+                .cloned()
+                .unwrap_or_default(),
+            Some(_) => vec![],
+        }
+    }
+}
+
+impl Completer for Session {
+    type Candidate = Pair;
+
+    fn complete(
+        &self,
+        line: &str,
+        pos: usize,
+        _ctx: &rustyline::Context<'_>,
+    ) -> rustyline::Result<(usize, Vec<Pair>)> {
+        let line = &line[..pos];
+        let current_word_start = line.rfind(char::is_whitespace).map_or(0, |index| index + 1);
+        let current_word = &line[current_word_start..];
+
+        let words = split_line(line).unwrap_or_default();
+        let previous_words = if current_word.is_empty() {
+            &words[..]
+        } else {
+            &words[..words.len().saturating_sub(1)]
+        };
+
+        let candidates = self
+            .candidates(previous_words.last().map(String::as_str), &words)
+            .into_iter()
+            .filter(|candidate| candidate.starts_with(current_word))
+            .map(|candidate| Pair {
+                display: candidate.clone(),
+                replacement: candidate,
+            })
+            .collect();
+
+        Ok((current_word_start, candidates))
+    }
+}
+
+impl Hinter for Session {
+    type Hint = String;
+}
+
+impl Highlighter for Session {}
+
+impl Validator for Session {}
+
+impl Helper for Session {}
+
+/// Reads lines until `exit` or end of input and passes split lines to `execute`.
+/// Errors returned by `execute` are printed and do not end the session
+pub fn run_console(mut execute: impl FnMut(Vec<String>, &mut Session) -> Result<()>) -> Result<()> {
+    let mut editor: Editor<Session, DefaultHistory> =
+        Editor::new().context("Failed to start the console")?;
+    editor.set_helper(Some(Session::default()));
+
+    let history_file = Utf8PathBuf::from(shellexpand::tilde(HISTORY_FILE).to_string());
+    // the history file doesn't exist before the first session
+    let _ = editor.load_history(&history_file);
+
+    println!("Type `help` to list available commands, `exit` or Ctrl+D to quit");
+
+    loop {
+        let line = match editor.readline(PROMPT) {
+            Ok(line) => line,
+            Err(ReadlineError::Interrupted) => continue,
+            Err(ReadlineError::Eof) => break,
+            Err(error) => return Err(error).context("Failed to read the console input"),
+        };
+        if line.trim().is_empty() {
+            continue;
+        }
+        editor.add_history_entry(line.as_str())?;
+
+        let words = match split_line(&line) {
+            Ok(words) => words,
+            Err(error) => {
+                eprintln!("error: {error}");
+                continue;
+            }
+        };
+
+        match words[0].as_str() {
+            "exit" | "quit" => break,
+            "help" => print_help(),
+            _ => {
+                let session = editor.helper_mut().expect("Console helper is always set");
+                if let Err(error) = execute(words, session) {
+                    eprintln!("error: {error:#}");
+                }
+            }
+        }
+    }
+
+    editor
+        .save_history(&history_file)
+        .with_context(|| format!("Failed to save the console history to {history_file}"))?;
+
+    Ok(())
+}
+
+fn print_help() {
+    println!(
+        "Available commands: {}. Run `<command> --help` for details of a command.",
+        CONSOLE_COMMANDS.join(", ")
+    );
+    println!("Global flags passed to `sncast` before `console` apply to all commands.");
+    println!("Contract addresses and function names of contracts used in the session are completed with Tab.");
+}
+
+/// Fetches names of functions from the ABI of the contract class,
+/// returns no names for Cairo 0 contracts
+pub async fn fetch_function_names(
+    provider: &JsonRpcClient<HttpTransport>,
+    contract_address: Felt,
+) -> Result<Vec<String>> {
+    let contract_class = provider
+        .get_class_at(BlockId::Tag(BlockTag::Pending), contract_address)
+        .await
+        .map_err(handle_rpc_error)?;
+    let ContractClass::Sierra(class) = contract_class else {
+        return Ok(vec![]);
+    };
+    let abi: Vec<AbiEntry> =
+        serde_json::from_str(&class.abi).context("Failed to parse contract ABI")?;
+
+    Ok(flatten_abi(&abi)
+        .into_iter()
+        .filter_map(|entry| match entry {
+            AbiEntry::Function(function) => Some(function.name.clone()),
+            _ => None,
+        })
+        .collect())
+}
+
+/// Finds the value of `--contract-address` in already typed words
+fn contract_address(words: &[String]) -> Option<Felt> {
+    words
+        .windows(2)
+        .find(|pair| pair[0] == "-d" || pair[0] == "--contract-address")
+        .and_then(|pair| Felt::from_hex(&pair[1]).ok())
+}
+
+/// Splits the line into words like a shell, handling single and double quotes
+pub fn split_line(line: &str) -> Result<Vec<String>> {
+    let mut words = vec![];
+    let mut word: Option<String> = None;
+    let mut quote = None;
+
+    for char in line.chars() {
+        match (quote, char) {
+            (Some(open), char) if char == open => quote = None,
+            (Some(_), char) => word.get_or_insert_with(String::new).push(char),
+            (None, '"' | '\'') => {
+                quote = Some(char);
+                word.get_or_insert_with(String::new);
+            }
+            (None, char) if char.is_whitespace() => words.extend(word.take()),
+            (None, char) => word.get_or_insert_with(String::new).push(char),
+        }
+    }
+
+    if let Some(quote) = quote {
+        bail!("Unclosed quote {quote}");
+    }
+    words.extend(word);
+
+    Ok(words)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{split_line, Session};
+    use starknet::core::types::Felt;
+
+    fn words(line: &str) -> Vec<String> {
+        split_line(line).unwrap()
+    }
+
+    #[test]
+    fn test_split_line() {
+        assert_eq!(
+            words(r#"invoke -d 0x1 -f put --calldata "0x1 0x2" --memo 'a "b"'"#),
+            vec![
+                "invoke",
+                "-d",
+                "0x1",
+                "-f",
+                "put",
+                "--calldata",
+                "0x1 0x2",
+                "--memo",
+                "a \"b\""
+            ]
+        );
+        assert_eq!(words("  call   ''  "), vec!["call", ""]);
+        assert!(split_line("call \"0x1").is_err());
+    }
+
+    #[test]
+    fn test_candidates() {
+        let mut session = Session::default();
+        session.add_address(Felt::from(0x123_u16));
+        session.cache_abi(
+            Felt::from(0x123_u16),
+            vec!["get".to_string(), "put".to_string()],
+        );
+
+        assert!(session
+            .candidates(None, &[])
+            .contains(&"invoke".to_string()));
+        assert_eq!(
+            session.candidates(Some("-d"), &words("call -d")),
+            vec!["0x123"]
+        );
+        assert_eq!(
+            session.candidates(Some("--function"), &words("call -d 0x123 --function")),
+            vec!["get", "put"]
+        );
+        assert!(session
+            .candidates(Some("-f"), &words("call -d 0x456 -f"))
+            .is_empty());
+    }
+}
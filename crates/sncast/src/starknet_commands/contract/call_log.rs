@@ -0,0 +1,271 @@
+use crate::starknet_commands::account::watch::{parse_calls, FunctionCall};
+use anyhow::{ensure, Context, Result};
+use clap::Args;
+use serde::Serialize;
+use sncast::handle_rpc_error;
+use sncast::helpers::abi_decoder::{decode_function_arguments, flatten_abi};
+use sncast::helpers::hex::join_hex;
+use sncast::helpers::rpc::RpcArgs;
+use sncast::response::print::OutputFormat;
+use starknet::core::types::contract::AbiEntry;
+use starknet::core::types::{
+    BlockId, ContractClass, Felt, InvokeTransaction, MaybePendingBlockWithTxs, Transaction,
+};
+use starknet::core::utils::get_selector_from_name;
+use starknet::providers::jsonrpc::HttpTransport;
+use starknet::providers::{JsonRpcClient, Provider};
+
+#[derive(Args, Debug)]
+#[command(
+    about = "List calls of a contract function made by invoke transactions in a range of blocks"
+)]
+pub struct CallLog {
+    /// Address of the contract to inspect
+    #[clap(long = "contract", value_name = "ADDRESS")]
+    pub contract_address: Felt,
+
+    /// Name of the function to inspect
+    #[clap(long)]
+    pub function: String,
+
+    /// Number of the first block to inspect
+    #[clap(long)]
+    pub from_block: u64,
+
+    /// Number of the last block to inspect, defaults to the latest block
+    #[clap(long)]
+    pub to_block: Option<u64>,
+
+    #[clap(flatten)]
+    pub rpc: RpcArgs,
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+struct LoggedCall {
+    block_number: u64,
+    transaction_hash: Felt,
+    caller: Option<Felt>,
+    /// Arguments decoded using the contract ABI, or raw calldata if they can't be decoded
+    arguments: Vec<String>,
+}
+
+pub async fn call_log(
+    call_log: &CallLog,
+    provider: &JsonRpcClient<HttpTransport>,
+    output_format: OutputFormat,
+) -> Result<()> {
+    let selector = get_selector_from_name(&call_log.function)
+        .with_context(|| format!("Invalid function name: {}", call_log.function))?;
+    let to_block = match call_log.to_block {
+        Some(to_block) => to_block,
+        None => provider.block_number().await.map_err(handle_rpc_error)?,
+    };
+    ensure!(
+        call_log.from_block <= to_block,
+        "--from-block must not be greater than the last inspected block ({to_block})"
+    );
+
+    let abi = fetch_abi(provider, call_log.contract_address, to_block).await;
+    let input_names = match &abi {
+        Some(abi) => Some(input_names(abi, &call_log.function)?),
+        None => None,
+    };
+
+    let mut calls = vec![];
+    for block_number in call_log.from_block..=to_block {
+        let transactions = match provider
+            .get_block_with_txs(BlockId::Number(block_number))
+            .await
+            .map_err(handle_rpc_error)?
+        {
+            MaybePendingBlockWithTxs::Block(block) => block.transactions,
+            MaybePendingBlockWithTxs::PendingBlock(block) => block.transactions,
+        };
+
+        for transaction in &transactions {
+            let (caller, transaction_calls) = invoked_calls(transaction);
+            let matching_calls = transaction_calls
+                .iter()
+                .filter(|call| call.to == call_log.contract_address && call.selector == selector);
+
+            for call in matching_calls {
+                let transaction_hash = *transaction.transaction_hash();
+                // calldata which doesn't match the ABI, e.g. of a reverted call, is logged raw
+                let arguments = abi
+                    .as_ref()
+                    .and_then(|abi| decoded_arguments(abi, call).ok())
+                    .unwrap_or_else(|| vec![join_hex(&call.calldata, " ")]);
+
+                calls.push(LoggedCall {
+                    block_number,
+                    transaction_hash,
+                    caller,
+                    arguments,
+                });
+            }
+        }
+    }
+
+    match output_format {
+        OutputFormat::Json => println!("{}", serde_json::to_string(&calls)?),
+        OutputFormat::Human => print!("{}", to_csv(&calls, input_names.as_deref())),
+    }
+
+    Ok(())
+}
+
+async fn fetch_abi(
+    provider: &JsonRpcClient<HttpTransport>,
+    contract_address: Felt,
+    block_number: u64,
+) -> Option<Vec<AbiEntry>> {
+    let class = provider
+        .get_class_at(BlockId::Number(block_number), contract_address)
+        .await
+        .ok()?;
+    let ContractClass::Sierra(class) = class else {
+        return None;
+    };
+    serde_json::from_str(&class.abi).ok()
+}
+
+fn input_names(abi: &[AbiEntry], function: &str) -> Result<Vec<String>> {
+    flatten_abi(abi)
+        .into_iter()
+        .find_map(|entry| match entry {
+            AbiEntry::Function(abi_function) if abi_function.name == function => Some(
+                abi_function
+                    .inputs
+                    .iter()
+                    .map(|input| input.name.clone())
+                    .collect(),
+            ),
+            _ => None,
+        })
+        .with_context(|| format!("Function `{function}` not found in the contract ABI"))
+}
+
+fn decoded_arguments(abi: &[AbiEntry], call: &FunctionCall) -> Result<Vec<String>> {
+    let (_, arguments) = decode_function_arguments(abi, call.selector, &call.calldata)?;
+
+    Ok(arguments.into_iter().map(|(_, value)| value).collect())
+}
+
+/// Returns the sender and calls of an invoke transaction, either direct or made by an account `__execute__`
+fn invoked_calls(transaction: &Transaction) -> (Option<Felt>, Vec<FunctionCall>) {
+    match transaction {
+        Transaction::Invoke(InvokeTransaction::V0(tx)) => (
+            None,
+            vec![FunctionCall {
+                to: tx.contract_address,
+                selector: tx.entry_point_selector,
+                calldata: tx.calldata.clone(),
+            }],
+        ),
+        Transaction::Invoke(InvokeTransaction::V1(tx)) => {
+            (Some(tx.sender_address), parse_calls(&tx.calldata))
+        }
+        Transaction::Invoke(InvokeTransaction::V3(tx)) => {
+            (Some(tx.sender_address), parse_calls(&tx.calldata))
+        }
+        _ => (None, vec![]),
+    }
+}
+
+/// Formats the calls as CSV with a column for each function input,
+/// or a single `calldata` column if the inputs are not known
+fn to_csv(calls: &[LoggedCall], input_names: Option<&[String]>) -> String {
+    let mut header = vec![
+        "block".to_string(),
+        "tx_hash".to_string(),
+        "caller".to_string(),
+    ];
+    match input_names {
+        Some(input_names) => header.extend(input_names.iter().cloned()),
+        None => header.push("calldata".to_string()),
+    }
+
+    let mut lines = vec![csv_line(&header)];
+    for call in calls {
+        let mut fields = vec![
+            call.block_number.to_string(),
+            format!("{:#x}", call.transaction_hash),
+            call.caller
+                .map(|caller| format!("{caller:#x}"))
+                .unwrap_or_default(),
+        ];
+        fields.extend(call.arguments.iter().cloned());
+        lines.push(csv_line(&fields));
+    }
+
+    lines.into_iter().map(|line| format!("{line}\n")).collect()
+}
+
+fn csv_line(fields: &[String]) -> String {
+    fields
+        .iter()
+        .map(|field| {
+            if field.contains([',', '"', '\n']) {
+                format!("\"{}\"", field.replace('"', "\"\""))
+            } else {
+                field.clone()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{to_csv, LoggedCall};
+    use indoc::indoc;
+    use starknet::core::types::Felt;
+
+    #[test]
+    fn test_to_csv() {
+        let calls = vec![
+            LoggedCall {
+                block_number: 5,
+                transaction_hash: Felt::from(0x123_u16),
+                caller: Some(Felt::from(0x456_u16)),
+                arguments: vec!["0x1".to_string(), "Point { x: 1, y: 2 }".to_string()],
+            },
+            LoggedCall {
+                block_number: 6,
+                transaction_hash: Felt::from(0x789_u16),
+                caller: None,
+                arguments: vec!["0x2".to_string(), "\"quoted\"".to_string()],
+            },
+        ];
+
+        assert_eq!(
+            to_csv(
+                &calls,
+                Some(&["recipient".to_string(), "point".to_string()])
+            ),
+            indoc! {r#"
+                block,tx_hash,caller,recipient,point
+                5,0x123,0x456,0x1,"Point { x: 1, y: 2 }"
+                6,0x789,,0x2,"""quoted"""
+            "#}
+        );
+    }
+
+    #[test]
+    fn test_to_csv_without_abi() {
+        let calls = vec![LoggedCall {
+            block_number: 5,
+            transaction_hash: Felt::from(0x123_u16),
+            caller: Some(Felt::from(0x456_u16)),
+            arguments: vec!["0x1 0x2".to_string()],
+        }];
+
+        assert_eq!(
+            to_csv(&calls, None),
+            indoc! {"
+                block,tx_hash,caller,calldata
+                5,0x123,0x456,0x1 0x2
+            "}
+        );
+    }
+}
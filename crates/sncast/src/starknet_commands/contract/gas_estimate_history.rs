@@ -0,0 +1,321 @@
+use crate::starknet_commands::account::watch::{parse_calls, FunctionCall};
+use anyhow::{ensure, Context, Result};
+use clap::Args;
+use serde::Serialize;
+use sncast::handle_rpc_error;
+use sncast::helpers::fee::FeeToken;
+use sncast::helpers::rpc::RpcArgs;
+use sncast::response::print::OutputFormat;
+use sncast::NumbersFormat;
+use starknet::core::types::{
+    BlockId, Felt, InvokeTransaction, MaybePendingBlockWithReceipts, PriceUnit, Transaction,
+};
+use starknet::core::utils::get_selector_from_name;
+use starknet::providers::jsonrpc::HttpTransport;
+use starknet::providers::{JsonRpcClient, Provider};
+
+/// Width of the longest bar of the chart in characters
+const CHART_WIDTH: usize = 40;
+
+#[derive(Args, Debug)]
+#[command(about = "Show fees paid for invocations of a contract function in recent blocks")]
+pub struct GasEstimateHistory {
+    /// Address of the contract to inspect
+    #[clap(long = "contract", value_name = "ADDRESS")]
+    pub contract_address: Felt,
+
+    /// Name of the function to inspect
+    #[clap(long)]
+    pub function: String,
+
+    /// Number of recent blocks to scan for invocations
+    #[clap(long, default_value_t = 1000)]
+    pub blocks: u64,
+
+    /// Number of bars the scanned blocks are grouped into
+    #[clap(long, default_value_t = 20)]
+    pub buckets: u64,
+
+    /// Token in which the fees were paid
+    #[clap(long, value_enum, default_value_t = FeeToken::Eth)]
+    pub fee_token: FeeToken,
+
+    #[clap(flatten)]
+    pub rpc: RpcArgs,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct FeeSample {
+    pub block_number: u64,
+    pub transaction_hash: Felt,
+    pub fee: u128,
+}
+
+#[derive(Serialize)]
+struct FeeHistory<'a> {
+    contract_address: Felt,
+    function: &'a str,
+    from_block: u64,
+    to_block: u64,
+    samples: &'a [FeeSample],
+}
+
+#[derive(Debug, PartialEq)]
+struct Bucket {
+    from_block: u64,
+    to_block: u64,
+    invocations: usize,
+    average_fee: Option<u128>,
+}
+
+pub async fn gas_estimate_history(
+    history: &GasEstimateHistory,
+    provider: &JsonRpcClient<HttpTransport>,
+    numbers_format: NumbersFormat,
+    output_format: OutputFormat,
+) -> Result<()> {
+    ensure!(history.blocks > 0, "--blocks must be greater than 0");
+    ensure!(history.buckets > 0, "--buckets must be greater than 0");
+
+    let selector = get_selector_from_name(&history.function)
+        .with_context(|| format!("Invalid function name: {}", history.function))?;
+    let unit = match history.fee_token {
+        FeeToken::Eth => PriceUnit::Wei,
+        FeeToken::Strk => PriceUnit::Fri,
+    };
+
+    let to_block = provider.block_number().await.map_err(handle_rpc_error)?;
+    let from_block = to_block.saturating_sub(history.blocks - 1);
+
+    let mut samples = vec![];
+    for block_number in from_block..=to_block {
+        let transactions = match provider
+            .get_block_with_receipts(BlockId::Number(block_number))
+            .await
+            .map_err(handle_rpc_error)?
+        {
+            MaybePendingBlockWithReceipts::Block(block) => block.transactions,
+            MaybePendingBlockWithReceipts::PendingBlock(block) => block.transactions,
+        };
+
+        for transaction in transactions {
+            if !invokes(&transaction.transaction, history.contract_address, selector) {
+                continue;
+            }
+
+            let fee = transaction.receipt.actual_fee();
+            if fee.unit != unit {
+                continue;
+            }
+            samples.push(FeeSample {
+                block_number,
+                transaction_hash: *transaction.transaction.transaction_hash(),
+                fee: u128::try_from(fee.amount.to_biguint())
+                    .context("Fee amount does not fit in u128")?,
+            });
+        }
+    }
+
+    let fee_history = FeeHistory {
+        contract_address: history.contract_address,
+        function: &history.function,
+        from_block,
+        to_block,
+        samples: &samples,
+    };
+
+    match output_format {
+        OutputFormat::Json => println!("{}", serde_json::to_string(&fee_history)?),
+        OutputFormat::Human => print_chart(&fee_history, history, numbers_format),
+    }
+
+    Ok(())
+}
+
+/// Checks whether the transaction calls the given function of the contract,
+/// either directly or as one of the calls of an account `__execute__`
+fn invokes(transaction: &Transaction, contract_address: Felt, selector: Felt) -> bool {
+    let calls = match transaction {
+        Transaction::Invoke(InvokeTransaction::V0(tx)) => vec![FunctionCall {
+            to: tx.contract_address,
+            selector: tx.entry_point_selector,
+            calldata: vec![],
+        }],
+        Transaction::Invoke(InvokeTransaction::V1(tx)) => parse_calls(&tx.calldata),
+        Transaction::Invoke(InvokeTransaction::V3(tx)) => parse_calls(&tx.calldata),
+        _ => vec![],
+    };
+
+    calls
+        .iter()
+        .any(|call| call.to == contract_address && call.selector == selector)
+}
+
+fn print_chart(
+    fee_history: &FeeHistory,
+    history: &GasEstimateHistory,
+    numbers_format: NumbersFormat,
+) {
+    let contract_address = match numbers_format {
+        NumbersFormat::Decimal => format!("{:#}", fee_history.contract_address),
+        NumbersFormat::Default | NumbersFormat::Hex => {
+            format!("{:#x}", fee_history.contract_address)
+        }
+    };
+    println!(
+        "Fees paid in {:?} for `{}` of {contract_address} in blocks {}-{}:",
+        history.fee_token, fee_history.function, fee_history.from_block, fee_history.to_block
+    );
+
+    if fee_history.samples.is_empty() {
+        println!("No invocations found");
+        return;
+    }
+
+    let buckets = group_into_buckets(
+        fee_history.samples,
+        fee_history.from_block,
+        fee_history.to_block,
+        history.buckets,
+    );
+    let max_fee = buckets
+        .iter()
+        .filter_map(|bucket| bucket.average_fee)
+        .max()
+        .unwrap_or_default();
+    let label_width = format!("{}-{}", fee_history.from_block, fee_history.to_block).len();
+
+    for bucket in &buckets {
+        let label = format!("{}-{}", bucket.from_block, bucket.to_block);
+        let bar = match bucket.average_fee {
+            Some(average_fee) => format!(
+                "{} {average_fee} ({} tx)",
+                "█".repeat(bar_length(average_fee, max_fee)),
+                bucket.invocations
+            ),
+            None => "-".to_string(),
+        };
+        println!("{label:>label_width$} | {bar}");
+    }
+
+    let fees: Vec<u128> = fee_history
+        .samples
+        .iter()
+        .map(|sample| sample.fee)
+        .collect();
+    println!(
+        "invocations: {}, min: {}, average: {}, max: {}",
+        fees.len(),
+        fees.iter().min().unwrap_or(&0),
+        average(&fees).unwrap_or_default(),
+        fees.iter().max().unwrap_or(&0)
+    );
+}
+
+/// Splits blocks `from_block..=to_block` into at most `count` ranges of equal length
+/// and computes the average fee of invocations in each of them
+fn group_into_buckets(
+    samples: &[FeeSample],
+    from_block: u64,
+    to_block: u64,
+    count: u64,
+) -> Vec<Bucket> {
+    let blocks = to_block - from_block + 1;
+    let bucket_size = blocks.div_ceil(count);
+
+    (from_block..=to_block)
+        .step_by(usize::try_from(bucket_size).unwrap_or(usize::MAX))
+        .map(|start| {
+            let end = (start + bucket_size - 1).min(to_block);
+            let fees: Vec<u128> = samples
+                .iter()
+                .filter(|sample| (start..=end).contains(&sample.block_number))
+                .map(|sample| sample.fee)
+                .collect();
+
+            Bucket {
+                from_block: start,
+                to_block: end,
+                invocations: fees.len(),
+                average_fee: average(&fees),
+            }
+        })
+        .collect()
+}
+
+fn average(values: &[u128]) -> Option<u128> {
+    if values.is_empty() {
+        return None;
+    }
+    Some(values.iter().sum::<u128>() / values.len() as u128)
+}
+
+fn bar_length(value: u128, max_value: u128) -> usize {
+    if max_value == 0 {
+        return 0;
+    }
+    // Every non-zero value gets at least one character to be distinguishable from missing data
+    let length = value * CHART_WIDTH as u128 / max_value;
+    usize::try_from(length).unwrap_or(CHART_WIDTH).max(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{bar_length, group_into_buckets, Bucket, FeeSample};
+    use starknet::core::types::Felt;
+
+    fn sample(block_number: u64, fee: u128) -> FeeSample {
+        FeeSample {
+            block_number,
+            transaction_hash: Felt::ZERO,
+            fee,
+        }
+    }
+
+    #[test]
+    fn test_group_into_buckets() {
+        let samples = [sample(10, 100), sample(11, 300), sample(15, 50)];
+
+        let buckets = group_into_buckets(&samples, 10, 17, 3);
+
+        assert_eq!(
+            buckets,
+            vec![
+                Bucket {
+                    from_block: 10,
+                    to_block: 12,
+                    invocations: 2,
+                    average_fee: Some(200),
+                },
+                Bucket {
+                    from_block: 13,
+                    to_block: 15,
+                    invocations: 1,
+                    average_fee: Some(50),
+                },
+                Bucket {
+                    from_block: 16,
+                    to_block: 17,
+                    invocations: 0,
+                    average_fee: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_group_into_more_buckets_than_blocks() {
+        let buckets = group_into_buckets(&[sample(5, 1)], 5, 6, 20);
+
+        assert_eq!(buckets.len(), 2);
+        assert_eq!(buckets[0].average_fee, Some(1));
+        assert_eq!(buckets[1].average_fee, None);
+    }
+
+    #[test]
+    fn test_bar_length() {
+        assert_eq!(bar_length(100, 100), 40);
+        assert_eq!(bar_length(50, 100), 20);
+        assert_eq!(bar_length(1, 1_000_000), 1);
+    }
+}
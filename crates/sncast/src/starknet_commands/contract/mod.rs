@@ -0,0 +1,23 @@
+use clap::{Args, Subcommand};
+
+pub mod call_log;
+pub mod gas_estimate_history;
+pub mod storage_diff;
+
+use call_log::CallLog;
+use gas_estimate_history::GasEstimateHistory;
+use storage_diff::StorageDiff;
+
+#[derive(Args)]
+#[command(about = "Inspect contracts deployed on Starknet", long_about = None)]
+pub struct Contract {
+    #[clap(subcommand)]
+    pub command: Commands,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Commands {
+    CallLog(CallLog),
+    GasEstimateHistory(GasEstimateHistory),
+    StorageDiff(StorageDiff),
+}
@@ -0,0 +1,219 @@
+use anyhow::{ensure, Context, Result};
+use camino::Utf8PathBuf;
+use clap::Args;
+use serde::Serialize;
+use sncast::handle_rpc_error;
+use sncast::helpers::abi_decoder::{flatten_abi, parse_abi};
+use sncast::helpers::rpc::RpcArgs;
+use sncast::helpers::storage::storage_key;
+use sncast::response::print::OutputFormat;
+use starknet::core::types::contract::AbiEntry;
+use starknet::core::types::{BlockId, Felt, MaybePendingStateUpdate};
+use starknet::providers::jsonrpc::HttpTransport;
+use starknet::providers::{JsonRpcClient, Provider};
+use std::collections::HashMap;
+use std::fs;
+
+#[derive(Args, Debug)]
+#[command(about = "Show changes of the storage of a contract in a range of blocks")]
+pub struct StorageDiff {
+    /// Address of the contract to inspect
+    #[clap(long = "contract", value_name = "ADDRESS")]
+    pub contract_address: Felt,
+
+    /// Number of the first block to inspect
+    #[clap(long)]
+    pub from_block: u64,
+
+    /// Number of the last block to inspect, defaults to the latest block
+    #[clap(long)]
+    pub to_block: Option<u64>,
+
+    /// Path to the ABI of the contract or to its contract class, used to resolve storage keys to variable names
+    #[clap(long)]
+    pub abi: Option<Utf8PathBuf>,
+
+    #[clap(flatten)]
+    pub rpc: RpcArgs,
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+struct StorageChange {
+    block_number: u64,
+    key: Felt,
+    variable: Option<String>,
+    old_value: Felt,
+    new_value: Felt,
+}
+
+pub async fn storage_diff(
+    storage_diff: &StorageDiff,
+    provider: &JsonRpcClient<HttpTransport>,
+    output_format: OutputFormat,
+) -> Result<()> {
+    let to_block = match storage_diff.to_block {
+        Some(to_block) => to_block,
+        None => provider.block_number().await.map_err(handle_rpc_error)?,
+    };
+    ensure!(
+        storage_diff.from_block <= to_block,
+        "--from-block must not be greater than the last inspected block ({to_block})"
+    );
+
+    let variables = match &storage_diff.abi {
+        Some(abi_path) => {
+            let contents = fs::read_to_string(abi_path)
+                .with_context(|| format!("Failed to read {abi_path}"))?;
+            let abi = parse_abi(&contents)
+                .with_context(|| format!("Failed to parse ABI in {abi_path}"))?;
+            variable_names(&abi)
+        }
+        None => HashMap::new(),
+    };
+
+    // Values of keys changed in the inspected range so far, older values are fetched on demand
+    let mut current_values: HashMap<Felt, Felt> = HashMap::new();
+    let mut changes = vec![];
+    for block_number in storage_diff.from_block..=to_block {
+        let state_diff = match provider
+            .get_state_update(BlockId::Number(block_number))
+            .await
+            .map_err(handle_rpc_error)?
+        {
+            MaybePendingStateUpdate::Update(update) => update.state_diff,
+            MaybePendingStateUpdate::PendingUpdate(update) => update.state_diff,
+        };
+
+        let storage_entries = state_diff
+            .storage_diffs
+            .into_iter()
+            .filter(|diff| diff.address == storage_diff.contract_address)
+            .flat_map(|diff| diff.storage_entries);
+        for entry in storage_entries {
+            let old_value = match current_values.get(&entry.key) {
+                Some(value) => *value,
+                None => match block_number.checked_sub(1) {
+                    Some(previous_block) => provider
+                        .get_storage_at(
+                            storage_diff.contract_address,
+                            entry.key,
+                            BlockId::Number(previous_block),
+                        )
+                        .await
+                        .map_err(handle_rpc_error)?,
+                    None => Felt::ZERO,
+                },
+            };
+            current_values.insert(entry.key, entry.value);
+
+            changes.push(StorageChange {
+                block_number,
+                key: entry.key,
+                variable: variables.get(&entry.key).cloned(),
+                old_value,
+                new_value: entry.value,
+            });
+        }
+    }
+
+    match output_format {
+        OutputFormat::Json => println!("{}", serde_json::to_string(&changes)?),
+        OutputFormat::Human => print_diff(&changes, storage_diff, to_block),
+    }
+
+    Ok(())
+}
+
+/// Maps storage keys of variables to their names. The ABI does not describe the storage layout,
+/// so names of functions (also without `get_` prefix) and struct members are used as candidates.
+/// Only keys of variables themselves are resolved, not of entries stored in maps
+fn variable_names(abi: &[AbiEntry]) -> HashMap<Felt, String> {
+    let mut candidates = vec![];
+    for entry in flatten_abi(abi) {
+        match entry {
+            AbiEntry::Function(function) => {
+                candidates.push(function.name.clone());
+                if let Some(name) = function.name.strip_prefix("get_") {
+                    candidates.push(name.to_string());
+                }
+            }
+            AbiEntry::Struct(abi_struct) => {
+                candidates.extend(abi_struct.members.iter().map(|member| member.name.clone()));
+            }
+            _ => {}
+        }
+    }
+
+    candidates
+        .into_iter()
+        .filter_map(|name| Some((storage_key(&name, None).ok()?, name)))
+        .collect()
+}
+
+fn print_diff(changes: &[StorageChange], storage_diff: &StorageDiff, to_block: u64) {
+    println!(
+        "Storage changes of {:#x} in blocks {}-{to_block}:",
+        storage_diff.contract_address, storage_diff.from_block
+    );
+    if changes.is_empty() {
+        println!("No changes found");
+        return;
+    }
+
+    let mut last_block = None;
+    for change in changes {
+        if last_block != Some(change.block_number) {
+            println!("block {}:", change.block_number);
+            last_block = Some(change.block_number);
+        }
+        let key = match &change.variable {
+            Some(variable) => format!("{variable} ({:#x})", change.key),
+            None => format!("{:#x}", change.key),
+        };
+        println!("+ {key}: {:#x} → {:#x}", change.old_value, change.new_value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::variable_names;
+    use sncast::helpers::abi_decoder::parse_abi;
+    use sncast::helpers::storage::storage_key;
+
+    #[test]
+    fn test_variable_names() {
+        let abi = parse_abi(
+            r#"[
+                {
+                    "type": "function",
+                    "name": "get_balance",
+                    "inputs": [],
+                    "outputs": [{ "type": "core::felt252" }],
+                    "state_mutability": "view"
+                },
+                {
+                    "type": "struct",
+                    "name": "contract::Config",
+                    "members": [{ "name": "owner", "type": "core::felt252" }]
+                }
+            ]"#,
+        )
+        .unwrap();
+
+        let names = variable_names(&abi);
+
+        assert_eq!(
+            names.get(&storage_key("balance", None).unwrap()),
+            Some(&"balance".to_string())
+        );
+        assert_eq!(
+            names.get(&storage_key("get_balance", None).unwrap()),
+            Some(&"get_balance".to_string())
+        );
+        assert_eq!(
+            names.get(&storage_key("owner", None).unwrap()),
+            Some(&"owner".to_string())
+        );
+        assert_eq!(names.len(), 3);
+    }
+}
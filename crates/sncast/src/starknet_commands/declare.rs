@@ -1,15 +1,17 @@
-use anyhow::{anyhow, Context, Result};
+use anyhow::{anyhow, ensure, Context, Result};
 use clap::{Args, ValueEnum};
 use scarb_api::StarknetContractArtifacts;
+use serde_json::Value;
 use sncast::helpers::error::token_not_supported_for_declaration;
-use sncast::helpers::fee::{FeeArgs, FeeSettings, FeeToken, PayableTransaction};
+use sncast::helpers::fee::{resolve_max_fee, FeeArgs, FeeSettings, FeeToken, PayableTransaction};
 use sncast::helpers::rpc::RpcArgs;
+use sncast::helpers::simulation::handle_simulation_result;
 use sncast::response::errors::StarknetCommandError;
-use sncast::response::structs::DeclareResponse;
+use sncast::response::structs::{DeclareResponse, SimulateResponse};
 use sncast::{apply_optional, handle_wait_for_tx, impl_payable_transaction, ErrorData, WaitForTx};
 use starknet::accounts::AccountError::Provider;
 use starknet::accounts::{ConnectedAccount, DeclarationV2, DeclarationV3};
-use starknet::core::types::{DeclareTransactionResult, Felt};
+use starknet::core::types::{DeclareTransactionResult, Felt, FlattenedSierraClass};
 use starknet::{
     accounts::{Account, SingleOwnerAccount},
     core::types::contract::{CompiledClass, SierraClass},
@@ -19,6 +21,9 @@ use starknet::{
 use std::collections::HashMap;
 use std::sync::Arc;
 
+/// Major version of Sierra that can be declared
+const SUPPORTED_SIERRA_MAJOR_VERSION: u64 = 1;
+
 #[derive(Args)]
 #[command(about = "Declare a contract to starknet", long_about = None)]
 pub struct Declare {
@@ -41,6 +46,14 @@ pub struct Declare {
     #[clap(short, long)]
     pub version: Option<DeclareVersion>,
 
+    /// Simulate the transaction and print the result without sending it
+    #[clap(long)]
+    pub simulate_only: bool,
+
+    /// Print only the class hash of the declared contract to stdout, other messages are printed to stderr
+    #[clap(long, conflicts_with = "simulate_only")]
+    pub class_hash_only: bool,
+
     #[clap(flatten)]
     pub rpc: RpcArgs,
 }
@@ -56,61 +69,67 @@ impl_payable_transaction!(Declare, token_not_supported_for_declaration,
     DeclareVersion::V3 => FeeToken::Strk
 );
 
-#[allow(clippy::too_many_lines)]
 pub async fn declare(
     declare: Declare,
     account: &SingleOwnerAccount<&JsonRpcClient<HttpTransport>, LocalWallet>,
     artifacts: &HashMap<String, StarknetContractArtifacts>,
     wait_config: WaitForTx,
 ) -> Result<DeclareResponse, StarknetCommandError> {
-    let fee_settings = declare
-        .fee_args
-        .clone()
-        .fee_token(declare.token_from_version())
-        .try_into_fee_settings(account.provider(), account.block_id())
-        .await?;
-
-    let contract_artifacts =
-        artifacts
-            .get(&declare.contract)
-            .ok_or(StarknetCommandError::ContractArtifactsNotFound(
-                ErrorData::new(declare.contract),
-            ))?;
+    let (contract_definition, casm_class_hash) =
+        load_contract_classes(&declare.contract, artifacts)?;
 
-    let contract_definition: SierraClass = serde_json::from_str(&contract_artifacts.sierra)
-        .context("Failed to parse sierra artifact")?;
-    let casm_contract_definition: CompiledClass =
-        serde_json::from_str(&contract_artifacts.casm).context("Failed to parse casm artifact")?;
+    declare_class(
+        declare
+            .fee_args
+            .clone()
+            .fee_token(declare.token_from_version()),
+        declare.nonce,
+        contract_definition,
+        casm_class_hash,
+        account,
+        wait_config,
+    )
+    .await
+}
 
-    let casm_class_hash = casm_contract_definition
-        .class_hash()
-        .map_err(anyhow::Error::from)?;
+/// Sends a declare transaction of an already compiled class
+pub async fn declare_class(
+    fee_args: FeeArgs,
+    nonce: Option<Felt>,
+    contract_definition: Arc<FlattenedSierraClass>,
+    casm_class_hash: Felt,
+    account: &SingleOwnerAccount<&JsonRpcClient<HttpTransport>, LocalWallet>,
+    wait_config: WaitForTx,
+) -> Result<DeclareResponse, StarknetCommandError> {
+    let fee_settings = fee_args
+        .try_into_fee_settings(account.provider(), account.block_id())
+        .await?;
 
     let declared = match fee_settings {
-        FeeSettings::Eth { max_fee } => {
-            let declaration = account.declare_v2(
-                Arc::new(contract_definition.flatten().map_err(anyhow::Error::from)?),
-                casm_class_hash,
-            );
+        FeeSettings::Eth { max_fee, gas_price } => {
+            let declaration = account.declare_v2(contract_definition, casm_class_hash);
 
-            let declaration = apply_optional(declaration, max_fee, DeclarationV2::max_fee);
-            let declaration = apply_optional(declaration, declare.nonce, DeclarationV2::nonce);
+            let declaration = apply_optional(declaration, nonce, DeclarationV2::nonce);
 
-            declaration.send().await
+            match resolve_max_fee(max_fee, gas_price, declaration.estimate_fee()).await {
+                Ok(max_fee) => {
+                    apply_optional(declaration, max_fee, DeclarationV2::max_fee)
+                        .send()
+                        .await
+                }
+                Err(error) => Err(error),
+            }
         }
         FeeSettings::Strk {
             max_gas,
             max_gas_unit_price,
         } => {
-            let declaration = account.declare_v3(
-                Arc::new(contract_definition.flatten().map_err(anyhow::Error::from)?),
-                casm_class_hash,
-            );
+            let declaration = account.declare_v3(contract_definition, casm_class_hash);
 
             let declaration = apply_optional(declaration, max_gas, DeclarationV3::gas);
             let declaration =
                 apply_optional(declaration, max_gas_unit_price, DeclarationV3::gas_price);
-            let declaration = apply_optional(declaration, declare.nonce, DeclarationV3::nonce);
+            let declaration = apply_optional(declaration, nonce, DeclarationV3::nonce);
 
             declaration.send().await
         }
@@ -135,3 +154,190 @@ pub async fn declare(
         _ => Err(anyhow!("Unknown RPC error").into()),
     }
 }
+
+pub async fn simulate_declare(
+    declare: Declare,
+    account: &SingleOwnerAccount<&JsonRpcClient<HttpTransport>, LocalWallet>,
+    artifacts: &HashMap<String, StarknetContractArtifacts>,
+) -> Result<SimulateResponse, StarknetCommandError> {
+    let fee_settings = declare
+        .fee_args
+        .clone()
+        .fee_token(declare.token_from_version())
+        .try_into_fee_settings(account.provider(), account.block_id())
+        .await?;
+
+    let (contract_definition, casm_class_hash) =
+        load_contract_classes(&declare.contract, artifacts)?;
+
+    let simulated = match fee_settings {
+        FeeSettings::Eth { max_fee, gas_price } => {
+            let declaration = account.declare_v2(contract_definition, casm_class_hash);
+
+            let declaration = apply_optional(declaration, declare.nonce, DeclarationV2::nonce);
+
+            match resolve_max_fee(max_fee, gas_price, declaration.estimate_fee()).await {
+                Ok(max_fee) => {
+                    apply_optional(declaration, max_fee, DeclarationV2::max_fee)
+                        .simulate(false, false)
+                        .await
+                }
+                Err(error) => Err(error),
+            }
+        }
+        FeeSettings::Strk {
+            max_gas,
+            max_gas_unit_price,
+        } => {
+            let declaration = account.declare_v3(contract_definition, casm_class_hash);
+
+            let declaration = apply_optional(declaration, max_gas, DeclarationV3::gas);
+            let declaration =
+                apply_optional(declaration, max_gas_unit_price, DeclarationV3::gas_price);
+            let declaration = apply_optional(declaration, declare.nonce, DeclarationV3::nonce);
+
+            declaration.simulate(false, false).await
+        }
+    };
+
+    handle_simulation_result(simulated)
+}
+
+pub fn load_contract_classes(
+    contract: &str,
+    artifacts: &HashMap<String, StarknetContractArtifacts>,
+) -> Result<(Arc<FlattenedSierraClass>, Felt), StarknetCommandError> {
+    let contract_artifacts =
+        artifacts
+            .get(contract)
+            .ok_or(StarknetCommandError::ContractArtifactsNotFound(
+                ErrorData::new(contract.to_string()),
+            ))?;
+
+    let contract_definition = parse_sierra_class(contract, &contract_artifacts.sierra)?;
+    let casm_contract_definition: CompiledClass =
+        serde_json::from_str(&contract_artifacts.casm).context("Failed to parse casm artifact")?;
+
+    let casm_class_hash = casm_contract_definition
+        .class_hash()
+        .map_err(anyhow::Error::from)?;
+
+    Ok((
+        Arc::new(contract_definition.flatten().map_err(anyhow::Error::from)?),
+        casm_class_hash,
+    ))
+}
+
+pub fn parse_sierra_class(contract: &str, sierra: &str) -> Result<SierraClass> {
+    let mut sierra: Value =
+        serde_json::from_str(sierra).context("Failed to parse sierra artifact")?;
+
+    // ABI can also be stored as a JSON string, the same way as in flattened classes
+    if let Some(Value::String(abi)) = sierra.get("abi") {
+        let abi: Value =
+            serde_json::from_str(abi).context("Failed to parse ABI of sierra artifact")?;
+        sierra["abi"] = abi;
+    }
+
+    let sierra_class: SierraClass =
+        serde_json::from_value(sierra).context("Failed to parse sierra artifact")?;
+
+    let (major, minor, patch) = sierra_version(&sierra_class.sierra_program)
+        .with_context(|| format!("Failed to read Sierra version of contract {contract}"))?;
+    ensure!(
+        major == SUPPORTED_SIERRA_MAJOR_VERSION,
+        "Unrecognized Sierra version {major}.{minor}.{patch} of contract {contract}, only Sierra {SUPPORTED_SIERRA_MAJOR_VERSION}.x.x classes can be declared"
+    );
+
+    Ok(sierra_class)
+}
+
+/// Serialized Sierra program starts with major, minor and patch of its Sierra version
+fn sierra_version(sierra_program: &[Felt]) -> Option<(u64, u64, u64)> {
+    let to_u64 = |felt: &Felt| u64::try_from(felt.to_biguint()).ok();
+
+    match sierra_program {
+        [major, minor, patch, ..] => Some((to_u64(major)?, to_u64(minor)?, to_u64(patch)?)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn sierra_artifact(sierra_program: &[&str], abi: &Value) -> String {
+        json!({
+            "sierra_program": sierra_program,
+            "sierra_program_debug_info": {
+                "type_names": [],
+                "libfunc_names": [],
+                "user_func_names": []
+            },
+            "contract_class_version": "0.1.0",
+            "entry_points_by_type": {
+                "EXTERNAL": [],
+                "L1_HANDLER": [],
+                "CONSTRUCTOR": []
+            },
+            "abi": abi
+        })
+        .to_string()
+    }
+
+    fn abi() -> Value {
+        json!([{
+            "type": "function",
+            "name": "get_balance",
+            "inputs": [],
+            "outputs": [{ "type": "core::felt252" }],
+            "state_mutability": "view"
+        }])
+    }
+
+    #[test]
+    fn parse_abi_as_array() {
+        let artifact = sierra_artifact(&["0x1", "0x3", "0x0", "0x2", "0x4", "0x0"], &abi());
+
+        let sierra_class = parse_sierra_class("Contract", &artifact).unwrap();
+
+        assert_eq!(sierra_class.abi.len(), 1);
+    }
+
+    #[test]
+    fn parse_abi_as_string() {
+        let artifact = sierra_artifact(
+            &["0x1", "0x5", "0x0", "0x2", "0x7", "0x0"],
+            &Value::String(abi().to_string()),
+        );
+
+        let sierra_class = parse_sierra_class("Contract", &artifact).unwrap();
+
+        assert_eq!(sierra_class.abi.len(), 1);
+    }
+
+    #[test]
+    fn unrecognized_sierra_version() {
+        let artifact = sierra_artifact(&["0x2", "0x0", "0x0", "0x2", "0x7", "0x0"], &abi());
+
+        let error = parse_sierra_class("Contract", &artifact).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "Unrecognized Sierra version 2.0.0 of contract Contract, only Sierra 1.x.x classes can be declared"
+        );
+    }
+
+    #[test]
+    fn missing_sierra_version() {
+        let artifact = sierra_artifact(&["0x1"], &abi());
+
+        let error = parse_sierra_class("Contract", &artifact).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "Failed to read Sierra version of contract Contract"
+        );
+    }
+}
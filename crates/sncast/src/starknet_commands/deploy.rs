@@ -1,17 +1,18 @@
-use anyhow::{anyhow, Result};
+use anyhow::{anyhow, bail, Result};
 use clap::{Args, ValueEnum};
 use sncast::helpers::error::token_not_supported_for_deployment;
-use sncast::helpers::fee::{FeeArgs, FeeSettings, FeeToken, PayableTransaction};
+use sncast::helpers::fee::{resolve_max_fee, FeeArgs, FeeSettings, FeeToken, PayableTransaction};
 use sncast::helpers::rpc::RpcArgs;
+use sncast::helpers::simulation::handle_simulation_result;
+use sncast::helpers::udc::compute_udc_deployed_address;
 use sncast::response::errors::StarknetCommandError;
-use sncast::response::structs::DeployResponse;
-use sncast::{extract_or_generate_salt, impl_payable_transaction, udc_uniqueness};
+use sncast::response::structs::{DeployResponse, SimulateResponse};
+use sncast::{extract_or_generate_salt, impl_payable_transaction};
 use sncast::{handle_wait_for_tx, WaitForTx};
 use starknet::accounts::AccountError::Provider;
 use starknet::accounts::{Account, ConnectedAccount, SingleOwnerAccount};
 use starknet::contract::ContractFactory;
 use starknet::core::types::Felt;
-use starknet::core::utils::get_udc_deployed_address;
 use starknet::providers::jsonrpc::HttpTransport;
 use starknet::providers::JsonRpcClient;
 use starknet::signers::LocalWallet;
@@ -46,6 +47,18 @@ pub struct Deploy {
     #[clap(short, long)]
     pub version: Option<DeployVersion>,
 
+    /// Simulate the transaction and print the result without sending it
+    #[clap(long)]
+    pub simulate_only: bool,
+
+    /// Abort the deployment if the computed contract address differs from this one
+    #[clap(long)]
+    pub assert_address: Option<Felt>,
+
+    /// Print only the address of the deployed contract to stdout, other messages are printed to stderr
+    #[clap(long, conflicts_with = "simulate_only")]
+    pub address_only: bool,
+
     #[clap(flatten)]
     pub rpc: RpcArgs,
 }
@@ -56,6 +69,27 @@ pub enum DeployVersion {
     V3,
 }
 
+impl Deploy {
+    /// Computes the address the contract will be deployed at, failing if it differs from `--assert-address`
+    pub fn contract_address(&self, salt: Felt, account_address: Felt) -> Result<Felt> {
+        let contract_address = compute_udc_deployed_address(
+            salt,
+            self.class_hash,
+            self.unique,
+            account_address,
+            &self.constructor_calldata,
+        );
+
+        if let Some(expected) = self.assert_address {
+            if contract_address != expected {
+                bail!("Computed contract address {contract_address:#x} does not match the expected address {expected:#x}");
+            }
+        }
+
+        Ok(contract_address)
+    }
+}
+
 impl_payable_transaction!(Deploy, token_not_supported_for_deployment,
     DeployVersion::V1 => FeeToken::Eth,
     DeployVersion::V3 => FeeToken::Strk
@@ -74,20 +108,21 @@ pub async fn deploy(
         .await?;
 
     let salt = extract_or_generate_salt(deploy.salt);
+    let contract_address = deploy.contract_address(salt, account.address())?;
     let factory = ContractFactory::new(deploy.class_hash, account);
     let result = match fee_settings {
-        FeeSettings::Eth { max_fee } => {
+        FeeSettings::Eth { max_fee, gas_price } => {
             let execution =
                 factory.deploy_v1(deploy.constructor_calldata.clone(), salt, deploy.unique);
-            let execution = match max_fee {
-                None => execution,
-                Some(max_fee) => execution.max_fee(max_fee),
-            };
             let execution = match deploy.nonce {
                 None => execution,
                 Some(nonce) => execution.nonce(nonce),
             };
-            execution.send().await
+            match resolve_max_fee(max_fee, gas_price, execution.estimate_fee()).await {
+                Ok(Some(max_fee)) => execution.max_fee(max_fee).send().await,
+                Ok(None) => execution.send().await,
+                Err(error) => Err(error),
+            }
         }
         FeeSettings::Strk {
             max_gas,
@@ -117,12 +152,7 @@ pub async fn deploy(
             account.provider(),
             result.transaction_hash,
             DeployResponse {
-                contract_address: get_udc_deployed_address(
-                    salt,
-                    deploy.class_hash,
-                    &udc_uniqueness(deploy.unique, account.address()),
-                    &deploy.constructor_calldata,
-                ),
+                contract_address,
                 transaction_hash: result.transaction_hash,
             },
             wait_config,
@@ -133,3 +163,55 @@ pub async fn deploy(
         _ => Err(anyhow!("Unknown RPC error").into()),
     }
 }
+
+pub async fn simulate_deploy(
+    deploy: Deploy,
+    account: &SingleOwnerAccount<&JsonRpcClient<HttpTransport>, LocalWallet>,
+) -> Result<SimulateResponse, StarknetCommandError> {
+    let fee_settings = deploy
+        .fee_args
+        .clone()
+        .fee_token(deploy.token_from_version())
+        .try_into_fee_settings(account.provider(), account.block_id())
+        .await?;
+
+    let salt = extract_or_generate_salt(deploy.salt);
+    deploy.contract_address(salt, account.address())?;
+    let factory = ContractFactory::new(deploy.class_hash, account);
+    let simulated = match fee_settings {
+        FeeSettings::Eth { max_fee, gas_price } => {
+            let execution = factory.deploy_v1(deploy.constructor_calldata, salt, deploy.unique);
+            let execution = match deploy.nonce {
+                None => execution,
+                Some(nonce) => execution.nonce(nonce),
+            };
+            match resolve_max_fee(max_fee, gas_price, execution.estimate_fee()).await {
+                Ok(Some(max_fee)) => execution.max_fee(max_fee).simulate(false, false).await,
+                Ok(None) => execution.simulate(false, false).await,
+                Err(error) => Err(error),
+            }
+        }
+        FeeSettings::Strk {
+            max_gas,
+            max_gas_unit_price,
+        } => {
+            let execution = factory.deploy_v3(deploy.constructor_calldata, salt, deploy.unique);
+
+            let execution = match max_gas {
+                None => execution,
+                Some(max_gas) => execution.gas(max_gas),
+            };
+            let execution = match max_gas_unit_price {
+                None => execution,
+                Some(max_gas_unit_price) => execution.gas_price(max_gas_unit_price),
+            };
+            let execution = match deploy.nonce {
+                None => execution,
+                Some(nonce) => execution.nonce(nonce),
+            };
+            execution.simulate(false, false).await
+        }
+    };
+
+    handle_simulation_result(simulated)
+}
@@ -0,0 +1,219 @@
+use crate::starknet_commands::declare::load_contract_classes;
+use anyhow::{anyhow, Context, Result};
+use clap::{ArgGroup, Args};
+use num_bigint::BigUint;
+use scarb_api::StarknetContractArtifacts;
+use sncast::extract_or_generate_salt;
+use sncast::helpers::fee::FeeToken;
+use sncast::helpers::rpc::RpcArgs;
+use sncast::response::errors::StarknetCommandError;
+use sncast::response::structs::EstimateFeeResponse;
+use starknet::accounts::AccountError::Provider;
+use starknet::accounts::{Account, ConnectedAccount, SingleOwnerAccount};
+use starknet::contract::ContractFactory;
+use starknet::core::types::{Call, Felt, MaybePendingBlockWithTxHashes, PriceUnit};
+use starknet::core::utils::get_selector_from_name;
+use starknet::providers::jsonrpc::HttpTransport;
+use starknet::providers::{JsonRpcClient, Provider as _};
+use starknet::signers::LocalWallet;
+use std::collections::HashMap;
+
+/// Number of decimals of ETH and STRK tokens
+const TOKEN_DECIMALS: usize = 18;
+
+/// Estimates fee of the ETH (v1) or STRK (v3) transaction, only the builder matching `$fee_token` is evaluated
+macro_rules! estimate_versioned_fee {
+    ($fee_token:expr, $v1:expr, $v3:expr) => {
+        match $fee_token {
+            FeeToken::Eth => $v1.estimate_fee().await,
+            FeeToken::Strk => $v3.estimate_fee().await,
+        }
+    };
+}
+
+#[derive(Args)]
+#[command(
+    about = "Estimate the fee of an invoke, deploy or declare transaction without sending it"
+)]
+#[clap(group(
+    ArgGroup::new("transaction")
+        .required(true)
+        .args(["contract_address", "class_hash", "contract_name"])
+))]
+pub struct EstimateFee {
+    /// Address of the contract to invoke
+    #[clap(short = 'd', long, requires = "function")]
+    pub contract_address: Option<Felt>,
+
+    /// Name of the function to invoke
+    #[clap(short, long, requires = "contract_address")]
+    pub function: Option<String>,
+
+    /// Calldata for the invoked function or the constructor of the deployed contract
+    #[clap(short, long, value_delimiter = ' ', num_args = 1..)]
+    pub calldata: Vec<Felt>,
+
+    /// Class hash of the contract to deploy
+    #[clap(short = 'g', long)]
+    pub class_hash: Option<Felt>,
+
+    /// Salt for the address of the deployed contract
+    #[clap(short, long, requires = "class_hash")]
+    pub salt: Option<Felt>,
+
+    /// If true, salt will be modified with an account address
+    #[clap(long, requires = "class_hash")]
+    pub unique: bool,
+
+    /// Name of the contract to declare
+    #[clap(long)]
+    pub contract_name: Option<String>,
+
+    /// Specifies scarb package to be used
+    #[clap(long, requires = "contract_name")]
+    pub package: Option<String>,
+
+    /// Token that transaction fee will be paid in [default: `fee-token` from snfoundry.toml or eth]
+    #[clap(long)]
+    pub fee_token: Option<FeeToken>,
+
+    #[clap(flatten)]
+    pub rpc: RpcArgs,
+}
+
+pub async fn estimate_fee(
+    estimate_fee: &EstimateFee,
+    fee_token: FeeToken,
+    account: &SingleOwnerAccount<&JsonRpcClient<HttpTransport>, LocalWallet>,
+    artifacts: &HashMap<String, StarknetContractArtifacts>,
+) -> Result<EstimateFeeResponse, StarknetCommandError> {
+    let result = if let Some(contract_address) = estimate_fee.contract_address {
+        let function = estimate_fee
+            .function
+            .as_deref()
+            .unwrap_or_else(|| unreachable!());
+        let call = Call {
+            to: contract_address,
+            selector: get_selector_from_name(function)
+                .context("Failed to convert entry point selector to FieldElement")?,
+            calldata: estimate_fee.calldata.clone(),
+        };
+
+        estimate_versioned_fee!(
+            fee_token,
+            account.execute_v1(vec![call]),
+            account.execute_v3(vec![call])
+        )
+    } else if let Some(class_hash) = estimate_fee.class_hash {
+        let factory = ContractFactory::new(class_hash, account);
+        let salt = extract_or_generate_salt(estimate_fee.salt);
+        let calldata = estimate_fee.calldata.clone();
+
+        estimate_versioned_fee!(
+            fee_token,
+            factory.deploy_v1(calldata, salt, estimate_fee.unique),
+            factory.deploy_v3(calldata, salt, estimate_fee.unique)
+        )
+    } else {
+        let contract = estimate_fee
+            .contract_name
+            .as_deref()
+            .unwrap_or_else(|| unreachable!());
+        let (contract_definition, casm_class_hash) = load_contract_classes(contract, artifacts)?;
+
+        estimate_versioned_fee!(
+            fee_token,
+            account.declare_v2(contract_definition, casm_class_hash),
+            account.declare_v3(contract_definition, casm_class_hash)
+        )
+    };
+
+    let fee_estimate = match result {
+        Ok(fee_estimate) => fee_estimate,
+        Err(Provider(error)) => return Err(StarknetCommandError::ProviderError(error.into())),
+        Err(_) => return Err(anyhow!("Unknown RPC error").into()),
+    };
+
+    let block = account
+        .provider()
+        .get_block_with_tx_hashes(account.block_id())
+        .await
+        .map_err(|error| StarknetCommandError::ProviderError(error.into()))?;
+    let (l1_gas_price, l1_data_gas_price) = match block {
+        MaybePendingBlockWithTxHashes::Block(block) => {
+            (block.l1_gas_price, block.l1_data_gas_price)
+        }
+        MaybePendingBlockWithTxHashes::PendingBlock(block) => {
+            (block.l1_gas_price, block.l1_data_gas_price)
+        }
+    };
+    let (fee_unit, symbol, block_l1_gas_price, block_l1_data_gas_price) = match fee_estimate.unit {
+        PriceUnit::Wei => (
+            "WEI",
+            "ETH",
+            l1_gas_price.price_in_wei,
+            l1_data_gas_price.price_in_wei,
+        ),
+        PriceUnit::Fri => (
+            "FRI",
+            "STRK",
+            l1_gas_price.price_in_fri,
+            l1_data_gas_price.price_in_fri,
+        ),
+    };
+
+    Ok(EstimateFeeResponse {
+        overall_fee: fee_estimate.overall_fee,
+        overall_fee_in_token: format!(
+            "{} {symbol}",
+            format_token_amount(&fee_estimate.overall_fee.to_biguint())
+        ),
+        fee_unit: fee_unit.to_string(),
+        l1_gas_consumed: fee_estimate.gas_consumed,
+        l1_gas_price: fee_estimate.gas_price,
+        l1_data_gas_consumed: fee_estimate.data_gas_consumed,
+        l1_data_gas_price: fee_estimate.data_gas_price,
+        block_l1_gas_price,
+        block_l1_data_gas_price,
+    })
+}
+
+/// Formats an amount given in the smallest units (Wei or Fri) as a decimal amount of tokens
+fn format_token_amount(amount: &BigUint) -> String {
+    let digits = format!(
+        "{:0>width$}",
+        amount.to_string(),
+        width = TOKEN_DECIMALS + 1
+    );
+    let (integer, fraction) = digits.split_at(digits.len() - TOKEN_DECIMALS);
+    let fraction = fraction.trim_end_matches('0');
+
+    if fraction.is_empty() {
+        integer.to_string()
+    } else {
+        format!("{integer}.{fraction}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::format_token_amount;
+    use num_bigint::BigUint;
+
+    #[test]
+    fn test_format_token_amount() {
+        assert_eq!(format_token_amount(&BigUint::from(0u8)), "0");
+        assert_eq!(
+            format_token_amount(&BigUint::from(1u8)),
+            "0.000000000000000001"
+        );
+        assert_eq!(
+            format_token_amount(&BigUint::from(1_230_000_000_000_000u64)),
+            "0.00123"
+        );
+        assert_eq!(
+            format_token_amount(&BigUint::from(25_000_000_000_000_000_000u128)),
+            "25"
+        );
+    }
+}
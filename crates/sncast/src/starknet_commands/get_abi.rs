@@ -0,0 +1,407 @@
+use anyhow::{bail, ensure, Context, Result};
+use clap::{Args, ValueEnum};
+use sncast::handle_rpc_error;
+use sncast::helpers::abi_decoder::{flatten_abi, parse_abi};
+use sncast::helpers::rpc::RpcArgs;
+use sncast::response::structs::GetAbiResponse;
+use starknet::core::types::contract::{
+    AbiEntry, AbiEvent, AbiFunction, AbiNamedMember, StateMutability, TypedAbiEvent,
+};
+use starknet::core::types::{BlockId, BlockTag, ContractClass, Felt};
+use starknet::providers::jsonrpc::HttpTransport;
+use starknet::providers::{JsonRpcClient, Provider};
+
+#[derive(Args)]
+#[command(about = "Print the ABI of a deployed contract", long_about = None)]
+pub struct GetAbi {
+    /// Address of the contract
+    #[clap(short = 'd', long)]
+    pub contract_address: Felt,
+
+    /// Print only the signature of the function with the given name
+    #[clap(short, long)]
+    pub function: Option<String>,
+
+    /// Format of the printed ABI
+    #[clap(long, value_enum, default_value_t = AbiFormat::Table)]
+    pub format: AbiFormat,
+
+    #[clap(flatten)]
+    pub rpc: RpcArgs,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+pub enum AbiFormat {
+    /// ABI entries as JSON, in the same form as stored in the contract class
+    Json,
+    /// One row per entry with its kind, name and signature
+    Table,
+    /// Cairo trait-like signatures of entry points
+    Cairo,
+}
+
+pub async fn get_abi(
+    get_abi: &GetAbi,
+    provider: &JsonRpcClient<HttpTransport>,
+) -> Result<GetAbiResponse> {
+    let contract_class = provider
+        .get_class_at(BlockId::Tag(BlockTag::Pending), get_abi.contract_address)
+        .await
+        .map_err(handle_rpc_error)?;
+    let ContractClass::Sierra(class) = contract_class else {
+        bail!(
+            "Contract {:#x} is a Cairo 0 contract, only ABIs of Sierra contracts are supported",
+            get_abi.contract_address
+        );
+    };
+    let abi = parse_abi(&class.abi)?;
+
+    let abi = match &get_abi.function {
+        Some(function) => {
+            let entries = find_entry_points(&abi, function);
+            ensure!(
+                !entries.is_empty(),
+                "Function {function} not found in the ABI of contract {:#x}",
+                get_abi.contract_address
+            );
+            entries
+        }
+        None => abi,
+    };
+
+    let abi = match get_abi.format {
+        AbiFormat::Json => {
+            serde_json::to_string_pretty(&abi).context("Failed to serialize the ABI")?
+        }
+        AbiFormat::Table => format_table(&abi),
+        AbiFormat::Cairo => format_cairo(&abi),
+    };
+
+    Ok(GetAbiResponse { abi })
+}
+
+/// Finds functions, constructors and L1 handlers with the given name, including ones defined in interfaces
+fn find_entry_points(abi: &[AbiEntry], name: &str) -> Vec<AbiEntry> {
+    flatten_abi(abi)
+        .into_iter()
+        .filter(|entry| match entry {
+            AbiEntry::Function(function) | AbiEntry::L1Handler(function) => function.name == name,
+            AbiEntry::Constructor(constructor) => constructor.name == name,
+            _ => false,
+        })
+        .cloned()
+        .collect()
+}
+
+fn format_table(abi: &[AbiEntry]) -> String {
+    let mut rows = vec![[
+        "KIND".to_string(),
+        "NAME".to_string(),
+        "SIGNATURE".to_string(),
+    ]];
+    for entry in flatten_abi(abi) {
+        let (kind, name, signature) = match entry {
+            AbiEntry::Function(function) => {
+                let kind = match function.state_mutability {
+                    StateMutability::View => "view",
+                    StateMutability::External => "external",
+                };
+                (kind, function.name.clone(), function_parameters(function))
+            }
+            AbiEntry::L1Handler(function) => (
+                "l1_handler",
+                function.name.clone(),
+                function_parameters(function),
+            ),
+            AbiEntry::Constructor(constructor) => (
+                "constructor",
+                constructor.name.clone(),
+                format!("({})", format_members(&constructor.inputs)),
+            ),
+            AbiEntry::Struct(abi_struct) => (
+                "struct",
+                short_type_name(&abi_struct.name),
+                braced(&format_members(&abi_struct.members)),
+            ),
+            AbiEntry::Enum(abi_enum) => (
+                "enum",
+                short_type_name(&abi_enum.name),
+                braced(&format_members(&abi_enum.variants)),
+            ),
+            AbiEntry::Event(event) => {
+                let (name, fields) = event_fields(event);
+                ("event", short_type_name(name), braced(&fields))
+            }
+            AbiEntry::Impl(abi_impl) => (
+                "impl",
+                abi_impl.name.clone(),
+                short_type_name(&abi_impl.interface_name),
+            ),
+            AbiEntry::Interface(_) => unreachable!("interfaces are flattened"),
+        };
+        rows.push([kind.to_string(), name, signature]);
+    }
+
+    let kind_width = rows.iter().map(|row| row[0].len()).max().unwrap_or(0);
+    let name_width = rows.iter().map(|row| row[1].len()).max().unwrap_or(0);
+    rows.iter()
+        .map(|[kind, name, signature]| {
+            format!("{kind:kind_width$}  {name:name_width$}  {signature}")
+                .trim_end()
+                .to_string()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn format_cairo(abi: &[AbiEntry]) -> String {
+    let mut sections = vec![];
+    for entry in abi {
+        match entry {
+            AbiEntry::Interface(interface) => {
+                let functions: Vec<String> = interface
+                    .items
+                    .iter()
+                    .filter_map(|item| match item {
+                        AbiEntry::Function(function) => Some(format!(
+                            "    {};",
+                            function_signature(function, "TContractState")
+                        )),
+                        _ => None,
+                    })
+                    .collect();
+                sections.push(format!(
+                    "#[starknet::interface]\ntrait {}<TContractState> {{\n{}\n}}",
+                    short_type_name(&interface.name),
+                    functions.join("\n")
+                ));
+            }
+            AbiEntry::Function(function) => sections.push(format!(
+                "#[external(v0)]\n{};",
+                function_signature(function, "ContractState")
+            )),
+            AbiEntry::L1Handler(function) => sections.push(format!(
+                "#[l1_handler]\n{};",
+                function_signature(function, "ContractState")
+            )),
+            AbiEntry::Constructor(constructor) => {
+                let parameters = std::iter::once("ref self: ContractState".to_string())
+                    .chain(constructor.inputs.iter().map(format_member))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                sections.push(format!(
+                    "#[constructor]\nfn {}({parameters});",
+                    constructor.name
+                ));
+            }
+            _ => {}
+        }
+    }
+    sections.join("\n\n")
+}
+
+fn function_signature(function: &AbiFunction, state: &str) -> String {
+    let self_parameter = match function.state_mutability {
+        StateMutability::View => format!("self: @{state}"),
+        StateMutability::External => format!("ref self: {state}"),
+    };
+    let parameters = std::iter::once(self_parameter)
+        .chain(function.inputs.iter().map(format_member))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    format!(
+        "fn {}({parameters}){}",
+        function.name,
+        format_outputs(function)
+    )
+}
+
+fn function_parameters(function: &AbiFunction) -> String {
+    format!(
+        "({}){}",
+        format_members(&function.inputs),
+        format_outputs(function)
+    )
+}
+
+fn format_outputs(function: &AbiFunction) -> String {
+    let outputs: Vec<String> = function
+        .outputs
+        .iter()
+        .map(|output| short_type_name(&output.r#type))
+        .collect();
+
+    match outputs.as_slice() {
+        [] => String::new(),
+        [output] => format!(" -> {output}"),
+        outputs => format!(" -> ({})", outputs.join(", ")),
+    }
+}
+
+fn format_members(members: &[AbiNamedMember]) -> String {
+    members
+        .iter()
+        .map(format_member)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn format_member(member: &AbiNamedMember) -> String {
+    format!("{}: {}", member.name, short_type_name(&member.r#type))
+}
+
+fn braced(fields: &str) -> String {
+    if fields.is_empty() {
+        "{}".to_string()
+    } else {
+        format!("{{ {fields} }}")
+    }
+}
+
+fn event_fields(event: &AbiEvent) -> (&str, String) {
+    let fields: Vec<String> = match event {
+        AbiEvent::Typed(TypedAbiEvent::Struct(event)) => event
+            .members
+            .iter()
+            .map(|member| format!("{}: {}", member.name, short_type_name(&member.r#type)))
+            .collect(),
+        AbiEvent::Typed(TypedAbiEvent::Enum(event)) => event
+            .variants
+            .iter()
+            .map(|variant| format!("{}: {}", variant.name, short_type_name(&variant.r#type)))
+            .collect(),
+        AbiEvent::Untyped(event) => event.inputs.iter().map(format_member).collect(),
+    };
+    let name = match event {
+        AbiEvent::Typed(TypedAbiEvent::Struct(event)) => &event.name,
+        AbiEvent::Typed(TypedAbiEvent::Enum(event)) => &event.name,
+        AbiEvent::Untyped(event) => &event.name,
+    };
+
+    (name, fields.join(", "))
+}
+
+/// Removes module paths from a type name, e.g. `core::array::Array::<core::felt252>` becomes `Array<felt252>`
+fn short_type_name(name: &str) -> String {
+    let name = name.replace("::<", "<");
+    let mut result = String::new();
+    let mut path = String::new();
+
+    for char in name.chars() {
+        if char.is_alphanumeric() || char == '_' || char == ':' {
+            path.push(char);
+        } else {
+            result.push_str(path.rsplit("::").next().unwrap_or_default());
+            path.clear();
+            result.push(char);
+        }
+    }
+    result.push_str(path.rsplit("::").next().unwrap_or_default());
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find_entry_points, format_cairo, format_table, short_type_name};
+    use starknet::core::types::contract::{
+        AbiConstructor, AbiEntry, AbiFunction, AbiInterface, AbiNamedMember, AbiOutput, AbiStruct,
+        StateMutability,
+    };
+
+    fn member(name: &str, ty: &str) -> AbiNamedMember {
+        AbiNamedMember {
+            name: name.to_string(),
+            r#type: ty.to_string(),
+        }
+    }
+
+    fn abi() -> Vec<AbiEntry> {
+        vec![
+            AbiEntry::Interface(AbiInterface {
+                name: "map::IMap".to_string(),
+                items: vec![
+                    AbiEntry::Function(AbiFunction {
+                        name: "put".to_string(),
+                        inputs: vec![
+                            member("key", "core::felt252"),
+                            member("value", "core::felt252"),
+                        ],
+                        outputs: vec![],
+                        state_mutability: StateMutability::External,
+                    }),
+                    AbiEntry::Function(AbiFunction {
+                        name: "get".to_string(),
+                        inputs: vec![member("key", "core::felt252")],
+                        outputs: vec![AbiOutput {
+                            r#type: "core::array::Array::<core::felt252>".to_string(),
+                        }],
+                        state_mutability: StateMutability::View,
+                    }),
+                ],
+            }),
+            AbiEntry::Constructor(AbiConstructor {
+                name: "constructor".to_string(),
+                inputs: vec![member(
+                    "owner",
+                    "core::starknet::contract_address::ContractAddress",
+                )],
+            }),
+            AbiEntry::Struct(AbiStruct {
+                name: "map::Position".to_string(),
+                members: vec![member("x", "core::integer::u8")],
+            }),
+        ]
+    }
+
+    #[test]
+    fn test_short_type_name() {
+        assert_eq!(short_type_name("core::felt252"), "felt252");
+        assert_eq!(
+            short_type_name("core::array::Array::<(core::felt252, core::integer::u8)>"),
+            "Array<(felt252, u8)>"
+        );
+        assert_eq!(short_type_name("()"), "()");
+    }
+
+    #[test]
+    fn test_format_table() {
+        assert_eq!(
+            format_table(&abi()),
+            "\
+KIND         NAME         SIGNATURE
+external     put          (key: felt252, value: felt252)
+view         get          (key: felt252) -> Array<felt252>
+constructor  constructor  (owner: ContractAddress)
+struct       Position     { x: u8 }"
+        );
+    }
+
+    #[test]
+    fn test_format_cairo() {
+        assert_eq!(
+            format_cairo(&abi()),
+            "\
+#[starknet::interface]
+trait IMap<TContractState> {
+    fn put(ref self: TContractState, key: felt252, value: felt252);
+    fn get(self: @TContractState, key: felt252) -> Array<felt252>;
+}
+
+#[constructor]
+fn constructor(ref self: ContractState, owner: ContractAddress);"
+        );
+    }
+
+    #[test]
+    fn test_find_entry_points() {
+        let entries = find_entry_points(&abi(), "get");
+
+        assert_eq!(
+            format_cairo(&entries),
+            "#[external(v0)]\nfn get(self: @ContractState, key: felt252) -> Array<felt252>;"
+        );
+        assert!(find_entry_points(&abi(), "Position").is_empty());
+    }
+}
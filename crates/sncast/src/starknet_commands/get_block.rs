@@ -0,0 +1,90 @@
+use anyhow::Result;
+use clap::{Args, ValueEnum};
+use sncast::handle_rpc_error;
+use sncast::helpers::rpc::RpcArgs;
+use sncast::response::structs::{Decimal, GetBlockResponse};
+use starknet::core::types::{BlockId, BlockTag, Felt, MaybePendingBlockWithTxHashes};
+use starknet::providers::jsonrpc::HttpTransport;
+use starknet::providers::{JsonRpcClient, Provider};
+
+#[derive(Args)]
+#[command(about = "Get information about a block", long_about = None)]
+pub struct GetBlock {
+    /// Number of the block
+    #[clap(long, conflicts_with_all = ["hash", "tag"])]
+    pub number: Option<u64>,
+
+    /// Hash of the block
+    #[clap(long, conflicts_with = "tag")]
+    pub hash: Option<Felt>,
+
+    /// Tag of the block, used if neither `--number` nor `--hash` is passed
+    #[clap(long, value_enum, default_value_t = Tag::Latest)]
+    pub tag: Tag,
+
+    /// Also show hashes of all transactions in the block
+    #[clap(long)]
+    pub full_txns: bool,
+
+    #[clap(flatten)]
+    pub rpc: RpcArgs,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+pub enum Tag {
+    Latest,
+    Pending,
+}
+
+impl GetBlock {
+    fn block_id(&self) -> BlockId {
+        match (self.number, self.hash) {
+            (Some(number), _) => BlockId::Number(number),
+            (None, Some(hash)) => BlockId::Hash(hash),
+            (None, None) => BlockId::Tag(match self.tag {
+                Tag::Latest => BlockTag::Latest,
+                Tag::Pending => BlockTag::Pending,
+            }),
+        }
+    }
+}
+
+pub async fn get_block(
+    get_block: &GetBlock,
+    provider: &JsonRpcClient<HttpTransport>,
+) -> Result<GetBlockResponse> {
+    let block = provider
+        .get_block_with_tx_hashes(get_block.block_id())
+        .await
+        .map_err(handle_rpc_error)?;
+
+    let mut response = match block {
+        MaybePendingBlockWithTxHashes::Block(block) => GetBlockResponse {
+            block_number: Some(Decimal(block.block_number)),
+            block_hash: Some(block.block_hash),
+            timestamp: Decimal(block.timestamp),
+            sequencer_address: block.sequencer_address,
+            transaction_count: Decimal(block.transactions.len() as u64),
+            state_root: Some(block.new_root),
+            parent_hash: block.parent_hash,
+            transactions: Some(block.transactions),
+        },
+        // pending block is not sealed yet, so it has no number, hash and state root
+        MaybePendingBlockWithTxHashes::PendingBlock(block) => GetBlockResponse {
+            block_number: None,
+            block_hash: None,
+            timestamp: Decimal(block.timestamp),
+            sequencer_address: block.sequencer_address,
+            transaction_count: Decimal(block.transactions.len() as u64),
+            state_root: None,
+            parent_hash: block.parent_hash,
+            transactions: Some(block.transactions),
+        },
+    };
+
+    if !get_block.full_txns {
+        response.transactions = None;
+    }
+
+    Ok(response)
+}
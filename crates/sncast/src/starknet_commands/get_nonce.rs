@@ -0,0 +1,82 @@
+use anyhow::{ensure, Context, Result};
+use clap::Args;
+use sncast::helpers::configuration::CastConfig;
+use sncast::helpers::rpc::RpcArgs;
+use sncast::response::structs::GetNonceResponse;
+use sncast::{
+    get_account_data_from_accounts_file, get_account_data_from_json,
+    get_account_data_from_keystore, get_chain_id, handle_rpc_error,
+};
+use starknet::core::types::{BlockId, BlockTag, Felt};
+use starknet::providers::jsonrpc::HttpTransport;
+use starknet::providers::{JsonRpcClient, Provider};
+
+#[derive(Args)]
+#[command(about = "Get the nonce of an account", long_about = None)]
+pub struct GetNonce {
+    /// Address of the account, if not passed the address of the account set with `--account` is used
+    #[clap(long)]
+    pub address: Option<Felt>,
+
+    /// Return the nonce from the pending block instead of the latest one
+    #[clap(long)]
+    pub pending: bool,
+
+    #[clap(flatten)]
+    pub rpc: RpcArgs,
+}
+
+pub async fn get_nonce(
+    get_nonce: &GetNonce,
+    config: &CastConfig,
+    provider: &JsonRpcClient<HttpTransport>,
+) -> Result<GetNonceResponse> {
+    let address = match get_nonce.address {
+        Some(address) => address,
+        None => account_address(config, provider).await?,
+    };
+    let block_id = if get_nonce.pending {
+        BlockId::Tag(BlockTag::Pending)
+    } else {
+        BlockId::Tag(BlockTag::Latest)
+    };
+
+    let nonce = provider
+        .get_nonce(block_id, address)
+        .await
+        .map_err(handle_rpc_error)?;
+
+    Ok(GetNonceResponse {
+        address,
+        nonce,
+        nonce_hex: format!("{nonce:#x}"),
+    })
+}
+
+async fn account_address(
+    config: &CastConfig,
+    provider: &JsonRpcClient<HttpTransport>,
+) -> Result<Felt> {
+    let account = config.account.as_str();
+    if let Some(account_json) = &config.account_json {
+        return get_account_data_from_json(account_json)?
+            .address
+            .context("Failed to get address from --account-json");
+    }
+    ensure!(
+        !account.is_empty(),
+        "Either --address or --account must be passed, or account must be set in snfoundry.toml"
+    );
+
+    let account_data = match &config.keystore {
+        Some(keystore) => get_account_data_from_keystore(account, keystore)?,
+        None => {
+            let chain_id = get_chain_id(provider).await?;
+            get_account_data_from_accounts_file(account, chain_id, &config.accounts_file)?
+        }
+    };
+
+    account_data
+        .address
+        .with_context(|| format!("Account {account} has no address"))
+}
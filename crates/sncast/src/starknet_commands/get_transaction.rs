@@ -0,0 +1,236 @@
+use crate::starknet_commands::account::watch::{describe_call, parse_calls, FunctionCall};
+use anyhow::Result;
+use clap::Args;
+use sncast::handle_rpc_error;
+use sncast::helpers::hex::join_hex;
+use sncast::helpers::rpc::RpcArgs;
+use sncast::response::structs::{ExecutionStatus, GetTransactionResponse};
+use starknet::core::types::{
+    DeclareTransaction, DeployAccountTransaction, Event, ExecutionResult, FeePayment, Felt,
+    InvokeTransaction, PriceUnit, ResourceBoundsMapping, Transaction, TransactionReceipt,
+};
+use starknet::providers::jsonrpc::HttpTransport;
+use starknet::providers::{JsonRpcClient, Provider};
+use std::collections::HashMap;
+
+#[derive(Args)]
+#[command(about = "Get details of a transaction with calls decoded using ABIs of called contracts")]
+pub struct GetTransaction {
+    /// Hash of the transaction
+    pub transaction_hash: Felt,
+
+    /// Also show the receipt of the transaction: actual fee, execution status and emitted events
+    #[clap(long)]
+    pub receipt: bool,
+
+    #[clap(flatten)]
+    pub rpc: RpcArgs,
+}
+
+pub async fn get_transaction(
+    get_transaction: &GetTransaction,
+    provider: &JsonRpcClient<HttpTransport>,
+) -> Result<GetTransactionResponse> {
+    let transaction = provider
+        .get_transaction_by_hash(get_transaction.transaction_hash)
+        .await
+        .map_err(handle_rpc_error)?;
+
+    let mut response = describe_transaction(&transaction);
+
+    if let Some(calls) = transaction_calls(&transaction) {
+        let mut abis = HashMap::new();
+        let mut described_calls = vec![];
+        for call in &calls {
+            described_calls.push(describe_call(call, provider, &mut abis).await);
+        }
+        response.calls = Some(described_calls);
+    }
+
+    if get_transaction.receipt {
+        let receipt = provider
+            .get_transaction_receipt(get_transaction.transaction_hash)
+            .await
+            .map_err(handle_rpc_error)?
+            .receipt;
+        let (actual_fee, events) = fee_and_events(&receipt);
+
+        response.actual_fee = Some(format_fee(actual_fee));
+        match receipt.execution_result() {
+            ExecutionResult::Succeeded => {
+                response.execution_status = Some(ExecutionStatus::Succeeded);
+            }
+            ExecutionResult::Reverted { reason } => {
+                response.execution_status = Some(ExecutionStatus::Reverted);
+                response.revert_reason = Some(reason.clone());
+            }
+        }
+        response.events = Some(events.iter().map(format_event).collect());
+    }
+
+    Ok(response)
+}
+
+fn describe_transaction(transaction: &Transaction) -> GetTransactionResponse {
+    let mut response = GetTransactionResponse {
+        transaction_hash: *transaction.transaction_hash(),
+        ..GetTransactionResponse::default()
+    };
+
+    match transaction {
+        Transaction::Invoke(InvokeTransaction::V0(tx)) => {
+            response.transaction_type = "INVOKE".to_string();
+            response.version = "0".to_string();
+            response.contract_address = Some(tx.contract_address);
+            response.max_fee = Some(format!("{:#}", tx.max_fee));
+            response.signature.clone_from(&tx.signature);
+        }
+        Transaction::Invoke(InvokeTransaction::V1(tx)) => {
+            response.transaction_type = "INVOKE".to_string();
+            response.version = "1".to_string();
+            response.sender_address = Some(tx.sender_address);
+            response.nonce = Some(tx.nonce);
+            response.max_fee = Some(format!("{:#}", tx.max_fee));
+            response.signature.clone_from(&tx.signature);
+        }
+        Transaction::Invoke(InvokeTransaction::V3(tx)) => {
+            response.transaction_type = "INVOKE".to_string();
+            response.version = "3".to_string();
+            response.sender_address = Some(tx.sender_address);
+            response.nonce = Some(tx.nonce);
+            response.resource_bounds = Some(format_resource_bounds(&tx.resource_bounds));
+            response.tip = Some(tx.tip.to_string());
+            response.signature.clone_from(&tx.signature);
+        }
+        Transaction::L1Handler(tx) => {
+            response.transaction_type = "L1_HANDLER".to_string();
+            response.version = format!("{:#}", tx.version);
+            response.contract_address = Some(tx.contract_address);
+            response.nonce = Some(Felt::from(tx.nonce));
+        }
+        Transaction::Declare(DeclareTransaction::V0(tx)) => {
+            response.transaction_type = "DECLARE".to_string();
+            response.version = "0".to_string();
+            response.sender_address = Some(tx.sender_address);
+            response.class_hash = Some(tx.class_hash);
+            response.max_fee = Some(format!("{:#}", tx.max_fee));
+            response.signature.clone_from(&tx.signature);
+        }
+        Transaction::Declare(DeclareTransaction::V1(tx)) => {
+            response.transaction_type = "DECLARE".to_string();
+            response.version = "1".to_string();
+            response.sender_address = Some(tx.sender_address);
+            response.class_hash = Some(tx.class_hash);
+            response.nonce = Some(tx.nonce);
+            response.max_fee = Some(format!("{:#}", tx.max_fee));
+            response.signature.clone_from(&tx.signature);
+        }
+        Transaction::Declare(DeclareTransaction::V2(tx)) => {
+            response.transaction_type = "DECLARE".to_string();
+            response.version = "2".to_string();
+            response.sender_address = Some(tx.sender_address);
+            response.class_hash = Some(tx.class_hash);
+            response.nonce = Some(tx.nonce);
+            response.max_fee = Some(format!("{:#}", tx.max_fee));
+            response.signature.clone_from(&tx.signature);
+        }
+        Transaction::Declare(DeclareTransaction::V3(tx)) => {
+            response.transaction_type = "DECLARE".to_string();
+            response.version = "3".to_string();
+            response.sender_address = Some(tx.sender_address);
+            response.class_hash = Some(tx.class_hash);
+            response.nonce = Some(tx.nonce);
+            response.resource_bounds = Some(format_resource_bounds(&tx.resource_bounds));
+            response.tip = Some(tx.tip.to_string());
+            response.signature.clone_from(&tx.signature);
+        }
+        Transaction::Deploy(tx) => {
+            response.transaction_type = "DEPLOY".to_string();
+            response.version = format!("{:#}", tx.version);
+            response.class_hash = Some(tx.class_hash);
+            response.constructor_calldata = Some(tx.constructor_calldata.clone());
+        }
+        Transaction::DeployAccount(DeployAccountTransaction::V1(tx)) => {
+            response.transaction_type = "DEPLOY_ACCOUNT".to_string();
+            response.version = "1".to_string();
+            response.class_hash = Some(tx.class_hash);
+            response.nonce = Some(tx.nonce);
+            response.constructor_calldata = Some(tx.constructor_calldata.clone());
+            response.max_fee = Some(format!("{:#}", tx.max_fee));
+            response.signature.clone_from(&tx.signature);
+        }
+        Transaction::DeployAccount(DeployAccountTransaction::V3(tx)) => {
+            response.transaction_type = "DEPLOY_ACCOUNT".to_string();
+            response.version = "3".to_string();
+            response.class_hash = Some(tx.class_hash);
+            response.nonce = Some(tx.nonce);
+            response.constructor_calldata = Some(tx.constructor_calldata.clone());
+            response.resource_bounds = Some(format_resource_bounds(&tx.resource_bounds));
+            response.tip = Some(tx.tip.to_string());
+            response.signature.clone_from(&tx.signature);
+        }
+    }
+
+    response
+}
+
+/// Calls made by the transaction, `None` for transactions which do not call contracts
+fn transaction_calls(transaction: &Transaction) -> Option<Vec<FunctionCall>> {
+    match transaction {
+        Transaction::Invoke(InvokeTransaction::V0(tx)) => Some(vec![FunctionCall {
+            to: tx.contract_address,
+            selector: tx.entry_point_selector,
+            calldata: tx.calldata.clone(),
+        }]),
+        Transaction::Invoke(InvokeTransaction::V1(tx)) => Some(parse_calls(&tx.calldata)),
+        Transaction::Invoke(InvokeTransaction::V3(tx)) => Some(parse_calls(&tx.calldata)),
+        Transaction::L1Handler(tx) => Some(vec![FunctionCall {
+            to: tx.contract_address,
+            selector: tx.entry_point_selector,
+            calldata: tx.calldata.clone(),
+        }]),
+        Transaction::Declare(_) | Transaction::Deploy(_) | Transaction::DeployAccount(_) => None,
+    }
+}
+
+fn format_resource_bounds(resource_bounds: &ResourceBoundsMapping) -> Vec<String> {
+    [
+        ("L1_GAS", &resource_bounds.l1_gas),
+        ("L2_GAS", &resource_bounds.l2_gas),
+    ]
+    .into_iter()
+    .map(|(resource, bounds)| {
+        format!(
+            "{resource}: max_amount: {}, max_price_per_unit: {}",
+            bounds.max_amount, bounds.max_price_per_unit
+        )
+    })
+    .collect()
+}
+
+fn fee_and_events(receipt: &TransactionReceipt) -> (&FeePayment, &[Event]) {
+    match receipt {
+        TransactionReceipt::Invoke(receipt) => (&receipt.actual_fee, &receipt.events),
+        TransactionReceipt::L1Handler(receipt) => (&receipt.actual_fee, &receipt.events),
+        TransactionReceipt::Declare(receipt) => (&receipt.actual_fee, &receipt.events),
+        TransactionReceipt::Deploy(receipt) => (&receipt.actual_fee, &receipt.events),
+        TransactionReceipt::DeployAccount(receipt) => (&receipt.actual_fee, &receipt.events),
+    }
+}
+
+fn format_fee(fee: &FeePayment) -> String {
+    let unit = match fee.unit {
+        PriceUnit::Wei => "WEI",
+        PriceUnit::Fri => "FRI",
+    };
+    format!("{:#} {unit}", fee.amount)
+}
+
+fn format_event(event: &Event) -> String {
+    format!(
+        "from_address: {:#x}, keys: [{}], data: [{}]",
+        event.from_address,
+        join_hex(&event.keys, ", "),
+        join_hex(&event.data, ", ")
+    )
+}
@@ -1,28 +1,37 @@
-use anyhow::{anyhow, Result};
+use anyhow::{anyhow, ensure, Context, Result};
+use camino::Utf8PathBuf;
 use clap::{Args, ValueEnum};
 use sncast::helpers::error::token_not_supported_for_invoke;
-use sncast::helpers::fee::{FeeArgs, FeeSettings, FeeToken, PayableTransaction};
+use sncast::helpers::fee::{
+    resolve_max_fee, FeeArgs, FeeSettings, FeeToken, PayableTransaction, ResourceBounds,
+};
 use sncast::helpers::rpc::RpcArgs;
+use sncast::helpers::simulation::handle_simulation_result;
 use sncast::response::errors::StarknetCommandError;
-use sncast::response::structs::InvokeResponse;
-use sncast::{apply_optional, handle_wait_for_tx, impl_payable_transaction, WaitForTx};
+use sncast::response::structs::{InvokeResponse, SimulateResponse};
+use sncast::{
+    apply_optional, handle_wait_for_tx, impl_payable_transaction, TransactionError,
+    WaitForTransactionError, WaitForTx,
+};
 use starknet::accounts::AccountError::Provider;
 use starknet::accounts::{Account, ConnectedAccount, ExecutionV1, ExecutionV3, SingleOwnerAccount};
 use starknet::core::types::{Call, Felt, InvokeTransactionResult};
 use starknet::providers::jsonrpc::HttpTransport;
 use starknet::providers::JsonRpcClient;
 use starknet::signers::LocalWallet;
+use std::time::Duration;
+use tokio::time::sleep;
 
 #[derive(Args, Clone)]
 #[command(about = "Invoke a contract on Starknet")]
 pub struct Invoke {
     /// Address of contract to invoke
-    #[clap(short = 'd', long)]
-    pub contract_address: Felt,
+    #[clap(short = 'd', long, required_unless_present = "batch_file")]
+    pub contract_address: Option<Felt>,
 
     /// Name of the function to invoke
-    #[clap(short, long)]
-    pub function: String,
+    #[clap(short, long, required_unless_present = "batch_file")]
+    pub function: Option<String>,
 
     /// Calldata for the invoked function
     #[clap(short, long, value_delimiter = ' ', num_args = 1..)]
@@ -39,13 +48,66 @@ pub struct Invoke {
     #[clap(short, long)]
     pub version: Option<InvokeVersion>,
 
+    /// Max amount and max price per unit of L1 gas for a v3 invoke, e.g. `--l1-gas 1000,100000000000`
+    #[clap(
+        long,
+        value_name = "MAX_AMOUNT,MAX_PRICE_PER_UNIT",
+        conflicts_with_all = ["max_fee", "max_gas", "max_gas_unit_price"]
+    )]
+    pub l1_gas: Option<ResourceBounds>,
+
+    /// Simulate the transaction and print the result without sending it
+    #[clap(short = 's', long, visible_alias = "simulate")]
+    pub simulate_only: bool,
+
+    /// JSONL file with one invocation per line, each sent as a separate transaction,
+    /// e.g. `{"contract": "0x1", "function": "put", "calldata": ["0x1", "0x2"], "memo": "first"}`
+    #[clap(
+        long,
+        conflicts_with_all = ["contract_address", "function", "calldata", "simulate_only"]
+    )]
+    pub batch_file: Option<Utf8PathBuf>,
+
+    /// Send all transactions from `--batch-file` without waiting for the previous ones to be accepted
+    #[clap(long, requires = "batch_file")]
+    pub parallel: bool,
+
+    /// Keep sending transactions from `--batch-file` after one of them fails
+    #[clap(long, requires = "batch_file")]
+    pub continue_on_error: bool,
+
+    /// Number of times the transaction is sent again, with a new nonce and fee estimate, if it reverts.
+    /// Waits for the transaction to be accepted even without `--wait`
+    #[clap(
+        long,
+        value_name = "N",
+        conflicts_with_all = ["nonce", "batch_file", "simulate_only"]
+    )]
+    pub retry_on_revert: Option<u8>,
+
+    /// Seconds to wait before sending a reverted transaction again
+    #[clap(
+        long,
+        value_name = "SECONDS",
+        default_value_t = 10,
+        requires = "retry_on_revert"
+    )]
+    pub retry_delay: u64,
+
+    /// Send the transaction again only if its revert reason contains this text,
+    /// so reverts caused by the contract logic are not retried
+    #[clap(long, value_name = "SUBSTRING", requires = "retry_on_revert")]
+    pub retry_on_message: Option<String>,
+
     #[clap(flatten)]
     pub rpc: RpcArgs,
 }
 
 #[derive(ValueEnum, Debug, Clone)]
 pub enum InvokeVersion {
+    #[value(alias = "1")]
     V1,
+    #[value(alias = "3")]
     V3,
 }
 
@@ -54,24 +116,100 @@ impl_payable_transaction!(Invoke, token_not_supported_for_invoke,
     InvokeVersion::V3 => FeeToken::Strk
 );
 
+impl Invoke {
+    pub fn resolved_fee_args(&self) -> Result<FeeArgs> {
+        let fee_args = self.fee_args.clone().fee_token(self.token_from_version());
+
+        let Some(l1_gas) = self.l1_gas else {
+            return Ok(fee_args);
+        };
+        ensure!(
+            fee_args.fee_token == Some(FeeToken::Strk),
+            "--l1-gas is supported only for v3 invoke"
+        );
+
+        Ok(FeeArgs {
+            max_gas: Some(l1_gas.max_amount.into()),
+            max_gas_unit_price: Some(l1_gas.max_price_per_unit.into()),
+            ..fee_args
+        })
+    }
+
+    fn call(&self, function_selector: Felt) -> Result<Call> {
+        Ok(Call {
+            to: self
+                .contract_address
+                .context("--contract-address must be passed")?,
+            selector: function_selector,
+            calldata: self.calldata.clone(),
+        })
+    }
+}
+
 pub async fn invoke(
     invoke: Invoke,
     function_selector: Felt,
     account: &SingleOwnerAccount<&JsonRpcClient<HttpTransport>, LocalWallet>,
     wait_config: WaitForTx,
 ) -> Result<InvokeResponse, StarknetCommandError> {
-    let fee_args = invoke
-        .fee_args
-        .clone()
-        .fee_token(invoke.token_from_version());
-
-    let call = Call {
-        to: invoke.contract_address,
-        selector: function_selector,
-        calldata: invoke.calldata.clone(),
+    let fee_args = invoke.resolved_fee_args()?;
+    let call = invoke.call(function_selector)?;
+
+    let Some(retries) = invoke.retry_on_revert else {
+        return execute_calls(account, vec![call], fee_args, invoke.nonce, wait_config).await;
+    };
+
+    // the transaction has to be accepted to know whether it reverted
+    let wait_config = WaitForTx {
+        wait: true,
+        ..wait_config
     };
+    let mut retry = 0;
+    loop {
+        // the nonce and fee are fetched again for every attempt
+        let result = execute_calls(
+            account,
+            vec![call.clone()],
+            fee_args.clone(),
+            None,
+            wait_config,
+        )
+        .await;
+
+        match &result {
+            Err(StarknetCommandError::WaitForTransactionError(
+                WaitForTransactionError::TransactionError(TransactionError::Reverted(error)),
+            )) if retry < retries
+                && should_retry(&error.data, invoke.retry_on_message.as_deref()) =>
+            {
+                retry += 1;
+                eprintln!(
+                    "Transaction reverted, sending it again in {}s (retry {retry}/{retries})",
+                    invoke.retry_delay
+                );
+                sleep(Duration::from_secs(invoke.retry_delay)).await;
+            }
+            _ => return result,
+        }
+    }
+}
+
+fn should_retry(revert_reason: &str, retry_on_message: Option<&str>) -> bool {
+    match retry_on_message {
+        Some(message) => revert_reason.contains(message),
+        None => true,
+    }
+}
+
+pub async fn simulate_invoke(
+    invoke: Invoke,
+    function_selector: Felt,
+    account: &SingleOwnerAccount<&JsonRpcClient<HttpTransport>, LocalWallet>,
+) -> Result<SimulateResponse, StarknetCommandError> {
+    let fee_args = invoke.resolved_fee_args()?;
+    let call = invoke.call(function_selector)?;
 
-    execute_calls(account, vec![call], fee_args, invoke.nonce, wait_config).await
+    simulate_calls(account, vec![call], fee_args, invoke.nonce).await
 }
 
 pub async fn execute_calls(
@@ -86,12 +224,18 @@ pub async fn execute_calls(
         .await?;
 
     let result = match fee_settings {
-        FeeSettings::Eth { max_fee } => {
+        FeeSettings::Eth { max_fee, gas_price } => {
             let execution_calls = account.execute_v1(calls);
 
-            let execution = apply_optional(execution_calls, max_fee, ExecutionV1::max_fee);
-            let execution = apply_optional(execution, nonce, ExecutionV1::nonce);
-            execution.send().await
+            let execution = apply_optional(execution_calls, nonce, ExecutionV1::nonce);
+            match resolve_max_fee(max_fee, gas_price, execution.estimate_fee()).await {
+                Ok(max_fee) => {
+                    apply_optional(execution, max_fee, ExecutionV1::max_fee)
+                        .send()
+                        .await
+                }
+                Err(error) => Err(error),
+            }
         }
         FeeSettings::Strk {
             max_gas,
@@ -119,3 +263,57 @@ pub async fn execute_calls(
         _ => Err(anyhow!("Unknown RPC error").into()),
     }
 }
+
+pub async fn simulate_calls(
+    account: &SingleOwnerAccount<&JsonRpcClient<HttpTransport>, LocalWallet>,
+    calls: Vec<Call>,
+    fee_args: FeeArgs,
+    nonce: Option<Felt>,
+) -> Result<SimulateResponse, StarknetCommandError> {
+    let fee_settings = fee_args
+        .try_into_fee_settings(account.provider(), account.block_id())
+        .await?;
+
+    let simulated = match fee_settings {
+        FeeSettings::Eth { max_fee, gas_price } => {
+            let execution_calls = account.execute_v1(calls);
+
+            let execution = apply_optional(execution_calls, nonce, ExecutionV1::nonce);
+            match resolve_max_fee(max_fee, gas_price, execution.estimate_fee()).await {
+                Ok(max_fee) => {
+                    apply_optional(execution, max_fee, ExecutionV1::max_fee)
+                        .simulate(false, false)
+                        .await
+                }
+                Err(error) => Err(error),
+            }
+        }
+        FeeSettings::Strk {
+            max_gas,
+            max_gas_unit_price,
+        } => {
+            let execution_calls = account.execute_v3(calls);
+
+            let execution = apply_optional(execution_calls, max_gas, ExecutionV3::gas);
+            let execution = apply_optional(execution, max_gas_unit_price, ExecutionV3::gas_price);
+            let execution = apply_optional(execution, nonce, ExecutionV3::nonce);
+            execution.simulate(false, false).await
+        }
+    };
+
+    handle_simulation_result(simulated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::should_retry;
+
+    #[test]
+    fn test_should_retry() {
+        let revert_reason = "Error in the called contract: 'Oracle price is stale'";
+
+        assert!(should_retry(revert_reason, None));
+        assert!(should_retry(revert_reason, Some("price is stale")));
+        assert!(!should_retry(revert_reason, Some("Insufficient balance")));
+    }
+}
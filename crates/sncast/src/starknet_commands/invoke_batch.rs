@@ -0,0 +1,298 @@
+use crate::starknet_commands::invoke::{execute_calls, Invoke};
+use anyhow::{anyhow, ensure, Context, Result};
+use camino::Utf8Path;
+use futures::future::join_all;
+use serde::Deserialize;
+use shared::print::print_as_warning;
+use sncast::helpers::fee::{FeeArgs, FeeToken};
+use sncast::response::errors::{handle_starknet_command_error, StarknetCommandError};
+use sncast::response::structs::InvokeResponse;
+use sncast::{handle_rpc_error, handle_wait_for_tx, WaitForTx};
+use starknet::accounts::{ConnectedAccount, SingleOwnerAccount};
+use starknet::core::types::{Call, Felt};
+use starknet::core::utils::get_selector_from_name;
+use starknet::providers::jsonrpc::HttpTransport;
+use starknet::providers::JsonRpcClient;
+use starknet::signers::LocalWallet;
+use std::fs;
+
+/// Single line of a `--batch-file`
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct BatchEntry {
+    pub contract: Felt,
+    pub function: String,
+    #[serde(default)]
+    pub calldata: Vec<Felt>,
+    pub memo: Option<String>,
+}
+
+impl BatchEntry {
+    fn call(&self) -> Result<Call> {
+        Ok(Call {
+            to: self.contract,
+            selector: get_selector_from_name(&self.function)
+                .context("Failed to convert entry point selector to FieldElement")?,
+            calldata: self.calldata.clone(),
+        })
+    }
+}
+
+/// Reads entries of a batch file, skipping empty lines
+pub fn read_batch_file(path: &Utf8Path) -> Result<Vec<BatchEntry>> {
+    let contents =
+        fs::read_to_string(path).with_context(|| format!("Failed to read batch file {path}"))?;
+
+    contents
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            serde_json::from_str(line)
+                .with_context(|| format!("Invalid entry in line {} of {path}", index + 1))
+        })
+        .collect()
+}
+
+/// Sends every entry as a separate invoke transaction and calls `report` with the result of each of them.
+/// Fails once all results are reported if any of the transactions failed.
+///
+/// By default every transaction is sent after the previous one is accepted. With `--parallel` all transactions
+/// are sent one after another with consecutive nonces, and then, if `--wait` is passed, awaited concurrently
+pub async fn invoke_batch(
+    invoke: &Invoke,
+    entries: &[BatchEntry],
+    account: &SingleOwnerAccount<&JsonRpcClient<HttpTransport>, LocalWallet>,
+    wait_config: WaitForTx,
+    mut report: impl FnMut(&BatchEntry, Result<InvokeResponse>) -> Result<()>,
+) -> Result<()> {
+    let fee_args = invoke.resolved_fee_args()?;
+    let mut failed = 0;
+    let mut report = |entry: &BatchEntry, result: Result<InvokeResponse>| {
+        if result.is_err() {
+            failed += 1;
+        }
+        report(entry, result)
+    };
+
+    if invoke.parallel {
+        ensure!(
+            has_explicit_fee(&fee_args),
+            "--parallel requires the fee to be passed explicitly, as fees of transactions with future nonces can't be estimated. \
+            Pass --max-fee for ETH fee payment, or two of --max-fee, --max-gas and --max-gas-unit-price for STRK fee payment"
+        );
+    } else {
+        for (index, entry) in entries.iter().enumerate() {
+            // the nonce is fetched from the pending block once the previous transaction is accepted
+            let nonce = if index == 0 { invoke.nonce } else { None };
+            let result = match entry.call() {
+                Ok(call) => execute_calls(
+                    account,
+                    vec![call],
+                    fee_args.clone(),
+                    nonce,
+                    WaitForTx {
+                        wait: true,
+                        ..wait_config
+                    },
+                )
+                .await
+                .map_err(handle_starknet_command_error),
+                Err(error) => Err(error),
+            };
+
+            let is_err = result.is_err();
+            report(entry, result)?;
+            if is_err && !invoke.continue_on_error {
+                warn_about_skipped(entries.len() - index - 1);
+                break;
+            }
+        }
+        return ensure_all_succeeded(failed, entries.len());
+    }
+
+    let mut nonce = match invoke.nonce {
+        Some(nonce) => nonce,
+        None => account.get_nonce().await.map_err(handle_rpc_error)?,
+    };
+    let mut sent = vec![];
+
+    for (index, entry) in entries.iter().enumerate() {
+        let result = match entry.call() {
+            Ok(call) => execute_calls(
+                account,
+                vec![call],
+                fee_args.clone(),
+                Some(nonce),
+                WaitForTx {
+                    wait: false,
+                    ..wait_config
+                },
+            )
+            .await
+            .map_err(handle_starknet_command_error),
+            Err(error) => Err(error),
+        };
+
+        match result {
+            Ok(response) => {
+                // a transaction which was not received by the node doesn't use up the nonce
+                nonce += Felt::ONE;
+                if wait_config.wait {
+                    sent.push((entry, response.transaction_hash));
+                } else {
+                    report(entry, Ok(response))?;
+                }
+            }
+            Err(error) => {
+                report(entry, Err(error))?;
+                if !invoke.continue_on_error {
+                    warn_about_skipped(entries.len() - index - 1);
+                    break;
+                }
+            }
+        }
+    }
+
+    let results = join_all(sent.iter().map(|(_, transaction_hash)| {
+        handle_wait_for_tx(
+            account.provider(),
+            *transaction_hash,
+            InvokeResponse {
+                transaction_hash: *transaction_hash,
+            },
+            wait_config,
+        )
+    }))
+    .await;
+
+    for ((entry, _), result) in sent.into_iter().zip(results) {
+        report(
+            entry,
+            result
+                .map_err(|error| handle_starknet_command_error(StarknetCommandError::from(error))),
+        )?;
+    }
+
+    ensure_all_succeeded(failed, entries.len())
+}
+
+/// Checks whether the fee can be resolved without estimating it
+fn has_explicit_fee(fee_args: &FeeArgs) -> bool {
+    match fee_args.fee_token {
+        Some(FeeToken::Strk) => {
+            let passed = [
+                fee_args.max_fee,
+                fee_args.max_gas,
+                fee_args.max_gas_unit_price,
+            ];
+            passed.iter().flatten().count() >= 2
+        }
+        _ => fee_args.max_fee.is_some(),
+    }
+}
+
+fn ensure_all_succeeded(failed: usize, total: usize) -> Result<()> {
+    ensure!(
+        failed == 0,
+        "{failed} of {total} transaction(s) from the batch file failed"
+    );
+    Ok(())
+}
+
+fn warn_about_skipped(skipped: usize) {
+    if skipped > 0 {
+        print_as_warning(&anyhow!(
+            "Batch stopped after a failed transaction, {skipped} remaining transaction(s) were not sent. Pass --continue-on-error to send them anyway"
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{has_explicit_fee, read_batch_file, BatchEntry};
+    use camino::Utf8PathBuf;
+    use sncast::helpers::fee::{FeeArgs, FeeToken};
+    use starknet::core::types::Felt;
+    use std::fs;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_read_batch_file() {
+        let tempdir = tempdir().unwrap();
+        let path = Utf8PathBuf::try_from(tempdir.path().join("batch.jsonl")).unwrap();
+        fs::write(
+            &path,
+            "{\"contract\": \"0x1\", \"function\": \"put\", \"calldata\": [\"0x2\", \"0x3\"], \"memo\": \"first\"}\n\n{\"contract\": \"0x4\", \"function\": \"get\"}\n",
+        )
+        .unwrap();
+
+        let entries = read_batch_file(&path).unwrap();
+
+        assert_eq!(
+            entries,
+            vec![
+                BatchEntry {
+                    contract: Felt::ONE,
+                    function: "put".to_string(),
+                    calldata: vec![Felt::TWO, Felt::THREE],
+                    memo: Some("first".to_string()),
+                },
+                BatchEntry {
+                    contract: Felt::from(4_u8),
+                    function: "get".to_string(),
+                    calldata: vec![],
+                    memo: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_read_batch_file_invalid_line() {
+        let tempdir = tempdir().unwrap();
+        let path = Utf8PathBuf::try_from(tempdir.path().join("batch.jsonl")).unwrap();
+        fs::write(
+            &path,
+            "{\"contract\": \"0x1\", \"function\": \"put\"}\n{\"contract\": \"0x1\"}\n",
+        )
+        .unwrap();
+
+        let error = read_batch_file(&path).unwrap_err();
+
+        assert!(error.to_string().starts_with("Invalid entry in line 2"));
+    }
+
+    fn fee_args(fee_token: FeeToken) -> FeeArgs {
+        FeeArgs {
+            fee_token: Some(fee_token),
+            max_fee: None,
+            gas_price: None,
+            max_gas: None,
+            max_gas_unit_price: None,
+        }
+    }
+
+    #[test]
+    fn test_has_explicit_fee() {
+        assert!(!has_explicit_fee(&fee_args(FeeToken::Eth)));
+        assert!(!has_explicit_fee(&FeeArgs {
+            gas_price: Some(Felt::ONE),
+            ..fee_args(FeeToken::Eth)
+        }));
+        assert!(has_explicit_fee(&FeeArgs {
+            max_fee: Some(Felt::ONE),
+            ..fee_args(FeeToken::Eth)
+        }));
+
+        assert!(!has_explicit_fee(&FeeArgs {
+            max_gas: Some(Felt::ONE),
+            ..fee_args(FeeToken::Strk)
+        }));
+        assert!(has_explicit_fee(&FeeArgs {
+            max_gas: Some(Felt::ONE),
+            max_gas_unit_price: Some(Felt::ONE),
+            ..fee_args(FeeToken::Strk)
+        }));
+    }
+}
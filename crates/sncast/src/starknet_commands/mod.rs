@@ -1,10 +1,25 @@
 pub mod account;
+pub mod block;
 pub mod call;
+pub mod class;
+pub mod class_hash;
+pub mod console;
+pub mod contract;
 pub mod declare;
 pub mod deploy;
+pub mod estimate_fee;
+pub mod get_abi;
+pub mod get_block;
+pub mod get_nonce;
+pub mod get_transaction;
 pub mod invoke;
+pub mod invoke_batch;
 pub mod multicall;
+pub mod predict_fee;
 pub mod script;
 pub mod show_config;
+pub mod storage_read;
+pub mod tx;
+pub mod tx_log;
 pub mod tx_status;
 pub mod verify;
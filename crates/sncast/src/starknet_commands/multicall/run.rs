@@ -1,6 +1,6 @@
 use crate::starknet_commands::invoke::{execute_calls, InvokeVersion};
 use anyhow::anyhow;
-use anyhow::{Context, Result};
+use anyhow::{ensure, Context, Result};
 use camino::Utf8PathBuf;
 use clap::Args;
 use serde::Deserialize;
@@ -8,24 +8,30 @@ use sncast::helpers::constants::UDC_ADDRESS;
 use sncast::helpers::error::token_not_supported_for_invoke;
 use sncast::helpers::fee::{FeeArgs, FeeToken, PayableTransaction};
 use sncast::helpers::rpc::RpcArgs;
+use sncast::helpers::udc::compute_udc_deployed_address;
 use sncast::response::errors::handle_starknet_command_error;
-use sncast::response::structs::InvokeResponse;
-use sncast::{extract_or_generate_salt, impl_payable_transaction, udc_uniqueness, WaitForTx};
-use starknet::accounts::{Account, SingleOwnerAccount};
-use starknet::core::types::{Call, Felt};
-use starknet::core::utils::{get_selector_from_name, get_udc_deployed_address};
+use sncast::response::structs::{InvokeResponse, MulticallDryRunResponse};
+use sncast::{extract_or_generate_salt, handle_rpc_error, impl_payable_transaction, WaitForTx};
+use starknet::accounts::{Account, ConnectedAccount, ExecutionEncoder, SingleOwnerAccount};
+use starknet::core::types::contract::AbiEntry;
+use starknet::core::types::{BlockId, BlockTag, Call, ContractClass, Felt, LegacyContractAbiEntry};
+use starknet::core::utils::get_selector_from_name;
 use starknet::providers::jsonrpc::HttpTransport;
-use starknet::providers::JsonRpcClient;
+use starknet::providers::{JsonRpcClient, Provider};
 use starknet::signers::LocalWallet;
 use std::collections::HashMap;
 
 #[derive(Args, Debug, Clone)]
-#[command(about = "Execute a multicall from a .toml file", long_about = None)]
+#[command(about = "Execute a multicall from a .toml or .json file", long_about = None)]
 pub struct Run {
-    /// Path to the toml file with declared operations
+    /// Path to the toml or json file with declared operations
     #[clap(short = 'p', long = "path")]
     pub path: Utf8PathBuf,
 
+    /// Print calldata of the multicall without sending it
+    #[clap(long)]
+    pub dry_run: bool,
+
     #[clap(flatten)]
     pub fee_args: FeeArgs,
 
@@ -64,12 +70,37 @@ pub async fn run(
     wait_config: WaitForTx,
 ) -> Result<InvokeResponse> {
     let fee_args = run.fee_args.clone().fee_token(run.token_from_version());
+    let parsed_calls = parse_calls(&run.path, account).await?;
+
+    execute_calls(account, parsed_calls, fee_args, None, wait_config)
+        .await
+        .map_err(handle_starknet_command_error)
+}
+
+pub async fn dry_run(
+    run: Run,
+    account: &SingleOwnerAccount<&JsonRpcClient<HttpTransport>, LocalWallet>,
+) -> Result<MulticallDryRunResponse> {
+    let parsed_calls = parse_calls(&run.path, account).await?;
+
+    Ok(MulticallDryRunResponse {
+        calldata: account.encode_calls(&parsed_calls),
+    })
+}
 
-    let contents = std::fs::read_to_string(&run.path)?;
-    let items_map: HashMap<String, Vec<toml::Value>> =
-        toml::from_str(&contents).with_context(|| format!("Failed to parse {}", run.path))?;
+async fn parse_calls(
+    path: &Utf8PathBuf,
+    account: &SingleOwnerAccount<&JsonRpcClient<HttpTransport>, LocalWallet>,
+) -> Result<Vec<Call>> {
+    let contents = std::fs::read_to_string(path)?;
+    let items_map: HashMap<String, Vec<toml::Value>> = if path.extension() == Some("json") {
+        serde_json::from_str(&contents).with_context(|| format!("Failed to parse {path}"))?
+    } else {
+        toml::from_str(&contents).with_context(|| format!("Failed to parse {path}"))?
+    };
 
     let mut contracts = HashMap::new();
+    let mut deployed_class_hashes = HashMap::new();
     let mut parsed_calls: Vec<Call> = vec![];
 
     for call in items_map.get("call").unwrap_or(&vec![]) {
@@ -100,13 +131,15 @@ pub async fn run(
                     calldata,
                 });
 
-                let contract_address = get_udc_deployed_address(
+                let contract_address = compute_udc_deployed_address(
                     salt,
                     deploy_call.class_hash,
-                    &udc_uniqueness(deploy_call.unique, account.address()),
+                    deploy_call.unique,
+                    account.address(),
                     &parsed_inputs,
                 );
-                contracts.insert(deploy_call.id, contract_address.to_string());
+                contracts.insert(deploy_call.id.clone(), contract_address.to_string());
+                deployed_class_hashes.insert(deploy_call.id, deploy_call.class_hash);
             }
             Some("invoke") => {
                 let invoke_call: InvokeCall = toml::from_str(toml::to_string(&call)?.as_str())
@@ -115,13 +148,37 @@ pub async fn run(
                 if let Some(addr) = contracts.get(&invoke_call.contract_address) {
                     contract_address = addr;
                 }
+                let contract_address: Felt = contract_address
+                    .parse()
+                    .context("Failed to parse contract address to Felt")?;
+
+                // contracts deployed in the same multicall do not exist yet, so their class is used
+                let contract_class = match deployed_class_hashes.get(&invoke_call.contract_address)
+                {
+                    Some(class_hash) => {
+                        account
+                            .provider()
+                            .get_class(BlockId::Tag(BlockTag::Pending), class_hash)
+                            .await
+                    }
+                    None => {
+                        account
+                            .provider()
+                            .get_class_at(BlockId::Tag(BlockTag::Pending), contract_address)
+                            .await
+                    }
+                }
+                .map_err(handle_rpc_error)?;
+                ensure!(
+                    abi_contains_function(&contract_class, &invoke_call.function)?,
+                    "Function `{}` not found in ABI of contract at address {contract_address:#x}",
+                    invoke_call.function
+                );
 
                 let calldata = parse_inputs(&invoke_call.inputs, &contracts)?;
 
                 parsed_calls.push(Call {
-                    to: contract_address
-                        .parse()
-                        .context("Failed to parse contract address to Felt")?,
+                    to: contract_address,
                     selector: get_selector_from_name(&invoke_call.function)?,
                     calldata,
                 });
@@ -133,9 +190,30 @@ pub async fn run(
         }
     }
 
-    execute_calls(account, parsed_calls, fee_args, None, wait_config)
-        .await
-        .map_err(handle_starknet_command_error)
+    Ok(parsed_calls)
+}
+
+fn abi_contains_function(contract_class: &ContractClass, function: &str) -> Result<bool> {
+    let contains = match contract_class {
+        ContractClass::Sierra(class) => {
+            let abi: Vec<AbiEntry> =
+                serde_json::from_str(&class.abi).context("Failed to parse contract ABI")?;
+            abi.iter().any(|entry| match entry {
+                AbiEntry::Function(abi_function) => abi_function.name == function,
+                AbiEntry::Interface(interface) => interface.items.iter().any(
+                    |item| matches!(item, AbiEntry::Function(abi_function) if abi_function.name == function),
+                ),
+                _ => false,
+            })
+        }
+        ContractClass::Legacy(class) => class.abi.as_ref().is_some_and(|abi| {
+            abi.iter().any(
+                |entry| matches!(entry, LegacyContractAbiEntry::Function(abi_function) if abi_function.name == function),
+            )
+        }),
+    };
+
+    Ok(contains)
 }
 
 fn parse_inputs(inputs: &Vec<String>, contracts: &HashMap<String, String>) -> Result<Vec<Felt>> {
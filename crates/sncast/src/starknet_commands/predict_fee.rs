@@ -0,0 +1,164 @@
+use anyhow::{ensure, Context, Result};
+use clap::{Args, ValueEnum};
+use sncast::handle_rpc_error;
+use sncast::helpers::fee::FeeToken;
+use sncast::helpers::rpc::RpcArgs;
+use sncast::response::structs::{Decimal, PredictFeeResponse};
+use starknet::core::types::{
+    BlockId, MaybePendingBlockWithReceipts, PriceUnit, TransactionReceipt,
+};
+use starknet::providers::jsonrpc::HttpTransport;
+use starknet::providers::{JsonRpcClient, Provider};
+
+/// Upper bound on the number of blocks that are scanned when collecting fee samples
+const MAX_SCANNED_BLOCKS: u64 = 1000;
+
+#[derive(Args)]
+#[command(about = "Predict max fee for a transaction based on fees paid in recent blocks")]
+pub struct PredictFee {
+    /// Number of recent transactions used to compute the prediction
+    #[clap(long, default_value_t = 100)]
+    pub samples: u64,
+
+    /// Percentile of the actual fees paid that will be suggested as max fee
+    #[clap(long, default_value_t = 95, value_parser = clap::value_parser!(u8).range(1..=100))]
+    pub percentile: u8,
+
+    /// Type of transactions taken into account
+    #[clap(long, value_enum, default_value_t = TransactionType::Invoke)]
+    pub transaction_type: TransactionType,
+
+    /// Token in which the sampled fees were paid
+    #[clap(long, value_enum, default_value_t = FeeToken::Eth)]
+    pub fee_token: FeeToken,
+
+    #[clap(flatten)]
+    pub rpc: RpcArgs,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq)]
+pub enum TransactionType {
+    Invoke,
+    Declare,
+    DeployAccount,
+}
+
+impl TransactionType {
+    fn matches(self, receipt: &TransactionReceipt) -> bool {
+        matches!(
+            (self, receipt),
+            (TransactionType::Invoke, TransactionReceipt::Invoke(_))
+                | (TransactionType::Declare, TransactionReceipt::Declare(_))
+                | (
+                    TransactionType::DeployAccount,
+                    TransactionReceipt::DeployAccount(_)
+                )
+        )
+    }
+}
+
+pub async fn predict_fee(
+    predict_fee: &PredictFee,
+    provider: &JsonRpcClient<HttpTransport>,
+) -> Result<PredictFeeResponse> {
+    ensure!(predict_fee.samples > 0, "--samples must be greater than 0");
+
+    let unit = match predict_fee.fee_token {
+        FeeToken::Eth => PriceUnit::Wei,
+        FeeToken::Strk => PriceUnit::Fri,
+    };
+
+    let latest_block_number = provider.block_number().await.map_err(handle_rpc_error)?;
+
+    let mut fees = vec![];
+    let mut blocks_scanned = 0;
+    let mut block_number = latest_block_number;
+
+    while (fees.len() as u64) < predict_fee.samples && blocks_scanned < MAX_SCANNED_BLOCKS {
+        let block = provider
+            .get_block_with_receipts(BlockId::Number(block_number))
+            .await
+            .map_err(handle_rpc_error)?;
+        blocks_scanned += 1;
+
+        let transactions = match block {
+            MaybePendingBlockWithReceipts::Block(block) => block.transactions,
+            MaybePendingBlockWithReceipts::PendingBlock(block) => block.transactions,
+        };
+
+        fees.extend(
+            transactions
+                .iter()
+                .map(|transaction| &transaction.receipt)
+                .filter(|receipt| predict_fee.transaction_type.matches(receipt))
+                .map(TransactionReceipt::actual_fee)
+                .filter(|fee| fee.unit == unit)
+                .map(|fee| fee.amount),
+        );
+
+        if block_number == 0 {
+            break;
+        }
+        block_number -= 1;
+    }
+
+    fees.truncate(usize::try_from(predict_fee.samples).unwrap_or(usize::MAX));
+
+    let max_fee = percentile(&mut fees, predict_fee.percentile).with_context(|| {
+        format!(
+            "No {:?} transactions paid in {:?} found in the last {blocks_scanned} blocks",
+            predict_fee.transaction_type, predict_fee.fee_token
+        )
+    })?;
+
+    Ok(PredictFeeResponse {
+        max_fee,
+        samples: Decimal(fees.len() as u64),
+        blocks_scanned: Decimal(blocks_scanned),
+    })
+}
+
+/// Nearest-rank percentile of the given values
+fn percentile<T: Ord + Copy>(values: &mut [T], percentile: u8) -> Option<T> {
+    if values.is_empty() {
+        return None;
+    }
+    values.sort_unstable();
+
+    let rank = (usize::from(percentile) * values.len()).div_ceil(100);
+    Some(values[rank.saturating_sub(1)])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::percentile;
+
+    #[test]
+    fn test_percentile_empty() {
+        assert_eq!(percentile::<u64>(&mut [], 95), None);
+    }
+
+    #[test]
+    fn test_percentile_single_value() {
+        assert_eq!(percentile(&mut [7], 1), Some(7));
+        assert_eq!(percentile(&mut [7], 100), Some(7));
+    }
+
+    #[test]
+    fn test_percentile_nearest_rank() {
+        let mut values: Vec<u64> = (1..=100).rev().collect();
+
+        assert_eq!(percentile(&mut values, 95), Some(95));
+        assert_eq!(percentile(&mut values, 50), Some(50));
+        assert_eq!(percentile(&mut values, 100), Some(100));
+    }
+
+    #[test]
+    fn test_percentile_rounds_up() {
+        let mut values = [10, 20, 30];
+
+        assert_eq!(percentile(&mut values, 50), Some(20));
+        assert_eq!(percentile(&mut values, 34), Some(20));
+        assert_eq!(percentile(&mut values, 33), Some(10));
+    }
+}
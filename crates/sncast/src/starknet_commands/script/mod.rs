@@ -3,6 +3,7 @@ use crate::starknet_commands::script::run::Run;
 use clap::{Args, Subcommand};
 
 pub mod init;
+pub mod progress;
 pub mod run;
 
 #[derive(Args)]
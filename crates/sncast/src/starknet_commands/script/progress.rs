@@ -0,0 +1,156 @@
+use starknet::core::types::Felt;
+use std::fmt::{Display, Formatter};
+use std::sync::mpsc::{channel, Sender};
+use std::thread::{self, JoinHandle};
+
+/// Progress of a single `call`, `declare`, `deploy` or `invoke` made by a script
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProgressEvent {
+    /// Number of the operation in the script, starting from 1
+    pub step: usize,
+    pub operation: &'static str,
+    pub target: String,
+    pub status: StepStatus,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum StepStatus {
+    Pending,
+    /// The transaction was accepted
+    Confirmed {
+        transaction_hash: Felt,
+    },
+    /// The call returned a result
+    Done,
+    /// The transaction was already sent successfully in a previous run, according to the state file
+    Skipped,
+    Failed {
+        error: String,
+    },
+}
+
+impl Display for ProgressEvent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let Self {
+            step,
+            operation,
+            target,
+            status,
+        } = self;
+        write!(f, "[{step}] {operation} {target}: ")?;
+
+        match status {
+            StepStatus::Pending => write!(f, "pending"),
+            StepStatus::Confirmed { transaction_hash } => {
+                write!(f, "confirmed, transaction hash: {transaction_hash:#x}")
+            }
+            StepStatus::Done => write!(f, "done"),
+            StepStatus::Skipped => {
+                write!(f, "skipped, already completed according to the state file")
+            }
+            StepStatus::Failed { error } => write!(f, "failed: {error}"),
+        }
+    }
+}
+
+/// Sends progress events of a script, numbering its operations
+pub struct ProgressReporter {
+    sender: Option<Sender<ProgressEvent>>,
+    steps: usize,
+}
+
+impl ProgressReporter {
+    /// Reporter which discards all events, used with `--quiet`
+    #[must_use]
+    pub fn disabled() -> Self {
+        Self {
+            sender: None,
+            steps: 0,
+        }
+    }
+
+    /// Starts a new step of the script and returns its number
+    pub fn start(&mut self, operation: &'static str, target: &str) -> usize {
+        self.steps += 1;
+        self.send(self.steps, operation, target, StepStatus::Pending);
+        self.steps
+    }
+
+    /// Reports a step which did not need to be executed
+    pub fn skip(&mut self, operation: &'static str, target: &str) {
+        self.steps += 1;
+        self.send(self.steps, operation, target, StepStatus::Skipped);
+    }
+
+    pub fn finish(&self, step: usize, operation: &'static str, target: &str, status: StepStatus) {
+        self.send(step, operation, target, status);
+    }
+
+    fn send(&self, step: usize, operation: &'static str, target: &str, status: StepStatus) {
+        if let Some(sender) = &self.sender {
+            // the printer only stops once the reporter is dropped
+            let _ = sender.send(ProgressEvent {
+                step,
+                operation,
+                target: target.to_string(),
+                status,
+            });
+        }
+    }
+}
+
+/// Spawns a thread printing progress events to stderr, so that stdout only contains the result of the script.
+/// The thread finishes once the returned reporter is dropped
+#[must_use]
+pub fn spawn_progress_printer() -> (ProgressReporter, JoinHandle<()>) {
+    let (sender, receiver) = channel::<ProgressEvent>();
+    let printer = thread::spawn(move || {
+        for event in receiver {
+            eprintln!("{event}");
+        }
+    });
+
+    (
+        ProgressReporter {
+            sender: Some(sender),
+            steps: 0,
+        },
+        printer,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ProgressEvent, StepStatus};
+    use starknet::core::types::Felt;
+
+    fn event(status: StepStatus) -> String {
+        ProgressEvent {
+            step: 2,
+            operation: "deploy",
+            target: "class 0x123".to_string(),
+            status,
+        }
+        .to_string()
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            event(StepStatus::Pending),
+            "[2] deploy class 0x123: pending"
+        );
+        assert_eq!(
+            event(StepStatus::Confirmed {
+                transaction_hash: Felt::from(0xabc_u16)
+            }),
+            "[2] deploy class 0x123: confirmed, transaction hash: 0xabc"
+        );
+        assert_eq!(
+            event(StepStatus::Failed {
+                error: "Insufficient balance".to_string()
+            }),
+            "[2] deploy class 0x123: failed: Insufficient balance"
+        );
+    }
+}
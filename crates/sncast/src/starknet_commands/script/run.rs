@@ -1,16 +1,17 @@
 use crate::starknet_commands::declare::Declare;
 use crate::starknet_commands::deploy::Deploy;
 use crate::starknet_commands::invoke::Invoke;
+use crate::starknet_commands::script::progress::{ProgressReporter, StepStatus};
 use crate::starknet_commands::{call, declare, deploy, invoke, tx_status};
-use crate::{get_account, WaitForTx};
-use anyhow::{anyhow, Context, Result};
+use crate::{get_account_from_config, WaitForTx};
+use anyhow::{anyhow, bail, Context, Result};
 use blockifier::execution::deprecated_syscalls::DeprecatedSyscallSelector;
 use blockifier::execution::entry_point::CallEntryPoint;
 use blockifier::execution::execution_utils::ReadOnlySegments;
 use blockifier::execution::syscalls::hint_processor::SyscallHintProcessor;
 use blockifier::state::cached_state::CachedState;
 use cairo_lang_runner::short_string::as_cairo_short_string;
-use cairo_lang_runner::{build_hints_dict, RunResultValue, SierraCasmRunner};
+use cairo_lang_runner::{build_hints_dict, Arg, RunResultValue, SierraCasmRunner};
 use cairo_lang_sierra::program::VersionedProgram;
 use cairo_lang_sierra_to_casm::metadata::MetadataComputationConfig;
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
@@ -22,6 +23,7 @@ use camino::Utf8PathBuf;
 use clap::Args;
 use conversions::byte_array::ByteArray;
 use conversions::serde::deserialize::BufferReader;
+use conversions::serde::serialize::SerializeToFeltVec;
 use itertools::chain;
 use runtime::starknet::context::{build_context, SerializableBlockInfo};
 use runtime::starknet::state::DictStateReader;
@@ -34,27 +36,36 @@ use scarb_metadata::{Metadata, PackageMetadata};
 use semver::{Comparator, Op, Version, VersionReq};
 use shared::print::print_as_warning;
 use shared::utils::build_readable_text;
-use sncast::get_nonce;
 use sncast::helpers::configuration::CastConfig;
 use sncast::helpers::constants::SCRIPT_LIB_ARTIFACT_NAME;
 use sncast::helpers::fee::ScriptFeeSettings;
 use sncast::helpers::rpc::RpcArgs;
-use sncast::response::structs::ScriptRunResponse;
+use sncast::response::errors::StarknetCommandError;
+use sncast::response::print::{print_command_result, OutputFormat};
+use sncast::response::structs::{ScriptRunResponse, ScriptTimeoutResponse};
 use sncast::state::hashing::{
     generate_declare_tx_id, generate_deploy_tx_id, generate_invoke_tx_id,
 };
 use sncast::state::state_file::StateManager;
+use sncast::{get_nonce, AddressFormat, NumbersFormat};
 use starknet::accounts::{Account, SingleOwnerAccount};
-use starknet::core::types::{BlockId, BlockTag::Pending};
+use starknet::core::types::{BlockId, BlockTag::Pending, Felt};
 use starknet::providers::jsonrpc::HttpTransport;
 use starknet::providers::JsonRpcClient;
 use starknet::signers::LocalWallet;
 use std::collections::HashMap;
 use std::fs;
+use std::process;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 use tokio::runtime::Runtime;
 
 type ScriptStarknetContractArtifacts = StarknetContractArtifacts;
 
+/// Hashes of transactions successfully sent by the script so far
+pub type CompletedTransactions = Arc<Mutex<Vec<Felt>>>;
+
 #[derive(Args, Debug)]
 #[command(about = "Execute a deployment script")]
 pub struct Run {
@@ -69,10 +80,31 @@ pub struct Run {
     #[clap(long)]
     pub no_state_file: bool,
 
+    /// Terminate the script if it does not finish within the given number of seconds
+    #[clap(long, value_name = "SECONDS")]
+    pub script_timeout: Option<u64>,
+
+    /// Named arguments passed to the script `main` function as `ScriptArgs`
+    #[clap(long, num_args = 1.., value_name = "KEY=VALUE", value_parser = parse_script_arg)]
+    pub args: Vec<(String, String)>,
+
+    /// Do not print the progress of calls and transactions made by the script
+    #[clap(short, long)]
+    pub quiet: bool,
+
     #[clap(flatten)]
     pub rpc: RpcArgs,
 }
 
+fn parse_script_arg(arg: &str) -> Result<(String, String), String> {
+    match arg.split_once('=') {
+        Some((key, value)) if !key.is_empty() => Ok((key.to_string(), value.to_string())),
+        _ => Err(format!(
+            "Invalid script argument `{arg}`, expected `KEY=VALUE`"
+        )),
+    }
+}
+
 pub struct CastScriptExtension<'a> {
     pub provider: &'a JsonRpcClient<HttpTransport>,
     pub account: Option<&'a SingleOwnerAccount<&'a JsonRpcClient<HttpTransport>, LocalWallet>>,
@@ -80,6 +112,8 @@ pub struct CastScriptExtension<'a> {
     pub config: &'a CastConfig,
     pub artifacts: &'a HashMap<String, StarknetContractArtifacts>,
     pub state: StateManager,
+    pub completed_transactions: CompletedTransactions,
+    pub progress: ProgressReporter,
 }
 
 impl<'a> CastScriptExtension<'a> {
@@ -102,10 +136,13 @@ impl<'a> ExtensionLogic for CastScriptExtension<'a> {
     ) -> Result<CheatcodeHandlingResult, EnhancedHintError> {
         let res = match selector {
             "call" => {
-                let contract_address = input_reader.read()?;
-                let function_selector = input_reader.read()?;
+                let contract_address: Felt = input_reader.read()?;
+                let function_selector: Felt = input_reader.read()?;
                 let calldata_felts = input_reader.read()?;
 
+                let target = call_target(contract_address, function_selector);
+                let step = self.progress.start("call", &target);
+
                 let call_result = self.tokio_runtime.block_on(call::call(
                     contract_address,
                     function_selector,
@@ -113,6 +150,13 @@ impl<'a> ExtensionLogic for CastScriptExtension<'a> {
                     self.provider,
                     &BlockId::Tag(Pending),
                 ));
+
+                let status = match &call_result {
+                    Ok(_) => StepStatus::Done,
+                    Err(error) => failed(error),
+                };
+                self.progress.finish(step, "call", &target, status);
+
                 Ok(CheatcodeHandlingResult::from_serializable(call_result))
             }
             "declare" => {
@@ -126,6 +170,8 @@ impl<'a> ExtensionLogic for CastScriptExtension<'a> {
                     nonce,
                     package: None,
                     version: None,
+                    simulate_only: false,
+                    class_hash_only: false,
                     rpc: RpcArgs::default(),
                 };
 
@@ -134,9 +180,11 @@ impl<'a> ExtensionLogic for CastScriptExtension<'a> {
                 if let Some(success_output) =
                     self.state.get_output_if_success(declare_tx_id.as_str())
                 {
+                    self.progress.skip("declare", &contract);
                     return Ok(CheatcodeHandlingResult::from_serializable(success_output));
                 }
 
+                let step = self.progress.start("declare", &contract);
                 let declare_result = self.tokio_runtime.block_on(declare::declare(
                     declare,
                     self.account()?,
@@ -144,18 +192,35 @@ impl<'a> ExtensionLogic for CastScriptExtension<'a> {
                     WaitForTx {
                         wait: true,
                         wait_params: self.config.wait_params,
+                        status_to_stderr: false,
                     },
                 ));
 
+                // held while the state is updated, so the script is never terminated in between
+                let mut completed_transactions = self
+                    .completed_transactions
+                    .lock()
+                    .expect("Failed to lock completed transactions");
                 self.state.maybe_insert_tx_entry(
                     declare_tx_id.as_str(),
                     selector,
                     &declare_result,
                 )?;
+                if let Ok(response) = &declare_result {
+                    completed_transactions.push(response.transaction_hash);
+                }
+                drop(completed_transactions);
+
+                self.progress.finish(
+                    step,
+                    "declare",
+                    &contract,
+                    transaction_status(&declare_result, |response| response.transaction_hash),
+                );
                 Ok(CheatcodeHandlingResult::from_serializable(declare_result))
             }
             "deploy" => {
-                let class_hash = input_reader.read()?;
+                let class_hash: Felt = input_reader.read()?;
                 let constructor_calldata = input_reader.read()?;
                 let salt = input_reader.read()?;
                 let unique = input_reader.read()?;
@@ -170,61 +235,94 @@ impl<'a> ExtensionLogic for CastScriptExtension<'a> {
                     fee_args,
                     nonce,
                     version: None,
+                    simulate_only: false,
+                    assert_address: None,
+                    address_only: false,
                     rpc: RpcArgs::default(),
                 };
 
                 let deploy_tx_id =
                     generate_deploy_tx_id(class_hash, &deploy.constructor_calldata, salt, unique);
 
+                let target = format!("class {class_hash:#x}");
                 if let Some(success_output) =
                     self.state.get_output_if_success(deploy_tx_id.as_str())
                 {
+                    self.progress.skip("deploy", &target);
                     return Ok(CheatcodeHandlingResult::from_serializable(success_output));
                 }
 
+                let step = self.progress.start("deploy", &target);
                 let deploy_result = self.tokio_runtime.block_on(deploy::deploy(
                     deploy,
                     self.account()?,
                     WaitForTx {
                         wait: true,
                         wait_params: self.config.wait_params,
+                        status_to_stderr: false,
                     },
                 ));
 
+                // held while the state is updated, so the script is never terminated in between
+                let mut completed_transactions = self
+                    .completed_transactions
+                    .lock()
+                    .expect("Failed to lock completed transactions");
                 self.state.maybe_insert_tx_entry(
                     deploy_tx_id.as_str(),
                     selector,
                     &deploy_result,
                 )?;
-
+                if let Ok(response) = &deploy_result {
+                    completed_transactions.push(response.transaction_hash);
+                }
+                drop(completed_transactions);
+
+                self.progress.finish(
+                    step,
+                    "deploy",
+                    &target,
+                    transaction_status(&deploy_result, |response| response.transaction_hash),
+                );
                 Ok(CheatcodeHandlingResult::from_serializable(deploy_result))
             }
             "invoke" => {
-                let contract_address = input_reader.read()?;
-                let function_selector = input_reader.read()?;
+                let contract_address: Felt = input_reader.read()?;
+                let function_selector: Felt = input_reader.read()?;
                 let calldata: Vec<_> = input_reader.read()?;
                 let fee_args = input_reader.read::<ScriptFeeSettings>()?.into();
                 let nonce = input_reader.read()?;
 
                 let invoke = Invoke {
-                    contract_address,
-                    function: String::new(),
+                    contract_address: Some(contract_address),
+                    function: None,
                     calldata: calldata.clone(),
                     fee_args,
                     nonce,
                     version: None,
+                    l1_gas: None,
+                    simulate_only: false,
+                    batch_file: None,
+                    parallel: false,
+                    continue_on_error: false,
+                    retry_on_revert: None,
+                    retry_delay: 0,
+                    retry_on_message: None,
                     rpc: RpcArgs::default(),
                 };
 
                 let invoke_tx_id =
                     generate_invoke_tx_id(contract_address, function_selector, &calldata);
 
+                let target = call_target(contract_address, function_selector);
                 if let Some(success_output) =
                     self.state.get_output_if_success(invoke_tx_id.as_str())
                 {
+                    self.progress.skip("invoke", &target);
                     return Ok(CheatcodeHandlingResult::from_serializable(success_output));
                 }
 
+                let step = self.progress.start("invoke", &target);
                 let invoke_result = self.tokio_runtime.block_on(invoke::invoke(
                     invoke,
                     function_selector,
@@ -232,15 +330,31 @@ impl<'a> ExtensionLogic for CastScriptExtension<'a> {
                     WaitForTx {
                         wait: true,
                         wait_params: self.config.wait_params,
+                        status_to_stderr: false,
                     },
                 ));
 
+                // held while the state is updated, so the script is never terminated in between
+                let mut completed_transactions = self
+                    .completed_transactions
+                    .lock()
+                    .expect("Failed to lock completed transactions");
                 self.state.maybe_insert_tx_entry(
                     invoke_tx_id.as_str(),
                     selector,
                     &invoke_result,
                 )?;
-
+                if let Ok(response) = &invoke_result {
+                    completed_transactions.push(response.transaction_hash);
+                }
+                drop(completed_transactions);
+
+                self.progress.finish(
+                    step,
+                    "invoke",
+                    &target,
+                    transaction_status(&invoke_result, |response| response.transaction_hash),
+                );
                 Ok(CheatcodeHandlingResult::from_serializable(invoke_result))
             }
             "get_nonce" => {
@@ -292,6 +406,9 @@ pub fn run(
     tokio_runtime: Runtime,
     config: &CastConfig,
     state_file_path: Option<Utf8PathBuf>,
+    completed_transactions: CompletedTransactions,
+    script_args: &[(String, String)],
+    progress: ProgressReporter,
 ) -> Result<ScriptRunResponse> {
     warn_if_sncast_std_not_compatible(metadata)?;
     let artifacts = inject_lib_artifact(metadata, package_metadata, artifacts)?;
@@ -318,7 +435,26 @@ pub fn run(
     let func = runner.find_function(name_suffix.as_str())
         .context("Failed to find main function in script - please make sure `sierra-replace-ids` is not set to `false` for `dev` profile in script's Scarb.toml")?;
 
-    let (entry_code, builtins) = runner.create_entry_code(func, &Vec::new(), usize::MAX)?;
+    let takes_script_args = func.signature.param_types.iter().any(|ty| {
+        ty.debug_name
+            .as_ref()
+            .is_some_and(|name| name.ends_with("ScriptArgs"))
+    });
+    let args = if takes_script_args {
+        vec![Arg::Array(
+            serialize_script_args(script_args)
+                .into_iter()
+                .map(Arg::Value)
+                .collect(),
+        )]
+    } else {
+        if !script_args.is_empty() {
+            bail!("Script arguments were passed with `--args`, but `main` function of the script does not accept `ScriptArgs`");
+        }
+        vec![]
+    };
+
+    let (entry_code, builtins) = runner.create_entry_code(func, &args, usize::MAX)?;
     let footer = SierraCasmRunner::create_code_footer();
     let instructions = chain!(
         entry_code.iter(),
@@ -352,15 +488,10 @@ pub fn run(
         ReadOnlySegments::default(),
     );
 
-    let account = if config.account.is_empty() {
+    let account = if config.account.is_empty() && config.account_json.is_none() {
         None
     } else {
-        Some(tokio_runtime.block_on(get_account(
-            &config.account,
-            &config.accounts_file,
-            provider,
-            config.keystore.clone(),
-        ))?)
+        Some(tokio_runtime.block_on(get_account_from_config(config, provider))?)
     };
     let state = StateManager::from(state_file_path)?;
 
@@ -371,6 +502,8 @@ pub fn run(
         artifacts: &artifacts,
         account: account.as_ref(),
         state,
+        completed_transactions,
+        progress,
     };
 
     let mut cast_runtime = ExtendedRuntime {
@@ -401,6 +534,90 @@ pub fn run(
     }
 }
 
+fn call_target(contract_address: Felt, function_selector: Felt) -> String {
+    format!("{contract_address:#x}, selector {function_selector:#x}")
+}
+
+fn transaction_status<T>(
+    result: &Result<T, StarknetCommandError>,
+    transaction_hash: impl Fn(&T) -> Felt,
+) -> StepStatus {
+    match result {
+        Ok(response) => StepStatus::Confirmed {
+            transaction_hash: transaction_hash(response),
+        },
+        Err(error) => failed(error),
+    }
+}
+
+fn failed(error: &StarknetCommandError) -> StepStatus {
+    StepStatus::Failed {
+        error: error.to_string(),
+    }
+}
+
+/// Serializes script arguments the way `ScriptArgs` from `sncast_std` expects them.
+/// Values which are valid numbers are additionally passed as felts, so they can be read with `get_felt`
+fn serialize_script_args(script_args: &[(String, String)]) -> Vec<Felt> {
+    script_args
+        .iter()
+        .map(|(key, value)| {
+            let felt_value = Felt::from_str(value).ok();
+            (
+                ByteArray::from(key.as_str()),
+                ByteArray::from(value.as_str()),
+                felt_value,
+            )
+        })
+        .collect::<Vec<_>>()
+        .serialize_to_vec()
+}
+
+/// Spawns a task that terminates the process with exit code 2 once `script_timeout` seconds pass,
+/// reporting transactions completed by the script before that
+pub fn exit_on_script_timeout(
+    tokio_runtime: &Runtime,
+    script_timeout: u64,
+    completed_transactions: CompletedTransactions,
+    numbers_format: NumbersFormat,
+    address_format: AddressFormat,
+    output_format: OutputFormat,
+) {
+    tokio_runtime.spawn(async move {
+        tokio::time::sleep(Duration::from_secs(script_timeout)).await;
+
+        let completed_transactions = completed_transactions
+            .lock()
+            .expect("Failed to lock completed transactions");
+        if !completed_transactions.is_empty() {
+            let result = Ok(ScriptTimeoutResponse {
+                completed_transactions: completed_transactions.clone(),
+            });
+            print_command_result(
+                "script run",
+                &result,
+                numbers_format,
+                address_format,
+                output_format,
+            )
+            .expect("Failed to print completed transactions");
+        }
+
+        let result: Result<ScriptRunResponse> =
+            Err(anyhow!("Script timed out after {script_timeout} seconds."));
+        print_command_result(
+            "script run",
+            &result,
+            numbers_format,
+            address_format,
+            output_format,
+        )
+        .expect("Failed to print script timeout error");
+
+        process::exit(2);
+    });
+}
+
 fn sncast_std_version_requirement() -> VersionReq {
     let version = Version::parse(env!("CARGO_PKG_VERSION")).unwrap();
     let comparator = Comparator {
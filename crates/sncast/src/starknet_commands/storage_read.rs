@@ -0,0 +1,58 @@
+use anyhow::Result;
+use clap::{ArgGroup, Args};
+use sncast::helpers::rpc::RpcArgs;
+use sncast::helpers::storage::storage_key;
+use sncast::response::structs::StorageReadResponse;
+use sncast::{get_block_id, handle_rpc_error};
+use starknet::core::types::Felt;
+use starknet::providers::jsonrpc::HttpTransport;
+use starknet::providers::{JsonRpcClient, Provider};
+
+#[derive(Args)]
+#[command(about = "Read a raw storage slot of a deployed contract", long_about = None)]
+#[command(group(ArgGroup::new("storage_slot").required(true).args(["key", "variable"])))]
+pub struct StorageRead {
+    /// Address of the contract (hex)
+    #[clap(short = 'd', long)]
+    pub contract_address: Felt,
+
+    /// Storage key to read
+    #[clap(short, long)]
+    pub key: Option<Felt>,
+
+    /// Name of the storage variable to read, the key is computed as `sn_keccak` of the name
+    #[clap(long)]
+    pub variable: Option<String>,
+
+    /// Name of the storage node field stored in the variable
+    #[clap(long, requires = "variable")]
+    pub field: Option<String>,
+
+    /// Block identifier on which storage should be read.
+    /// Possible values: pending, latest, block hash (0x prefixed string)
+    /// and block number (u64)
+    #[clap(short, long, default_value = "pending")]
+    pub block_id: String,
+
+    #[clap(flatten)]
+    pub rpc: RpcArgs,
+}
+
+pub async fn storage_read(
+    storage_read: &StorageRead,
+    provider: &JsonRpcClient<HttpTransport>,
+) -> Result<StorageReadResponse> {
+    let key = match (&storage_read.key, &storage_read.variable) {
+        (Some(key), _) => *key,
+        (None, Some(variable)) => storage_key(variable, storage_read.field.as_deref())?,
+        (None, None) => unreachable!("Either --key or --variable is required"),
+    };
+    let block_id = get_block_id(&storage_read.block_id)?;
+
+    let value = provider
+        .get_storage_at(storage_read.contract_address, key, block_id)
+        .await
+        .map_err(handle_rpc_error)?;
+
+    Ok(StorageReadResponse { key, value })
+}
@@ -0,0 +1,170 @@
+use anyhow::{anyhow, bail, ensure, Context, Result};
+use clap::Args;
+use shared::print::print_as_warning;
+use sncast::helpers::constants::{ETH_ADDRESS, STRK_ADDRESS};
+use sncast::helpers::rpc::RpcArgs;
+use sncast::response::structs::TxCancelResponse;
+use sncast::{handle_rpc_error, handle_wait_for_tx, WaitForTx};
+use starknet::accounts::{Account, AccountError, ConnectedAccount, SingleOwnerAccount};
+use starknet::core::types::{
+    Call, Felt, InvokeTransaction, InvokeTransactionResult, Transaction, TransactionStatus,
+};
+use starknet::core::utils::get_selector_from_name;
+use starknet::providers::jsonrpc::HttpTransport;
+use starknet::providers::{JsonRpcClient, Provider};
+use starknet::signers::LocalWallet;
+
+#[derive(Args, Debug)]
+#[command(about = "Cancel a pending transaction by replacing it with a zero-value self-transfer")]
+pub struct Cancel {
+    /// Hash of the transaction to cancel
+    pub transaction_hash: Felt,
+
+    /// Multiplier applied to the max fee (v1) or max L1 gas price (v3) of the cancelled transaction
+    #[clap(long, default_value_t = 1.1)]
+    pub fee_multiplier: f64,
+
+    #[clap(flatten)]
+    pub rpc: RpcArgs,
+}
+
+/// Fee settings of the cancelled transaction the replacement has to outbid
+enum ReplacedFee {
+    V1 {
+        max_fee: u128,
+    },
+    V3 {
+        max_gas: u64,
+        max_gas_unit_price: u128,
+    },
+}
+
+pub async fn cancel(
+    cancel: &Cancel,
+    account: &SingleOwnerAccount<&JsonRpcClient<HttpTransport>, LocalWallet>,
+    wait_config: WaitForTx,
+) -> Result<TxCancelResponse> {
+    ensure!(
+        cancel.fee_multiplier > 1.0,
+        "--fee-multiplier must be greater than 1, otherwise the replacement is not accepted"
+    );
+
+    let provider = account.provider();
+    let status = provider
+        .get_transaction_status(cancel.transaction_hash)
+        .await
+        .map_err(handle_rpc_error)?;
+    if !matches!(status, TransactionStatus::Received) {
+        print_as_warning(&anyhow!(
+            "Transaction {:#x} has already been included in a block, so it can no longer be cancelled",
+            cancel.transaction_hash
+        ));
+        bail!("Transaction was not cancelled");
+    }
+
+    let transaction = provider
+        .get_transaction_by_hash(cancel.transaction_hash)
+        .await
+        .map_err(handle_rpc_error)?;
+    let (sender_address, nonce, replaced_fee) = match transaction {
+        Transaction::Invoke(InvokeTransaction::V1(tx)) => (
+            tx.sender_address,
+            tx.nonce,
+            ReplacedFee::V1 {
+                max_fee: u128::try_from(tx.max_fee).context("Max fee does not fit in u128")?,
+            },
+        ),
+        Transaction::Invoke(InvokeTransaction::V3(tx)) => (
+            tx.sender_address,
+            tx.nonce,
+            ReplacedFee::V3 {
+                max_gas: tx.resource_bounds.l1_gas.max_amount,
+                max_gas_unit_price: tx.resource_bounds.l1_gas.max_price_per_unit,
+            },
+        ),
+        _ => bail!("Only v1 and v3 invoke transactions can be cancelled"),
+    };
+    ensure!(
+        sender_address == account.address(),
+        "Transaction {:#x} was sent by {sender_address:#x}, not by the used account {:#x}",
+        cancel.transaction_hash,
+        account.address()
+    );
+
+    let result = match replaced_fee {
+        ReplacedFee::V1 { max_fee } => {
+            account
+                .execute_v1(vec![self_transfer(ETH_ADDRESS, sender_address)])
+                .nonce(nonce)
+                .max_fee(Felt::from(scale(max_fee, cancel.fee_multiplier)))
+                .send()
+                .await
+        }
+        ReplacedFee::V3 {
+            max_gas,
+            max_gas_unit_price,
+        } => {
+            account
+                .execute_v3(vec![self_transfer(STRK_ADDRESS, sender_address)])
+                .nonce(nonce)
+                .gas(max_gas)
+                .gas_price(scale(max_gas_unit_price, cancel.fee_multiplier))
+                .send()
+                .await
+        }
+    };
+
+    let transaction_hash = match result {
+        Ok(InvokeTransactionResult { transaction_hash }) => transaction_hash,
+        Err(AccountError::Provider(error)) => return Err(handle_rpc_error(error)),
+        Err(error) => bail!("Failed to send the replacement transaction: {error}"),
+    };
+
+    let response = TxCancelResponse {
+        transaction_hash,
+        cancelled_transaction_hash: cancel.transaction_hash,
+        nonce,
+    };
+    Ok(handle_wait_for_tx(
+        provider,
+        transaction_hash,
+        response,
+        WaitForTx {
+            wait: true,
+            ..wait_config
+        },
+    )
+    .await?)
+}
+
+/// Transfer of 0 tokens from the account to itself
+fn self_transfer(token_address: Felt, account_address: Felt) -> Call {
+    Call {
+        to: token_address,
+        selector: get_selector_from_name("transfer").expect("Failed to get transfer selector"),
+        // recipient and u256 amount split into low and high parts
+        calldata: vec![account_address, Felt::ZERO, Felt::ZERO],
+    }
+}
+
+/// Multiplies `value` by `multiplier`, rounding up so that the result is always greater than `value`
+fn scale(value: u128, multiplier: f64) -> u128 {
+    // multiplier is applied with a precision of 0.001 to avoid floating point precision loss for large fees
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let per_mille = (multiplier * 1000.0).round() as u128;
+    let scaled = value.saturating_mul(per_mille).div_ceil(1000);
+    scaled.max(value.saturating_add(1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::scale;
+
+    #[test]
+    fn test_scale() {
+        assert_eq!(scale(1000, 1.1), 1100);
+        assert_eq!(scale(1001, 1.1), 1102);
+        assert_eq!(scale(0, 1.1), 1);
+        assert_eq!(scale(10, 1.01), 11);
+    }
+}
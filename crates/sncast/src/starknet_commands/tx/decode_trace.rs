@@ -0,0 +1,304 @@
+use anyhow::{anyhow, bail, Context, Result};
+use camino::{Utf8Path, Utf8PathBuf};
+use clap::Args;
+use sncast::handle_rpc_error;
+use sncast::helpers::abi_decoder::{
+    decode_function_call, decode_return_value, flatten_abi, parse_abi,
+};
+use sncast::helpers::hex::join_hex;
+use sncast::helpers::rpc::RpcArgs;
+use sncast::response::structs::TxDecodeTraceResponse;
+use starknet::core::types::contract::AbiEntry;
+use starknet::core::types::{
+    BlockId, BlockTag, ContractClass, ExecuteInvocation, Felt, FunctionInvocation, TransactionTrace,
+};
+use starknet::core::utils::get_selector_from_name;
+use starknet::providers::jsonrpc::HttpTransport;
+use starknet::providers::{JsonRpcClient, Provider, ProviderError};
+use std::collections::HashMap;
+use std::fs;
+
+const INDENT: &str = "  ";
+
+#[derive(Args, Debug)]
+#[command(about = "Print the call tree of a transaction with decoded arguments and return values")]
+pub struct DecodeTrace {
+    /// Hash of the transaction
+    pub transaction_hash: Felt,
+
+    /// Directory with ABIs of classes named `<CLASS_HASH>.json`, used instead of fetching them from the network
+    #[clap(long)]
+    pub abi_dir: Option<Utf8PathBuf>,
+
+    #[clap(flatten)]
+    pub rpc: RpcArgs,
+}
+
+/// Top level invocation of a transaction, e.g. `execute` or `validate`
+struct Section<'a> {
+    name: &'static str,
+    invocation: Result<&'a FunctionInvocation, &'a str>,
+}
+
+pub async fn decode_trace(
+    decode_trace: &DecodeTrace,
+    provider: &JsonRpcClient<HttpTransport>,
+) -> Result<TxDecodeTraceResponse> {
+    let trace = match provider
+        .trace_transaction(decode_trace.transaction_hash)
+        .await
+    {
+        Ok(trace) => trace,
+        Err(ProviderError::Other(error)) => bail!(
+            "Failed to trace the transaction, the node may not support `starknet_traceTransaction`: {error}"
+        ),
+        Err(error) => return Err(handle_rpc_error(error)),
+    };
+    let sections = sections(&trace);
+
+    let mut abis = match &decode_trace.abi_dir {
+        Some(abi_dir) => read_abi_dir(abi_dir)?,
+        None => HashMap::new(),
+    };
+
+    let mut class_hashes = vec![];
+    for section in &sections {
+        if let Ok(invocation) = section.invocation {
+            collect_class_hashes(invocation, &mut class_hashes);
+        }
+    }
+    for class_hash in class_hashes {
+        if !abis.contains_key(&class_hash) {
+            // classes without an available ABI are printed with raw selectors and values
+            if let Some(abi) = fetch_abi(provider, class_hash).await {
+                abis.insert(class_hash, abi);
+            }
+        }
+    }
+
+    let mut lines = vec![];
+    for section in &sections {
+        match section.invocation {
+            Ok(invocation) => {
+                lines.push(format!("{}:", section.name));
+                format_invocation(invocation, &abis, 1, &mut lines);
+            }
+            Err(revert_reason) => {
+                lines.push(format!("{}: reverted: {revert_reason}", section.name));
+            }
+        }
+    }
+
+    Ok(TxDecodeTraceResponse {
+        trace: lines.join("\n"),
+    })
+}
+
+fn sections(trace: &TransactionTrace) -> Vec<Section<'_>> {
+    let (validate, main, fee_transfer) = match trace {
+        TransactionTrace::Invoke(trace) => {
+            let execute = match &trace.execute_invocation {
+                ExecuteInvocation::Success(invocation) => Ok(invocation),
+                ExecuteInvocation::Reverted(reverted) => Err(reverted.revert_reason.as_str()),
+            };
+            (
+                trace.validate_invocation.as_ref(),
+                Some(("execute", execute)),
+                trace.fee_transfer_invocation.as_ref(),
+            )
+        }
+        TransactionTrace::DeployAccount(trace) => (
+            trace.validate_invocation.as_ref(),
+            Some(("constructor", Ok(&trace.constructor_invocation))),
+            trace.fee_transfer_invocation.as_ref(),
+        ),
+        TransactionTrace::Declare(trace) => (
+            trace.validate_invocation.as_ref(),
+            None,
+            trace.fee_transfer_invocation.as_ref(),
+        ),
+        TransactionTrace::L1Handler(trace) => (
+            None,
+            Some(("l1_handler", Ok(&trace.function_invocation))),
+            None,
+        ),
+    };
+
+    validate
+        .map(|invocation| Section {
+            name: "validate",
+            invocation: Ok(invocation),
+        })
+        .into_iter()
+        .chain(main.map(|(name, invocation)| Section { name, invocation }))
+        .chain(fee_transfer.map(|invocation| Section {
+            name: "fee_transfer",
+            invocation: Ok(invocation),
+        }))
+        .collect()
+}
+
+/// Reads ABIs from files named after class hashes, containing either the ABI or the whole contract class
+fn read_abi_dir(abi_dir: &Utf8Path) -> Result<HashMap<Felt, Vec<AbiEntry>>> {
+    let mut abis = HashMap::new();
+    let entries =
+        fs::read_dir(abi_dir).with_context(|| format!("Failed to read directory {abi_dir}"))?;
+
+    for entry in entries {
+        let path = Utf8PathBuf::try_from(entry?.path())?;
+        if path.extension() != Some("json") {
+            continue;
+        }
+        let class_hash = path
+            .file_stem()
+            .and_then(|stem| Felt::from_hex(stem).ok())
+            .ok_or_else(|| {
+                anyhow!("ABI file {path} must be named after a class hash, e.g. 0x123.json")
+            })?;
+        let contents =
+            fs::read_to_string(&path).with_context(|| format!("Failed to read file {path}"))?;
+        let abi = parse_abi(&contents).with_context(|| format!("Invalid ABI file {path}"))?;
+        abis.insert(class_hash, abi);
+    }
+
+    Ok(abis)
+}
+
+async fn fetch_abi(
+    provider: &JsonRpcClient<HttpTransport>,
+    class_hash: Felt,
+) -> Option<Vec<AbiEntry>> {
+    let class = provider
+        .get_class(BlockId::Tag(BlockTag::Latest), class_hash)
+        .await
+        .ok()?;
+    match class {
+        ContractClass::Sierra(class) => parse_abi(&class.abi).ok(),
+        ContractClass::Legacy(_) => None,
+    }
+}
+
+fn collect_class_hashes(invocation: &FunctionInvocation, class_hashes: &mut Vec<Felt>) {
+    if !class_hashes.contains(&invocation.class_hash) {
+        class_hashes.push(invocation.class_hash);
+    }
+    for call in &invocation.calls {
+        collect_class_hashes(call, class_hashes);
+    }
+}
+
+fn format_invocation(
+    invocation: &FunctionInvocation,
+    abis: &HashMap<Felt, Vec<AbiEntry>>,
+    depth: usize,
+    lines: &mut Vec<String>,
+) {
+    let call = format_call(
+        abis.get(&invocation.class_hash).map(Vec::as_slice),
+        invocation.entry_point_selector,
+        &invocation.calldata,
+        &invocation.result,
+    );
+    lines.push(format!(
+        "{}{:#x} {call}",
+        INDENT.repeat(depth),
+        invocation.contract_address
+    ));
+
+    for call in &invocation.calls {
+        format_invocation(call, abis, depth + 1, lines);
+    }
+}
+
+/// Formats a single call as `name(arguments) -> result`, falling back to raw values
+/// when the ABI is unknown or does not match the call
+fn format_call(
+    abi: Option<&[AbiEntry]>,
+    selector: Felt,
+    calldata: &[Felt],
+    result: &[Felt],
+) -> String {
+    let decoded = abi.and_then(|abi| {
+        let call = decode_function_call(abi, selector, calldata).ok()?;
+        let result = function_name(abi, selector)
+            .and_then(|name| decode_return_value(abi, &name, result).ok())
+            .unwrap_or_else(|| format!("[{}]", join_hex(result, ", ")));
+        Some((call, result))
+    });
+
+    match decoded {
+        Some((call, result)) if result.is_empty() => call,
+        Some((call, result)) => format!("{call} -> {result}"),
+        None => format!(
+            "selector {selector:#x}, calldata: [{}], result: [{}]",
+            join_hex(calldata, ", "),
+            join_hex(result, ", ")
+        ),
+    }
+}
+
+fn function_name(abi: &[AbiEntry], selector: Felt) -> Option<String> {
+    flatten_abi(abi).into_iter().find_map(|entry| match entry {
+        AbiEntry::Function(function)
+            if get_selector_from_name(&function.name).ok() == Some(selector) =>
+        {
+            Some(function.name.clone())
+        }
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::format_call;
+    use starknet::core::types::contract::{
+        AbiEntry, AbiFunction, AbiNamedMember, AbiOutput, StateMutability,
+    };
+    use starknet::core::types::Felt;
+    use starknet::core::utils::get_selector_from_name;
+
+    fn abi() -> Vec<AbiEntry> {
+        vec![AbiEntry::Function(AbiFunction {
+            name: "transfer".to_string(),
+            inputs: vec![
+                AbiNamedMember {
+                    name: "recipient".to_string(),
+                    r#type: "core::starknet::contract_address::ContractAddress".to_string(),
+                },
+                AbiNamedMember {
+                    name: "amount".to_string(),
+                    r#type: "core::integer::u256".to_string(),
+                },
+            ],
+            outputs: vec![AbiOutput {
+                r#type: "core::bool".to_string(),
+            }],
+            state_mutability: StateMutability::External,
+        })]
+    }
+
+    #[test]
+    fn test_format_call() {
+        let selector = get_selector_from_name("transfer").unwrap();
+        let calldata = [Felt::from(0x123_u16), Felt::from(100_u8), Felt::ZERO];
+
+        assert_eq!(
+            format_call(Some(&abi()), selector, &calldata, &[Felt::ONE]),
+            "transfer(recipient: 0x123, amount: 100) -> true"
+        );
+        assert_eq!(
+            format_call(None, selector, &calldata, &[Felt::ONE]),
+            format!("selector {selector:#x}, calldata: [0x123, 0x64, 0x0], result: [0x1]")
+        );
+    }
+
+    #[test]
+    fn test_format_call_mismatched_abi() {
+        let calldata = [Felt::ONE];
+
+        assert_eq!(
+            format_call(Some(&abi()), Felt::TWO, &calldata, &[]),
+            "selector 0x2, calldata: [0x1], result: []"
+        );
+    }
+}
@@ -0,0 +1,20 @@
+use clap::{Args, Subcommand};
+
+pub mod cancel;
+pub mod decode_trace;
+
+use cancel::Cancel;
+use decode_trace::DecodeTrace;
+
+#[derive(Args)]
+#[command(about = "Manage sent transactions", long_about = None)]
+pub struct Tx {
+    #[clap(subcommand)]
+    pub command: Commands,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Commands {
+    Cancel(Cancel),
+    DecodeTrace(DecodeTrace),
+}
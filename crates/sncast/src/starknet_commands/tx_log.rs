@@ -0,0 +1,40 @@
+use anyhow::Result;
+use clap::Args;
+use sncast::helpers::tx_log::{default_tx_log_path, read_tx_log, TxLogEntry};
+use sncast::response::print::OutputFormat;
+use sncast::NumbersFormat;
+
+#[derive(Args)]
+#[command(about = "List transactions annotated with `--memo`", long_about = None)]
+pub struct TxLog {}
+
+fn format_hash(entry: &TxLogEntry, numbers_format: NumbersFormat) -> String {
+    match numbers_format {
+        NumbersFormat::Decimal => format!("{:#}", entry.transaction_hash),
+        NumbersFormat::Default | NumbersFormat::Hex => format!("{:#x}", entry.transaction_hash),
+    }
+}
+
+pub fn print_tx_log(numbers_format: NumbersFormat, output_format: OutputFormat) -> Result<()> {
+    let path = default_tx_log_path();
+    let entries = read_tx_log(&path)?;
+
+    match output_format {
+        OutputFormat::Json => print!("{}", serde_json::to_string_pretty(&entries)?),
+        OutputFormat::Human => {
+            if entries.is_empty() {
+                println!("No transactions logged at {path}");
+                return Ok(());
+            }
+
+            println!("Logged transactions (at {path}):");
+            for entry in &entries {
+                println!("- {}:", format_hash(entry, numbers_format));
+                println!("  memo: {}", entry.memo);
+                println!("  timestamp: {}", entry.timestamp);
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -2,9 +2,12 @@ use clap::Args;
 use sncast::helpers::rpc::RpcArgs;
 use sncast::response::errors::StarknetCommandError;
 use sncast::response::structs::{ExecutionStatus, FinalityStatus, TransactionStatusResponse};
-use starknet::core::types::{Felt, TransactionExecutionStatus, TransactionStatus};
+use sncast::WaitForTransactionError;
+use starknet::core::types::{Felt, StarknetError, TransactionExecutionStatus, TransactionStatus};
 use starknet::providers::jsonrpc::HttpTransport;
-use starknet::providers::{JsonRpcClient, Provider};
+use starknet::providers::{JsonRpcClient, Provider, ProviderError};
+use std::time::{Duration, Instant};
+use tokio::time::sleep;
 
 #[derive(Args)]
 #[command(about = "Get the status of a transaction")]
@@ -12,6 +15,18 @@ pub struct TxStatus {
     /// Hash of the transaction
     pub transaction_hash: Felt,
 
+    /// Poll the transaction status until the transaction is accepted, rejected or reverted
+    #[clap(long)]
+    pub wait: bool,
+
+    /// Interval between transaction status checks in seconds
+    #[clap(long, value_name = "SECONDS", default_value_t = 5, requires = "wait")]
+    pub poll_interval: u64,
+
+    /// Time in seconds after which polling is stopped, if not passed polling never stops
+    #[clap(long, value_name = "SECONDS", requires = "wait")]
+    pub timeout: Option<u64>,
+
     #[clap(flatten)]
     pub rpc: RpcArgs,
 }
@@ -27,6 +42,66 @@ pub async fn tx_status(
         .map_err(|error| StarknetCommandError::ProviderError(error.into()))
 }
 
+/// Polls the status of the transaction until it is accepted on L2 or L1 or rejected,
+/// printing the status to stderr every time it changes
+pub async fn wait_for_tx_status(
+    provider: &JsonRpcClient<HttpTransport>,
+    transaction_hash: Felt,
+    poll_interval: Duration,
+    timeout: Option<Duration>,
+) -> Result<TransactionStatusResponse, StarknetCommandError> {
+    let start = Instant::now();
+    let mut last_status = None;
+
+    loop {
+        match provider.get_transaction_status(transaction_hash).await {
+            Ok(status) => {
+                let description = describe_status(&status);
+                if last_status.as_ref() != Some(&description) {
+                    eprintln!("Transaction status: {description}");
+                    last_status = Some(description);
+                }
+                if !matches!(status, TransactionStatus::Received) {
+                    return Ok(build_transaction_status_response(&status));
+                }
+            }
+            Err(ProviderError::StarknetError(StarknetError::TransactionHashNotFound)) => {}
+            Err(error) => return Err(StarknetCommandError::ProviderError(error.into())),
+        }
+
+        if timeout.is_some_and(|timeout| start.elapsed() >= timeout) {
+            return Err(WaitForTransactionError::TimedOut.into());
+        }
+        sleep(poll_interval).await;
+    }
+}
+
+fn describe_status(status: &TransactionStatus) -> String {
+    match status {
+        TransactionStatus::Received => "RECEIVED".to_string(),
+        TransactionStatus::Rejected => "REJECTED".to_string(),
+        TransactionStatus::AcceptedOnL2(execution_status) => {
+            format!(
+                "ACCEPTED_ON_L2 ({})",
+                describe_execution_status(*execution_status)
+            )
+        }
+        TransactionStatus::AcceptedOnL1(execution_status) => {
+            format!(
+                "ACCEPTED_ON_L1 ({})",
+                describe_execution_status(*execution_status)
+            )
+        }
+    }
+}
+
+fn describe_execution_status(status: TransactionExecutionStatus) -> &'static str {
+    match status {
+        TransactionExecutionStatus::Succeeded => "SUCCEEDED",
+        TransactionExecutionStatus::Reverted => "REVERTED",
+    }
+}
+
 fn build_transaction_status_response(status: &TransactionStatus) -> TransactionStatusResponse {
     match status {
         TransactionStatus::Received => TransactionStatusResponse {
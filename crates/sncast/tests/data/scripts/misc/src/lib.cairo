@@ -1,3 +1,4 @@
 mod call_happy;
 mod call_fail;
 mod using_starknet_syscall;
+mod script_args;
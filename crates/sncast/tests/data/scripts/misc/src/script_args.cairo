@@ -0,0 +1,12 @@
+use sncast_std::{ScriptArgs, ScriptArgsTrait};
+
+fn main(args: ScriptArgs) {
+    let token_name = args.get("TOKEN_NAME").unwrap();
+    assert(token_name == "MyToken", 'invalid TOKEN_NAME');
+
+    let initial_supply = args.get_felt("INITIAL_SUPPLY").unwrap();
+    assert(initial_supply == 1000000, 'invalid INITIAL_SUPPLY');
+
+    assert(args.get("MISSING").is_none(), 'MISSING should not be set');
+    assert(args.get_felt("TOKEN_NAME").is_none(), 'TOKEN_NAME is not a number');
+}
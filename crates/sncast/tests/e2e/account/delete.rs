@@ -2,7 +2,10 @@ use crate::helpers::constants::ACCOUNT_FILE_PATH;
 use crate::helpers::runner::runner;
 use crate::{e2e::account::helpers::create_tempdir_with_accounts_file, helpers::constants::URL};
 use indoc::indoc;
+use serde_json::Value;
 use shared::test_utils::output_assert::{assert_stderr_contains, AsOutput};
+use std::fs;
+use tempfile::tempdir;
 
 #[test]
 pub fn test_no_accounts_in_network() {
@@ -194,3 +197,113 @@ pub fn test_accept_only_url_or_network() {
         "},
     );
 }
+
+#[test]
+pub fn test_deployed_account_without_force() {
+    let accounts_file_name = "temp_accounts.json";
+    let temp_dir = create_tempdir_with_accounts_file(accounts_file_name, true);
+
+    let args = vec![
+        "--accounts-file",
+        &accounts_file_name,
+        "account",
+        "delete",
+        "--name",
+        "user4",
+        "--network",
+        "custom-network",
+        "--yes",
+    ];
+
+    let snapbox = runner(&args).current_dir(temp_dir.path());
+
+    let output = snapbox.assert().success();
+    assert_stderr_contains(
+        output,
+        indoc! {r"
+        command: account delete
+        error: Account user4 is marked as deployed, pass --force to delete it anyway
+        "},
+    );
+}
+
+#[test]
+pub fn test_deployed_account_with_force() {
+    let accounts_file_name = "temp_accounts.json";
+    let temp_dir = create_tempdir_with_accounts_file(accounts_file_name, true);
+
+    let args = vec![
+        "--accounts-file",
+        &accounts_file_name,
+        "account",
+        "delete",
+        "--name",
+        "user4",
+        "--network",
+        "custom-network",
+        "--yes",
+        "--force",
+    ];
+
+    let snapbox = runner(&args).current_dir(temp_dir.path());
+
+    snapbox.assert().success().stdout_matches(indoc! {r"
+        command: account delete
+        result: Account successfully removed
+    "});
+
+    let contents = fs::read_to_string(temp_dir.path().join(accounts_file_name))
+        .expect("Unable to read the accounts file");
+    let items: Value = serde_json::from_str(&contents).expect("Failed to parse the accounts file");
+    assert!(items["custom-network"]["user4"].is_null());
+    assert!(!items["custom-network"]["user3"].is_null());
+}
+
+#[test]
+pub fn test_accounts_file_empty_after_deletion() {
+    let accounts_file_name = "temp_accounts.json";
+    let temp_dir = tempdir().expect("Unable to create a temporary directory");
+    fs::write(
+        temp_dir.path().join(accounts_file_name),
+        indoc! {r#"
+        {
+            "custom-network": {
+                "user3": {
+                    "private_key": "0xe3e70682c2094cac629f6fbed82c07cd",
+                    "public_key": "0x7e52885445756b313ea16849145363ccb73fb4ab0440dbac333cf9d13de82b9",
+                    "address": "0x7e00d496e324876bbc8531f2d9a82bf154d1a04a50218ee74cdd372f75a551a"
+                }
+            }
+        }
+        "#},
+    )
+    .expect("Unable to write the accounts file");
+
+    let args = vec![
+        "--accounts-file",
+        &accounts_file_name,
+        "account",
+        "delete",
+        "--name",
+        "user3",
+        "--network",
+        "custom-network",
+        "--yes",
+    ];
+
+    let snapbox = runner(&args).current_dir(temp_dir.path());
+
+    snapbox.assert().success().stdout_matches(indoc! {r"
+        command: account delete
+        result: Account successfully removed
+    "});
+
+    let contents = fs::read_to_string(temp_dir.path().join(accounts_file_name))
+        .expect("Unable to read the accounts file");
+    assert_eq!(contents, "{}");
+
+    // the emptied file is still a valid accounts file
+    let args = vec!["--accounts-file", &accounts_file_name, "account", "list"];
+    let snapbox = runner(&args).current_dir(temp_dir.path());
+    snapbox.assert().success();
+}
@@ -54,6 +54,7 @@ pub async fn test_happy_case_eth(class_hash: &str, account_type: &str) {
     let stdout_str = bdg.as_stdout();
     assert!(stdout_str.contains("account deploy"));
     assert!(stdout_str.contains("transaction_hash"));
+    assert!(bdg.as_stderr().contains("Estimated fee: "));
 
     let contents = fs::read_to_string(tempdir.path().join(accounts_file)).unwrap();
     let items: Value = serde_json::from_str(&contents).expect("Failed to parse accounts file at ");
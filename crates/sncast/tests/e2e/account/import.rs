@@ -9,6 +9,8 @@ use conversions::string::IntoHexStr;
 use indoc::{formatdoc, indoc};
 use serde_json::json;
 use shared::test_utils::output_assert::assert_stderr_contains;
+use sncast::helpers::constants::KEYSTORE_PASSWORD_ENV_VAR;
+use std::env;
 use std::fs::{self, File};
 use tempfile::tempdir;
 use test_case::test_case;
@@ -345,7 +347,6 @@ pub async fn test_missing_arguments() {
         indoc! {r"
         error: the following required arguments were not provided:
           --address <ADDRESS>
-          --type <ACCOUNT_TYPE>
         "},
     );
 }
@@ -683,3 +684,80 @@ pub async fn test_invalid_address_computation() {
         error: Computed address {computed_address} does not match the provided address 0x123. Please ensure that the provided salt, class hash, and account type are correct.
     "});
 }
+
+#[tokio::test]
+pub async fn test_private_key_from_keystore() {
+    let tempdir = tempdir().expect("Unable to create a temporary directory");
+    let accounts_file = "accounts.json";
+    let keystore_path = Utf8PathBuf::from("tests/data/keystore/predeployed_key.json")
+        .canonicalize_utf8()
+        .unwrap();
+    env::set_var(KEYSTORE_PASSWORD_ENV_VAR, "123");
+
+    let args = vec![
+        "--accounts-file",
+        accounts_file,
+        "account",
+        "import",
+        "--url",
+        URL,
+        "--name",
+        "my_account_import",
+        "--address",
+        "0x123",
+        "--keystore",
+        keystore_path.as_str(),
+        "--class-hash",
+        DEVNET_OZ_CLASS_HASH_CAIRO_0,
+        "--type",
+        "oz",
+    ];
+
+    let snapbox = runner(&args).current_dir(tempdir.path());
+
+    snapbox.assert().stdout_matches(indoc! {r"
+        command: account import
+        add_profile: --add-profile flag was not set. No profile added to snfoundry.toml
+    "});
+
+    let contents = fs::read_to_string(tempdir.path().join(accounts_file))
+        .expect("Unable to read created file");
+    let contents_json: serde_json::Value = serde_json::from_str(&contents).unwrap();
+    assert_eq!(
+        contents_json["alpha-sepolia"]["my_account_import"]["public_key"],
+        "0xd39cc3278f855cb025b28409d16137792175638a8acec3b5b3d2487d2472a6"
+    );
+}
+
+#[tokio::test]
+pub async fn test_undetectable_account_type() {
+    let tempdir = tempdir().expect("Unable to create a temporary directory");
+
+    let args = vec![
+        "--accounts-file",
+        "accounts.json",
+        "account",
+        "import",
+        "--url",
+        URL,
+        "--name",
+        "my_account_import",
+        "--address",
+        "0x123",
+        "--private-key",
+        "0x456",
+        "--class-hash",
+        DEVNET_OZ_CLASS_HASH_CAIRO_0,
+    ];
+
+    let snapbox = runner(&args).current_dir(tempdir.path());
+    let output = snapbox.assert().success();
+
+    assert_stderr_contains(
+        output,
+        indoc! {r"
+        command: account import
+        error: Failed to detect account type for class hash [..]. Please provide the account type with --type
+        "},
+    );
+}
@@ -1,7 +1,7 @@
 use anyhow::Context;
 use indoc::formatdoc;
 use serde_json::{json, Value};
-use shared::test_utils::output_assert::{assert_stderr_contains, assert_stdout_contains, AsOutput};
+use shared::test_utils::output_assert::{assert_stdout_contains, AsOutput};
 use tempfile::tempdir;
 
 use crate::{e2e::account::helpers::create_tempdir_with_accounts_file, helpers::runner::runner};
@@ -381,16 +381,35 @@ fn test_accounts_file_does_not_exist() {
     let args = vec!["--accounts-file", &accounts_file_name, "account", "list"];
 
     let snapbox = runner(&args).current_dir(temp_dir.path());
-    let output = snapbox.assert().failure();
+    let output = snapbox.assert().success();
 
-    assert!(output.as_stdout().is_empty());
+    assert!(output.as_stderr().is_empty());
 
-    let expected = "Error: Accounts file = some_inexistent_file.json does not exist! \
-        If you do not have an account create one with `account create` command \
+    let expected = "No accounts file found at some_inexistent_file.json. \
+        If you do not have an account create one with `account create` command, \
         or if you're using a custom accounts file, make sure \
         to supply correct path to it with `--accounts-file` argument.";
 
-    assert_stderr_contains(output, expected);
+    assert_stdout_contains(output, expected);
+}
+
+#[test]
+fn test_accounts_file_does_not_exist_json() {
+    let accounts_file_name = "some_inexistent_file.json";
+    let temp_dir = tempdir().expect("Unable to create a temporary directory");
+
+    let args = vec![
+        "--json",
+        "--accounts-file",
+        &accounts_file_name,
+        "account",
+        "list",
+    ];
+
+    let snapbox = runner(&args).current_dir(temp_dir.path());
+    let output = snapbox.assert().success();
+
+    assert_eq!(output.as_stdout(), "{}");
 }
 
 #[test]
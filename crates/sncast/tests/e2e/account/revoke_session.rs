@@ -0,0 +1,123 @@
+use crate::helpers::constants::{ACCOUNT, ACCOUNT_FILE_PATH, URL};
+use crate::helpers::fixtures::{get_transaction_hash, get_transaction_receipt, mint_token};
+use crate::helpers::runner::runner;
+use serde_json::json;
+use shared::test_utils::output_assert::assert_stderr_contains;
+use starknet::core::types::{ExecutionResult, Felt, TransactionReceipt::Invoke};
+use starknet::core::utils::get_contract_address;
+use std::fs;
+use tempfile::tempdir;
+
+// Argent account v0.4.0, the first version supporting sessions
+const ARGENT_SESSIONS_CLASS_HASH: &str =
+    "0x036078334509b514626504edc9fb252328d1a240e4e948bef8d0c08dff45927f";
+const PRIVATE_KEY: &str = "0x48c8aa111cc645462239d7117d3f2dd3c7c4fb498bda63df9cccf60f829e30";
+const PUBLIC_KEY: &str = "0x6d3a3cb3dee41f7240e0d63e23e8828191090ec50e49b65ddb81bd78fa83979";
+const SALT: &str = "0x2";
+
+#[tokio::test]
+async fn test_happy_case() {
+    // Owner is a Starknet signer with the public key, guardian is none
+    let constructor_calldata = ["0x0", PUBLIC_KEY, "0x1"];
+    let address = get_contract_address(
+        Felt::from_hex(SALT).unwrap(),
+        Felt::from_hex(ARGENT_SESSIONS_CLASS_HASH).unwrap(),
+        &constructor_calldata.map(|felt| Felt::from_hex(felt).unwrap()),
+        Felt::ZERO,
+    );
+    let address = format!("{address:#x}");
+
+    let mut args = vec![
+        "--accounts-file",
+        ACCOUNT_FILE_PATH,
+        "--account",
+        ACCOUNT,
+        "deploy",
+        "--url",
+        URL,
+        "--fee-token",
+        "eth",
+        "--class-hash",
+        ARGENT_SESSIONS_CLASS_HASH,
+        "--salt",
+        SALT,
+        "--constructor-calldata",
+    ];
+    args.extend(constructor_calldata);
+    runner(&args).assert().success();
+
+    mint_token(&address, u64::MAX).await;
+
+    let tempdir = tempdir().unwrap();
+    let accounts = json!({
+        "alpha-sepolia": {
+            "my_account": {
+                "address": address,
+                "class_hash": ARGENT_SESSIONS_CLASS_HASH,
+                "deployed": true,
+                "legacy": false,
+                "private_key": PRIVATE_KEY,
+                "public_key": PUBLIC_KEY,
+                "salt": SALT,
+                "type": "argent"
+            }
+        }
+    });
+    fs::write(tempdir.path().join("accounts.json"), accounts.to_string()).unwrap();
+
+    let args = vec![
+        "--accounts-file",
+        "accounts.json",
+        "--account",
+        "my_account",
+        "--json",
+        "account",
+        "revoke-session",
+        "--url",
+        URL,
+        "--session-hash",
+        "0x123",
+        "--max-fee",
+        "99999999999999999",
+        "--fee-token",
+        "eth",
+    ];
+
+    let snapbox = runner(&args).current_dir(tempdir.path());
+    let output = snapbox.assert().success().get_output().stdout.clone();
+
+    let hash = get_transaction_hash(&output);
+    let receipt = get_transaction_receipt(hash).await;
+
+    let Invoke(receipt) = receipt else {
+        panic!("Expected an invoke transaction receipt");
+    };
+    assert!(matches!(
+        receipt.execution_result,
+        ExecutionResult::Succeeded
+    ));
+}
+
+#[test]
+fn test_not_argent_account() {
+    let args = vec![
+        "--accounts-file",
+        ACCOUNT_FILE_PATH,
+        "--account",
+        ACCOUNT,
+        "account",
+        "revoke-session",
+        "--url",
+        URL,
+        "--session-hash",
+        "0x123",
+    ];
+
+    let snapbox = runner(&args);
+    let output = snapbox.assert().failure();
+
+    assert_stderr_contains(
+        output,
+        "Error: Sessions can only be managed for Argent accounts",
+    );
+}
@@ -30,6 +30,62 @@ fn test_happy_case() {
     "});
 }
 
+#[test]
+fn test_decode() {
+    let args = vec![
+        "--accounts-file",
+        ACCOUNT_FILE_PATH,
+        "call",
+        "--url",
+        URL,
+        "--contract-address",
+        MAP_CONTRACT_ADDRESS_SEPOLIA,
+        "--function",
+        "get",
+        "--calldata",
+        "0x0",
+        "--block-id",
+        "latest",
+        "--decode",
+    ];
+
+    let snapbox = runner(&args);
+
+    snapbox.assert().success().stdout_eq(indoc! {r"
+        command: call
+        decoded_response: 0x0
+        response: [0x0]
+    "});
+}
+
+#[test]
+fn test_call_trace() {
+    let args = vec![
+        "--accounts-file",
+        ACCOUNT_FILE_PATH,
+        "--account",
+        "user1",
+        "call",
+        "--url",
+        URL,
+        "--contract-address",
+        MAP_CONTRACT_ADDRESS_SEPOLIA,
+        "--function",
+        "get",
+        "--calldata",
+        "0x0",
+        "--call-trace",
+    ];
+
+    let snapbox = runner(&args);
+
+    snapbox.assert().success().stdout_matches(indoc! {r"
+        command: call
+        call_trace: [..]::[..](0x0) -> [0x0]
+        response: [0x0]
+    "});
+}
+
 #[tokio::test]
 async fn test_call_after_storage_changed() {
     invoke_contract(
@@ -203,3 +259,52 @@ fn test_wrong_block_id() {
         "},
     );
 }
+
+#[test]
+fn test_block_alias() {
+    let args = vec![
+        "call",
+        "--url",
+        URL,
+        "--contract-address",
+        MAP_CONTRACT_ADDRESS_SEPOLIA,
+        "--function",
+        "get",
+        "--calldata",
+        "0x0",
+        "--block",
+        "latest",
+    ];
+
+    let snapbox = runner(&args);
+
+    snapbox.assert().success().stdout_eq(indoc! {r"
+        command: call
+        response: [0x0]
+    "});
+}
+
+#[test]
+fn test_pending_block_warning() {
+    let args = vec![
+        "call",
+        "--url",
+        URL,
+        "--contract-address",
+        MAP_CONTRACT_ADDRESS_SEPOLIA,
+        "--function",
+        "get",
+        "--calldata",
+        "0x0",
+        "--block",
+        "pending",
+    ];
+
+    let snapbox = runner(&args);
+
+    snapbox.assert().success().stdout_eq(indoc! {r"
+        [WARNING] The pending block can still change, so results of calls on it may not be reproducible. Pass a block number or hash to call on a specific block
+        command: call
+        response: [0x0]
+    "});
+}
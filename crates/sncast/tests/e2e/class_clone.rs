@@ -0,0 +1,61 @@
+use crate::helpers::constants::{
+    ACCOUNT, ACCOUNT_FILE_PATH, DEVNET_OZ_CLASS_HASH_CAIRO_0, MAP_CONTRACT_CLASS_HASH_SEPOLIA, URL,
+};
+use crate::helpers::runner::runner;
+use indoc::indoc;
+use shared::test_utils::output_assert::assert_stderr_contains;
+
+#[tokio::test]
+async fn test_class_already_declared() {
+    // The class is downloaded, verified and compiled before the declaration is rejected
+    let args = vec![
+        "--accounts-file",
+        ACCOUNT_FILE_PATH,
+        "--account",
+        ACCOUNT,
+        "class",
+        "clone",
+        MAP_CONTRACT_CLASS_HASH_SEPOLIA,
+        "--from-url",
+        URL,
+        "--url",
+        URL,
+    ];
+
+    let output = runner(&args).assert().success();
+
+    assert_stderr_contains(
+        output,
+        indoc! {r"
+        command: class clone
+        error: An error occurred [..]Class with hash[..]is already declared[..]
+        "},
+    );
+}
+
+#[tokio::test]
+async fn test_cairo_0_class() {
+    let args = vec![
+        "--accounts-file",
+        ACCOUNT_FILE_PATH,
+        "--account",
+        ACCOUNT,
+        "class",
+        "clone",
+        DEVNET_OZ_CLASS_HASH_CAIRO_0,
+        "--from-url",
+        URL,
+        "--url",
+        URL,
+    ];
+
+    let output = runner(&args).assert().success();
+
+    assert_stderr_contains(
+        output,
+        indoc! {r"
+        command: class clone
+        error: Class with hash [..] is a Cairo 0 class, only Sierra classes can be cloned
+        "},
+    );
+}
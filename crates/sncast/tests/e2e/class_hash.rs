@@ -0,0 +1,114 @@
+use crate::helpers::fixtures::copy_directory_to_tempdir;
+use crate::helpers::runner::runner;
+use indoc::{formatdoc, indoc};
+use scarb_api::ScarbCommand;
+use shared::test_utils::output_assert::{assert_stderr_contains, assert_stdout_contains};
+
+const CONTRACT_PATH: &str = "target/dev/map_Map.contract_class.json";
+
+#[test]
+fn test_class_hash() {
+    let contract_path = copy_directory_to_tempdir("tests/data/contracts/map");
+    ScarbCommand::new_with_stdio()
+        .current_dir(contract_path.path())
+        .arg("build")
+        .run()
+        .expect("Failed to build the contract");
+
+    let args = vec!["class-hash", CONTRACT_PATH, "--compiled-class-hash"];
+    let output = runner(&args)
+        .current_dir(contract_path.path())
+        .assert()
+        .success();
+
+    let stdout = String::from_utf8(output.get_output().stdout.clone()).unwrap();
+    assert!(stdout.contains("class_hash: 0x"));
+    assert!(stdout.contains("compiled_class_hash: 0x"));
+
+    let sierra = std::fs::read_to_string(contract_path.path().join(CONTRACT_PATH)).unwrap();
+    let args = vec!["class-hash", "-", "--compiled-class-hash"];
+    runner(&args)
+        .current_dir(contract_path.path())
+        .stdin(sierra)
+        .assert()
+        .success()
+        .stdout_eq(stdout);
+}
+
+#[test]
+fn test_missing_artifact() {
+    let args = vec!["class-hash", "missing.contract_class.json"];
+    let output = runner(&args).assert().success();
+
+    assert_stderr_contains(
+        output,
+        indoc! {r"
+        command: class-hash
+        error: Failed to read Sierra artifact from missing.contract_class.json
+        "},
+    );
+}
+
+#[test]
+fn test_class_checksum() {
+    let contract_path = copy_directory_to_tempdir("tests/data/contracts/map");
+    ScarbCommand::new_with_stdio()
+        .current_dir(contract_path.path())
+        .arg("build")
+        .run()
+        .expect("Failed to build the contract");
+
+    let output = runner(&["class-hash", CONTRACT_PATH])
+        .current_dir(contract_path.path())
+        .assert()
+        .success();
+    let stdout = String::from_utf8(output.get_output().stdout.clone()).unwrap();
+    let class_hash = stdout
+        .lines()
+        .find_map(|line| line.strip_prefix("class_hash: "))
+        .expect("Class hash not found in the output");
+
+    let args = vec![
+        "class",
+        "checksum",
+        "--artifact",
+        CONTRACT_PATH,
+        "--expected",
+        class_hash,
+    ];
+    let output = runner(&args)
+        .current_dir(contract_path.path())
+        .assert()
+        .success();
+    assert_stdout_contains(
+        output,
+        formatdoc! {r"
+        command: class checksum
+        class_hash: {class_hash}
+        expected_class_hash: {class_hash}
+        message: Class hash of the artifact matches the expected one
+        "},
+    );
+
+    let args = vec![
+        "class",
+        "checksum",
+        "--artifact",
+        CONTRACT_PATH,
+        "--expected",
+        "0x123",
+    ];
+    let output = runner(&args)
+        .current_dir(contract_path.path())
+        .assert()
+        .code(1);
+    assert_stdout_contains(
+        output,
+        formatdoc! {r"
+        command: class checksum
+        class_hash: {class_hash}
+        expected_class_hash: 0x123
+        message: Class hash of the artifact {class_hash} does not match the expected 0x123. [..]
+        "},
+    );
+}
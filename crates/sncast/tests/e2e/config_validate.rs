@@ -0,0 +1,83 @@
+use crate::helpers::constants::{ACCOUNT, ACCOUNT_FILE_PATH, URL};
+use crate::helpers::runner::runner;
+use camino::Utf8PathBuf;
+use indoc::{formatdoc, indoc};
+use shared::test_utils::output_assert::assert_stdout_contains;
+use std::fs;
+use tempfile::tempdir;
+
+#[test]
+fn test_valid_config() {
+    let tempdir = tempdir().unwrap();
+    let accounts_file =
+        Utf8PathBuf::from_path_buf(fs::canonicalize(ACCOUNT_FILE_PATH).unwrap()).unwrap();
+    fs::write(
+        tempdir.path().join("snfoundry.toml"),
+        formatdoc! {r#"
+            [sncast.default]
+            url = "{URL}"
+            account = "{ACCOUNT}"
+            accounts-file = "{accounts_file}"
+            wait-params = {{ timeout = 300, retry-interval = 5 }}
+        "#},
+    )
+    .unwrap();
+
+    let output = runner(&["--config-validate"])
+        .current_dir(tempdir.path())
+        .assert()
+        .success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        Validated [..]snfoundry.toml
+        Profiles: default
+        Configuration is valid
+        "},
+    );
+}
+
+#[test]
+fn test_invalid_config() {
+    let tempdir = tempdir().unwrap();
+    fs::write(
+        tempdir.path().join("snfoundry.toml"),
+        indoc! {r#"
+            [sncast.default]
+            url = "not a url"
+            accounts_file = "accounts.json"
+
+            [sncast.other]
+            account = "nonexistent"
+            accounts-file = "accounts.json"
+        "#},
+    )
+    .unwrap();
+
+    let output = runner(&["--config-validate"])
+        .current_dir(tempdir.path())
+        .assert()
+        .code(1);
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        Profiles: default, other
+        error: [default] Unknown key `accounts_file`, did you mean `accounts-file`?
+        error: [default] Invalid url `not a url`: relative URL without a base
+        error: [other] Accounts file accounts.json does not exist
+        Found 3 error(s)
+        "},
+    );
+}
+
+#[test]
+fn test_config_validate_with_subcommand() {
+    let output = runner(&["--config-validate", "show-config"])
+        .assert()
+        .failure();
+
+    let stderr = String::from_utf8(output.get_output().stderr.clone()).unwrap();
+    assert!(stderr.contains("--config-validate cannot be used with a subcommand"));
+}
@@ -0,0 +1,68 @@
+use crate::helpers::constants::{MAP_CONTRACT_ADDRESS_SEPOLIA, URL};
+use crate::helpers::fixtures::{
+    create_and_deploy_oz_account, get_transaction_hash, get_transaction_receipt,
+};
+use crate::helpers::runner::runner;
+use indoc::formatdoc;
+use shared::test_utils::output_assert::assert_stdout_contains;
+use sncast::get_provider;
+use starknet::providers::Provider;
+
+#[tokio::test]
+async fn test_undecodable_call() {
+    let tempdir = create_and_deploy_oz_account().await;
+    let provider = get_provider(URL).unwrap();
+    let from_block = provider.block_number().await.unwrap() + 1;
+
+    // `put` takes a key and a value, so calldata with a single felt can't be decoded
+    let args = vec![
+        "--accounts-file",
+        "accounts.json",
+        "--account",
+        "my_account",
+        "--json",
+        "invoke",
+        "--url",
+        URL,
+        "--contract-address",
+        MAP_CONTRACT_ADDRESS_SEPOLIA,
+        "--function",
+        "put",
+        "--calldata",
+        "0x1",
+        "--fee-token",
+        "eth",
+        "--max-fee",
+        "99999999999999999",
+    ];
+    let output = runner(&args).current_dir(tempdir.path()).assert().success();
+    let transaction_hash = get_transaction_hash(&output.get_output().stdout);
+    get_transaction_receipt(transaction_hash).await;
+    let to_block = provider.block_number().await.unwrap();
+
+    let from_block = from_block.to_string();
+    let to_block = to_block.to_string();
+    let args = vec![
+        "contract",
+        "call-log",
+        "--url",
+        URL,
+        "--contract",
+        MAP_CONTRACT_ADDRESS_SEPOLIA,
+        "--function",
+        "put",
+        "--from-block",
+        &from_block,
+        "--to-block",
+        &to_block,
+    ];
+    let output = runner(&args).assert().success();
+
+    assert_stdout_contains(
+        output,
+        formatdoc! {r"
+            block,tx_hash,caller,key,value
+            [..],{transaction_hash:#x},[..],0x1
+        "},
+    );
+}
@@ -675,3 +675,34 @@ async fn test_no_scarb_profile() {
         "},
     );
 }
+
+#[tokio::test]
+async fn test_class_hash_only() {
+    let contract_path = duplicate_contract_directory_with_salt(
+        CONTRACTS_DIR.to_string() + "/map",
+        "put",
+        "class_hash_only",
+    );
+    let accounts_json_path = get_accounts_path("tests/data/accounts/accounts.json");
+    let args = vec![
+        "--accounts-file",
+        accounts_json_path.as_str(),
+        "--account",
+        "user13",
+        "--wait",
+        "declare",
+        "--url",
+        URL,
+        "--contract-name",
+        "Map",
+        "--max-fee",
+        "99999999999999999",
+        "--fee-token",
+        "eth",
+        "--class-hash-only",
+    ];
+
+    let snapbox = runner(&args).current_dir(contract_path.path());
+
+    snapbox.assert().success().stdout_matches("0x[..]\n");
+}
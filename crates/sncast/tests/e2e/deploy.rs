@@ -404,3 +404,59 @@ fn test_too_low_max_fee() {
         "},
     );
 }
+
+#[test]
+fn test_assert_address_mismatch() {
+    let args = vec![
+        "--accounts-file",
+        ACCOUNT_FILE_PATH,
+        "--account",
+        "user7",
+        "deploy",
+        "--url",
+        URL,
+        "--class-hash",
+        MAP_CONTRACT_CLASS_HASH_SEPOLIA,
+        "--salt",
+        "0x2",
+        "--unique",
+        "--assert-address",
+        "0x1",
+    ];
+
+    let snapbox = runner(&args);
+    let output = snapbox.assert().success();
+
+    assert_stderr_contains(
+        output,
+        indoc! {r"
+        command: deploy
+        error: Computed contract address 0x[..] does not match the expected address 0x1
+        "},
+    );
+}
+
+#[test]
+fn test_address_only() {
+    let args = vec![
+        "--accounts-file",
+        ACCOUNT_FILE_PATH,
+        "--account",
+        "user14",
+        "--wait",
+        "deploy",
+        "--url",
+        URL,
+        "--class-hash",
+        MAP_CONTRACT_CLASS_HASH_SEPOLIA,
+        "--max-fee",
+        "99999999999999999",
+        "--fee-token",
+        "eth",
+        "--address-only",
+    ];
+
+    let snapbox = runner(&args);
+
+    snapbox.assert().success().stdout_matches("0x[..]\n");
+}
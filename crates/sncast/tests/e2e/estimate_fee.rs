@@ -0,0 +1,114 @@
+use crate::helpers::constants::{
+    ACCOUNT_FILE_PATH, MAP_CONTRACT_ADDRESS_SEPOLIA, MAP_CONTRACT_CLASS_HASH_SEPOLIA, URL,
+};
+use crate::helpers::runner::runner;
+use indoc::indoc;
+use shared::test_utils::output_assert::{assert_stderr_contains, assert_stdout_contains};
+use test_case::test_case;
+
+#[test_case("eth", "WEI", "ETH"; "eth")]
+#[test_case("strk", "FRI", "STRK"; "strk")]
+#[tokio::test]
+async fn test_happy_case_invoke(fee_token: &str, fee_unit: &str, symbol: &str) {
+    let args = vec![
+        "--accounts-file",
+        ACCOUNT_FILE_PATH,
+        "--account",
+        "oz",
+        "estimate-fee",
+        "--url",
+        URL,
+        "--contract-address",
+        MAP_CONTRACT_ADDRESS_SEPOLIA,
+        "--function",
+        "put",
+        "--calldata",
+        "0x1 0x2",
+        "--fee-token",
+        fee_token,
+    ];
+
+    let output = runner(&args).assert().success();
+    let stdout = String::from_utf8(output.get_output().stdout.clone()).unwrap();
+
+    assert!(stdout.contains("command: estimate-fee"));
+    assert!(stdout.contains(&format!("fee_unit: {fee_unit}\n")));
+    assert!(stdout.contains(&format!(" {symbol}\n")));
+    assert!(stdout.contains("block_l1_gas_price: "));
+    assert!(stdout.contains("l1_data_gas_consumed: "));
+}
+
+#[tokio::test]
+async fn test_happy_case_deploy() {
+    let args = vec![
+        "--accounts-file",
+        ACCOUNT_FILE_PATH,
+        "--account",
+        "oz",
+        "estimate-fee",
+        "--url",
+        URL,
+        "--class-hash",
+        MAP_CONTRACT_CLASS_HASH_SEPOLIA,
+        "--unique",
+    ];
+
+    let output = runner(&args).assert().success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        command: estimate-fee
+        block_l1_data_gas_price: [..]
+        block_l1_gas_price: [..]
+        fee_unit: WEI
+        l1_data_gas_consumed: [..]
+        l1_data_gas_price: [..]
+        l1_gas_consumed: [..]
+        l1_gas_price: [..]
+        overall_fee: [..]
+        overall_fee_in_token: [..] ETH
+        "},
+    );
+}
+
+#[tokio::test]
+async fn test_nonexistent_function() {
+    let args = vec![
+        "--accounts-file",
+        ACCOUNT_FILE_PATH,
+        "--account",
+        "oz",
+        "estimate-fee",
+        "--url",
+        URL,
+        "--contract-address",
+        MAP_CONTRACT_ADDRESS_SEPOLIA,
+        "--function",
+        "nonexistent_put",
+        "--calldata",
+        "0x1 0x2",
+    ];
+
+    let output = runner(&args).assert().success();
+
+    assert_stderr_contains(
+        output,
+        indoc! {r"
+        command: estimate-fee
+        error: [..]
+        "},
+    );
+}
+
+#[test]
+fn test_no_transaction_passed() {
+    let args = vec!["estimate-fee", "--url", URL];
+
+    let output = runner(&args).assert().failure();
+
+    assert_stderr_contains(
+        output,
+        "error: the following required arguments were not provided:",
+    );
+}
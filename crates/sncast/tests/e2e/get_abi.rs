@@ -0,0 +1,105 @@
+use crate::helpers::constants::{MAP_CONTRACT_ADDRESS_SEPOLIA, URL};
+use crate::helpers::runner::runner;
+use indoc::indoc;
+use serde_json::{json, Value};
+use shared::test_utils::output_assert::{assert_stderr_contains, assert_stdout_contains};
+
+#[tokio::test]
+async fn test_happy_case_cairo_format() {
+    let args = vec![
+        "get-abi",
+        "--url",
+        URL,
+        "--contract-address",
+        MAP_CONTRACT_ADDRESS_SEPOLIA,
+        "--format",
+        "cairo",
+    ];
+
+    let snapbox = runner(&args);
+
+    snapbox.assert().success().stdout_eq(indoc! {r"
+        command: get-abi
+        abi:
+        #[starknet::interface]
+        trait IMap<TContractState> {
+            fn put(ref self: TContractState, key: felt252, value: felt252);
+            fn get(self: @TContractState, key: felt252) -> felt252;
+        }
+    "});
+}
+
+#[tokio::test]
+async fn test_happy_case_function() {
+    let args = vec![
+        "get-abi",
+        "--url",
+        URL,
+        "--contract-address",
+        MAP_CONTRACT_ADDRESS_SEPOLIA,
+        "--function",
+        "get",
+    ];
+
+    let output = runner(&args).assert().success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        command: get-abi
+        abi:
+        KIND  NAME  SIGNATURE
+        view  get   (key: felt252) -> felt252
+        "},
+    );
+}
+
+#[tokio::test]
+async fn test_happy_case_json() {
+    let args = vec![
+        "--json",
+        "get-abi",
+        "--url",
+        URL,
+        "--contract-address",
+        MAP_CONTRACT_ADDRESS_SEPOLIA,
+        "--function",
+        "get",
+        "--format",
+        "cairo",
+    ];
+
+    let output = runner(&args).assert().success();
+    let stdout: Value = serde_json::from_slice(&output.get_output().stdout).unwrap();
+
+    assert_eq!(
+        stdout,
+        json!({
+            "command": "get-abi",
+            "abi": "#[external(v0)]\nfn get(self: @ContractState, key: felt252) -> felt252;",
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_function_not_found() {
+    let args = vec![
+        "get-abi",
+        "--url",
+        URL,
+        "--contract-address",
+        MAP_CONTRACT_ADDRESS_SEPOLIA,
+        "--function",
+        "nonexistent",
+    ];
+
+    let output = runner(&args).assert().success();
+
+    assert_stderr_contains(
+        output,
+        indoc! {r"
+        command: get-abi
+        error: Function nonexistent not found in the ABI of contract 0x[..]
+        "},
+    );
+}
@@ -0,0 +1,73 @@
+use crate::helpers::constants::URL;
+use crate::helpers::runner::runner;
+use indoc::indoc;
+use shared::test_utils::output_assert::{assert_stderr_contains, assert_stdout_contains};
+
+#[tokio::test]
+async fn test_happy_case_number() {
+    let args = vec!["get-block", "--number", "1", "--url", URL];
+
+    let output = runner(&args).assert().success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        command: get-block
+        block_hash: 0x[..]
+        block_number: 1
+        parent_hash: 0x[..]
+        sequencer_address: 0x[..]
+        state_root: 0x[..]
+        timestamp: [..]
+        transaction_count: [..]
+        "},
+    );
+}
+
+#[tokio::test]
+async fn test_happy_case_full_txns() {
+    let args = vec!["get-block", "--number", "1", "--full-txns", "--url", URL];
+
+    let output = runner(&args).assert().success();
+    let stdout = String::from_utf8(output.get_output().stdout.clone()).unwrap();
+
+    assert!(stdout.contains("transactions: [0x"));
+}
+
+#[tokio::test]
+async fn test_happy_case_latest() {
+    let args = vec!["get-block", "--url", URL];
+
+    let output = runner(&args).assert().success();
+    let stdout = String::from_utf8(output.get_output().stdout.clone()).unwrap();
+
+    assert!(stdout.contains("block_number: "));
+    assert!(!stdout.contains("transactions:"));
+}
+
+#[tokio::test]
+async fn test_happy_case_pending() {
+    let args = vec!["get-block", "--tag", "pending", "--url", URL];
+
+    let output = runner(&args).assert().success();
+    let stdout = String::from_utf8(output.get_output().stdout.clone()).unwrap();
+
+    assert!(stdout.contains("parent_hash: 0x"));
+    assert!(!stdout.contains("block_number:"));
+    assert!(!stdout.contains("state_root:"));
+}
+
+#[tokio::test]
+async fn test_block_not_found() {
+    let args = vec!["get-block", "--hash", "0x123", "--url", URL];
+
+    let output = runner(&args).assert().success();
+
+    assert_stderr_contains(
+        output,
+        indoc! {r"
+        command: get-block
+        error: Block was not found
+        "},
+    );
+}
@@ -0,0 +1,53 @@
+use crate::helpers::constants::{MAP_CONTRACT_ADDRESS_SEPOLIA, URL};
+use crate::helpers::runner::runner;
+use indoc::indoc;
+use shared::test_utils::output_assert::assert_stderr_contains;
+
+#[tokio::test]
+async fn test_happy_case_address() {
+    let args = vec![
+        "get-nonce",
+        "--url",
+        URL,
+        "--address",
+        MAP_CONTRACT_ADDRESS_SEPOLIA,
+        "--pending",
+    ];
+
+    let output = runner(&args).assert().success();
+    let stdout = String::from_utf8(output.get_output().stdout.clone()).unwrap();
+
+    assert!(stdout.contains("command: get-nonce"));
+    assert!(stdout.contains("nonce: 0\n"));
+    assert!(stdout.contains("nonce_hex: 0x0\n"));
+}
+
+#[tokio::test]
+async fn test_contract_not_found() {
+    let args = vec!["get-nonce", "--url", URL, "--address", "0x1"];
+
+    let output = runner(&args).assert().success();
+
+    assert_stderr_contains(
+        output,
+        indoc! {r"
+        command: get-nonce
+        error: There is no contract at the specified address
+        "},
+    );
+}
+
+#[tokio::test]
+async fn test_no_address_nor_account() {
+    let args = vec!["get-nonce", "--url", URL];
+
+    let output = runner(&args).assert().success();
+
+    assert_stderr_contains(
+        output,
+        indoc! {r"
+        command: get-nonce
+        error: Either --address or --account must be passed, or account must be set in snfoundry.toml
+        "},
+    );
+}
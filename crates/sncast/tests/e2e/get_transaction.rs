@@ -0,0 +1,83 @@
+use crate::helpers::constants::{
+    MAP_CONTRACT_CLASS_HASH_SEPOLIA, MAP_CONTRACT_DECLARE_TX_HASH_SEPOLIA, URL,
+};
+use crate::helpers::runner::runner;
+use indoc::indoc;
+use shared::test_utils::output_assert::{assert_stderr_contains, assert_stdout_contains};
+
+const SUCCEEDED_TX_HASH: &str =
+    "0x07d2067cd7675f88493a9d773b456c8d941457ecc2f6201d2fe6b0607daadfd1";
+const REVERTED_TX_HASH: &str = "0x00ae35dacba17cde62b8ceb12e3b18f4ab6e103fa2d5e3d9821cb9dc59d59a3c";
+
+#[tokio::test]
+async fn test_happy_case_invoke() {
+    let args = vec!["get-transaction", SUCCEEDED_TX_HASH, "--url", URL];
+
+    let output = runner(&args).assert().success();
+    let stdout = String::from_utf8(output.get_output().stdout.clone()).unwrap();
+
+    assert!(stdout.contains("command: get-transaction"));
+    assert!(stdout.contains("transaction_type: INVOKE\n"));
+    assert!(stdout.contains("calls: [0x"));
+    assert!(stdout.contains("sender_address: 0x"));
+    assert!(stdout.contains("signature: [0x"));
+    assert!(!stdout.contains("execution_status"));
+}
+
+#[tokio::test]
+async fn test_happy_case_declare() {
+    let args = vec![
+        "get-transaction",
+        MAP_CONTRACT_DECLARE_TX_HASH_SEPOLIA,
+        "--url",
+        URL,
+    ];
+
+    let output = runner(&args).assert().success();
+    let stdout = String::from_utf8(output.get_output().stdout.clone()).unwrap();
+
+    assert!(stdout.contains("transaction_type: DECLARE\n"));
+    assert!(stdout.contains(&format!("class_hash: {MAP_CONTRACT_CLASS_HASH_SEPOLIA}\n")));
+    assert!(!stdout.contains("calls:"));
+}
+
+#[tokio::test]
+async fn test_receipt_reverted() {
+    let args = vec![
+        "get-transaction",
+        REVERTED_TX_HASH,
+        "--receipt",
+        "--url",
+        URL,
+    ];
+
+    let output = runner(&args).assert().success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        command: get-transaction
+        actual_fee: [..]
+        calls: [..]
+        events: [..]
+        execution_status: Reverted
+        [..]
+        revert_reason: [..]
+        "},
+    );
+}
+
+#[tokio::test]
+async fn test_incorrect_transaction_hash() {
+    let args = vec!["get-transaction", "0x1", "--url", URL];
+
+    let output = runner(&args).assert().success();
+
+    assert_stderr_contains(
+        output,
+        indoc! {r"
+        command: get-transaction
+        error: Transaction with provided hash was not found (does not exist)
+        "},
+    );
+}
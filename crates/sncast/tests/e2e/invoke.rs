@@ -6,11 +6,11 @@ use crate::helpers::fixtures::{
     get_transaction_receipt,
 };
 use crate::helpers::runner::runner;
-use indoc::indoc;
+use indoc::{formatdoc, indoc};
 use shared::test_utils::output_assert::{assert_stderr_contains, assert_stdout_contains};
 use sncast::helpers::constants::{ARGENT_CLASS_HASH, BRAAVOS_CLASS_HASH, OZ_CLASS_HASH};
 use sncast::AccountType;
-use starknet::core::types::{Felt, TransactionReceipt::Invoke};
+use starknet::core::types::{Felt, PriceUnit, TransactionReceipt::Invoke};
 use test_case::test_case;
 
 #[test_case("oz_cairo_0"; "cairo_0_account")]
@@ -94,6 +94,322 @@ async fn test_happy_case_human_readable() {
     );
 }
 
+#[tokio::test]
+async fn test_happy_case_default_fee_token() {
+    let args = vec![
+        "--accounts-file",
+        ACCOUNT_FILE_PATH,
+        "--account",
+        "oz",
+        "--json",
+        "invoke",
+        "--url",
+        URL,
+        "--contract-address",
+        MAP_CONTRACT_ADDRESS_SEPOLIA,
+        "--function",
+        "put",
+        "--calldata",
+        "0x1 0x2",
+    ];
+
+    let snapbox = runner(&args);
+    let output = snapbox.assert().success().get_output().stdout.clone();
+
+    let hash = get_transaction_hash(&output);
+    let receipt = get_transaction_receipt(hash).await;
+
+    let Invoke(receipt) = receipt else {
+        panic!("Expected an invoke transaction receipt");
+    };
+    assert_eq!(receipt.actual_fee.unit, PriceUnit::Wei);
+}
+
+#[test_case(false; "sequential")]
+#[test_case(true; "parallel")]
+#[tokio::test]
+async fn test_happy_case_batch_file(parallel: bool) {
+    let tempdir = create_and_deploy_oz_account().await;
+    std::fs::write(
+        tempdir.path().join("batch.jsonl"),
+        formatdoc! {r#"
+            {{"contract": "{MAP_CONTRACT_ADDRESS_SEPOLIA}", "function": "put", "calldata": ["0x1", "0x2"], "memo": "first"}}
+            {{"contract": "{MAP_CONTRACT_ADDRESS_SEPOLIA}", "function": "put", "calldata": ["0x3", "0x4"]}}
+        "#},
+    )
+    .unwrap();
+
+    let mut args = vec![
+        "--accounts-file",
+        "accounts.json",
+        "--account",
+        "my_account",
+        "--wait",
+        "invoke",
+        "--url",
+        URL,
+        "--batch-file",
+        "batch.jsonl",
+        "--fee-token",
+        "eth",
+    ];
+    if parallel {
+        args.extend(["--parallel", "--max-fee", "99999999999999999"]);
+    }
+
+    let snapbox = runner(&args).current_dir(tempdir.path());
+    let output = snapbox.assert().success();
+    let stdout = String::from_utf8(output.get_output().stdout.clone()).unwrap();
+
+    assert_eq!(stdout.matches("command: invoke").count(), 2);
+    assert_eq!(stdout.matches("transaction_hash: 0x").count(), 2);
+}
+
+#[tokio::test]
+async fn test_batch_file_stops_on_error() {
+    let tempdir = create_and_deploy_oz_account().await;
+    std::fs::write(
+        tempdir.path().join("batch.jsonl"),
+        formatdoc! {r#"
+            {{"contract": "{MAP_CONTRACT_ADDRESS_SEPOLIA}", "function": "nonexistent_put", "calldata": ["0x1", "0x2"]}}
+            {{"contract": "{MAP_CONTRACT_ADDRESS_SEPOLIA}", "function": "put", "calldata": ["0x3", "0x4"]}}
+        "#},
+    )
+    .unwrap();
+
+    let args = vec![
+        "--accounts-file",
+        "accounts.json",
+        "--account",
+        "my_account",
+        "invoke",
+        "--url",
+        URL,
+        "--batch-file",
+        "batch.jsonl",
+        "--fee-token",
+        "eth",
+    ];
+
+    let snapbox = runner(&args).current_dir(tempdir.path());
+    let output = snapbox.assert().failure();
+    let stdout = String::from_utf8(output.get_output().stdout.clone()).unwrap();
+
+    assert_stdout_contains(
+        stdout,
+        "[WARNING] Batch stopped after a failed transaction, 1 remaining transaction(s) were not sent. Pass --continue-on-error to send them anyway",
+    );
+    assert_stderr_contains(
+        output,
+        "Error: 1 of 2 transaction(s) from the batch file failed",
+    );
+}
+
+#[tokio::test]
+async fn test_batch_file_parallel_without_max_fee() {
+    let tempdir = create_and_deploy_oz_account().await;
+    std::fs::write(
+        tempdir.path().join("batch.jsonl"),
+        formatdoc! {r#"
+            {{"contract": "{MAP_CONTRACT_ADDRESS_SEPOLIA}", "function": "put", "calldata": ["0x1", "0x2"]}}
+        "#},
+    )
+    .unwrap();
+
+    let args = vec![
+        "--accounts-file",
+        "accounts.json",
+        "--account",
+        "my_account",
+        "invoke",
+        "--url",
+        URL,
+        "--batch-file",
+        "batch.jsonl",
+        "--parallel",
+        "--fee-token",
+        "eth",
+    ];
+
+    let snapbox = runner(&args).current_dir(tempdir.path());
+    let output = snapbox.assert().failure();
+
+    assert_stderr_contains(
+        output,
+        "Error: --parallel requires the fee to be passed explicitly, [..]",
+    );
+}
+
+#[tokio::test]
+async fn test_happy_case_with_memo() {
+    let tempdir = create_and_deploy_oz_account().await;
+
+    let args = vec![
+        "--accounts-file",
+        "accounts.json",
+        "--account",
+        "my_account",
+        "--json",
+        "--memo",
+        "put first value",
+        "invoke",
+        "--url",
+        URL,
+        "--contract-address",
+        MAP_CONTRACT_ADDRESS_SEPOLIA,
+        "--function",
+        "put",
+        "--calldata",
+        "0x1 0x2",
+        "--max-fee",
+        "99999999999999999",
+        "--fee-token",
+        "eth",
+    ];
+
+    let snapbox = runner(&args).current_dir(tempdir.path());
+    let output = snapbox.assert().success().get_output().stdout.clone();
+    let hash = get_transaction_hash(&output);
+
+    let snapbox = runner(&["tx-log"]).current_dir(tempdir.path());
+    let output = snapbox.assert().success();
+
+    assert_stdout_contains(
+        output,
+        formatdoc! {
+            "
+            Logged transactions (at .snfoundry_cache/tx_log.json):
+            - {hash:#x}:
+              memo: put first value
+              timestamp: [..]
+            "
+        },
+    );
+}
+
+#[tokio::test]
+async fn test_happy_case_simulate_only() {
+    let tempdir = create_and_deploy_oz_account().await;
+
+    let args = vec![
+        "--accounts-file",
+        "accounts.json",
+        "--account",
+        "my_account",
+        "--int-format",
+        "invoke",
+        "--url",
+        URL,
+        "--contract-address",
+        MAP_CONTRACT_ADDRESS_SEPOLIA,
+        "--function",
+        "put",
+        "--calldata",
+        "0x1 0x2",
+        "--fee-token",
+        "eth",
+        "--simulate-only",
+    ];
+
+    let snapbox = runner(&args).current_dir(tempdir.path());
+    let output = snapbox.assert().success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {
+            "
+            command: invoke
+            execution_status: Succeeded
+            fee_unit: WEI
+            overall_fee: [..]
+            storage_diffs: [..]
+            transaction_trace: [..]
+            "
+        },
+    );
+}
+
+#[tokio::test]
+async fn test_happy_case_dry_run() {
+    let tempdir = create_and_deploy_oz_account().await;
+
+    let args = vec![
+        "--accounts-file",
+        "accounts.json",
+        "--account",
+        "my_account",
+        "--int-format",
+        "--dry-run",
+        "invoke",
+        "--url",
+        URL,
+        "--contract-address",
+        MAP_CONTRACT_ADDRESS_SEPOLIA,
+        "--function",
+        "put",
+        "--calldata",
+        "0x1 0x2",
+        "--fee-token",
+        "eth",
+    ];
+
+    let snapbox = runner(&args).current_dir(tempdir.path());
+    let output = snapbox.assert().success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {
+            "
+            command: invoke
+            execution_status: Succeeded
+            fee_unit: WEI
+            overall_fee: [..]
+            storage_diffs: [..]
+            transaction_trace: [..]
+            "
+        },
+    );
+}
+
+#[tokio::test]
+async fn test_happy_case_simulate_short() {
+    let tempdir = create_and_deploy_oz_account().await;
+
+    let args = vec![
+        "--accounts-file",
+        "accounts.json",
+        "--account",
+        "my_account",
+        "invoke",
+        "--url",
+        URL,
+        "--contract-address",
+        MAP_CONTRACT_ADDRESS_SEPOLIA,
+        "--function",
+        "put",
+        "--calldata",
+        "0x1 0x2",
+        "--fee-token",
+        "strk",
+        "-s",
+    ];
+
+    let snapbox = runner(&args).current_dir(tempdir.path());
+    let output = snapbox.assert().success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {
+            "
+            command: invoke
+            call_stack_depth: 2
+            execution_status: Succeeded
+            fee_unit: FRI
+            "
+        },
+    );
+}
+
 #[test_case(DEVNET_OZ_CLASS_HASH_CAIRO_0.parse().unwrap(), AccountType::OpenZeppelin; "cairo_0_class_hash")]
 #[test_case(OZ_CLASS_HASH, AccountType::OpenZeppelin; "cairo_1_class_hash")]
 #[test_case(ARGENT_CLASS_HASH, AccountType::Argent; "argent_class_hash")]
@@ -221,6 +537,75 @@ async fn test_happy_case_strk_different_fees(
     assert!(matches!(receipt, Invoke(_)));
 }
 
+#[tokio::test]
+async fn test_happy_case_l1_gas() {
+    let tempdir = create_and_deploy_oz_account().await;
+    let args = vec![
+        "--accounts-file",
+        "accounts.json",
+        "--account",
+        "my_account",
+        "--int-format",
+        "--json",
+        "invoke",
+        "--url",
+        URL,
+        "--contract-address",
+        MAP_CONTRACT_ADDRESS_SEPOLIA,
+        "--function",
+        "put",
+        "--calldata",
+        "0x1 0x2",
+        "--version",
+        "3",
+        "--l1-gas",
+        "999,999999999999",
+    ];
+
+    let snapbox = runner(&args).current_dir(tempdir.path());
+    let output = snapbox.assert().success().get_output().stdout.clone();
+
+    let hash = get_transaction_hash(&output);
+    let receipt = get_transaction_receipt(hash).await;
+
+    assert!(matches!(receipt, Invoke(_)));
+}
+
+#[tokio::test]
+async fn test_l1_gas_v1() {
+    let tempdir = create_and_deploy_oz_account().await;
+    let args = vec![
+        "--accounts-file",
+        "accounts.json",
+        "--account",
+        "my_account",
+        "invoke",
+        "--url",
+        URL,
+        "--contract-address",
+        MAP_CONTRACT_ADDRESS_SEPOLIA,
+        "--function",
+        "put",
+        "--calldata",
+        "0x1 0x2",
+        "--version",
+        "1",
+        "--l1-gas",
+        "999,999999999999",
+    ];
+
+    let snapbox = runner(&args).current_dir(tempdir.path());
+    let output = snapbox.assert().success();
+
+    assert_stderr_contains(
+        output,
+        indoc! {r"
+        command: invoke
+        error: --l1-gas is supported only for v3 invoke
+        "},
+    );
+}
+
 #[test_case("eth", "v3"; "eth-v3")]
 #[test_case("strk", "v1"; "strk-v1")]
 #[tokio::test]
@@ -386,3 +771,168 @@ fn test_too_low_max_fee() {
         "},
     );
 }
+
+#[tokio::test]
+async fn test_retry_on_revert() {
+    let tempdir = create_and_deploy_account(OZ_CLASS_HASH, AccountType::OpenZeppelin).await;
+
+    let args = vec![
+        "--accounts-file",
+        "accounts.json",
+        "--account",
+        "my_account",
+        "invoke",
+        "--url",
+        URL,
+        "--contract-address",
+        MAP_CONTRACT_ADDRESS_SEPOLIA,
+        "--function",
+        "put",
+        "--calldata",
+        "0x1",
+        "--max-fee",
+        "99999999999999999",
+        "--fee-token",
+        "eth",
+        "--retry-on-revert",
+        "2",
+        "--retry-delay",
+        "1",
+    ];
+
+    let snapbox = runner(&args).current_dir(tempdir.path());
+    let output = snapbox.assert().success();
+
+    assert_stderr_contains(
+        output,
+        indoc! {r"
+        Transaction reverted, sending it again in 1s (retry 1/2)
+        Transaction reverted, sending it again in 1s (retry 2/2)
+        command: invoke
+        error: Transaction has been reverted = [..]Failed to deserialize param #2[..]
+        "},
+    );
+}
+
+#[tokio::test]
+async fn test_retry_on_revert_message_not_matching() {
+    let tempdir = create_and_deploy_account(OZ_CLASS_HASH, AccountType::OpenZeppelin).await;
+
+    let args = vec![
+        "--accounts-file",
+        "accounts.json",
+        "--account",
+        "my_account",
+        "invoke",
+        "--url",
+        URL,
+        "--contract-address",
+        MAP_CONTRACT_ADDRESS_SEPOLIA,
+        "--function",
+        "put",
+        "--calldata",
+        "0x1",
+        "--max-fee",
+        "99999999999999999",
+        "--fee-token",
+        "eth",
+        "--retry-on-revert",
+        "2",
+        "--retry-on-message",
+        "Oracle price is stale",
+    ];
+
+    let snapbox = runner(&args).current_dir(tempdir.path());
+    let output = snapbox.assert().success();
+
+    let stderr = String::from_utf8(output.get_output().stderr.clone()).unwrap();
+    assert!(!stderr.contains("sending it again"));
+    assert_stderr_contains(
+        output,
+        indoc! {r"
+        command: invoke
+        error: Transaction has been reverted = [..]Failed to deserialize param #2[..]
+        "},
+    );
+}
+
+#[test]
+fn test_retry_on_revert_with_nonce() {
+    let args = vec![
+        "--accounts-file",
+        ACCOUNT_FILE_PATH,
+        "--account",
+        ACCOUNT,
+        "invoke",
+        "--url",
+        URL,
+        "--contract-address",
+        MAP_CONTRACT_ADDRESS_SEPOLIA,
+        "--function",
+        "put",
+        "--calldata",
+        "0x1 0x2",
+        "--nonce",
+        "1",
+        "--retry-on-revert",
+        "2",
+    ];
+
+    let output = runner(&args).assert().failure();
+
+    assert_stderr_contains(output, "error: the argument [..] cannot be used with [..]");
+}
+
+#[test]
+fn test_no_wait_with_sequential_batch() {
+    let args = vec![
+        "--accounts-file",
+        ACCOUNT_FILE_PATH,
+        "--account",
+        ACCOUNT,
+        "--no-wait",
+        "invoke",
+        "--url",
+        URL,
+        "--batch-file",
+        "batch.jsonl",
+        "--fee-token",
+        "eth",
+    ];
+
+    let output = runner(&args).assert().failure();
+
+    assert_stderr_contains(
+        output,
+        "Error: --no-wait can be used with --batch-file only together with --parallel, otherwise every transaction is sent after the previous one is accepted",
+    );
+}
+
+#[test]
+fn test_no_wait_with_retry_on_revert() {
+    let args = vec![
+        "--accounts-file",
+        ACCOUNT_FILE_PATH,
+        "--account",
+        ACCOUNT,
+        "--no-wait",
+        "invoke",
+        "--url",
+        URL,
+        "--contract-address",
+        MAP_CONTRACT_ADDRESS_SEPOLIA,
+        "--function",
+        "put",
+        "--calldata",
+        "0x1 0x2",
+        "--retry-on-revert",
+        "2",
+    ];
+
+    let output = runner(&args).assert().failure();
+
+    assert_stderr_contains(
+        output,
+        "Error: --no-wait cannot be used with --retry-on-revert, the transaction has to be accepted to know whether it reverted",
+    );
+}
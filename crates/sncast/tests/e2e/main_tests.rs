@@ -6,10 +6,11 @@ use crate::helpers::fixtures::{
 };
 use crate::helpers::runner::runner;
 use configuration::copy_config_to_tempdir;
-use indoc::indoc;
-use shared::test_utils::output_assert::assert_stderr_contains;
-use sncast::helpers::constants::KEYSTORE_PASSWORD_ENV_VAR;
-use std::env;
+use indoc::{formatdoc, indoc};
+use shared::test_utils::output_assert::{assert_stderr_contains, assert_stdout_contains};
+use sncast::helpers::constants::{KEYSTORE_PASSWORD_ENV_VAR, SNCAST_PROFILE_ENV_VAR};
+use std::{env, fs};
+use tempfile::tempdir;
 
 #[tokio::test]
 async fn test_happy_case_from_sncast_config() {
@@ -320,3 +321,181 @@ async fn test_keystore_declare() {
 
     assert!(snapbox.assert().success().get_output().stderr.is_empty());
 }
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_keystore_password_file() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let tempdir = tempdir().unwrap();
+    let password_file = tempdir.path().join("password");
+    fs::write(&password_file, "123\n").unwrap();
+    fs::set_permissions(&password_file, fs::Permissions::from_mode(0o600)).unwrap();
+
+    let my_key_path = get_keystores_path("tests/data/keystore/predeployed_key.json");
+    let my_account_path = get_keystores_path("tests/data/keystore/predeployed_account.json");
+    let args = vec![
+        "--keystore",
+        my_key_path.as_str(),
+        "--account",
+        my_account_path.as_str(),
+        "--keystore-password-file",
+        password_file.to_str().unwrap(),
+        "get-nonce",
+        "--url",
+        URL,
+    ];
+
+    let output = runner(&args).assert().success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        command: get-nonce
+        address: 0x[..]
+        "},
+    );
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_keystore_password_file_readable_by_others() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let tempdir = tempdir().unwrap();
+    let password_file = tempdir.path().join("password");
+    fs::write(&password_file, "123").unwrap();
+    fs::set_permissions(&password_file, fs::Permissions::from_mode(0o644)).unwrap();
+
+    let my_key_path = get_keystores_path("tests/data/keystore/predeployed_key.json");
+    let my_account_path = get_keystores_path("tests/data/keystore/predeployed_account.json");
+    let args = vec![
+        "--keystore",
+        my_key_path.as_str(),
+        "--account",
+        my_account_path.as_str(),
+        "--keystore-password-file",
+        password_file.to_str().unwrap(),
+        "get-nonce",
+        "--url",
+        URL,
+    ];
+
+    let output = runner(&args).assert().success();
+
+    assert_stdout_contains(
+        output,
+        "[WARNING] Keystore password file [..] is readable by all users, restrict its permissions with `chmod 600 [..]`",
+    );
+}
+
+#[tokio::test]
+async fn test_profile_not_found() {
+    let tempdir = copy_config_to_tempdir("tests/data/files/correct_snfoundry.toml", None).unwrap();
+    let args = vec!["--profile", "profile100", "show-config"];
+
+    let output = runner(&args).current_dir(tempdir.path()).assert().failure();
+
+    assert_stderr_contains(
+        output,
+        "Error: Profile [profile100] not found in config, available profiles: default, profile1, profile2, profile3, profile4, profile5",
+    );
+}
+
+#[tokio::test]
+async fn test_profile_without_config_file() {
+    let tempdir = tempdir().unwrap();
+    let args = vec!["--profile", "profile1", "show-config", "--url", URL];
+
+    let output = runner(&args).current_dir(tempdir.path()).assert().success();
+
+    assert_stdout_contains(
+        output,
+        "[WARNING] Profile [profile1] is ignored, snfoundry.toml config file was not found in the current directory or any of its parents",
+    );
+}
+
+#[tokio::test]
+async fn test_profile_from_env() {
+    let tempdir = copy_config_to_tempdir("tests/data/files/correct_snfoundry.toml", None).unwrap();
+    let args = vec!["show-config"];
+
+    let output = runner(&args)
+        .env(SNCAST_PROFILE_ENV_VAR, "profile5")
+        .current_dir(tempdir.path())
+        .assert()
+        .success();
+
+    assert_stdout_contains(output, "account: user8");
+}
+
+#[tokio::test]
+async fn test_profile_flag_overrides_env() {
+    let tempdir = copy_config_to_tempdir("tests/data/files/correct_snfoundry.toml", None).unwrap();
+    let args = vec!["--profile", "profile1", "show-config"];
+
+    let output = runner(&args)
+        .env(SNCAST_PROFILE_ENV_VAR, "profile5")
+        .current_dir(tempdir.path())
+        .assert()
+        .success();
+
+    assert_stdout_contains(output, "account: user3");
+}
+
+#[tokio::test]
+async fn test_profile_matrix() {
+    let tempdir = copy_config_to_tempdir("tests/data/files/correct_snfoundry.toml", None).unwrap();
+    let matrix = format!("[profile1,profile5] x [{URL},http://127.0.0.1:1/rpc]");
+    let args = vec!["--profile-matrix", &matrix, "show-config"];
+
+    let output = runner(&args).current_dir(tempdir.path()).assert().failure();
+
+    assert_stdout_contains(
+        output,
+        formatdoc! {r"
+        profile: profile1, url: {URL}
+        rpc_url: {URL}
+        PROFILE   URL[..]RESULT
+        profile1  {URL}[..]success
+        profile1  http://127.0.0.1:1/rpc[..]failed
+        profile5  {URL}[..]success
+        profile5  http://127.0.0.1:1/rpc[..]failed
+        "},
+    );
+}
+
+#[tokio::test]
+async fn test_profile_matrix_with_url() {
+    let tempdir = copy_config_to_tempdir("tests/data/files/correct_snfoundry.toml", None).unwrap();
+    let matrix = format!("[profile1] x [{URL}]");
+    let args = vec!["--profile-matrix", &matrix, "show-config", "--url", URL];
+
+    let output = runner(&args).current_dir(tempdir.path()).assert().failure();
+
+    assert_stderr_contains(
+        output,
+        "Error: --url cannot be used with --profile-matrix, urls are taken from the matrix",
+    );
+}
+
+#[tokio::test]
+async fn test_dry_run_unsupported_command() {
+    let args = vec!["--dry-run", "get-nonce", "--url", URL];
+
+    let output = runner(&args).assert().failure();
+
+    assert_stderr_contains(
+        output,
+        "Error: --dry-run can only be used with declare, deploy and invoke commands",
+    );
+}
+
+#[tokio::test]
+async fn test_no_wait_conflicts_with_wait() {
+    let args = vec!["--wait", "--no-wait", "get-nonce", "--url", URL];
+
+    let output = runner(&args).assert().failure();
+
+    assert_stderr_contains(output, "error: the argument [..] cannot be used with [..]");
+}
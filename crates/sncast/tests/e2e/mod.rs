@@ -1,11 +1,22 @@
 mod account;
 mod call;
+mod class_clone;
+mod class_hash;
+mod config_validate;
+mod contract_call_log;
 mod declare;
 mod deploy;
+mod estimate_fee;
+mod get_abi;
+mod get_block;
+mod get_nonce;
+mod get_transaction;
 mod invoke;
 mod main_tests;
 mod multicall;
 mod script;
 mod show_config;
+mod storage_read;
+mod tx_decode_trace;
 mod tx_status;
 mod verify;
@@ -187,7 +187,7 @@ async fn test_invoke_fail() {
         output,
         indoc! {r"
         command: multicall run
-        error: An error occurred in the called contract [..]
+        error: There is no contract at the specified address
         "},
     );
 }
@@ -222,7 +222,115 @@ async fn test_deploy_success_invoke_fails() {
         output,
         indoc! {r"
         command: multicall run
-        error: An error occurred in the called contract [..]
+        error: There is no contract at the specified address
+        "},
+    );
+}
+
+#[tokio::test]
+async fn test_json_file() {
+    let path = project_root::get_project_root().expect("failed to get project root path");
+    let path = Path::new(&path)
+        .join(MULTICALL_CONFIGS_DIR)
+        .join("deploy_invoke.json");
+    let path = path.to_str().expect("failed converting path to str");
+
+    let args = vec![
+        "--accounts-file",
+        ACCOUNT_FILE_PATH,
+        "--account",
+        "user12",
+        "multicall",
+        "run",
+        "--url",
+        URL,
+        "--path",
+        path,
+        "--fee-token",
+        "eth",
+    ];
+
+    let snapbox = runner(&args);
+    let output = snapbox.assert();
+
+    let stderr_str = output.as_stderr();
+    assert!(
+        stderr_str.is_empty(),
+        "Multicall error, stderr: \n{stderr_str}",
+    );
+
+    output.stdout_matches(indoc! {r"
+        command: multicall run
+        transaction_hash: 0x[..]
+
+        To see invocation details, visit:
+        transaction: [..]
+    "});
+}
+
+#[tokio::test]
+async fn test_dry_run() {
+    let path = project_root::get_project_root().expect("failed to get project root path");
+    let path = Path::new(&path)
+        .join(MULTICALL_CONFIGS_DIR)
+        .join("deploy_invoke.toml");
+    let path = path.to_str().expect("failed converting path to str");
+
+    let args = vec![
+        "--accounts-file",
+        ACCOUNT_FILE_PATH,
+        "--account",
+        "oz",
+        "multicall",
+        "run",
+        "--url",
+        URL,
+        "--path",
+        path,
+        "--fee-token",
+        "eth",
+        "--dry-run",
+    ];
+
+    let snapbox = runner(&args);
+
+    snapbox.assert().success().stdout_matches(indoc! {r"
+        command: multicall run
+        calldata: [0x3, [..]]
+    "});
+}
+
+#[tokio::test]
+async fn test_function_not_in_abi() {
+    let path = project_root::get_project_root().expect("failed to get project root path");
+    let path = Path::new(&path)
+        .join(MULTICALL_CONFIGS_DIR)
+        .join("invoke_unknown_function.toml");
+    let path = path.to_str().expect("failed converting path to str");
+
+    let args = vec![
+        "--accounts-file",
+        ACCOUNT_FILE_PATH,
+        "--account",
+        "oz",
+        "multicall",
+        "run",
+        "--url",
+        URL,
+        "--path",
+        path,
+        "--fee-token",
+        "eth",
+    ];
+
+    let snapbox = runner(&args);
+    let output = snapbox.assert().success();
+
+    assert_stderr_contains(
+        output,
+        indoc! {r"
+        command: multicall run
+        error: Function `not_existing` not found in ABI of contract at address 0xcd8f9ab31324bb93251837e4efb4223ee195454f6304fcfcb277e277653008
         "},
     );
 }
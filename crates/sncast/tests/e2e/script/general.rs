@@ -544,3 +544,57 @@ async fn test_using_release_profile() {
         status: success
     "});
 }
+
+#[tokio::test]
+async fn test_script_args() {
+    let script_dir =
+        copy_script_directory_to_tempdir(SCRIPTS_DIR.to_owned() + "/misc", Vec::<String>::new());
+
+    let script_name = "script_args";
+    let args = vec![
+        "script",
+        "run",
+        &script_name,
+        "--url",
+        URL,
+        "--args",
+        "TOKEN_NAME=MyToken",
+        "INITIAL_SUPPLY=1000000",
+    ];
+
+    let snapbox = runner(&args).current_dir(script_dir.path());
+
+    snapbox.assert().success().stdout_matches(indoc! {r"
+        ...
+        command: script run
+        status: success
+    "});
+}
+
+#[tokio::test]
+async fn test_script_args_not_accepted_by_main() {
+    let script_dir =
+        copy_script_directory_to_tempdir(SCRIPTS_DIR.to_owned() + "/misc", Vec::<String>::new());
+
+    let script_name = "call_happy";
+    let args = vec![
+        "script",
+        "run",
+        &script_name,
+        "--url",
+        URL,
+        "--args",
+        "TOKEN_NAME=MyToken",
+    ];
+
+    let snapbox = runner(&args).current_dir(script_dir.path());
+    let output = snapbox.assert().success();
+
+    assert_stderr_contains(
+        output,
+        indoc! {r"
+        command: script run
+        error: Script arguments were passed with `--args`, but `main` function of the script does not accept `ScriptArgs`
+        "},
+    );
+}
@@ -0,0 +1,66 @@
+use crate::helpers::constants::{MAP_CONTRACT_ADDRESS_SEPOLIA, URL};
+use crate::helpers::runner::runner;
+use indoc::{formatdoc, indoc};
+use shared::test_utils::output_assert::assert_stderr_contains;
+use sncast::helpers::storage::storage_key;
+
+#[tokio::test]
+async fn test_read_variable() {
+    let args = vec![
+        "storage-read",
+        "--url",
+        URL,
+        "--contract-address",
+        MAP_CONTRACT_ADDRESS_SEPOLIA,
+        "--variable",
+        "storage",
+    ];
+    let key = storage_key("storage", None).unwrap();
+
+    runner(&args).assert().success().stdout_eq(formatdoc! {r"
+        command: storage-read
+        key: {key:#x}
+        value: 0x0
+    "});
+}
+
+#[tokio::test]
+async fn test_read_key() {
+    let args = vec![
+        "storage-read",
+        "--url",
+        URL,
+        "--contract-address",
+        MAP_CONTRACT_ADDRESS_SEPOLIA,
+        "--key",
+        "0x1",
+    ];
+
+    runner(&args).assert().success().stdout_eq(indoc! {r"
+        command: storage-read
+        key: 0x1
+        value: 0x0
+    "});
+}
+
+#[tokio::test]
+async fn test_contract_not_found() {
+    let args = vec![
+        "storage-read",
+        "--url",
+        URL,
+        "--contract-address",
+        "0x1",
+        "--key",
+        "0x1",
+    ];
+    let output = runner(&args).assert().success();
+
+    assert_stderr_contains(
+        output,
+        indoc! {r"
+        command: storage-read
+        error: There is no contract at the specified address
+        "},
+    );
+}
@@ -0,0 +1,90 @@
+use crate::helpers::constants::URL;
+use crate::helpers::runner::runner;
+use indoc::indoc;
+use serde_json::Value;
+use shared::test_utils::output_assert::{assert_stderr_contains, assert_stdout_contains};
+use tempfile::tempdir;
+
+const SUCCEEDED_TX_HASH: &str =
+    "0x07d2067cd7675f88493a9d773b456c8d941457ecc2f6201d2fe6b0607daadfd1";
+
+#[tokio::test]
+async fn test_happy_case() {
+    let args = vec!["tx", "decode-trace", SUCCEEDED_TX_HASH, "--url", URL];
+
+    let output = runner(&args).assert().success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        command: tx decode-trace
+        trace:
+        validate:
+          0x[..]
+        execute:
+          0x[..]
+        "},
+    );
+}
+
+#[tokio::test]
+async fn test_happy_case_json() {
+    let args = vec![
+        "--json",
+        "tx",
+        "decode-trace",
+        SUCCEEDED_TX_HASH,
+        "--url",
+        URL,
+    ];
+
+    let output = runner(&args).assert().success();
+    let stdout: Value = serde_json::from_slice(&output.get_output().stdout).unwrap();
+
+    assert_eq!(stdout["command"], "tx decode-trace");
+    assert!(stdout["trace"]
+        .as_str()
+        .unwrap()
+        .starts_with("validate:\n  0x"));
+}
+
+#[tokio::test]
+async fn test_incorrect_transaction_hash() {
+    let args = vec!["tx", "decode-trace", "0x1", "--url", URL];
+
+    let output = runner(&args).assert().success();
+
+    assert_stderr_contains(
+        output,
+        indoc! {r"
+        command: tx decode-trace
+        error: [..]
+        "},
+    );
+}
+
+#[tokio::test]
+async fn test_invalid_abi_file_name() {
+    let abi_dir = tempdir().unwrap();
+    std::fs::write(abi_dir.path().join("erc20.json"), "[]").unwrap();
+
+    let args = vec![
+        "tx",
+        "decode-trace",
+        SUCCEEDED_TX_HASH,
+        "--abi-dir",
+        abi_dir.path().to_str().unwrap(),
+        "--url",
+        URL,
+    ];
+
+    let output = runner(&args).assert().success();
+
+    assert_stderr_contains(
+        output,
+        indoc! {r"
+        command: tx decode-trace
+        error: ABI file [..]erc20.json must be named after a class hash, e.g. 0x123.json
+        "},
+    );
+}
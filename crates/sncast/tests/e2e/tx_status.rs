@@ -45,3 +45,49 @@ async fn test_reverted() {
         finality_status: AcceptedOnL1
     "});
 }
+
+#[tokio::test]
+async fn test_wait_succeeded() {
+    let args = vec!["tx-status", SUCCEEDED_TX_HASH, "--wait", "--url", URL];
+    let snapbox = runner(&args);
+    let output = snapbox.assert().success();
+
+    assert_stderr_contains(output, "Transaction status: ACCEPTED_ON_L1 (SUCCEEDED)");
+}
+
+#[tokio::test]
+async fn test_wait_reverted() {
+    let args = vec!["tx-status", REVERTED_TX_HASH, "--wait", "--url", URL];
+    let snapbox = runner(&args);
+
+    snapbox.assert().code(1).stdout_eq(indoc! {r"
+        command: tx-status
+        execution_status: Reverted
+        finality_status: AcceptedOnL1
+    "});
+}
+
+#[tokio::test]
+async fn test_wait_timeout() {
+    let args = vec![
+        "tx-status",
+        "0x1",
+        "--wait",
+        "--poll-interval",
+        "1",
+        "--timeout",
+        "2",
+        "--url",
+        URL,
+    ];
+    let snapbox = runner(&args);
+    let output = snapbox.assert().code(1);
+
+    assert_stderr_contains(
+        output,
+        indoc! {r"
+        command: tx-status
+        error: Failed to get transaction status: sncast timed out while waiting for transaction to succeed
+        "},
+    );
+}
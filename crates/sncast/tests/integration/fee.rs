@@ -1,10 +1,11 @@
 use crate::helpers::constants::URL;
 use sncast::helpers::constants::OZ_CLASS_HASH;
-use sncast::helpers::fee::{FeeArgs, FeeSettings, FeeToken};
+use sncast::helpers::fee::{FeeArgs, FeeSettings, FeeToken, ResourceBounds};
 use starknet::accounts::{AccountFactory, OpenZeppelinAccountFactory};
 use starknet::providers::jsonrpc::HttpTransport;
 use starknet::providers::{JsonRpcClient, Provider};
 use starknet::signers::{LocalWallet, SigningKey};
+use std::str::FromStr;
 use url::Url;
 
 const MAX_FEE: u64 = 1_000_000_000_000;
@@ -27,6 +28,7 @@ async fn test_happy_case_eth() {
     let args = FeeArgs {
         fee_token: Some(FeeToken::Eth),
         max_fee: Some(100_u32.into()),
+        gas_price: None,
         max_gas: None,
         max_gas_unit_price: None,
     };
@@ -39,7 +41,8 @@ async fn test_happy_case_eth() {
     assert_eq!(
         settings,
         FeeSettings::Eth {
-            max_fee: Some(100_u32.into())
+            max_fee: Some(100_u32.into()),
+            gas_price: None,
         }
     );
 }
@@ -51,6 +54,7 @@ async fn test_max_gas_eth() {
     let args = FeeArgs {
         fee_token: Some(FeeToken::Eth),
         max_fee: Some(100_u32.into()),
+        gas_price: None,
         max_gas: Some(100_u32.into()),
         max_gas_unit_price: None,
     };
@@ -72,6 +76,7 @@ async fn test_max_gas_unit_price_eth() {
     let args = FeeArgs {
         fee_token: Some(FeeToken::Eth),
         max_fee: Some(100_u32.into()),
+        gas_price: None,
         max_gas: None,
         max_gas_unit_price: Some(100_u32.into()),
     };
@@ -93,6 +98,7 @@ async fn test_all_args() {
     let args = FeeArgs {
         fee_token: Some(FeeToken::Strk),
         max_fee: Some(100_u32.into()),
+        gas_price: None,
         max_gas: Some(100_u32.into()),
         max_gas_unit_price: Some(100_u32.into()),
     };
@@ -114,6 +120,7 @@ async fn test_max_fee_less_than_max_gas() {
     let args = FeeArgs {
         fee_token: Some(FeeToken::Strk),
         max_fee: Some(50_u32.into()),
+        gas_price: None,
         max_gas: Some(100_u32.into()),
         max_gas_unit_price: None,
     };
@@ -135,6 +142,7 @@ async fn test_max_fee_less_than_max_gas_unit_price() {
     let args = FeeArgs {
         fee_token: Some(FeeToken::Strk),
         max_fee: Some(50_u32.into()),
+        gas_price: None,
         max_gas: None,
         max_gas_unit_price: Some(100_u32.into()),
     };
@@ -155,6 +163,7 @@ async fn test_strk_fee_get_max_fee() {
     let args = FeeArgs {
         fee_token: Some(FeeToken::Strk),
         max_fee: Some(MAX_FEE.into()),
+        gas_price: None,
         max_gas: None,
         max_gas_unit_price: None,
     };
@@ -185,6 +194,7 @@ async fn test_strk_fee_get_max_fee_with_max_gas() {
     let args = FeeArgs {
         fee_token: Some(FeeToken::Strk),
         max_fee: Some(MAX_FEE.into()),
+        gas_price: None,
         max_gas: Some(1_000_000_u32.into()),
         max_gas_unit_price: None,
     };
@@ -223,6 +233,7 @@ async fn test_strk_fee_get_max_gas_and_max_gas_unit_price() {
     let args = FeeArgs {
         fee_token: Some(FeeToken::Strk),
         max_fee: None,
+        gas_price: None,
         max_gas: Some(1_000_000_u32.into()),
         max_gas_unit_price: Some(1_000_u32.into()),
     };
@@ -248,6 +259,7 @@ async fn test_strk_fee_get_max_fee_with_max_gas_unit_price() {
     let args = FeeArgs {
         fee_token: Some(FeeToken::Strk),
         max_fee: Some(MAX_FEE.into()),
+        gas_price: None,
         max_gas: None,
         max_gas_unit_price: Some(1_000_u32.into()),
     };
@@ -286,6 +298,7 @@ async fn test_strk_fee_get_none() {
     let args = FeeArgs {
         fee_token: Some(FeeToken::Strk),
         max_fee: None,
+        gas_price: None,
         max_gas: None,
         max_gas_unit_price: None,
     };
@@ -303,3 +316,71 @@ async fn test_strk_fee_get_none() {
         }
     );
 }
+
+#[tokio::test]
+async fn test_gas_price_eth() {
+    let factory = get_factory().await;
+
+    let args = FeeArgs {
+        fee_token: Some(FeeToken::Eth),
+        max_fee: None,
+        gas_price: Some(100_u32.into()),
+        max_gas: None,
+        max_gas_unit_price: None,
+    };
+
+    let settings = args
+        .try_into_fee_settings(factory.provider(), factory.block_id())
+        .await
+        .unwrap();
+
+    assert_eq!(
+        settings,
+        FeeSettings::Eth {
+            max_fee: None,
+            gas_price: Some(100_u32.into()),
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_gas_price_strk() {
+    let factory = get_factory().await;
+
+    let args = FeeArgs {
+        fee_token: Some(FeeToken::Strk),
+        max_fee: None,
+        gas_price: Some(100_u32.into()),
+        max_gas: None,
+        max_gas_unit_price: None,
+    };
+
+    let error = args
+        .try_into_fee_settings(factory.provider(), factory.block_id())
+        .await
+        .unwrap_err();
+
+    assert!(error
+        .to_string()
+        .contains("--gas-price is not supported for STRK fee payment"));
+}
+
+#[test]
+fn test_parse_resource_bounds() {
+    let bounds = ResourceBounds::from_str("1000,0x174876e800").unwrap();
+
+    assert_eq!(
+        bounds,
+        ResourceBounds {
+            max_amount: 1000,
+            max_price_per_unit: 100_000_000_000,
+        }
+    );
+}
+
+#[test]
+fn test_parse_resource_bounds_invalid() {
+    assert!(ResourceBounds::from_str("1000").is_err());
+    assert!(ResourceBounds::from_str("1000,abc").is_err());
+    assert!(ResourceBounds::from_str("0x10000000000000000,1").is_err());
+}
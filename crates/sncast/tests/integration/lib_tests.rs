@@ -4,7 +4,7 @@ use crate::helpers::constants::{
 use crate::helpers::fixtures::create_test_provider;
 
 use camino::Utf8PathBuf;
-use shared::rpc::{get_rpc_version, is_expected_version};
+use shared::rpc::{get_rpc_version, is_expected_version, RetryConfig};
 use sncast::{check_if_legacy_contract, get_account, get_provider};
 use starknet::accounts::Account;
 use starknet::macros::felt;
@@ -139,7 +139,9 @@ async fn test_get_account_failed_to_convert_field_elements() {
 #[tokio::test]
 async fn test_supported_rpc_version_matches_devnet_version() {
     let provider = create_test_provider();
-    let devnet_spec_version = get_rpc_version(&provider).await.unwrap();
+    let devnet_spec_version = get_rpc_version(&provider, RetryConfig::default())
+        .await
+        .unwrap();
     assert!(is_expected_version(&devnet_spec_version));
 }
 
@@ -105,6 +105,7 @@ async fn test_happy_path_handle_wait_for_tx() {
         WaitForTx {
             wait: true,
             wait_params: ValidatedWaitParams::new(5, 63),
+            status_to_stderr: false,
         },
     )
     .await;
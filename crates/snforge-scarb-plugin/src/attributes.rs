@@ -2,13 +2,17 @@ use crate::args::Arguments;
 use cairo_lang_macro::{Diagnostic, Diagnostics};
 use cairo_lang_syntax::node::db::SyntaxGroup;
 
+pub mod as_l1_handler;
 pub mod available_gas;
 pub mod fork;
 pub mod fuzzer;
 pub mod ignore;
 pub mod internal_config_statement;
+pub mod parallel_fork;
 pub mod should_panic;
+pub mod tag;
 pub mod test;
+pub mod timeout;
 
 pub trait AttributeInfo {
     const ATTR_NAME: &'static str;
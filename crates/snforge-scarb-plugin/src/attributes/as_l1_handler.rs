@@ -0,0 +1,34 @@
+use super::{AttributeInfo, AttributeTypeData};
+use crate::{
+    args::Arguments, attributes::AttributeCollector,
+    config_statement::extend_with_config_cheatcodes,
+};
+use cairo_lang_macro::{Diagnostic, Diagnostics, ProcMacroResult, TokenStream};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+
+pub struct AsL1HandlerCollector;
+
+impl AttributeInfo for AsL1HandlerCollector {
+    const ATTR_NAME: &'static str = "as_l1_handler";
+}
+
+impl AttributeTypeData for AsL1HandlerCollector {
+    const CHEATCODE_NAME: &'static str = "set_config_l1_handler";
+}
+
+impl AttributeCollector for AsL1HandlerCollector {
+    fn args_into_config_expression(
+        _db: &dyn SyntaxGroup,
+        args: Arguments,
+        _warns: &mut Vec<Diagnostic>,
+    ) -> Result<String, Diagnostics> {
+        args.assert_is_empty::<Self>()?;
+
+        Ok("snforge_std::_config_types::L1HandlerConfig { is_l1_handler: true }".to_string())
+    }
+}
+
+#[must_use]
+pub fn as_l1_handler(args: TokenStream, item: TokenStream) -> ProcMacroResult {
+    extend_with_config_cheatcodes::<AsL1HandlerCollector>(args, item)
+}
@@ -30,18 +30,29 @@ impl AttributeCollector for ForkCollector {
         args: Arguments,
         _warns: &mut Vec<Diagnostic>,
     ) -> Result<String, Diagnostics> {
-        let expr = branch!(
-            inline_args(db, &args),
-            overridden_args(db, &args),
-            from_file_args(db, &args)
-        )?;
-
-        Ok(expr)
+        fork_config_expression::<Self>(db, &args)
     }
 }
 
-fn inline_args(db: &dyn SyntaxGroup, args: &Arguments) -> Result<String, Diagnostic> {
-    let named_args = args.named_only::<ForkCollector>()?;
+/// Builds the `ForkConfig` expression from arguments of `#[fork]` or other attributes accepting the same arguments
+pub fn fork_config_expression<T: AttributeInfo>(
+    db: &dyn SyntaxGroup,
+    args: &Arguments,
+) -> Result<String, Diagnostics> {
+    let expr = branch!(
+        inline_args::<T>(db, args),
+        overridden_args::<T>(db, args),
+        from_file_args::<T>(db, args)
+    )?;
+
+    Ok(expr)
+}
+
+fn inline_args<T: AttributeInfo>(
+    db: &dyn SyntaxGroup,
+    args: &Arguments,
+) -> Result<String, Diagnostic> {
+    let named_args = args.named_only::<T>()?;
 
     let block_id = named_args.one_of_once(&[
         BlockIdVariants::Hash,
@@ -50,8 +61,8 @@ fn inline_args(db: &dyn SyntaxGroup, args: &Arguments) -> Result<String, Diagnos
     ])?;
     let url = named_args.as_once("url")?;
 
-    let block_id = BlockId::parse_from_expr::<ForkCollector>(db, &block_id, block_id.0.as_ref())?;
-    let url = Url::parse_from_expr::<ForkCollector>(db, url, "url")?;
+    let block_id = BlockId::parse_from_expr::<T>(db, &block_id, block_id.0.as_ref())?;
+    let url = Url::parse_from_expr::<T>(db, url, "url")?;
 
     let block_id = block_id.as_cairo_expression();
     let url = url.as_cairo_expression();
@@ -68,12 +79,13 @@ fn inline_args(db: &dyn SyntaxGroup, args: &Arguments) -> Result<String, Diagnos
     ))
 }
 
-fn from_file_args(db: &dyn SyntaxGroup, args: &Arguments) -> Result<String, Diagnostic> {
-    let &[arg] = args
-        .unnamed_only::<ForkCollector>()?
-        .of_length::<1, ForkCollector>()?;
+fn from_file_args<T: AttributeInfo>(
+    db: &dyn SyntaxGroup,
+    args: &Arguments,
+) -> Result<String, Diagnostic> {
+    let &[arg] = args.unnamed_only::<T>()?.of_length::<1, T>()?;
 
-    let name = String::parse_from_expr::<ForkCollector>(db, arg.1, arg.0.to_string().as_str())?;
+    let name = String::parse_from_expr::<T>(db, arg.1, arg.0.to_string().as_str())?;
 
     let name = name.as_cairo_expression();
 
@@ -82,8 +94,11 @@ fn from_file_args(db: &dyn SyntaxGroup, args: &Arguments) -> Result<String, Diag
     ))
 }
 
-fn overridden_args(db: &dyn SyntaxGroup, args: &Arguments) -> Result<String, Diagnostic> {
-    let &[arg] = args.unnamed().of_length::<1, ForkCollector>()?;
+fn overridden_args<T: AttributeInfo>(
+    db: &dyn SyntaxGroup,
+    args: &Arguments,
+) -> Result<String, Diagnostic> {
+    let &[arg] = args.unnamed().of_length::<1, T>()?;
 
     let block_id = args.named.one_of_once(&[
         BlockIdVariants::Hash,
@@ -91,8 +106,8 @@ fn overridden_args(db: &dyn SyntaxGroup, args: &Arguments) -> Result<String, Dia
         BlockIdVariants::Tag,
     ])?;
 
-    let block_id = BlockId::parse_from_expr::<ForkCollector>(db, &block_id, block_id.0.as_ref())?;
-    let name = String::parse_from_expr::<ForkCollector>(db, arg.1, arg.0.to_string().as_str())?;
+    let block_id = BlockId::parse_from_expr::<T>(db, &block_id, block_id.0.as_ref())?;
+    let name = String::parse_from_expr::<T>(db, arg.1, arg.0.to_string().as_str())?;
 
     let block_id = block_id.as_cairo_expression();
     let name = name.as_cairo_expression();
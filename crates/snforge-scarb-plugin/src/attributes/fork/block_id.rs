@@ -1,4 +1,3 @@
-use super::ForkCollector;
 use crate::{
     attributes::ErrorExt,
     cairo_expression::CairoExpression,
@@ -24,11 +23,26 @@ impl AsRef<str> for BlockIdVariants {
     }
 }
 
+#[derive(Debug, Clone, Copy)]
+pub enum BlockTag {
+    Latest,
+    Pending,
+}
+
+impl CairoExpression for BlockTag {
+    fn as_cairo_expression(&self) -> String {
+        match self {
+            Self::Latest => "snforge_std::_config_types::BlockTag::Latest".to_string(),
+            Self::Pending => "snforge_std::_config_types::BlockTag::Pending".to_string(),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum BlockId {
     Hash(Number),
     Number(Number),
-    Tag,
+    Tag(BlockTag),
 }
 
 impl CairoExpression for BlockId {
@@ -42,7 +56,10 @@ impl CairoExpression for BlockId {
                 "snforge_std::_config_types::BlockId::BlockNumber({})",
                 number.as_cairo_expression()
             ),
-            Self::Tag => "snforge_std::_config_types::BlockId::BlockTag".to_string(),
+            Self::Tag(tag) => format!(
+                "snforge_std::_config_types::BlockId::BlockTag({})",
+                tag.as_cairo_expression()
+            ),
         }
     }
 }
@@ -61,31 +78,26 @@ impl ParseFromExpr<(BlockIdVariants, &Expr)> for BlockId {
                     if segments.len() == 1 {
                         let segment = segments.last().unwrap();
 
-                        // currently no other tags
-                        if segment.identifier(db).as_str() == "latest" {
-                            return Ok(Self::Tag);
+                        match segment.identifier(db).as_str() {
+                            "latest" => return Ok(Self::Tag(BlockTag::Latest)),
+                            "pending" => return Ok(Self::Tag(BlockTag::Pending)),
+                            _ => {}
                         }
                     }
                 }
-                Err(ForkCollector::error(format!(
-                    "<{arg_name}> value incorrect, expected: latest",
+                Err(T::error(format!(
+                    "<{arg_name}> value incorrect, expected: latest or pending",
                 )))
             }
             BlockIdVariants::Hash => {
-                let hash = Number::parse_from_expr::<ForkCollector>(
-                    db,
-                    block_args,
-                    BlockIdVariants::Hash.as_ref(),
-                )?;
+                let hash =
+                    Number::parse_from_expr::<T>(db, block_args, BlockIdVariants::Hash.as_ref())?;
 
                 Ok(Self::Hash(hash))
             }
             BlockIdVariants::Number => {
-                let number = Number::parse_from_expr::<ForkCollector>(
-                    db,
-                    block_args,
-                    BlockIdVariants::Number.as_ref(),
-                )?;
+                let number =
+                    Number::parse_from_expr::<T>(db, block_args, BlockIdVariants::Number.as_ref())?;
 
                 Ok(Self::Number(number))
             }
@@ -0,0 +1,34 @@
+use crate::{
+    args::Arguments,
+    attributes::{
+        fork::fork_config_expression, AttributeCollector, AttributeInfo, AttributeTypeData,
+    },
+    config_statement::extend_with_config_cheatcodes,
+};
+use cairo_lang_macro::{Diagnostic, Diagnostics, ProcMacroResult, TokenStream};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+
+pub struct ParallelForkCollector;
+
+impl AttributeInfo for ParallelForkCollector {
+    const ATTR_NAME: &'static str = "parallel_fork";
+}
+
+impl AttributeTypeData for ParallelForkCollector {
+    const CHEATCODE_NAME: &'static str = "set_config_parallel_fork";
+}
+
+impl AttributeCollector for ParallelForkCollector {
+    fn args_into_config_expression(
+        db: &dyn SyntaxGroup,
+        args: Arguments,
+        _warns: &mut Vec<Diagnostic>,
+    ) -> Result<String, Diagnostics> {
+        fork_config_expression::<Self>(db, &args)
+    }
+}
+
+#[must_use]
+pub fn parallel_fork(args: TokenStream, item: TokenStream) -> ProcMacroResult {
+    extend_with_config_cheatcodes::<ParallelForkCollector>(args, item)
+}
@@ -0,0 +1,51 @@
+use crate::{
+    args::Arguments,
+    attributes::{AttributeCollector, AttributeInfo, AttributeTypeData, ErrorExt},
+    cairo_expression::CairoExpression,
+    config_statement::extend_with_config_cheatcodes,
+    types::ParseFromExpr,
+};
+use cairo_lang_macro::{Diagnostic, Diagnostics, ProcMacroResult, TokenStream};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+
+pub struct TagCollector;
+
+impl AttributeInfo for TagCollector {
+    const ATTR_NAME: &'static str = "tag";
+}
+
+impl AttributeTypeData for TagCollector {
+    const CHEATCODE_NAME: &'static str = "set_config_tag";
+}
+
+impl AttributeCollector for TagCollector {
+    fn args_into_config_expression(
+        db: &dyn SyntaxGroup,
+        args: Arguments,
+        _warns: &mut Vec<Diagnostic>,
+    ) -> Result<String, Diagnostics> {
+        let args = args.unnamed_only::<Self>()?;
+
+        if args.is_empty() {
+            Err(Self::error("expected at least one tag"))?;
+        }
+
+        let tags = args
+            .iter()
+            .map(|(position, expr)| {
+                String::parse_from_expr::<Self>(db, expr, position.to_string().as_str())
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let tags = tags.as_cairo_expression();
+
+        Ok(format!(
+            "snforge_std::_config_types::TagConfig {{ tags: {tags} }}"
+        ))
+    }
+}
+
+#[must_use]
+pub fn tag(args: TokenStream, item: TokenStream) -> ProcMacroResult {
+    extend_with_config_cheatcodes::<TagCollector>(args, item)
+}
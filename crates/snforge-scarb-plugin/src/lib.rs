@@ -1,6 +1,8 @@
 use attributes::{
-    available_gas::available_gas, fork::fork, fuzzer::fuzzer, ignore::ignore,
-    internal_config_statement::internal_config_statement, should_panic::should_panic, test::test,
+    as_l1_handler::as_l1_handler, available_gas::available_gas, fork::fork, fuzzer::fuzzer,
+    ignore::ignore, internal_config_statement::internal_config_statement,
+    parallel_fork::parallel_fork, should_panic::should_panic, tag::tag, test::test,
+    timeout::timeout,
 };
 use cairo_lang_macro::{attribute_macro, executable_attribute, ProcMacroResult, TokenStream};
 
@@ -43,6 +45,11 @@ fn fork(args: TokenStream, item: TokenStream) -> ProcMacroResult {
     fork(args, item)
 }
 
+#[attribute_macro]
+fn parallel_fork(args: TokenStream, item: TokenStream) -> ProcMacroResult {
+    parallel_fork(args, item)
+}
+
 #[attribute_macro]
 fn available_gas(args: TokenStream, item: TokenStream) -> ProcMacroResult {
     available_gas(args, item)
@@ -52,3 +59,18 @@ fn available_gas(args: TokenStream, item: TokenStream) -> ProcMacroResult {
 fn should_panic(args: TokenStream, item: TokenStream) -> ProcMacroResult {
     should_panic(args, item)
 }
+
+#[attribute_macro]
+fn tag(args: TokenStream, item: TokenStream) -> ProcMacroResult {
+    tag(args, item)
+}
+
+#[attribute_macro]
+fn as_l1_handler(args: TokenStream, item: TokenStream) -> ProcMacroResult {
+    as_l1_handler(args, item)
+}
+
+#[attribute_macro]
+fn timeout(args: TokenStream, item: TokenStream) -> ProcMacroResult {
+    timeout(args, item)
+}
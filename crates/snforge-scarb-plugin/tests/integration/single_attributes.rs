@@ -1,7 +1,11 @@
+mod as_l1_handler;
 mod available_gas;
 mod fork;
 mod fuzzer;
 mod ignore;
 mod internal_config_statement;
+mod parallel_fork;
 mod should_panic;
+mod tag;
 mod test;
+mod timeout;
@@ -0,0 +1,69 @@
+use crate::utils::{assert_diagnostics, assert_output, EMPTY_FN};
+use cairo_lang_macro::{Diagnostic, TokenStream};
+use indoc::formatdoc;
+use snforge_scarb_plugin::attributes::as_l1_handler::as_l1_handler;
+
+#[test]
+fn fails_with_args() {
+    let item = TokenStream::new(EMPTY_FN.into());
+    let args = TokenStream::new("(123)".into());
+
+    let result = as_l1_handler(args, item);
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error(
+            "#[as_l1_handler] does not accept any arguments",
+        )],
+    );
+}
+
+#[test]
+fn works_without_args() {
+    let item = TokenStream::new(EMPTY_FN.into());
+    let args = TokenStream::new(String::new());
+
+    let result = as_l1_handler(args, item);
+
+    assert_diagnostics(&result, &[]);
+
+    assert_output(
+        &result,
+        "
+            fn empty_fn() {
+                if snforge_std::_cheatcode::_is_config_run() {
+                    let mut data = array![];
+
+                    snforge_std::_config_types::L1HandlerConfig {
+                        is_l1_handler: true
+                    }
+                    .serialize(ref data);
+
+                    starknet::testing::cheatcode::<'set_config_l1_handler'>(data.span());
+
+                    return;
+                }
+            }
+        ",
+    );
+}
+
+#[test]
+fn is_used_once() {
+    let item = TokenStream::new(formatdoc!(
+        "
+            #[as_l1_handler]
+            {EMPTY_FN}
+        "
+    ));
+    let args = TokenStream::new(String::new());
+
+    let result = as_l1_handler(args, item);
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error(
+            "#[as_l1_handler] can only be used once per item",
+        )],
+    );
+}
@@ -0,0 +1,108 @@
+use crate::utils::{assert_diagnostics, assert_output, EMPTY_FN};
+use cairo_lang_macro::{Diagnostic, TokenStream};
+use indoc::formatdoc;
+use snforge_scarb_plugin::attributes::parallel_fork::parallel_fork;
+
+#[test]
+fn fails_without_args() {
+    let item = TokenStream::new(EMPTY_FN.into());
+    let args = TokenStream::new("()".into());
+
+    let result = parallel_fork(args, item);
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error(formatdoc!(
+            "
+                All options failed
+                - variant: exactly one of <block_hash> | <block_number> | <block_tag> should be specified, got 0
+                - variant: #[parallel_fork] expected 1 arguments, got: 0
+                - variant: #[parallel_fork] expected 1 arguments, got: 0
+                Resolve at least one of them
+            "
+        ))],
+    );
+}
+
+#[test]
+fn accepts_string() {
+    let item = TokenStream::new(EMPTY_FN.into());
+    let args = TokenStream::new(r#"("test")"#.into());
+
+    let result = parallel_fork(args, item);
+
+    assert_diagnostics(&result, &[]);
+
+    assert_output(
+        &result,
+        r#"
+            fn empty_fn() {
+                if snforge_std::_cheatcode::_is_config_run() {
+
+                    let mut data = array![];
+
+                    snforge_std::_config_types::ForkConfig::Named("test")
+                        .serialize(ref data);
+
+                    starknet::testing::cheatcode::<'set_config_parallel_fork'>(data.span());
+
+                    return;
+                }
+            }
+        "#,
+    );
+}
+
+#[test]
+fn accepts_inline_config() {
+    let item = TokenStream::new(EMPTY_FN.into());
+    let args = TokenStream::new(r#"(url: "http://example.com", block_number: 23)"#.into());
+
+    let result = parallel_fork(args, item);
+
+    assert_diagnostics(&result, &[]);
+
+    assert_output(
+        &result,
+        r#"
+            fn empty_fn() {
+                if snforge_std::_cheatcode::_is_config_run() {
+
+                    let mut data = array![];
+
+                    snforge_std::_config_types::ForkConfig::Inline(
+                        snforge_std::_config_types::InlineForkConfig {
+                            url: "http://example.com/",
+                            block: snforge_std::_config_types::BlockId::BlockNumber(0x17)
+                        }
+                    )
+                    .serialize(ref data);
+
+                    starknet::testing::cheatcode::<'set_config_parallel_fork'>(data.span());
+
+                    return;
+                }
+            }
+        "#,
+    );
+}
+
+#[test]
+fn is_used_once() {
+    let item = TokenStream::new(formatdoc!(
+        "
+            #[parallel_fork]
+            {EMPTY_FN}
+        "
+    ));
+    let args = TokenStream::new(r#"("test")"#.into());
+
+    let result = parallel_fork(args, item);
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error(
+            "#[parallel_fork] can only be used once per item",
+        )],
+    );
+}
@@ -0,0 +1,125 @@
+use crate::utils::{assert_diagnostics, assert_output, EMPTY_FN};
+use cairo_lang_macro::{Diagnostic, TokenStream};
+use indoc::formatdoc;
+use snforge_scarb_plugin::attributes::tag::tag;
+
+#[test]
+fn fails_with_empty() {
+    let item = TokenStream::new(EMPTY_FN.into());
+    let args = TokenStream::new("()".into());
+
+    let result = tag(args, item);
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error("#[tag] expected at least one tag")],
+    );
+}
+
+#[test]
+fn fails_with_named() {
+    let item = TokenStream::new(EMPTY_FN.into());
+    let args = TokenStream::new(r#"(name: "slow")"#.into());
+
+    let result = tag(args, item);
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error(
+            "#[tag] can be used with unnamed attributes only",
+        )],
+    );
+}
+
+#[test]
+fn fails_with_non_string() {
+    let item = TokenStream::new(EMPTY_FN.into());
+    let args = TokenStream::new(r#"("slow", 'integration')"#.into());
+
+    let result = tag(args, item);
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error(
+            "#[tag] <1> invalid type, should be: double quotted string",
+        )],
+    );
+}
+
+#[test]
+fn work_with_single_tag() {
+    let item = TokenStream::new(EMPTY_FN.into());
+    let args = TokenStream::new(r#"("slow")"#.into());
+
+    let result = tag(args, item);
+
+    assert_diagnostics(&result, &[]);
+
+    assert_output(
+        &result,
+        r#"
+            fn empty_fn() {
+                if snforge_std::_cheatcode::_is_config_run() {
+                    let mut data = array![];
+
+                    snforge_std::_config_types::TagConfig {
+                        tags: array!["slow",]
+                    }
+                    .serialize(ref data);
+
+                    starknet::testing::cheatcode::<'set_config_tag'>(data.span());
+
+                    return;
+                }
+            }
+        "#,
+    );
+}
+
+#[test]
+fn work_with_multiple_tags() {
+    let item = TokenStream::new(EMPTY_FN.into());
+    let args = TokenStream::new(r#"("slow", "integration")"#.into());
+
+    let result = tag(args, item);
+
+    assert_diagnostics(&result, &[]);
+
+    assert_output(
+        &result,
+        r#"
+            fn empty_fn() {
+                if snforge_std::_cheatcode::_is_config_run() {
+                    let mut data = array![];
+
+                    snforge_std::_config_types::TagConfig {
+                        tags: array!["slow","integration",]
+                    }
+                    .serialize(ref data);
+
+                    starknet::testing::cheatcode::<'set_config_tag'>(data.span());
+
+                    return;
+                }
+            }
+        "#,
+    );
+}
+
+#[test]
+fn is_used_once() {
+    let item = TokenStream::new(formatdoc!(
+        "
+            #[tag]
+            {EMPTY_FN}
+        "
+    ));
+    let args = TokenStream::new(r#"("slow")"#.into());
+
+    let result = tag(args, item);
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error("#[tag] can only be used once per item")],
+    );
+}
@@ -369,6 +369,55 @@ pub fn tx_status(transaction_hash: felt252) -> Result<TxStatusResult, ScriptComm
     result_data
 }
 
+/// Named arguments passed to the script with `sncast script run --args KEY=VALUE...`
+#[derive(Drop, Copy)]
+pub struct ScriptArgs {
+    serialized: Span<felt252>,
+}
+
+#[generate_trait]
+pub impl ScriptArgsImpl of ScriptArgsTrait {
+    /// Returns the value passed for `key` or `Option::None` if it was not passed
+    fn get(self: @ScriptArgs, key: ByteArray) -> Option<ByteArray> {
+        match find_script_arg(*self.serialized, key) {
+            Option::Some((value, _)) => Option::Some(value),
+            Option::None => Option::None,
+        }
+    }
+
+    /// Returns the value passed for `key` as `felt252` or `Option::None` if it was not passed
+    /// or is not a decimal or hex number
+    fn get_felt(self: @ScriptArgs, key: ByteArray) -> Option<felt252> {
+        match find_script_arg(*self.serialized, key) {
+            Option::Some((_, felt_value)) => felt_value,
+            Option::None => Option::None,
+        }
+    }
+}
+
+fn find_script_arg(
+    mut serialized: Span<felt252>, key: ByteArray
+) -> Option<(ByteArray, Option<felt252>)> {
+    let mut args: Array<(ByteArray, ByteArray, Option<felt252>)> =
+        match Serde::deserialize(ref serialized) {
+        Option::Some(args) => args,
+        Option::None => panic!("script args deserialize failed")
+    };
+
+    loop {
+        match args.pop_front() {
+            Option::Some((
+                arg_key, value, felt_value
+            )) => {
+                if arg_key == key {
+                    break Option::Some((value, felt_value));
+                }
+            },
+            Option::None => { break Option::None; },
+        }
+    }
+}
+
 fn handle_cheatcode(input: Span<felt252>) -> Span<felt252> {
     let first = *input.at(0);
     let input = input.slice(1, input.len() - 1);
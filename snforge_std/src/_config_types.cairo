@@ -3,9 +3,15 @@ struct AvailableGasConfig {
     gas: felt252
 }
 
+#[derive(Drop, Serde)]
+enum BlockTag {
+    Latest: (),
+    Pending: ()
+}
+
 #[derive(Drop, Serde)]
 enum BlockId {
-    BlockTag: (),
+    BlockTag: BlockTag,
     BlockHash: felt252,
     BlockNumber: felt252
 }
@@ -52,3 +58,18 @@ struct ShouldPanicConfig {
 struct IgnoreConfig {
     is_ignored: bool,
 }
+
+#[derive(Drop, Serde)]
+struct TagConfig {
+    tags: Array<ByteArray>,
+}
+
+#[derive(Drop, Serde)]
+struct L1HandlerConfig {
+    is_l1_handler: bool,
+}
+
+#[derive(Drop, Serde)]
+struct TimeoutConfig {
+    ms: felt252,
+}
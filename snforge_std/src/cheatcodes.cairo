@@ -97,6 +97,32 @@ fn stop_mock_call(contract_address: ContractAddress, function_selector: felt252)
     );
 }
 
+/// Returns the number of calls to the function with given name and contract address that returned
+/// mocked data.
+/// - `contract_address` - targeted contracts' address
+/// - `function_selector` - hashed name of the target function (can be obtained with `selector!`
+/// macro)
+fn get_mock_call_count(contract_address: ContractAddress, function_selector: felt252) -> u32 {
+    let contract_address_felt: felt252 = contract_address.into();
+    let mut output = handle_cheatcode(
+        cheatcode::<'get_mock_call_count'>(array![contract_address_felt, function_selector].span())
+    );
+    Serde::<u32>::deserialize(ref output).unwrap()
+}
+
+/// Asserts that the mocked function with given name and contract address was called exactly
+/// `n_times`.
+/// - `contract_address` - targeted contracts' address
+/// - `function_selector` - hashed name of the target function (can be obtained with `selector!`
+/// macro)
+/// - `n_times` - expected number of calls that returned mocked data
+fn assert_called_times(contract_address: ContractAddress, function_selector: felt252, n_times: u32) {
+    let count = get_mock_call_count(contract_address, function_selector);
+    assert!(
+        count == n_times, "Mocked function was called {} times, expected {} times", count, n_times
+    );
+}
+
 #[derive(Drop, Serde, PartialEq, Debug)]
 pub enum ReplaceBytecodeError {
     /// Means that the contract does not exist, and thus bytecode cannot be replaced
@@ -0,0 +1,60 @@
+use super::{
+    ExecutionInfoMock, Operation, CheatArguments, CheatSpan, cheat_execution_info, ContractAddress
+};
+
+/// Changes the address returned by `get_contract_address` for the given contract address and span.
+/// Storage of the contract is not affected, only the returned address is changed.
+/// - `contract_address` - instance of `ContractAddress` specifying which contract to cheat
+/// - `new_address` - contract address to be returned
+/// - `span` - instance of `CheatSpan` specifying the number of contract calls with the cheat
+/// applied
+fn cheat_contract_address(
+    contract_address: ContractAddress, new_address: ContractAddress, span: CheatSpan
+) {
+    let mut execution_info: ExecutionInfoMock = Default::default();
+
+    execution_info
+        .contract_address =
+            Operation::Start(
+                CheatArguments { value: new_address, span, target: contract_address, }
+            );
+
+    cheat_execution_info(execution_info);
+}
+
+/// Changes the address returned by `get_contract_address` for all contracts.
+/// - `new_address` - contract address to be returned
+fn start_cheat_contract_address_global(new_address: ContractAddress) {
+    let mut execution_info: ExecutionInfoMock = Default::default();
+
+    execution_info.contract_address = Operation::StartGlobal(new_address);
+
+    cheat_execution_info(execution_info);
+}
+
+/// Cancels the `start_cheat_contract_address_global`.
+fn stop_cheat_contract_address_global() {
+    let mut execution_info: ExecutionInfoMock = Default::default();
+
+    execution_info.contract_address = Operation::StopGlobal;
+
+    cheat_execution_info(execution_info);
+}
+
+/// Changes the address returned by `get_contract_address` for the given contract_address.
+/// - `contract_address` - instance of `ContractAddress` specifying which contract to cheat
+/// - `new_address` - contract address to be returned
+fn start_cheat_contract_address(contract_address: ContractAddress, new_address: ContractAddress) {
+    cheat_contract_address(contract_address, new_address, CheatSpan::Indefinite);
+}
+
+/// Cancels the `cheat_contract_address` / `start_cheat_contract_address` for the given
+/// contract_address.
+/// - `contract_address` - instance of `ContractAddress` specifying which contract to stop cheating
+fn stop_cheat_contract_address(contract_address: ContractAddress) {
+    let mut execution_info: ExecutionInfoMock = Default::default();
+
+    execution_info.contract_address = Operation::Stop(contract_address);
+
+    cheat_execution_info(execution_info);
+}
@@ -36,6 +36,8 @@ use cheatcodes::test_selector;
 use cheatcodes::mock_call;
 use cheatcodes::start_mock_call;
 use cheatcodes::stop_mock_call;
+use cheatcodes::get_mock_call_count;
+use cheatcodes::assert_called_times;
 use cheatcodes::replace_bytecode;
 use cheatcodes::cheat_execution_info;
 use cheatcodes::execution_info::ExecutionInfoMock;
@@ -49,6 +51,11 @@ use cheatcodes::execution_info::caller_address::start_cheat_caller_address_globa
 use cheatcodes::execution_info::caller_address::stop_cheat_caller_address;
 use cheatcodes::execution_info::caller_address::stop_cheat_caller_address_global;
 use cheatcodes::execution_info::caller_address::start_cheat_caller_address;
+use cheatcodes::execution_info::contract_address::cheat_contract_address;
+use cheatcodes::execution_info::contract_address::start_cheat_contract_address_global;
+use cheatcodes::execution_info::contract_address::stop_cheat_contract_address;
+use cheatcodes::execution_info::contract_address::stop_cheat_contract_address_global;
+use cheatcodes::execution_info::contract_address::start_cheat_contract_address;
 use cheatcodes::execution_info::block_number::cheat_block_number;
 use cheatcodes::execution_info::block_number::start_cheat_block_number_global;
 use cheatcodes::execution_info::block_number::stop_cheat_block_number;
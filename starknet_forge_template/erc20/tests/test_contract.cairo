@@ -0,0 +1,87 @@
+use starknet::{ContractAddress, contract_address_const};
+
+use snforge_std::{
+    declare, ContractClassTrait, DeclareResultTrait, start_cheat_caller_address,
+    stop_cheat_caller_address, spy_events, EventSpyAssertionsTrait
+};
+
+use {{ PROJECT_NAME }}::IERC20SafeDispatcher;
+use {{ PROJECT_NAME }}::IERC20SafeDispatcherTrait;
+use {{ PROJECT_NAME }}::IERC20Dispatcher;
+use {{ PROJECT_NAME }}::IERC20DispatcherTrait;
+use {{ PROJECT_NAME }}::ERC20;
+
+const INITIAL_SUPPLY: u256 = 1000;
+
+fn owner() -> ContractAddress {
+    contract_address_const::<'owner'>()
+}
+
+fn recipient() -> ContractAddress {
+    contract_address_const::<'recipient'>()
+}
+
+fn deploy_token() -> ContractAddress {
+    let contract = declare("ERC20").unwrap().contract_class();
+
+    let mut calldata = array!['Token', 'TKN'];
+    INITIAL_SUPPLY.serialize(ref calldata);
+    owner().serialize(ref calldata);
+
+    let (contract_address, _) = contract.deploy(@calldata).unwrap();
+    contract_address
+}
+
+#[test]
+fn test_initial_supply() {
+    let contract_address = deploy_token();
+
+    let dispatcher = IERC20Dispatcher { contract_address };
+
+    assert(dispatcher.name() == 'Token', 'Invalid name');
+    assert(dispatcher.total_supply() == INITIAL_SUPPLY, 'Invalid total supply');
+    assert(dispatcher.balance_of(owner()) == INITIAL_SUPPLY, 'Invalid balance');
+}
+
+#[test]
+fn test_transfer() {
+    let contract_address = deploy_token();
+
+    let dispatcher = IERC20Dispatcher { contract_address };
+    let mut spy = spy_events();
+
+    start_cheat_caller_address(contract_address, owner());
+    dispatcher.transfer(recipient(), 100);
+    stop_cheat_caller_address(contract_address);
+
+    assert(dispatcher.balance_of(owner()) == INITIAL_SUPPLY - 100, 'Invalid owner balance');
+    assert(dispatcher.balance_of(recipient()) == 100, 'Invalid recipient balance');
+
+    spy
+        .assert_emitted(
+            @array![
+                (
+                    contract_address,
+                    ERC20::Event::Transfer(
+                        ERC20::Transfer { from: owner(), to: recipient(), value: 100 }
+                    )
+                )
+            ]
+        );
+}
+
+#[test]
+#[feature("safe_dispatcher")]
+fn test_cannot_transfer_more_than_balance() {
+    let contract_address = deploy_token();
+
+    let safe_dispatcher = IERC20SafeDispatcher { contract_address };
+
+    start_cheat_caller_address(contract_address, owner());
+    match safe_dispatcher.transfer(recipient(), INITIAL_SUPPLY + 1) {
+        Result::Ok(_) => core::panic_with_felt252('Should have panicked'),
+        Result::Err(panic_data) => {
+            assert(*panic_data.at(0) == 'Insufficient balance', *panic_data.at(0));
+        }
+    };
+}
@@ -0,0 +1,92 @@
+use starknet::ContractAddress;
+
+#[starknet::interface]
+pub trait INFT<TContractState> {
+    fn name(self: @TContractState) -> felt252;
+    fn balance_of(self: @TContractState, owner: ContractAddress) -> u256;
+    fn owner_of(self: @TContractState, token_id: u256) -> ContractAddress;
+    fn mint(ref self: TContractState, to: ContractAddress, token_id: u256);
+    fn transfer_from(
+        ref self: TContractState, from: ContractAddress, to: ContractAddress, token_id: u256
+    );
+}
+
+#[starknet::contract]
+pub mod NFT {
+    use core::num::traits::Zero;
+    use starknet::storage::{
+        Map, StorageMapReadAccess, StorageMapWriteAccess, StoragePointerReadAccess,
+        StoragePointerWriteAccess
+    };
+    use starknet::{ContractAddress, get_caller_address};
+
+    #[storage]
+    struct Storage {
+        name: felt252,
+        minter: ContractAddress,
+        owners: Map<u256, ContractAddress>,
+        balances: Map<ContractAddress, u256>,
+    }
+
+    #[event]
+    #[derive(Drop, PartialEq, starknet::Event)]
+    pub enum Event {
+        Transfer: Transfer,
+    }
+
+    #[derive(Drop, PartialEq, starknet::Event)]
+    pub struct Transfer {
+        #[key]
+        pub from: ContractAddress,
+        #[key]
+        pub to: ContractAddress,
+        #[key]
+        pub token_id: u256,
+    }
+
+    #[constructor]
+    fn constructor(ref self: ContractState, name: felt252, minter: ContractAddress) {
+        self.name.write(name);
+        self.minter.write(minter);
+    }
+
+    #[abi(embed_v0)]
+    impl NFTImpl of super::INFT<ContractState> {
+        fn name(self: @ContractState) -> felt252 {
+            self.name.read()
+        }
+
+        fn balance_of(self: @ContractState, owner: ContractAddress) -> u256 {
+            self.balances.read(owner)
+        }
+
+        fn owner_of(self: @ContractState, token_id: u256) -> ContractAddress {
+            let owner = self.owners.read(token_id);
+            assert(owner.is_non_zero(), 'Token does not exist');
+            owner
+        }
+
+        fn mint(ref self: ContractState, to: ContractAddress, token_id: u256) {
+            assert(get_caller_address() == self.minter.read(), 'Caller is not the minter');
+            assert(to.is_non_zero(), 'Mint to the zero address');
+            assert(self.owners.read(token_id).is_zero(), 'Token already minted');
+
+            self.owners.write(token_id, to);
+            self.balances.write(to, self.balances.read(to) + 1);
+            self.emit(Transfer { from: Zero::zero(), to, token_id });
+        }
+
+        fn transfer_from(
+            ref self: ContractState, from: ContractAddress, to: ContractAddress, token_id: u256
+        ) {
+            assert(get_caller_address() == from, 'Caller is not the owner');
+            assert(self.owners.read(token_id) == from, 'Token not owned by sender');
+            assert(to.is_non_zero(), 'Transfer to the zero address');
+
+            self.owners.write(token_id, to);
+            self.balances.write(from, self.balances.read(from) - 1);
+            self.balances.write(to, self.balances.read(to) + 1);
+            self.emit(Transfer { from, to, token_id });
+        }
+    }
+}
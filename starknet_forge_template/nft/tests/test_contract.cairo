@@ -0,0 +1,82 @@
+use starknet::{ContractAddress, contract_address_const};
+
+use snforge_std::{
+    declare, ContractClassTrait, DeclareResultTrait, start_cheat_caller_address,
+    stop_cheat_caller_address, spy_events, EventSpyAssertionsTrait
+};
+
+use {{ PROJECT_NAME }}::INFTSafeDispatcher;
+use {{ PROJECT_NAME }}::INFTSafeDispatcherTrait;
+use {{ PROJECT_NAME }}::INFTDispatcher;
+use {{ PROJECT_NAME }}::INFTDispatcherTrait;
+use {{ PROJECT_NAME }}::NFT;
+
+fn minter() -> ContractAddress {
+    contract_address_const::<'minter'>()
+}
+
+fn alice() -> ContractAddress {
+    contract_address_const::<'alice'>()
+}
+
+fn bob() -> ContractAddress {
+    contract_address_const::<'bob'>()
+}
+
+fn deploy_nft() -> ContractAddress {
+    let contract = declare("NFT").unwrap().contract_class();
+
+    let mut calldata = array!['Collection'];
+    minter().serialize(ref calldata);
+
+    let (contract_address, _) = contract.deploy(@calldata).unwrap();
+    contract_address
+}
+
+#[test]
+fn test_mint_and_transfer() {
+    let contract_address = deploy_nft();
+
+    let dispatcher = INFTDispatcher { contract_address };
+    let mut spy = spy_events();
+
+    start_cheat_caller_address(contract_address, minter());
+    dispatcher.mint(alice(), 1);
+    stop_cheat_caller_address(contract_address);
+
+    assert(dispatcher.owner_of(1) == alice(), 'Invalid owner after mint');
+
+    start_cheat_caller_address(contract_address, alice());
+    dispatcher.transfer_from(alice(), bob(), 1);
+    stop_cheat_caller_address(contract_address);
+
+    assert(dispatcher.owner_of(1) == bob(), 'Invalid owner after transfer');
+    assert(dispatcher.balance_of(alice()) == 0, 'Invalid alice balance');
+    assert(dispatcher.balance_of(bob()) == 1, 'Invalid bob balance');
+
+    spy
+        .assert_emitted(
+            @array![
+                (
+                    contract_address,
+                    NFT::Event::Transfer(NFT::Transfer { from: alice(), to: bob(), token_id: 1 })
+                )
+            ]
+        );
+}
+
+#[test]
+#[feature("safe_dispatcher")]
+fn test_only_minter_can_mint() {
+    let contract_address = deploy_nft();
+
+    let safe_dispatcher = INFTSafeDispatcher { contract_address };
+
+    start_cheat_caller_address(contract_address, alice());
+    match safe_dispatcher.mint(alice(), 1) {
+        Result::Ok(_) => core::panic_with_felt252('Should have panicked'),
+        Result::Err(panic_data) => {
+            assert(*panic_data.at(0) == 'Caller is not the minter', *panic_data.at(0));
+        }
+    };
+}